@@ -4,7 +4,10 @@
 extern crate bindgen;
 extern crate cc;
 
+use std::collections::HashMap;
 use std::env;
+use std::fmt::Write as _;
+use std::fs;
 use std::path::PathBuf;
 
 fn main() {
@@ -14,38 +17,99 @@ fn main() {
     let api_header = pb(&[&in_dir, "c-src", "cfs-api.h"]).to_string_unwrap();
     let shims_header = pb(&[&in_dir, "c-src", "cfs-shims.h"]).to_string_unwrap();
     let shims_c = pb(&[&in_dir, "c-src", "cfs-shims.c"]).to_string_unwrap();
+    let mock_c = pb(&[&in_dir, "c-src", "cfs-mock.c"]).to_string_unwrap();
 
     let out_file = pb(&[&out_dir, "cfs-all.rs"]).to_string_unwrap();
 
-    for f in [&api_header, &shims_header, &shims_c] {
+    for f in [&api_header, &shims_header, &shims_c, &mock_c] {
         println!("cargo:rerun-if-changed={}", f);
     }
 
+    // For model-checking the child-task handoff (see `src/handoff.rs`):
+    println!("cargo:rustc-check-cfg=cfg(loom)");
+    // Set when bindgen has wrapped the headers' `static inline` functions (see below):
+    println!("cargo:rustc-check-cfg=cfg(n2o4_static_fns)");
+    // Set for the optional cFE APIs the headers declare (see `api_cfgs`):
+    for (cfg, _) in OPTIONAL_APIS {
+        println!("cargo:rustc-check-cfg=cfg({})", cfg);
+    }
+
     let compile_defs = env_unwrap("RUST_CFS_SYS_COMPILE_DEFINITIONS");
     let include_dirs = env_unwrap("RUST_CFS_SYS_INCLUDE_DIRECTORIES");
     let compile_opts = env_unwrap("RUST_CFS_SYS_COMPILE_OPTIONS");
 
-    let bindings = bindgen::builder()
-        .header(&api_header)
-        .header(&shims_header)
-        .clang_args(compile_defs.split('@').map(|s| String::from("-D") + s))
-        .clang_args(include_dirs.split('@').map(|s| String::from("-I") + s))
-        .clang_args(compile_opts.split('@'))
-        .allowlist_recursively(true)
-        .allowlist_type("(CFE|OS|OSAL|CFE_PSP|CCSDS)_.*")
-        .allowlist_function("(CFE|OS|OSAL|CFE_PSP|SHIM)_.*")
-        .allowlist_var("(X_|S_)?(CFE|OS|OSAL|CFE_PSP)_.*")
-        .blocklist_function("CFE_ES_Main") // only to be called by the BSP
-        .blocklist_function("OS_BSP_.*") // ditto
-        .use_core()
-        .ctypes_prefix("::core::ffi")
-        .size_t_is_usize(true)
-        .generate_comments(false)
-        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
-        .generate()
-        .expect("Unable to generate cFS bindings");
-
-    bindings.write_to_file(&out_file).expect("Unable to write out cFS bindings");
+    // Directories with mission-specific configuration headers (`osconfig.h`, `cfe_platform_cfg.h`, etc.),
+    // searched ahead of the others so their headers take precedence:
+    println!("cargo:rerun-if-env-changed=RUST_CFS_SYS_CONFIG_DIRECTORIES");
+    let include_dirs = match env::var("RUST_CFS_SYS_CONFIG_DIRECTORIES") {
+        Ok(config_dirs) if !config_dirs.is_empty() => config_dirs + "@" + &include_dirs,
+        _ => include_dirs,
+    };
+
+    // Pregenerated bindings, if supplied, are used in place of running bindgen,
+    // after checking (below) that they match the configured headers.
+    let pregenerated = env::var("RUST_CFS_SYS_BINDINGS").ok().filter(|s| !s.is_empty());
+    println!("cargo:rerun-if-env-changed=RUST_CFS_SYS_BINDINGS");
+
+    let (bindings, only_types, static_fns_c) = match &pregenerated {
+        Some(bindings_file) => {
+            println!("cargo:rerun-if-changed={}", bindings_file);
+
+            let bindings = fs::read_to_string(bindings_file)
+                .expect("Unable to read pregenerated cFS bindings");
+
+            (bindings, None, None)
+        }
+        None => {
+            // bindgen can write out-of-line C wrappers for the headers' `static inline` functions,
+            // making them available (under their own names) without hand-written shims:
+            println!("cargo:rerun-if-env-changed=RUST_CFS_SYS_WRAP_STATIC_FNS");
+            let wrap_static_fns =
+                env::var("RUST_CFS_SYS_WRAP_STATIC_FNS").map_or(true, |v| v != "0");
+            let static_fns = pb(&[&out_dir, "cfs-static-fns"]);
+
+            let bindings = bindgen::builder()
+                .header(&api_header)
+                .header(&shims_header)
+                .clang_args(compile_defs.split('@').map(|s| String::from("-D") + s))
+                .clang_args(include_dirs.split('@').map(|s| String::from("-I") + s))
+                .clang_args(compile_opts.split('@'))
+                .allowlist_recursively(true)
+                .allowlist_type("(CFE|OS|OSAL|CFE_PSP|CCSDS)_.*")
+                .allowlist_function("(CFE|OS|OSAL|CFE_PSP|SHIM)_.*")
+                .allowlist_var("(X_|S_)?(CFE|OS|OSAL|CFE_PSP)_.*")
+                .blocklist_function("CFE_ES_Main") // only to be called by the BSP
+                .blocklist_function("OS_BSP_.*") // ditto
+                .use_core()
+                .ctypes_prefix("::core::ffi")
+                .size_t_is_usize(true)
+                .generate_comments(false)
+                .wrap_static_fns(wrap_static_fns)
+                .wrap_static_fns_path(&static_fns)
+                .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+                .generate()
+                .expect("Unable to generate cFS bindings");
+
+            let static_fns_c = wrap_static_fns.then(|| {
+                println!("cargo:rustc-cfg=n2o4_static_fns");
+                static_fns.with_extension("c").to_string_unwrap()
+            });
+            (bindings.to_string(), Some(HAND_BUILT_TYPES), static_fns_c)
+        }
+    };
+
+    // With pregenerated bindings, every type is checked; otherwise, only those
+    // n2o4 builds by hand, in case the C compiler disagrees with bindgen's clang.
+    let check_c = pb(&[&out_dir, "cfs-layout-check.c"]).to_string_unwrap();
+    fs::write(&check_c, layout_checks(&bindings, &[&api_header, &shims_header], only_types))
+        .expect("Unable to write out layout checks");
+
+    fs::write(&out_file, &bindings).expect("Unable to write out cFS bindings");
+    inline_fn_cfgs(&bindings);
+    api_cfgs(&bindings);
+
+    generate_msgids(&out_dir, &compile_defs, &include_dirs, &compile_opts);
+    generate_perfids(&out_dir, &compile_defs, &include_dirs, &compile_opts);
 
     let mut builder = cc::Build::new();
     builder.includes(include_dirs.split('@'));
@@ -57,7 +121,343 @@ fn main() {
         builder.flag(opt);
     }
 
-    builder.file(&shims_c).compile("cfs-shims");
+    builder.file(&shims_c);
+
+    builder.file(&check_c);
+
+    if let Some(static_fns_c) = &static_fns_c {
+        builder.file(static_fns_c);
+    }
+
+    // The fake cFE's variadic functions have to be written in C:
+    if env::var_os("CARGO_FEATURE_MOCK").is_some() {
+        builder.file(&mock_c);
+    }
+
+    builder.compile("cfs-shims");
+}
+
+/// Sets the `n2o4_*_repr` cfgs, which select pure-Rust implementations
+/// of some of cFE's and OSAL's inline functions
+/// (rather than calls to the C shims in `cfs-shims.c`),
+/// according to how the types they operate on are defined in `bindings`.
+///
+/// Each is set to `"plain"` if the type is an alias of an integer type,
+/// or `"struct"` if it's a struct wrapping an integer field of the expected name
+/// (as with cFE's strict resource ID and message ID types),
+/// and left unset otherwise (in which case the shims are used).
+fn inline_fn_cfgs(bindings: &str) {
+    let reprs = [
+        ("n2o4_msgid_repr", "CFE_SB_MsgId_t", Some("Value")),
+        ("n2o4_resourceid_repr", "CFE_ResourceId_t", Some("id")),
+        ("n2o4_osal_id_repr", "osal_id_t", None),
+    ];
+
+    for (cfg, type_name, field) in reprs {
+        println!("cargo:rustc-check-cfg=cfg({}, values(\"plain\", \"struct\"))", cfg);
+
+        let alias = format!("pub type {} = ", type_name);
+        let strukt = format!("pub struct {} {{", type_name);
+
+        let repr = if let Some(i) = bindings.find(&alias) {
+            let target = bindings[i + alias.len()..].split(';').next().unwrap_or("");
+            INTEGER_TYPES.contains(&target.trim()).then_some("plain")
+        } else if let Some(i) = bindings.find(&strukt) {
+            let body = bindings[i + strukt.len()..].split('}').next().unwrap_or("");
+            let fields: Vec<&str> =
+                body.split(',').map(str::trim).filter(|f| !f.is_empty()).collect();
+            match fields.as_slice() {
+                [only] => match only.strip_prefix("pub ").and_then(|f| f.split_once(": ")) {
+                    Some((name, ty)) if Some(name) == field && INTEGER_TYPES.contains(&ty) => {
+                        Some("struct")
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        if let Some(repr) = repr {
+            println!("cargo:rustc-cfg={}=\"{}\"", cfg, repr);
+        }
+    }
+}
+
+/// cFE APIs not present in all versions of cFE: for each, the cfg to set
+/// if the bindings declare it, and a function whose declaration indicates that they do.
+const OPTIONAL_APIS: &[(&str, &str)] = &[("n2o4_cfe_config", "CFE_Config_GetValue")];
+
+/// Sets the cfgs for the [optional APIs](OPTIONAL_APIS) declared in `bindings`.
+fn api_cfgs(bindings: &str) {
+    for (cfg, function) in OPTIONAL_APIS {
+        if bindings.contains(&format!("pub fn {}(", function)) {
+            println!("cargo:rustc-cfg={}", cfg);
+        }
+    }
+}
+
+/// How the bindings may spell the integer types underlying cFE and OSAL IDs.
+const INTEGER_TYPES: &[&str] = &["u32", "uint32", "CFE_SB_MsgId_Atom_t", "::core::ffi::c_uint"];
+
+/// Writes `msgids.rs` to `out_dir`: constants for the message IDs (`*_MID`)
+/// and topic IDs (`*_TOPICID`) defined by the headers
+/// listed in `RUST_CFS_SYS_MSGID_HEADERS` (if any).
+fn generate_msgids(out_dir: &str, compile_defs: &str, include_dirs: &str, compile_opts: &str) {
+    let consts = MacroConsts {
+        module:  "msgids",
+        env_var: "RUST_CFS_SYS_MSGID_HEADERS",
+        pattern: ".*_(MID|TOPICID)",
+        what:    "message ID",
+    };
+    consts.generate(out_dir, compile_defs, include_dirs, compile_opts, MsgIdCallbacks);
+}
+
+/// Writes `perfids.rs` to `out_dir`: constants for the performance-log IDs (`*_PERF_ID`)
+/// defined by the headers listed in `RUST_CFS_SYS_PERFID_HEADERS` (if any).
+fn generate_perfids(out_dir: &str, compile_defs: &str, include_dirs: &str, compile_opts: &str) {
+    let consts = MacroConsts {
+        module:  "perfids",
+        env_var: "RUST_CFS_SYS_PERFID_HEADERS",
+        pattern: ".*_PERF_ID",
+        what:    "performance ID",
+    };
+    consts.generate(out_dir, compile_defs, include_dirs, compile_opts, PerfIdCallbacks);
+}
+
+/// A module of constants generated from macros in mission-supplied headers.
+struct MacroConsts {
+    /// The name of the module; its contents are written to `<module>.rs`.
+    module: &'static str,
+
+    /// The environment variable listing the headers (`@`-separated).
+    env_var: &'static str,
+
+    /// A regex matching the names of the macros to turn into constants.
+    pattern: &'static str,
+
+    /// What the constants are, for error messages.
+    what: &'static str,
+}
+
+impl MacroConsts {
+    /// Writes the module's contents to `out_dir`
+    /// (or an empty file if no headers are listed),
+    /// with `callbacks` choosing the constants' types.
+    fn generate<C>(
+        &self,
+        out_dir: &str,
+        compile_defs: &str,
+        include_dirs: &str,
+        compile_opts: &str,
+        callbacks: C,
+    ) where
+        C: bindgen::callbacks::ParseCallbacks + 'static,
+    {
+        let out_file = pb(&[out_dir, &format!("{}.rs", self.module)]).to_string_unwrap();
+        let write_err = format!("Unable to write out {} constants", self.what);
+
+        println!("cargo:rerun-if-env-changed={}", self.env_var);
+        let headers = env::var(self.env_var).unwrap_or_default();
+        if headers.is_empty() {
+            fs::write(&out_file, "").expect(&write_err);
+            return;
+        }
+
+        // Including the headers from a wrapper lets them be found through the include path:
+        let mut wrapper = String::new();
+        for header in headers.split('@') {
+            writeln!(wrapper, "#include \"{}\"", header).unwrap();
+        }
+        let wrapper_file = pb(&[out_dir, &format!("{}-wrapper.h", self.module)]).to_string_unwrap();
+        fs::write(&wrapper_file, wrapper)
+            .expect(&format!("Unable to write out {} header wrapper", self.what));
+
+        bindgen::builder()
+            .header(&wrapper_file)
+            .clang_args(compile_defs.split('@').map(|s| String::from("-D") + s))
+            .clang_args(include_dirs.split('@').map(|s| String::from("-I") + s))
+            .clang_args(compile_opts.split('@'))
+            .allowlist_var(self.pattern)
+            // IDs are often defined in terms of function-like macros:
+            .clang_macro_fallback()
+            .clang_macro_fallback_build_dir(out_dir)
+            .use_core()
+            .ctypes_prefix("::core::ffi")
+            .generate_comments(false)
+            .parse_callbacks(Box::new(callbacks))
+            .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
+            .generate()
+            .expect(&format!("Unable to generate {} constants", self.what))
+            .write_to_file(&out_file)
+            .expect(&write_err);
+    }
+}
+
+/// Gives message ID and topic ID constants the appropriate types.
+#[derive(Debug)]
+struct MsgIdCallbacks;
+
+impl bindgen::callbacks::ParseCallbacks for MsgIdCallbacks {
+    fn int_macro(&self, name: &str, _value: i64) -> Option<bindgen::callbacks::IntKind> {
+        use bindgen::callbacks::IntKind;
+
+        if name.ends_with("_MID") {
+            Some(IntKind::Custom {
+                name:      "crate::cfe::sb::MsgId_Atom",
+                is_signed: false,
+            })
+        } else if name.ends_with("_TOPICID") {
+            Some(IntKind::U16)
+        } else {
+            None
+        }
+    }
+}
+
+/// Gives performance-log ID constants the type `n2o4::cfe::es::perf_log_add` takes.
+#[derive(Debug)]
+struct PerfIdCallbacks;
+
+impl bindgen::callbacks::ParseCallbacks for PerfIdCallbacks {
+    fn int_macro(&self, name: &str, _value: i64) -> Option<bindgen::callbacks::IntKind> {
+        name.ends_with("_PERF_ID").then_some(bindgen::callbacks::IntKind::U32)
+    }
+}
+
+/// The types `n2o4` constructs field-by-field (or otherwise assumes the layout of),
+/// whose layouts are checked against the C compiler's even when bindgen is run.
+const HAND_BUILT_TYPES: &[&str] = &[
+    "CCSDS_PrimaryHeader_t",
+    "CCSDS_ExtendedHeader_t",
+    "CCSDS_SpacePacket_t",
+    "CFE_MSG_Message_t",
+    "CFE_MSG_CommandHeader_t",
+    "CFE_MSG_CommandSecondaryHeader_t",
+    "CFE_MSG_TelemetryHeader_t",
+    "CFE_MSG_TelemetrySecondaryHeader_t",
+    "CFE_TBL_Info_t",
+    "CFE_TIME_SysTime_t",
+    "OS_SockAddr_t",
+    "OS_SockAddrData_t",
+    "OS_bin_sem_prop_t",
+];
+
+/// Generates C source that fails to compile
+/// unless the sizes, alignments, and field offsets recorded in `bindings`' layout tests
+/// match those of the types as declared in `headers` (under the configured compiler settings).
+///
+/// If `only_types` is given, only the types listed in it (by Rust or C name) are checked.
+fn layout_checks(bindings: &str, headers: &[&str], only_types: Option<&[&str]>) -> String {
+    let mut c_names: HashMap<&str, String> = HashMap::new();
+    for line in bindings.lines().map(str::trim) {
+        if let Some(name) = item_name(line, "pub struct ") {
+            c_names.entry(name).or_insert_with(|| c_type_name("struct", name));
+        } else if let Some(name) = item_name(line, "pub union ") {
+            c_names.entry(name).or_insert_with(|| c_type_name("union", name));
+        }
+    }
+
+    // Prefer typedef names, as typedef'd anonymous structs have no tag:
+    for line in bindings.lines().map(str::trim) {
+        if let Some(rest) = line.strip_prefix("pub type ") {
+            if let Some((alias, target)) = rest.trim_end_matches(';').split_once(" = ") {
+                if let Some(c_name) = c_names.get_mut(target) {
+                    if c_name.starts_with("struct ") || c_name.starts_with("union ") {
+                        *c_name = alias.to_owned();
+                    }
+                }
+            }
+        }
+    }
+
+    let mut out = String::from(
+        "/* Generated by n2o4's build script; do not edit. */\n\n#include <stddef.h>\n",
+    );
+    for header in headers {
+        writeln!(out, "#include \"{}\"", header).unwrap();
+    }
+    out.push('\n');
+
+    let mut n = 0;
+    for line in bindings.lines().map(str::trim) {
+        let (desc, value) = match line.strip_prefix("[\"").and_then(|l| l.split_once("\"][")) {
+            Some((desc, rest)) => match rest.rsplit_once(" - ") {
+                Some((_, value)) => (desc, value.trim_end_matches("usize];")),
+                None => continue,
+            },
+            None => continue,
+        };
+
+        let ty = desc.rsplit(' ').next().unwrap_or("").split("::").next().unwrap_or("");
+        let listed = |types: &[&str]| {
+            types.iter().any(|&t| t == ty || c_names.get(ty).map_or(false, |c_ty| c_ty == t))
+        };
+        if !only_types.map_or(true, listed) {
+            continue;
+        }
+
+        let check = if let Some(ty) = desc.strip_prefix("Size of ") {
+            c_names.get(ty).map(|c_ty| (format!("sizeof_{}", ty), format!("sizeof({})", c_ty)))
+        } else if let Some(ty) = desc.strip_prefix("Alignment of ") {
+            // (`_Alignof` needs C11; this doesn't.)
+            c_names.get(ty).map(|c_ty| {
+                (
+                    format!("alignof_{}", ty),
+                    format!("offsetof(struct {{ char c; {} t; }}, t)", c_ty),
+                )
+            })
+        } else if let Some(field) = desc.strip_prefix("Offset of field: ") {
+            match field.split_once("::") {
+                Some((ty, field)) if !field.contains("bindgen") && !field.ends_with('_') => {
+                    c_names.get(ty).map(|c_ty| {
+                        (
+                            format!("offsetof_{}_{}", ty, field),
+                            format!("offsetof({}, {})", c_ty, field),
+                        )
+                    })
+                }
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        if let Some((what, expr)) = check {
+            writeln!(
+                out,
+                "typedef char n2o4_layout_{}_{}[({}) == {} ? 1 : -1];",
+                n, what, expr, value
+            )
+            .unwrap();
+            n += 1;
+        }
+    }
+
+    out
+}
+
+/// If `line` declares an item with the given `prefix` (e.g., `pub struct `)
+/// not generated by bindgen for an anonymous type, returns its name.
+fn item_name<'a>(line: &'a str, prefix: &str) -> Option<&'a str> {
+    let rest = line.strip_prefix(prefix)?;
+    let name = rest.split(|c: char| !(c.is_alphanumeric() || c == '_')).next()?;
+    if name.is_empty() || name.contains("bindgen") {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// The C name to use for a `struct`/`union` (per `keyword`) named `name` by bindgen,
+/// absent a typedef.
+fn c_type_name(keyword: &str, name: &str) -> String {
+    if name.ends_with("_t") {
+        name.to_owned()
+    } else {
+        format!("{} {}", keyword, name)
+    }
 }
 
 fn env_unwrap(key: &str) -> String {
@@ -0,0 +1,94 @@
+/* Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+/* The variadic parts of the fake cFE used by the `mock` feature.
+ *
+ * Stable Rust can't define C-variadic functions, so these format their messages
+ * and pass them on to the Rust side of the fake (see src/mock/).
+ */
+
+#include <stdarg.h>
+#include <stdio.h>
+
+#include <cfe.h>
+
+/* The maximum length of a formatted system-log message, including terminating NUL. */
+#define MOCK_SYSLOG_MSG_SIZE 256
+
+/* Implemented in Rust. */
+CFE_Status_t MOCK_CFE_ES_CaptureSysLog(const char *Message);
+CFE_Status_t MOCK_CFE_EVS_CaptureEvent(uint16 EventID, uint16 EventType, uint32 AppID,
+                                       const CFE_TIME_SysTime_t *Time, const char *Message);
+
+CFE_Status_t CFE_ES_WriteToSysLog(const char *SpecStringPtr, ...)
+{
+    char    Message[MOCK_SYSLOG_MSG_SIZE];
+    va_list ArgPtr;
+
+    if (SpecStringPtr == NULL)
+    {
+        return CFE_ES_BAD_ARGUMENT;
+    }
+
+    va_start(ArgPtr, SpecStringPtr);
+    vsnprintf(Message, sizeof(Message), SpecStringPtr, ArgPtr);
+    va_end(ArgPtr);
+
+    return MOCK_CFE_ES_CaptureSysLog(Message);
+}
+
+CFE_Status_t CFE_EVS_SendEvent(uint16 EventID, uint16 EventType, const char *Spec, ...)
+{
+    char    Message[CFE_MISSION_EVS_MAX_MESSAGE_LENGTH];
+    va_list ArgPtr;
+
+    if (Spec == NULL)
+    {
+        return CFE_EVS_INVALID_PARAMETER;
+    }
+
+    va_start(ArgPtr, Spec);
+    vsnprintf(Message, sizeof(Message), Spec, ArgPtr);
+    va_end(ArgPtr);
+
+    return MOCK_CFE_EVS_CaptureEvent(EventID, EventType, CFE_RESOURCEID_TO_ULONG(CFE_ES_APPID_UNDEFINED),
+                                     NULL, Message);
+}
+
+CFE_Status_t CFE_EVS_SendEventWithAppID(uint16 EventID, uint16 EventType, CFE_ES_AppId_t AppID,
+                                        const char *Spec, ...)
+{
+    char    Message[CFE_MISSION_EVS_MAX_MESSAGE_LENGTH];
+    va_list ArgPtr;
+
+    if (Spec == NULL)
+    {
+        return CFE_EVS_INVALID_PARAMETER;
+    }
+
+    va_start(ArgPtr, Spec);
+    vsnprintf(Message, sizeof(Message), Spec, ArgPtr);
+    va_end(ArgPtr);
+
+    return MOCK_CFE_EVS_CaptureEvent(EventID, EventType, CFE_RESOURCEID_TO_ULONG(AppID), NULL, Message);
+}
+
+CFE_Status_t CFE_EVS_SendTimedEvent(CFE_TIME_SysTime_t Time, uint16 EventID, uint16 EventType,
+                                    const char *Spec, ...)
+{
+    char    Message[CFE_MISSION_EVS_MAX_MESSAGE_LENGTH];
+    va_list ArgPtr;
+
+    if (Spec == NULL)
+    {
+        return CFE_EVS_INVALID_PARAMETER;
+    }
+
+    va_start(ArgPtr, Spec);
+    vsnprintf(Message, sizeof(Message), Spec, ArgPtr);
+    va_end(ArgPtr);
+
+    return MOCK_CFE_EVS_CaptureEvent(EventID, EventType, CFE_RESOURCEID_TO_ULONG(CFE_ES_APPID_UNDEFINED),
+                                     &Time, Message);
+}
@@ -87,6 +87,31 @@ function(generate_cargo_vars CFS_APP)
     list(APPEND CEV "BINDGEN_EXTRA_CLANG_ARGS=${RBCF}")
   endif()
 
+  # Use the variable <app_name>_MSGID_HEADERS to list the headers
+  # to generate n2o4::msgids from:
+  if(DEFINED ${CFS_APP}_MSGID_HEADERS)
+    list(JOIN ${CFS_APP}_MSGID_HEADERS "@" MIDH)
+    list(APPEND CEV "RUST_CFS_SYS_MSGID_HEADERS=${MIDH}")
+  endif()
+
+  # Likewise, <app_name>_PERFID_HEADERS lists the headers
+  # to generate n2o4::perfids from:
+  if(DEFINED ${CFS_APP}_PERFID_HEADERS)
+    list(JOIN ${CFS_APP}_PERFID_HEADERS "@" PIDH)
+    list(APPEND CEV "RUST_CFS_SYS_PERFID_HEADERS=${PIDH}")
+  endif()
+
+  if(DEFINED RUST_CFS_SYS_BINDINGS)
+    list(APPEND CEV "RUST_CFS_SYS_BINDINGS=${RUST_CFS_SYS_BINDINGS}")
+  endif()
+
+  # Directories with mission-specific configuration headers,
+  # searched ahead of the application's include directories:
+  if(DEFINED RUST_CFS_SYS_CONFIG_DIRECTORIES)
+    list(JOIN RUST_CFS_SYS_CONFIG_DIRECTORIES "@" RCCD)
+    list(APPEND CEV "RUST_CFS_SYS_CONFIG_DIRECTORIES=${RCCD}")
+  endif()
+
   if(DEFINED RUSTC_WRAPPER_CMD)
     list(APPEND CEV "RUSTC_WRAPPER=${RUSTC_WRAPPER_CMD}")
   endif()
@@ -0,0 +1,120 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runs the sample application against `n2o4`'s `mock` backend,
+//! sending it a scripted series of commands and checking what it does in response.
+//!
+//! Exits with status 0 if everything went as expected, and 1 otherwise.
+
+use n2o4::cfe::msg::Command;
+use n2o4::cfe::sb::MsgId;
+use n2o4::mock;
+use sample_app::*;
+use std::time::Duration;
+use std::{mem, process, thread};
+
+/// Waits (in real time) until the application has sent an event with ID `event_id`.
+fn wait_for_event(event_id: u16) -> Vec<mock::evs::Event> {
+    let mut seen = Vec::new();
+
+    for _ in 0..1000 {
+        seen.extend(mock::evs::take_events());
+        if seen.iter().any(|e| e.event_id == event_id) {
+            return seen;
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    eprintln!("timed out waiting for event {}; saw {:?}", event_id, seen);
+    process::exit(1);
+}
+
+/// Sends a command with function code `fcn_code` and payload `payload` to the application.
+fn send<T: Copy>(fcn_code: u16, payload: T) {
+    Command::new(MsgId::from(SAMPLE_CMD_MID), fcn_code, payload)
+        .and_then(|mut cmd| cmd.transmit(true))
+        .expect("couldn't send command");
+}
+
+/// Requests housekeeping telemetry from the application and returns it.
+fn request_hk() -> SampleHk {
+    Command::new(MsgId::from(SAMPLE_SEND_HK_MID), 0, ())
+        .and_then(|mut cmd| cmd.transmit(true))
+        .expect("couldn't send HK request");
+
+    for _ in 0..1000 {
+        let hk = mock::sb::take_sent().into_iter().rev().find(|msg| {
+            mock::sb::msg_id_of(msg) == MsgId::from(SAMPLE_HK_TLM_MID)
+                && msg.len() >= mem::size_of::<SampleHk>()
+        });
+        if let Some(msg) = hk {
+            let payload = &msg[msg.len() - mem::size_of::<SampleHk>()..];
+            return unsafe { (payload.as_ptr() as *const SampleHk).read_unaligned() };
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+
+    eprintln!("timed out waiting for housekeeping telemetry");
+    process::exit(1);
+}
+
+fn check(what: &str, ok: bool) {
+    if !ok {
+        eprintln!("FAILED: {}", what);
+        process::exit(1);
+    }
+    println!("ok: {}", what);
+}
+
+fn main() {
+    let _guard = mock::exclusive();
+
+    // The application exits through `exit_app`, which (with the fake) panics;
+    // a panic can't unwind out of the `extern "C"` entry point,
+    // so the final checks happen in the panic hook.
+    std::panic::set_hook(Box::new(|info| {
+        let status = mock::es::exit_status();
+        if status != Some(n2o4::cfe::es::RunStatus::AppExit as u32) {
+            eprintln!("application exited with {:?}: {}", status, info);
+            eprintln!("system log: {:?}", mock::es::take_syslog());
+            process::exit(1);
+        }
+        println!("ok: application exited normally");
+        process::exit(0);
+    }));
+
+    thread::spawn(|| SAMPLE_AppMain());
+    wait_for_event(INIT_EID);
+
+    send(NOOP_CC, ());
+    check("no-op event sent", wait_for_event(NOOP_EID).iter().any(|e| e.event_id == NOOP_EID));
+
+    send(SET_GAIN_CC, 42u32);
+    let events = wait_for_event(SET_GAIN_EID);
+    check("gain change reported", events.iter().any(|e| e.message == "Gain set to 42"));
+
+    send(SET_GAIN_CC, 1000u32);
+    let events = wait_for_event(CMD_EIDS.failed);
+    check("out-of-range gain rejected", !events.iter().any(|e| e.event_id == SET_GAIN_EID));
+
+    send(SET_GAIN_CC, 7u8);
+    wait_for_event(CMD_EIDS.invalid_length);
+
+    send(9, ());
+    wait_for_event(CMD_EIDS.invalid_code);
+
+    let hk = request_hk();
+    check("housekeeping counts commands", hk.command_count == 2 && hk.command_error_count == 3);
+    check("housekeeping reports gain", hk.gain == 42);
+
+    send(RESET_COUNTERS_CC, ());
+    wait_for_event(RESET_EID);
+    let hk = request_hk();
+    check("counters reset", hk.command_count == 0 && hk.command_error_count == 0);
+
+    mock::es::request_exit();
+    thread::sleep(Duration::from_secs(5));
+
+    eprintln!("application didn't exit");
+    process::exit(1);
+}
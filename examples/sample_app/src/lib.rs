@@ -0,0 +1,245 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A small, complete cFS application written with `n2o4`.
+//!
+//! The application has one command pipe, which receives both ground commands
+//! (no-op, reset counters, and set gain) and housekeeping requests,
+//! and one table, which limits the gain that may be commanded.
+//! Everything it does goes through `n2o4`'s APIs,
+//! so it builds the same way against cFS and against `n2o4`'s `mock` backend
+//! (see `src/bin/sample_app_host.rs`).
+
+use n2o4::cfe::cmd::{CommandEventIds, CommandTable};
+use n2o4::cfe::evs::EventType;
+use n2o4::cfe::hk::{HkCounters, HkPayload, Housekeeping};
+use n2o4::cfe::msg::Command;
+use n2o4::cfe::sb::{MsgId, MsgId_Atom, TimeOut};
+use n2o4::cfe::tbl::{TableValidationFn, TblLoadSource, TblOptions};
+use n2o4::cfe::Status;
+use n2o4::table_validation_fn;
+use printf_wrap::PrintfFmt;
+
+/// Message ID of the application's ground commands.
+pub const SAMPLE_CMD_MID: MsgId_Atom = 0x1882;
+
+/// Message ID of the housekeeping request command.
+pub const SAMPLE_SEND_HK_MID: MsgId_Atom = 0x1883;
+
+/// Message ID of the housekeeping telemetry packet.
+pub const SAMPLE_HK_TLM_MID: MsgId_Atom = 0x0883;
+
+/// Function code of the no-op command.
+pub const NOOP_CC: u16 = 0;
+
+/// Function code of the reset-counters command.
+pub const RESET_COUNTERS_CC: u16 = 1;
+
+/// Function code of the set-gain command.
+pub const SET_GAIN_CC: u16 = 2;
+
+/// Event ID of the event sent once the application has started.
+pub const INIT_EID: u16 = 1;
+
+/// Event ID of the event sent in response to a no-op command.
+pub const NOOP_EID: u16 = 2;
+
+/// Event ID of the event sent in response to a reset-counters command.
+pub const RESET_EID: u16 = 3;
+
+/// Event ID of the event sent when the gain is changed.
+pub const SET_GAIN_EID: u16 = 4;
+
+/// Event IDs for commands the command table rejects.
+pub const CMD_EIDS: CommandEventIds = CommandEventIds {
+    invalid_code:   10,
+    invalid_length: 11,
+    failed:         12,
+};
+
+/// The application's configuration table.
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct SampleConfig {
+    /// The largest gain that may be commanded.
+    pub max_gain: u32,
+}
+
+/// The configuration the application starts with.
+static DEFAULT_CONFIG: SampleConfig = SampleConfig { max_gain: 100 };
+
+/// Rejects configurations with a maximum gain of 0 or above 1000.
+const VALIDATE_CONFIG: TableValidationFn<SampleConfig> =
+    table_validation_fn!(SampleConfig, |cfg| match cfg.max_gain {
+        1..=1000 => Ok(()),
+        _ => Err(-1),
+    });
+
+/// The application's housekeeping telemetry payload.
+#[derive(Clone, Copy, Default, Debug)]
+#[repr(C)]
+pub struct SampleHk {
+    /// Number of commands accepted.
+    pub command_count: u8,
+    /// Number of commands rejected.
+    pub command_error_count: u8,
+    /// Padding.
+    pub spare: [u8; 2],
+    /// The current gain.
+    pub gain: u32,
+}
+
+impl HkPayload for SampleHk {
+    fn update(&mut self, counters: &HkCounters) {
+        self.command_count = counters.command_count;
+        self.command_error_count = counters.command_error_count;
+    }
+}
+
+n2o4::cfe_app! {
+    entry: SAMPLE_AppMain;
+    resources: SampleResources;
+    events: [];
+    pipes: {
+        cmd_pipe: (16, c"SAMPLE_CMD_PIPE") => [SAMPLE_CMD_MID, SAMPLE_SEND_HK_MID],
+    };
+    tables: {
+        config: SampleConfig = (c"CONFIG", TblOptions::default(), Some(VALIDATE_CONFIG))
+            => TblLoadSource::Ref(&DEFAULT_CONFIG),
+    };
+    state: SampleApp;
+    init: SampleApp::new;
+    run: SampleApp::run_once;
+}
+
+/// State changed by ground commands.
+pub struct CommandState {
+    /// The current gain.
+    gain:            u32,
+    /// The largest gain allowed by the configuration table.
+    max_gain:        u32,
+    /// Whether a no-op command has been received.
+    noop_received:   bool,
+    /// Whether a reset-counters command has been received.
+    reset_requested: bool,
+}
+
+/// The application's ground commands.
+const COMMANDS: CommandTable<CommandState> = n2o4::command_table! {
+    state: CommandState,
+    events: CMD_EIDS,
+    commands: [
+        (SAMPLE_CMD_MID, NOOP_CC, (), CommandState::noop),
+        (SAMPLE_CMD_MID, RESET_COUNTERS_CC, (), CommandState::reset_counters),
+        (SAMPLE_CMD_MID, SET_GAIN_CC, u32, CommandState::set_gain),
+    ],
+};
+
+impl CommandState {
+    fn noop(&mut self, _cmd: &Command<()>) -> Result<(), Status> {
+        self.noop_received = true;
+        Ok(())
+    }
+
+    fn reset_counters(&mut self, _cmd: &Command<()>) -> Result<(), Status> {
+        self.reset_requested = true;
+        Ok(())
+    }
+
+    fn set_gain(&mut self, cmd: &Command<u32>) -> Result<(), Status> {
+        if cmd.payload > self.max_gain {
+            return Err(Status::ES_BAD_ARGUMENT);
+        }
+
+        self.gain = cmd.payload;
+        Ok(())
+    }
+}
+
+/// The application's state.
+pub struct SampleApp {
+    hk:       Housekeeping<SampleHk>,
+    commands: CommandState,
+}
+
+impl SampleApp {
+    fn new(res: &mut SampleResources) -> Result<Self, Status> {
+        let hk = Housekeeping::new(
+            MsgId::from(SAMPLE_HK_TLM_MID),
+            MsgId::from(SAMPLE_SEND_HK_MID),
+            SampleHk::default(),
+        )?;
+        let commands = CommandState {
+            gain:            0,
+            max_gain:        0,
+            noop_received:   false,
+            reset_requested: false,
+        };
+
+        let _ = res.events.send_event(
+            INIT_EID,
+            EventType::Information,
+            PrintfFmt::new_or_panic("Sample app initialized\0"),
+            (),
+        );
+
+        Ok(SampleApp { hk, commands })
+    }
+
+    fn run_once(&mut self, res: &mut SampleResources) -> Result<(), Status> {
+        // Pick up any new configuration:
+        let _ = res.config.manage();
+        self.commands.max_gain = res.config.get_ref(|cfg, _| cfg.max_gain)?;
+
+        res.cmd_pipe.receive_buffer(TimeOut::Millis(1000), |msg| {
+            let msg = match msg {
+                Ok(msg) => msg,
+                Err(Status::SB_TIME_OUT) => return Ok(()),
+                Err(status) => return Err(status),
+            };
+
+            self.hk.payload_mut().gain = self.commands.gain;
+            if self.hk.handle(msg)? {
+                return Ok(());
+            }
+
+            let old_gain = self.commands.gain;
+            let counters = self.hk.counters_mut();
+            if !COMMANDS.dispatch(&mut self.commands, msg, counters, &res.events)? {
+                return Ok(());
+            }
+
+            if self.commands.noop_received {
+                self.commands.noop_received = false;
+                let _ = res.events.send_event(
+                    NOOP_EID,
+                    EventType::Information,
+                    PrintfFmt::new_or_panic("No-op command received\0"),
+                    (),
+                );
+            }
+
+            if self.commands.reset_requested {
+                self.commands.reset_requested = false;
+                self.hk.reset_counters();
+                let _ = res.events.send_event(
+                    RESET_EID,
+                    EventType::Debug,
+                    PrintfFmt::new_or_panic("Counters reset\0"),
+                    (),
+                );
+            }
+
+            if self.commands.gain != old_gain {
+                let _ = res.events.send_event(
+                    SET_GAIN_EID,
+                    EventType::Information,
+                    PrintfFmt::new_or_panic("Gain set to %u\0"),
+                    (self.commands.gain,),
+                );
+            }
+
+            Ok(())
+        })
+    }
+}
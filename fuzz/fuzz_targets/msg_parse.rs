@@ -0,0 +1,66 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Feeds arbitrary bytes, as received messages, through `Message`'s accessors and casts.
+//!
+//! The bytes are delivered to a pipe using the `mock` backend,
+//! which (like the real software bus) makes the message as long as its header says;
+//! this mirrors a malformed packet coming in from the ground.
+//!
+//! Run with `cargo +nightly fuzz run msg_parse` from the repository root
+//! (with `RUST_CFS_SYS_INCLUDE_DIRECTORIES` set as for any other build).
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use n2o4::cfe::msg::Message;
+use n2o4::cfe::sb::{Pipe, TimeOut};
+use n2o4::mock;
+
+/// A payload with stricter alignment than the message headers.
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct Wide {
+    _a: u64,
+    _b: u32,
+}
+
+fn exercise(msg: &Message) {
+    let size = msg.size();
+    let _ = msg.msgid();
+    let _ = msg.msg_type();
+    let _ = msg.fcn_code();
+    let _ = msg.validate(true);
+
+    if let Ok(payload) = msg.payload() {
+        // Touch every byte, so out-of-bounds slices get caught.
+        let _ = payload.iter().fold(0u8, |acc, &b| acc ^ b);
+        assert!(payload.len() <= size.unwrap());
+    }
+
+    let _ = msg.try_cast_cmd::<()>();
+    let _ = msg.try_cast_cmd::<u8>();
+    let _ = msg.try_cast_cmd::<u32>();
+    let _ = msg.try_cast_cmd::<Wide>();
+    let _ = msg.try_cast_cmd::<[u8; 64]>();
+    let _ = msg.try_cast_tlm::<()>();
+    let _ = msg.try_cast_tlm::<u8>();
+    let _ = msg.try_cast_tlm::<u32>();
+    let _ = msg.try_cast_tlm::<Wide>();
+    let _ = msg.try_cast_tlm::<[u8; 64]>();
+}
+
+fuzz_target!(|data: &[u8]| {
+    let _guard = mock::exclusive();
+
+    let mut pipe = Pipe::new(1, c"FUZZ_PIPE").unwrap();
+    if !mock::sb::deliver("FUZZ_PIPE", data) {
+        return;
+    }
+
+    pipe.receive_buffer(TimeOut::Poll, |msg| {
+        if let Ok(msg) = msg {
+            exercise(msg);
+        }
+    });
+});
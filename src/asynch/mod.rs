@@ -0,0 +1,472 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A minimal `async` executor for cFE applications.
+//!
+//! This lets state-machine-heavy applications (file transfers, protocol bridges, etc.)
+//! be written as straight-line `async` code:
+//!
+//! ```rust,ignore
+//! n2o4::asynch::block_on(async {
+//!     loop {
+//!         let cmd = cmd_pipe.recv(|msg| parse(msg)).await;
+//!         // ...
+//!         Timer::after(OSTimeInterval::from_milliseconds(0, 250))?.await;
+//!     }
+//! })
+//! ```
+//!
+//! The executor runs a single future on the calling task, without allocation.
+//! Whenever that future is pending, the executor sleeps until
+//! a message arrives on a pipe being awaited
+//! (by blocking in `CFE_SB_ReceiveBuffer`)
+//! or the earliest pending [`Timer`] expires, whichever comes first.
+//! Futures that have no such event to wait on
+//! (e.g., ones polling for socket readiness)
+//! get polled again every [tick](Executor::with_tick);
+//! [`socket::AsyncSocket`] provides such futures for OSAL sockets.
+//!
+//! Concurrency within the one future can be had with any `no_std` combinator crate
+//! (e.g., `join` and `select` from `futures-util`).
+//!
+//! (The module is named `asynch` as `async` is a keyword.)
+
+use crate::cfe::msg::Message;
+use crate::cfe::sb::{Pipe, TimeOut};
+use crate::cfe::Status;
+use crate::osal::{OSTime, OSTimeInterval, OsalError};
+use crate::sys::*;
+use core::cell::Cell;
+use core::future::Future;
+use core::marker::PhantomPinned;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicPtr, Ordering};
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+pub mod socket;
+pub mod time;
+
+/// The most pipes the executor can wait on at once.
+const MAX_PIPES: usize = 8;
+
+/// Where the executor puts the result of a receive on behalf of a [`Recv`].
+type PipeSlot = Cell<Option<Result<*mut CFE_SB_Buffer_t, Status>>>;
+
+/// What the future being run is waiting on, as of its latest poll.
+struct Reactor {
+    pipes:     Cell<[Option<(CFE_SB_PipeId_t, *const PipeSlot)>; MAX_PIPES]>,
+    deadline:  Cell<Option<OSTime>>,
+    poll_soon: Cell<bool>,
+}
+
+impl Reactor {
+    const fn new() -> Self {
+        Reactor {
+            pipes:     Cell::new([None; MAX_PIPES]),
+            deadline:  Cell::new(None),
+            poll_soon: Cell::new(false),
+        }
+    }
+
+    fn clear(&self) {
+        self.pipes.set([None; MAX_PIPES]);
+        self.deadline.set(None);
+        self.poll_soon.set(false);
+    }
+
+    fn register_pipe(&self, pipe_id: CFE_SB_PipeId_t, slot: *const PipeSlot) {
+        let mut pipes = self.pipes.get();
+        match pipes.iter_mut().find(|p| p.is_none()) {
+            Some(p) => *p = Some((pipe_id, slot)),
+            // Out of room; fall back to polling.
+            None => self.poll_soon.set(true),
+        }
+        self.pipes.set(pipes);
+    }
+
+    fn unregister_pipe(&self, slot: *const PipeSlot) {
+        let mut pipes = self.pipes.get();
+        for p in pipes.iter_mut() {
+            if matches!(p, Some((_, s)) if *s == slot) {
+                *p = None;
+            }
+        }
+        self.pipes.set(pipes);
+    }
+
+    fn register_deadline(&self, deadline: OSTime) {
+        match self.deadline.get() {
+            Some(d) if d <= deadline => (),
+            _ => self.deadline.set(Some(deadline)),
+        }
+    }
+}
+
+/// The reactor of the executor (if any) running on each OSAL task.
+static REACTORS: [AtomicPtr<Reactor>; OS_MAX_TASKS as usize] = {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const NONE: AtomicPtr<Reactor> = AtomicPtr::new(core::ptr::null_mut());
+    [NONE; OS_MAX_TASKS as usize]
+};
+
+/// Returns the index into [`REACTORS`] for the calling task.
+fn task_index() -> Option<usize> {
+    let mut index: osal_index_t = 0;
+    let status = unsafe { OS_ConvertToArrayIndex(OS_TaskGetId(), &mut index) };
+
+    if status == OS_SUCCESS as i32 && (index as usize) < REACTORS.len() {
+        Some(index as usize)
+    } else {
+        None
+    }
+}
+
+/// Runs `f` on the reactor of the executor running on this task, if there is one.
+fn with_reactor<R>(f: impl FnOnce(&Reactor) -> R) -> Option<R> {
+    let ptr = REACTORS[task_index()?].load(Ordering::Acquire);
+
+    // Safety: the pointer is only non-null while the executor that put it there
+    // is running (on this task), and the reactor is only used on this task.
+    unsafe { ptr.as_ref() }.map(f)
+}
+
+/// Asks the executor to poll the current future again soon,
+/// for futures with nothing more specific to wait on.
+///
+/// Outside of this module's executor, this just wakes the task.
+pub(crate) fn poll_soon(cx: &mut Context<'_>) {
+    if with_reactor(|r| r.poll_soon.set(true)).is_none() {
+        cx.waker().wake_by_ref();
+    }
+}
+
+/// Asks the executor to poll the current future again at `deadline`
+/// (or soon, if that's already past).
+///
+/// Outside of this module's executor, this just wakes the task.
+pub(crate) fn poll_at(cx: &mut Context<'_>, deadline: OSTime) {
+    if millis_until(deadline) == 0 {
+        poll_soon(cx);
+    } else if with_reactor(|r| r.register_deadline(deadline)).is_none() {
+        cx.waker().wake_by_ref();
+    }
+}
+
+/// The waker used by the executor: as it re-polls its future after every wait anyway,
+/// wakeups need do nothing.
+fn noop_waker() -> Waker {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(|_| RAW, |_| (), |_| (), |_| ());
+    const RAW: RawWaker = RawWaker::new(core::ptr::null(), &VTABLE);
+
+    // Safety: the vtable functions trivially uphold the RawWaker contract.
+    unsafe { Waker::from_raw(RAW) }
+}
+
+/// An executor for a single future.
+#[derive(Clone, Copy, Debug)]
+pub struct Executor {
+    tick_ms: u32,
+}
+
+impl Executor {
+    /// The default [tick](Self::with_tick), in milliseconds.
+    pub const DEFAULT_TICK_MS: u32 = 10;
+
+    /// Creates an executor with the default tick.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_tick(Self::DEFAULT_TICK_MS)
+    }
+
+    /// Creates an executor which, when its future is waiting on something
+    /// that can't be blocked on directly
+    /// (more than one pipe, or something other than pipes and timers),
+    /// re-polls the future every `tick_ms` milliseconds.
+    #[inline]
+    pub const fn with_tick(tick_ms: u32) -> Self {
+        Executor {
+            tick_ms: if tick_ms == 0 { 1 } else { tick_ms },
+        }
+    }
+
+    /// Runs `fut` to completion on the calling task, returning its output.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the calling task isn't an OSAL task.
+    pub fn block_on<F: Future>(&self, fut: F) -> F::Output {
+        let index = task_index().expect("block_on called from outside an OSAL task");
+        let reactor = Reactor::new();
+
+        // Restores the previous reactor (if block_on calls are nested) on exit.
+        struct Registration<'a> {
+            slot: &'a AtomicPtr<Reactor>,
+            prev: *mut Reactor,
+        }
+
+        impl Drop for Registration<'_> {
+            fn drop(&mut self) {
+                self.slot.store(self.prev, Ordering::Release);
+            }
+        }
+
+        let slot = &REACTORS[index];
+        let _registration = Registration {
+            slot,
+            prev: slot.swap(&reactor as *const Reactor as *mut Reactor, Ordering::AcqRel),
+        };
+
+        let mut fut = fut;
+        // Safety: `fut` is shadowed, so it can't be moved again.
+        let mut fut = unsafe { Pin::new_unchecked(&mut fut) };
+
+        let waker = noop_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        loop {
+            reactor.clear();
+
+            if let Poll::Ready(output) = fut.as_mut().poll(&mut cx) {
+                return output;
+            }
+
+            self.wait(&reactor);
+        }
+    }
+
+    /// Sleeps until something the future is waiting on may have happened.
+    fn wait(&self, reactor: &Reactor) {
+        let pipes = reactor.pipes.get();
+        let mut pipes = pipes.iter().flatten();
+
+        let mut timeout_ms = reactor.deadline.get().map(millis_until);
+        let pipe_count = pipes.clone().count();
+        if reactor.poll_soon.get() || pipe_count > 1 || (pipe_count == 0 && timeout_ms.is_none()) {
+            timeout_ms = Some(timeout_ms.map_or(self.tick_ms, |t| t.min(self.tick_ms)));
+        }
+
+        match pipes.next() {
+            Some(&(pipe_id, slot)) if pipe_count == 1 => {
+                let time_out = timeout_ms.map_or(TimeOut::PendForever, TimeOut::Millis);
+                receive(pipe_id, slot, time_out);
+            }
+            Some(&(pipe_id, slot)) => {
+                let mut got_any = receive(pipe_id, slot, TimeOut::Poll);
+                for &(pipe_id, slot) in pipes {
+                    got_any |= receive(pipe_id, slot, TimeOut::Poll);
+                }
+                if !got_any {
+                    delay(timeout_ms);
+                }
+            }
+            None => delay(timeout_ms),
+        }
+    }
+}
+
+impl Default for Executor {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Runs `fut` to completion on the calling task
+/// using an [`Executor`] with the default tick.
+///
+/// # Panics
+///
+/// Panics if the calling task isn't an OSAL task.
+#[inline]
+pub fn block_on<F: Future>(fut: F) -> F::Output {
+    Executor::new().block_on(fut)
+}
+
+/// Receives from `pipe_id` into `slot`;
+/// returns whether anything (message or error) was put in the slot.
+fn receive(pipe_id: CFE_SB_PipeId_t, slot: *const PipeSlot, time_out: TimeOut) -> bool {
+    let mut buf: *mut CFE_SB_Buffer_t = core::ptr::null_mut();
+
+    let s: Status = crate::cfe::perf::timed(crate::cfe::perf::SB_RECEIVE_BUFFER, || unsafe {
+        CFE_SB_ReceiveBuffer(&mut buf, pipe_id, time_out.into())
+    })
+    .into();
+
+    let result = match s {
+        Status::SB_TIME_OUT | Status::SB_NO_MESSAGE => return false,
+        _ if s.severity() == crate::cfe::StatusSeverity::Error => Err(s),
+        _ if buf.is_null() => Err(Status::SB_BUFFER_INVALID),
+        _ => Ok(buf),
+    };
+
+    // Safety: slots are unregistered when their `Recv`s are dropped,
+    // and `Recv`s are pinned, so `slot` is valid.
+    unsafe { (*slot).set(Some(result)) };
+    true
+}
+
+/// Sleeps for `timeout_ms` milliseconds, if that's `Some` non-zero value.
+fn delay(timeout_ms: Option<u32>) {
+    if let Some(ms) = timeout_ms.filter(|&ms| ms > 0) {
+        let _ = crate::osal::task::delay(ms);
+    }
+}
+
+/// Returns the number of milliseconds (rounded up) until `deadline`, or 0 if it's past.
+fn millis_until(deadline: OSTime) -> u32 {
+    match crate::osal::local_time() {
+        Ok(now) if now < deadline => {
+            let micros = (deadline - now).total_microseconds();
+            ((micros + 999) / 1000).clamp(0, u32::MAX as i64) as u32
+        }
+        _ => 0,
+    }
+}
+
+impl Pipe {
+    /// Waits for a message on the pipe, then calls `closure` on it
+    /// (or on the error encountered), returning what `closure` returns.
+    ///
+    /// This is the `async` equivalent of [`receive_buffer`](Pipe::receive_buffer)
+    /// with a timeout of [`PendForever`](TimeOut::PendForever).
+    ///
+    /// Wraps `CFE_SB_ReceiveBuffer`.
+    #[doc(alias = "CFE_SB_ReceiveBuffer")]
+    #[inline]
+    pub fn recv<T, F>(&mut self, closure: F) -> Recv<'_, F>
+    where
+        F: for<'a> FnOnce(Result<&'a Message, Status>) -> T,
+    {
+        Recv {
+            pipe:    self,
+            slot:    Cell::new(None),
+            closure: Some(closure),
+            _pin:    PhantomPinned,
+        }
+    }
+}
+
+/// Future returned by [`Pipe::recv`].
+#[must_use = "futures do nothing unless polled"]
+pub struct Recv<'p, F> {
+    pipe:    &'p mut Pipe,
+    slot:    PipeSlot,
+    closure: Option<F>,
+    _pin:    PhantomPinned,
+}
+
+impl<'p, T, F> Future for Recv<'p, F>
+where
+    F: for<'a> FnOnce(Result<&'a Message, Status>) -> T,
+{
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        // Safety: nothing is moved out of `self` (only `closure`, which isn't structurally pinned).
+        let this = unsafe { self.get_unchecked_mut() };
+        let slot: *const PipeSlot = &this.slot;
+
+        let _ = with_reactor(|r| r.unregister_pipe(slot));
+
+        if this.slot.get().is_none() {
+            receive(this.pipe.id, slot, TimeOut::Poll);
+        }
+
+        match this.slot.take() {
+            Some(result) => {
+                let closure = this.closure.take().expect("Recv polled after completion");
+                let result = result.map(|buf| {
+                    // Safety: the buffer stays valid until the next receive on the pipe,
+                    // which can't happen while `this.pipe` is borrowed.
+                    Message::from_cfe(unsafe { &(*buf).Msg })
+                });
+                Poll::Ready(closure(result))
+            }
+            None => {
+                if with_reactor(|r| r.register_pipe(this.pipe.id, slot)).is_none() {
+                    cx.waker().wake_by_ref();
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<'p, F> Drop for Recv<'p, F> {
+    fn drop(&mut self) {
+        let slot: *const PipeSlot = &self.slot;
+        let _ = with_reactor(|r| r.unregister_pipe(slot));
+    }
+}
+
+/// A future that completes at a given (OSAL local) time.
+#[must_use = "futures do nothing unless polled"]
+#[derive(Clone, Copy, Debug)]
+pub struct Timer {
+    deadline: OSTime,
+}
+
+impl Timer {
+    /// Returns a timer that expires at `deadline`.
+    #[inline]
+    pub fn at(deadline: OSTime) -> Self {
+        Timer { deadline }
+    }
+
+    /// Returns a timer that expires once `interval` has passed from now.
+    ///
+    /// Wraps `OS_GetLocalTime`.
+    #[doc(alias = "OS_GetLocalTime")]
+    #[inline]
+    pub fn after(interval: OSTimeInterval) -> Result<Self, OsalError> {
+        Ok(Timer {
+            deadline: crate::osal::local_time()? + interval,
+        })
+    }
+
+    /// Returns the time the timer expires at.
+    #[inline]
+    pub fn deadline(&self) -> OSTime {
+        self.deadline
+    }
+}
+
+impl Future for Timer {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if millis_until(self.deadline) == 0 {
+            return Poll::Ready(());
+        }
+
+        poll_at(cx, self.deadline);
+        Poll::Pending
+    }
+}
+
+/// Returns a future that yields to the executor once before completing.
+#[inline]
+pub fn yield_now() -> YieldNow {
+    YieldNow { yielded: false }
+}
+
+/// Future returned by [`yield_now`].
+#[must_use = "futures do nothing unless polled"]
+#[derive(Debug)]
+pub struct YieldNow {
+    yielded: bool,
+}
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            return Poll::Ready(());
+        }
+
+        self.yielded = true;
+        poll_soon(cx);
+        Poll::Pending
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! `async` wrappers around [OSAL sockets](crate::osal::socket).
+//!
+//! Each operation waits (without blocking the task) for the socket to become ready
+//! using [`select_single`] with a zero timeout,
+//! then performs the operation, which then won't block.
+//!
+//! These futures work with any executor, not just the one in [this crate](super);
+//! as OSAL has no readiness notifications, though,
+//! a pending future asks to be polled again right away
+//! (or, under this crate's executor, after the next [tick](super::Executor::with_tick)).
+
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use crate::osal::select::{select_single, StreamState};
+use crate::osal::socket::*;
+use crate::osal::{ObjectId, OsalError};
+
+/// A [`Socket`] whose I/O operations are `async`.
+pub struct AsyncSocket<D: SocketDomain, T: SocketType, R: SocketRole> {
+    sock: Socket<D, T, R>,
+}
+
+impl<D: SocketDomain, T: SocketType, R: SocketRole> AsyncSocket<D, T, R> {
+    /// Wraps `sock`.
+    #[inline]
+    pub fn new(sock: Socket<D, T, R>) -> Self {
+        AsyncSocket { sock }
+    }
+
+    /// Returns a reference to the underlying socket.
+    #[inline]
+    pub fn get_ref(&self) -> &Socket<D, T, R> {
+        &self.sock
+    }
+
+    /// Unwraps the underlying socket.
+    #[inline]
+    pub fn into_inner(self) -> Socket<D, T, R> {
+        self.sock
+    }
+
+    /// Waits until the socket can be read from
+    /// (or, for a listening socket, a connection can be accepted) without blocking.
+    ///
+    /// Wraps `OS_SelectSingle`.
+    #[doc(alias = "OS_SelectSingle")]
+    #[inline]
+    pub fn readable(&self) -> Ready {
+        Ready {
+            id:     self.sock.as_id(),
+            wanted: StreamState::READABLE,
+        }
+    }
+
+    /// Waits until the socket can be written to without blocking.
+    ///
+    /// Wraps `OS_SelectSingle`.
+    #[doc(alias = "OS_SelectSingle")]
+    #[inline]
+    pub fn writable(&self) -> Ready {
+        Ready {
+            id:     self.sock.as_id(),
+            wanted: StreamState::WRITABLE,
+        }
+    }
+}
+
+impl<D: SocketDomain, T: SocketType, R: SocketRole> Clone for AsyncSocket<D, T, R> {
+    #[inline]
+    fn clone(&self) -> Self {
+        AsyncSocket { sock: self.sock.clone() }
+    }
+}
+
+impl<D: SocketDomain, T: SocketType, R: SocketRole> From<Socket<D, T, R>> for AsyncSocket<D, T, R> {
+    #[inline]
+    fn from(sock: Socket<D, T, R>) -> Self {
+        Self::new(sock)
+    }
+}
+
+impl<D: SocketDomain, T: SocketType> AsyncSocket<D, T, Connected> {
+    /// Reads up to `buf.len()` bytes from the connection into `buf`
+    /// once data is available.
+    ///
+    /// Upon success, returns the number of bytes actually read into `buf`,
+    /// or `0` if at the end of the stream.
+    ///
+    /// Wraps `OS_SelectSingle` and `OS_read`.
+    #[doc(alias("OS_SelectSingle", "OS_read"))]
+    pub async fn read(&self, buf: &mut [u8]) -> Result<usize, OsalError> {
+        self.readable().await?;
+        self.sock.read(buf)
+    }
+
+    /// Writes up to `buf.len()` bytes from `buf` to the connection
+    /// once it can accept data.
+    ///
+    /// Upon success, returns the number of bytes actually written.
+    ///
+    /// Wraps `OS_SelectSingle` and `OS_write`.
+    #[doc(alias("OS_SelectSingle", "OS_write"))]
+    pub async fn write(&self, buf: &[u8]) -> Result<usize, OsalError> {
+        self.writable().await?;
+        self.sock.write(buf)
+    }
+
+    /// Writes all of `buf` to the connection.
+    ///
+    /// Wraps `OS_SelectSingle` and `OS_write`.
+    #[doc(alias("OS_SelectSingle", "OS_write"))]
+    pub async fn write_all(&self, mut buf: &[u8]) -> Result<(), OsalError> {
+        while !buf.is_empty() {
+            match self.write(buf).await? {
+                0 => return Err(OsalError::OS_ERROR),
+                n => buf = &buf[n..],
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<D: SocketDomain> AsyncSocket<D, Stream, Bound> {
+    /// Waits for and accepts the next incoming connection on the listening socket.
+    ///
+    /// On success, returns a socket for the new connection
+    /// and the address of the connection's remote side.
+    ///
+    /// Wraps `OS_SelectSingle` and `OS_SocketAccept`.
+    #[doc(alias("OS_SelectSingle", "OS_SocketAccept"))]
+    pub async fn accept(
+        &self,
+    ) -> Result<(AsyncSocket<D, Stream, Connected>, SockAddr<D>), OsalError> {
+        loop {
+            self.readable().await?;
+
+            match self.sock.accept(Some(0)) {
+                // someone else got to the connection first
+                Err(SocketError::TimedOut) => continue,
+                Err(SocketError::Osal(err)) => return Err(err),
+                Ok((sock, addr)) => return Ok((AsyncSocket { sock }, addr)),
+            }
+        }
+    }
+}
+
+impl<D: SocketDomain, R: SocketRole> AsyncSocket<D, Datagram, R> {
+    /// Sends a message from the datagram socket to `remote_addr`,
+    /// using `buf` as the message contents,
+    /// once the socket can accept it.
+    ///
+    /// On success, returns the number of bytes of `buf` that were actually sent.
+    ///
+    /// Wraps `OS_SelectSingle` and `OS_SocketSendTo`.
+    #[doc(alias("OS_SelectSingle", "OS_SocketSendTo"))]
+    pub async fn send(&self, buf: &[u8], remote_addr: &SockAddr<D>) -> Result<usize, OsalError> {
+        self.writable().await?;
+        self.sock.send(buf, remote_addr)
+    }
+}
+
+impl<D: SocketDomain> AsyncSocket<D, Datagram, Bound> {
+    /// Waits for a message on the bound datagram socket, then reads it into `buf`.
+    ///
+    /// On success, returns the number of bytes written to `buf`
+    /// and the address of the message sender.
+    ///
+    /// Wraps `OS_SelectSingle` and `OS_SocketRecvFrom`.
+    #[doc(alias("OS_SelectSingle", "OS_SocketRecvFrom"))]
+    pub async fn recv(&self, buf: &mut [u8]) -> Result<(usize, SockAddr<D>), OsalError> {
+        loop {
+            self.readable().await?;
+
+            match self.sock.recv(buf, Some(0)) {
+                Err(SocketError::TimedOut) => continue,
+                Err(SocketError::Osal(err)) => return Err(err),
+                Ok(received) => return Ok(received),
+            }
+        }
+    }
+}
+
+/// Future returned by [`AsyncSocket::readable`] and [`AsyncSocket::writable`].
+#[must_use = "futures do nothing unless polled"]
+#[derive(Clone, Copy, Debug)]
+pub struct Ready {
+    id:     ObjectId,
+    wanted: StreamState,
+}
+
+impl Future for Ready {
+    type Output = Result<(), OsalError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match select_single(self.id, self.wanted, Some(0)) {
+            Ok(state) if !state.is_empty() => Poll::Ready(Ok(())),
+            Ok(_) => {
+                super::poll_soon(cx);
+                Poll::Pending
+            }
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
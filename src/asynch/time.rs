@@ -0,0 +1,225 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Sleeping and periodic wakeups backed by OSAL timers.
+//!
+//! Each [`Sleep`] and [`Ticker`] owns an OSAL timer (on a time base of its own)
+//! whose callback gives a counting semaphore;
+//! the futures complete once they can take the semaphore.
+//! Unlike [`Timer`](super::Timer), which compares against the local clock,
+//! these follow the OSAL timer, so a [`Ticker`] doesn't drift
+//! however late its ticks are consumed.
+//!
+//! The OSAL objects are named `AT` followed by hex digits,
+//! and count against the OSAL object limits while in use.
+
+use core::convert::TryFrom;
+use core::ffi::{c_char, c_void, CStr};
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicU16, Ordering};
+use core::task::{Context, Poll};
+
+use crate::osal::sync::OwnedCountSem;
+use crate::osal::{I32Ext, OSTime, OSTimeInterval, OsalError};
+use crate::sys::*;
+
+/// The period (in microseconds) of the time bases driving the timers.
+const TIMEBASE_TICK_US: u32 = 1000;
+
+/// Used to make timer names unique within a task.
+static NAME_COUNTER: AtomicU16 = AtomicU16::new(0);
+
+/// Timer callback: gives the counting semaphore whose ID is `arg`.
+unsafe extern "C" fn give_sem(_timer_id: osal_id_t, arg: *mut c_void) {
+    crate::ffi_errors::check_osal(OS_CountSemGive(arg as usize as osal_id_t));
+}
+
+/// An OSAL timer that gives a counting semaphore each time it expires.
+struct SemTimer {
+    sem:      OwnedCountSem,
+    timebase: osal_id_t,
+    timer:    osal_id_t,
+    next:     OSTime,
+    period:   OSTimeInterval,
+}
+
+impl SemTimer {
+    /// Starts a timer that first expires after `start`,
+    /// then every `period` after that (or never again, if `period` is zero).
+    fn new(start: OSTimeInterval, period: OSTimeInterval) -> Result<Self, OsalError> {
+        let start_us = as_micros(start)?;
+        let period_us = as_micros(period)?;
+
+        let mut name_buf = [0 as c_char; 16];
+        let name = unique_name(&mut name_buf);
+
+        let mut t = SemTimer {
+            sem: OwnedCountSem::new(name, 0)?,
+            timebase: X_OS_OBJECT_ID_UNDEFINED,
+            timer: X_OS_OBJECT_ID_UNDEFINED,
+            next: crate::osal::local_time()? + start,
+            period,
+        };
+
+        // If any of these fail, dropping `t` cleans up what was created.
+        unsafe {
+            OS_TimeBaseCreate(&mut t.timebase, name.as_ptr(), None).as_osal_status()?;
+            OS_TimeBaseSet(t.timebase, TIMEBASE_TICK_US, TIMEBASE_TICK_US).as_osal_status()?;
+
+            let arg = t.sem.id as usize as *mut c_void;
+            OS_TimerAdd(&mut t.timer, name.as_ptr(), t.timebase, Some(give_sem), arg)
+                .as_osal_status()?;
+
+            OS_TimerSet(t.timer, start_us, period_us).as_osal_status()?;
+        }
+
+        Ok(t)
+    }
+
+    /// Completes once the timer has expired (again).
+    fn poll_expired(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), OsalError>> {
+        match self.sem.timed_wait(0) {
+            Ok(true) => {
+                self.next = self.next + self.period;
+                Poll::Ready(Ok(()))
+            }
+            Ok(false) => {
+                super::poll_at(cx, self.next);
+                Poll::Pending
+            }
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
+
+/// Wraps `OS_TimerDelete` and `OS_TimeBaseDelete`.
+impl Drop for SemTimer {
+    fn drop(&mut self) {
+        if self.timer != X_OS_OBJECT_ID_UNDEFINED {
+            crate::ffi_errors::check_osal(unsafe { OS_TimerDelete(self.timer) });
+        }
+        if self.timebase != X_OS_OBJECT_ID_UNDEFINED {
+            crate::ffi_errors::check_osal(unsafe { OS_TimeBaseDelete(self.timebase) });
+        }
+    }
+}
+
+/// Converts `interval` to the microsecond count OSAL timers use.
+fn as_micros(interval: OSTimeInterval) -> Result<u32, OsalError> {
+    u32::try_from(interval.total_microseconds()).map_err(|_| OsalError::OS_TIMER_ERR_INVALID_ARGS)
+}
+
+/// Writes an OSAL object name unique to this timer into `buf`,
+/// made from the calling task's ID and a counter.
+fn unique_name(buf: &mut [c_char; 16]) -> &CStr {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+
+    let task = unsafe { OS_TaskGetId() };
+    let count = NAME_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let n = ((task as u64) << 16) | count as u64;
+
+    buf[0] = b'A' as c_char;
+    buf[1] = b'T' as c_char;
+    for i in 0..12 {
+        buf[2 + i] = HEX[((n >> (4 * (11 - i))) & 0xf) as usize] as c_char;
+    }
+    buf[14] = 0;
+
+    unsafe { CStr::from_ptr(buf.as_ptr()) }
+}
+
+/// Returns a future that completes once `interval` has passed,
+/// as measured by an OSAL timer.
+///
+/// A zero `interval` completes immediately;
+/// an interval that is negative or doesn't fit in a `u32` count of microseconds
+/// results in an error.
+///
+/// Wraps `OS_TimerAdd` and `OS_TimerSet`.
+#[doc(alias("OS_TimerAdd", "OS_TimerSet"))]
+#[inline]
+pub fn sleep(interval: OSTimeInterval) -> Result<Sleep, OsalError> {
+    let timer = match as_micros(interval)? {
+        0 => None,
+        _ => Some(SemTimer::new(interval, OSTimeInterval::from_microseconds(0, 0))?),
+    };
+
+    Ok(Sleep { timer })
+}
+
+/// Future returned by [`sleep`].
+#[must_use = "futures do nothing unless polled"]
+pub struct Sleep {
+    timer: Option<SemTimer>,
+}
+
+impl Future for Sleep {
+    type Output = Result<(), OsalError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let result = match self.timer.as_mut() {
+            Some(timer) => core::task::ready!(timer.poll_expired(cx)),
+            None => Ok(()),
+        };
+
+        // Free up the OSAL objects right away.
+        self.timer = None;
+        Poll::Ready(result)
+    }
+}
+
+/// A source of periodic wakeups, driven by an OSAL timer.
+///
+/// Ticks that aren't awaited right away aren't lost:
+/// if several periods pass between calls to [`tick`](Self::tick),
+/// that many subsequent calls complete immediately.
+pub struct Ticker {
+    timer: SemTimer,
+}
+
+impl Ticker {
+    /// Creates a ticker whose first tick comes one `period` from now.
+    ///
+    /// `period` must be positive and fit in a `u32` count of microseconds.
+    ///
+    /// Wraps `OS_TimerAdd` and `OS_TimerSet`.
+    #[doc(alias("OS_TimerAdd", "OS_TimerSet"))]
+    #[inline]
+    pub fn new(period: OSTimeInterval) -> Result<Self, OsalError> {
+        if as_micros(period)? == 0 {
+            return Err(OsalError::OS_TIMER_ERR_INVALID_ARGS);
+        }
+
+        Ok(Ticker {
+            timer: SemTimer::new(period, period)?,
+        })
+    }
+
+    /// Returns the ticker's period.
+    #[inline]
+    pub fn period(&self) -> OSTimeInterval {
+        self.timer.period
+    }
+
+    /// Returns a future that completes at the next tick.
+    #[inline]
+    pub fn tick(&mut self) -> Tick<'_> {
+        Tick { ticker: self }
+    }
+}
+
+/// Future returned by [`Ticker::tick`].
+#[must_use = "futures do nothing unless polled"]
+pub struct Tick<'t> {
+    ticker: &'t mut Ticker,
+}
+
+impl Future for Tick<'_> {
+    type Output = Result<(), OsalError>;
+
+    #[inline]
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.ticker.timer.poll_expired(cx)
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Application skeletons.
+//!
+//! The [`cfe_app!`](crate::cfe_app) macro generates an application's entry point,
+//! which sets up everything the application declares it needs
+//! (event registration, pipes and their subscriptions, and tables),
+//! then runs the application's main loop until cFE asks it to stop:
+//!
+//! ```rust,ignore
+//! n2o4::cfe_app! {
+//!     entry: SAMPLE_AppMain;
+//!     resources: SampleResources;
+//!     events: [];
+//!     pipes: {
+//!         cmd_pipe: (16, c"SAMPLE_CMD_PIPE") => [SAMPLE_CMD_MID, SAMPLE_SEND_HK_MID],
+//!     };
+//!     tables: {
+//!         config: SampleConfig = (c"CONFIG", TblOptions::default(), Some(VALIDATE_CONFIG))
+//!             => TblLoadSource::FileName(c"/cf/sample_config.tbl"),
+//!     };
+//!     state: SampleApp;
+//!     init: SampleApp::new;
+//!     run: SampleApp::run_once;
+//! }
+//!
+//! impl SampleApp {
+//!     fn new(res: &mut SampleResources) -> Result<Self, Status> {
+//!         // ...
+//!     }
+//!
+//!     fn run_once(&mut self, res: &mut SampleResources) -> Result<(), Status> {
+//!         res.cmd_pipe.receive_buffer(TimeOut::PendForever, |msg| {
+//!             // ...
+//!         })
+//!     }
+//! }
+//! ```
+
+use super::es::{exit_app, RunStatus};
+use super::Status;
+use crate::sys::*;
+use core::ffi::c_char;
+use printf_wrap::{null_str, NullString};
+
+/// Format string for [`fail`].
+const FAILED_FMT: NullString = null_str!("%.*s: %.*s failed (status = 0x%08X)\n");
+
+/// Logs that step `what` of application `app` failed with status `status`,
+/// then exits the application with an error.
+///
+/// Used by [`cfe_app!`](crate::cfe_app).
+#[doc(hidden)]
+#[cold]
+pub fn fail(app: &str, what: &str, status: Status) -> ! {
+    let syslog_status = unsafe {
+        CFE_ES_WriteToSysLog(
+            FAILED_FMT.as_ptr(),
+            app.len(),
+            app.as_ptr() as *const c_char,
+            what.len(),
+            what.as_ptr() as *const c_char,
+            status.as_num(),
+        )
+    };
+    crate::ffi_errors::check_cfe(crate::ffi_errors::Subsystem::Es, syslog_status);
+
+    exit_app(RunStatus::AppError)
+}
+
+/// Declares a cFE application's resources and generates its entry point.
+///
+/// The sections, all of which are required except `tables`, are:
+///
+/// * `entry`: the name of the entry point (an `extern "C"` function)
+///   that the application is started through.
+/// * `resources`: the name of a struct this macro declares,
+///   which holds the [`EventSender`](crate::cfe::evs::EventSender) (as field `events`),
+///   pipes, and table handles set up for the application.
+/// * `events`: the [binary filters](crate::cfe::evs::BinFilter) to register with EVS.
+/// * `pipes`: pipes to create, as `field: (depth, name) => [message IDs...]`,
+///   where each message ID (a [`MsgId_Atom`](crate::cfe::sb::MsgId_Atom))
+///   gets subscribed to on the pipe.
+/// * `tables`: tables to register, as `field: Type = (name, options, validation function) => load source`,
+///   using the arguments of [`TblHandle::register`](crate::cfe::tbl::TblHandle::register)
+///   and [`TblHandle::load`](crate::cfe::tbl::TblHandle::load).
+/// * `state`: the application's state type.
+/// * `init`: a `fn(&mut Resources) -> Result<State, Status>`
+///   that creates the application's state once everything else is set up.
+/// * `run`: a `fn(&mut State, &mut Resources) -> Result<(), Status>`
+///   that's called repeatedly (typically blocking on a pipe)
+///   for as long as [`run_loop`](crate::cfe::es::run_loop) says the application should run.
+///
+/// If any setup step fails, or `run` returns an error,
+/// the failure is logged to the system log and the application exits with an error.
+/// Otherwise, the application exits normally once cFE asks it to,
+/// or with the given status once [`request_app_exit`](crate::cfe::es::request_app_exit) is called.
+///
+/// See the [`app`](crate::cfe::app) module documentation for an example.
+#[macro_export]
+macro_rules! cfe_app {
+    (
+        entry: $entry:ident;
+        resources: $res:ident;
+        events: [ $($filter:expr),* $(,)? ];
+        pipes: { $( $pipe:ident: ($depth:expr, $pipe_name:expr) => [ $($mid:expr),* $(,)? ] ),* $(,)? };
+        $( tables: { $( $tbl:ident: $tbl_ty:ty = ($tbl_name:expr, $tbl_opts:expr, $tbl_vf:expr) => $tbl_src:expr ),* $(,)? }; )?
+        state: $state:ty;
+        init: $init:expr;
+        run: $run:expr $(;)?
+    ) => {
+        /// The resources set up for the application by `cfe_app!`.
+        pub struct $res {
+            /// For sending events.
+            pub events: $crate::cfe::evs::EventSender,
+            $(
+            #[doc = concat!("Pipe `", stringify!($pipe), "`.")]
+            pub $pipe: $crate::cfe::sb::Pipe,
+            )*
+            $($(
+            #[doc = concat!("Table `", stringify!($tbl), "`.")]
+            pub $tbl: $crate::cfe::tbl::TblHandle<$tbl_ty>,
+            )*)?
+        }
+
+        /// The entry point for the application, as generated by `cfe_app!`.
+        #[no_mangle]
+        pub extern "C" fn $entry() {
+            const APP: &str = stringify!($entry);
+            use $crate::cfe::app::fail;
+
+            let events = match $crate::cfe::evs::register::<$crate::cfe::evs::BinFilter>(&[$($filter),*]) {
+                Ok(events) => events,
+                Err(status) => fail(APP, "EVS registration", status),
+            };
+
+            $(
+            let mut $pipe = match $crate::cfe::sb::Pipe::new($depth, $pipe_name) {
+                Ok(pipe) => pipe,
+                Err(status) => fail(APP, concat!("creating pipe ", stringify!($pipe)), status),
+            };
+            $(
+            if let Err(status) = $pipe.subscribe($crate::cfe::sb::MsgId::from($mid)) {
+                fail(APP, concat!("subscribing pipe ", stringify!($pipe), " to ", stringify!($mid)), status);
+            }
+            )*
+            )*
+
+            $($(
+            let mut $tbl = match $crate::cfe::tbl::TblHandle::<$tbl_ty>::register($tbl_name, $tbl_opts, $tbl_vf) {
+                Ok((handle, _)) => handle,
+                Err(status) => fail(APP, concat!("registering table ", stringify!($tbl)), status),
+            };
+            if let Err(status) = $tbl.load($tbl_src) {
+                fail(APP, concat!("loading table ", stringify!($tbl)), status);
+            }
+            )*)?
+
+            let mut resources = $res {
+                events,
+                $( $pipe, )*
+                $($( $tbl, )*)?
+            };
+
+            let init: fn(&mut $res) -> ::core::result::Result<$state, $crate::cfe::Status> = $init;
+            let run: fn(&mut $state, &mut $res) -> ::core::result::Result<(), $crate::cfe::Status> = $run;
+
+            let mut state = match init(&mut resources) {
+                Ok(state) => state,
+                Err(status) => fail(APP, "initialization", status),
+            };
+
+            while $crate::cfe::es::run_loop($crate::cfe::es::requested_app_exit()) {
+                if let Err(status) = run(&mut state, &mut resources) {
+                    fail(APP, "main loop", status);
+                }
+            }
+
+            $crate::cfe::es::exit_app(
+                $crate::cfe::es::requested_app_exit().unwrap_or($crate::cfe::es::RunStatus::AppExit),
+            );
+        }
+    };
+}
@@ -0,0 +1,348 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Event catalogs: an application's event definitions in one place,
+//! in a form ground tools can read.
+//!
+//! The [`event_catalog!`](crate::event_catalog) macro defines
+//! an [`EventDef`] constant for each of an application's events
+//! and an [`EventCatalog`] listing them all:
+//!
+//! ```rust,ignore
+//! n2o4::event_catalog! {
+//!     pub static SAMPLE_APP_EVENTS for "SAMPLE_APP";
+//!
+//!     pub const INIT_EID = (1, Information, "SAMPLE_APP initialized, version %u.%u");
+//!     pub const BAD_CC_EID = (2, Error, "Invalid command code: CC = %u");
+//! }
+//!
+//! let _ = INIT_EID.send(&events, (1u32, 0u32));
+//! ```
+//!
+//! The macro also puts the catalog, in the text format of [`EventCatalog::to_blob`],
+//! in the `.n2o4_event_catalog` section of the object file (on ELF targets),
+//! from which it can be extracted at build time (e.g., with `objcopy --dump-section`).
+//! Alternatively, a host-side program (e.g., built with the `mock` feature)
+//! can print the catalog with [`EventCatalog::write_json`].
+
+use super::evs::{EventSender, EventType};
+use super::{PrintfArgs, Status};
+use core::fmt;
+use printf_wrap::PrintfFmt;
+
+/// The definition of one event: its ID, type, and format string.
+///
+/// Usually defined with [`event_catalog!`](crate::event_catalog).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct EventDef {
+    /// The event ID.
+    pub id: u16,
+
+    /// The type (severity) of the event.
+    pub event_type: EventType,
+
+    /// The name of the event (usually that of its constant).
+    pub name: &'static str,
+
+    /// The format string, with a terminating NUL.
+    format: &'static str,
+}
+
+impl EventDef {
+    /// Defines an event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `format` doesn't end with a NUL character.
+    #[inline]
+    pub const fn new(
+        id: u16,
+        event_type: EventType,
+        name: &'static str,
+        format: &'static str,
+    ) -> Self {
+        let bytes = format.as_bytes();
+        assert!(!bytes.is_empty() && bytes[bytes.len() - 1] == 0);
+
+        EventDef { id, event_type, name, format }
+    }
+
+    /// Returns the format string (without the terminating NUL).
+    #[inline]
+    pub fn format(&self) -> &'static str {
+        &self.format[..self.format.len() - 1]
+    }
+
+    /// Sends the event with the arguments `args` for the format string.
+    ///
+    /// Returns [`Status::EVS_INVALID_PARAMETER`] without sending anything
+    /// if the types of `args` don't match the format string.
+    ///
+    /// Wraps `CFE_EVS_SendEvent`.
+    #[doc(alias = "CFE_EVS_SendEvent")]
+    #[inline]
+    pub fn send<T: PrintfArgs>(&self, sender: &EventSender, args: T) -> Status {
+        match PrintfFmt::<T>::new(self.format) {
+            Ok(fmt) => sender.send_event(self.id, self.event_type, fmt, args),
+            Err(_) => Status::EVS_INVALID_PARAMETER,
+        }
+    }
+}
+
+/// All of an application's [event definitions](EventDef).
+///
+/// Usually defined with [`event_catalog!`](crate::event_catalog).
+#[derive(Clone, Copy, Debug)]
+pub struct EventCatalog {
+    /// The name of the application.
+    pub app: &'static str,
+
+    /// The application's events.
+    pub events: &'static [EventDef],
+}
+
+/// The first line of the text form of an [`EventCatalog`].
+const BLOB_HEADER: &[u8] = b"n2o4-event-catalog 1\n";
+
+impl EventCatalog {
+    /// Creates a catalog of application `app`'s events.
+    #[inline]
+    pub const fn new(app: &'static str, events: &'static [EventDef]) -> Self {
+        EventCatalog { app, events }
+    }
+
+    /// Returns the definition of the event with ID `id`, if any.
+    #[inline]
+    pub fn get(&self, id: u16) -> Option<&'static EventDef> {
+        self.events.iter().find(|e| e.id == id)
+    }
+
+    /// Returns whether two of the catalog's events have the same ID.
+    pub const fn has_duplicate_ids(&self) -> bool {
+        let mut i = 0;
+        while i < self.events.len() {
+            let mut j = i + 1;
+            while j < self.events.len() {
+                if self.events[i].id == self.events[j].id {
+                    return true;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        false
+    }
+
+    /// Returns the length, in bytes, of the catalog's [text form](Self::to_blob).
+    pub const fn blob_len(&self) -> usize {
+        self.write_blob([]).1
+    }
+
+    /// Returns the text form of the catalog:
+    /// a header line (`n2o4-event-catalog 1`), a line `app<TAB><app name>`,
+    /// and then a line `<ID><TAB><type><TAB><name><TAB><format string>` for each event,
+    /// where the type is `DEBUG`, `INFORMATION`, `ERROR`, or `CRITICAL`
+    /// and any tab, newline, or backslash in a string is escaped (`\t`, `\n`, or `\\`).
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `N` is the [`blob_len`](Self::blob_len).
+    pub const fn to_blob<const N: usize>(&self) -> [u8; N] {
+        let (blob, len) = self.write_blob([0; N]);
+        assert!(len == N);
+        blob
+    }
+
+    /// Writes as much of the text form as fits into `out`,
+    /// returning the result and the length of the whole.
+    const fn write_blob<const N: usize>(&self, out: [u8; N]) -> ([u8; N], usize) {
+        let (out, pos) = put_bytes(out, 0, BLOB_HEADER, false);
+        let (out, pos) = put_bytes(out, pos, b"app\t", false);
+        let (out, pos) = put_bytes(out, pos, self.app.as_bytes(), true);
+        let (mut out, mut pos) = put_bytes(out, pos, b"\n", false);
+
+        let mut i = 0;
+        while i < self.events.len() {
+            let event = &self.events[i];
+
+            // The ID, in decimal:
+            let mut digits = [0; 5];
+            let mut first = digits.len();
+            let mut id = event.id;
+            loop {
+                first -= 1;
+                digits[first] = b'0' + (id % 10) as u8;
+                id /= 10;
+                if id == 0 {
+                    break;
+                }
+            }
+            while first < digits.len() {
+                (out, pos) = put_bytes(out, pos, &[digits[first]], false);
+                first += 1;
+            }
+
+            (out, pos) = put_bytes(out, pos, b"\t", false);
+            (out, pos) = put_bytes(out, pos, type_name(event.event_type).as_bytes(), false);
+            (out, pos) = put_bytes(out, pos, b"\t", false);
+            (out, pos) = put_bytes(out, pos, event.name.as_bytes(), true);
+            (out, pos) = put_bytes(out, pos, b"\t", false);
+
+            // The format string, less its NUL:
+            let format = event.format.as_bytes();
+            let mut j = 0;
+            while j < format.len() - 1 {
+                (out, pos) = put_bytes(out, pos, &[format[j]], true);
+                j += 1;
+            }
+
+            (out, pos) = put_bytes(out, pos, b"\n", false);
+            i += 1;
+        }
+
+        (out, pos)
+    }
+
+    /// Writes the catalog as a JSON object, e.g.:
+    ///
+    /// ```json
+    /// {"app":"SAMPLE_APP","events":[{"id":1,"type":"INFORMATION","name":"INIT_EID","format":"..."}]}
+    /// ```
+    pub fn write_json<W: fmt::Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        w.write_str("{\"app\":")?;
+        write_json_str(w, self.app)?;
+        w.write_str(",\"events\":[")?;
+        for (i, event) in self.events.iter().enumerate() {
+            if i > 0 {
+                w.write_char(',')?;
+            }
+            write!(
+                w,
+                "{{\"id\":{},\"type\":\"{}\",\"name\":",
+                event.id,
+                type_name(event.event_type)
+            )?;
+            write_json_str(w, event.name)?;
+            w.write_str(",\"format\":")?;
+            write_json_str(w, event.format())?;
+            w.write_char('}')?;
+        }
+        w.write_str("]}")
+    }
+}
+
+/// Writes `bytes` (escaping tabs, newlines, and backslashes if `escape`)
+/// to `out` starting at `pos`, as far as they fit,
+/// and returns the result and the position after them.
+const fn put_bytes<const N: usize>(
+    mut out: [u8; N],
+    mut pos: usize,
+    bytes: &[u8],
+    escape: bool,
+) -> ([u8; N], usize) {
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let escaped = match b {
+            b'\t' if escape => b't',
+            b'\n' if escape => b'n',
+            b'\\' if escape => b'\\',
+            _ => 0,
+        };
+
+        if escaped != 0 {
+            if pos < N {
+                out[pos] = b'\\';
+            }
+            if pos + 1 < N {
+                out[pos + 1] = escaped;
+            }
+            pos += 2;
+        } else {
+            if pos < N {
+                out[pos] = b;
+            }
+            pos += 1;
+        }
+        i += 1;
+    }
+    (out, pos)
+}
+
+/// The name used for `event_type` in catalogs.
+const fn type_name(event_type: EventType) -> &'static str {
+    match event_type {
+        EventType::Debug => "DEBUG",
+        EventType::Information => "INFORMATION",
+        EventType::Error => "ERROR",
+        EventType::Critical => "CRITICAL",
+    }
+}
+
+/// Writes `s` as a JSON string.
+fn write_json_str<W: fmt::Write + ?Sized>(w: &mut W, s: &str) -> fmt::Result {
+    w.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => w.write_str("\\\"")?,
+            '\\' => w.write_str("\\\\")?,
+            '\n' => w.write_str("\\n")?,
+            '\t' => w.write_str("\\t")?,
+            c if (c as u32) < 0x20 => write!(w, "\\u{:04x}", c as u32)?,
+            c => w.write_char(c)?,
+        }
+    }
+    w.write_char('"')
+}
+
+/// Defines an application's events:
+/// an [`EventDef`] constant for each,
+/// and a static [`EventCatalog`] of them all.
+///
+/// Each event is given as `<visibility> const <NAME> = (<ID>, <EventType variant>, <format string>);`,
+/// where the format string is a string literal (without a terminating NUL).
+/// Compilation fails if two events have the same ID.
+///
+/// See the [`catalog`](crate::cfe::catalog) module documentation for an example
+/// and for how to get the catalog to ground tools.
+#[macro_export]
+macro_rules! event_catalog {
+    (
+        $(#[$cmeta:meta])*
+        $cvis:vis static $catalog:ident for $app:literal;
+
+        $(
+            $(#[$meta:meta])*
+            $vis:vis const $name:ident = ($id:expr, $event_type:ident, $fmt:literal);
+        )*
+    ) => {
+        $(
+            $(#[$meta])*
+            $vis const $name: $crate::cfe::catalog::EventDef = $crate::cfe::catalog::EventDef::new(
+                $id,
+                $crate::cfe::evs::EventType::$event_type,
+                ::core::stringify!($name),
+                ::core::concat!($fmt, "\0"),
+            );
+        )*
+
+        $(#[$cmeta])*
+        $cvis static $catalog: $crate::cfe::catalog::EventCatalog =
+            $crate::cfe::catalog::EventCatalog::new($app, &[ $($name),* ]);
+
+        const _: () = {
+            const CATALOG: $crate::cfe::catalog::EventCatalog =
+                $crate::cfe::catalog::EventCatalog::new($app, &[ $($name),* ]);
+            const LEN: usize = CATALOG.blob_len();
+
+            ::core::assert!(!CATALOG.has_duplicate_ids(), "duplicate event ID in event catalog");
+
+            #[used]
+            #[cfg_attr(
+                not(any(target_vendor = "apple", target_os = "windows")),
+                link_section = ".n2o4_event_catalog"
+            )]
+            static BLOB: [u8; LEN] = CATALOG.to_blob::<LEN>();
+        };
+    };
+}
@@ -0,0 +1,402 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Command ingest.
+//!
+//! A [`CommandTable`] maps (message ID, function code) pairs
+//! to handlers taking the application's state and a typed [`Command`].
+//! [Dispatching](CommandTable::dispatch) a message through the table
+//! checks its length against the handler's command type,
+//! calls the handler,
+//! updates the application's [command counters](HkCounters),
+//! and sends an error event for anything that goes wrong.
+//!
+//! Tables are declared with [`command_table!`](crate::command_table):
+//!
+//! ```rust,ignore
+//! const COMMANDS: CommandTable<MyApp> = n2o4::command_table! {
+//!     state: MyApp,
+//!     events: CommandEventIds { invalid_code: 10, invalid_length: 11, failed: 12 },
+//!     commands: [
+//!         (MY_APP_CMD_MID, 0, (), MyApp::noop),
+//!         (MY_APP_CMD_MID, 1, (), MyApp::reset_counters),
+//!         (MY_APP_CMD_MID, 2, SetModeArgs, MyApp::set_mode),
+//!     ],
+//! };
+//!
+//! impl MyApp {
+//!     fn set_mode(&mut self, cmd: &Command<SetModeArgs>) -> Result<(), Status> {
+//!         // ...
+//!     }
+//! }
+//!
+//! // then, in the main loop:
+//! if !COMMANDS.dispatch(&mut app, msg, hk.counters_mut(), &events)? {
+//!     // not a command in the table
+//! }
+//! ```
+//!
+//! The no-op and reset-counters commands every cFS application has
+//! can be left to the table, by declaring it with a [`StandardCommands`]
+//! (after `events`) instead of entries for them:
+//!
+//! ```rust,ignore
+//!     standard: StandardCommands {
+//!         msg_id: MY_APP_CMD_MID,
+//!         noop_code: NOOP_CC,
+//!         reset_counters_code: RESET_COUNTERS_CC,
+//!         noop_event: 1,
+//!         reset_counters_event: 2,
+//!         version: [1, 0, 0],
+//!         on_reset_counters: Some(MyApp::reset_counters),
+//!     },
+//! ```
+
+use super::evs::{EventSender, EventType};
+use super::hk::HkCounters;
+use super::msg::{Command, FunctionCode, Message};
+use super::sb::MsgId_Atom;
+use super::Status;
+use printf_wrap::PrintfFmt;
+
+/// Format string for the no-op command's event.
+const NOOP_FMT: PrintfFmt<(u32, u32, u32)> =
+    crate::printf_fmt!(u32, u32, u32; "No-op command: version %u.%u.%u");
+
+/// Format string for the event about a command with a function code not in the table.
+const INVALID_CODE_FMT: PrintfFmt<(MsgId_Atom, u32)> =
+    crate::printf_fmt!(MsgId_Atom, u32; "Invalid command code: MID = 0x%04X, CC = %u");
+
+/// Format string for the event about a command of the wrong length.
+const INVALID_LENGTH_FMT: PrintfFmt<(MsgId_Atom, u32, u32, u32)> = crate::printf_fmt!(
+    MsgId_Atom, u32, u32, u32;
+    "Invalid command length: MID = 0x%04X, CC = %u, Len = %u, Expected = %u"
+);
+
+/// Format string for the event about a command whose handler failed.
+const FAILED_FMT: PrintfFmt<(MsgId_Atom, u32, u32)> = crate::printf_fmt!(
+    MsgId_Atom, u32, u32;
+    "Command failed: MID = 0x%04X, CC = %u, Status = 0x%08X"
+);
+
+/// The function code conventionally used for the no-op command.
+pub const NOOP_CC: FunctionCode = 0;
+
+/// The function code conventionally used for the reset-counters command.
+pub const RESET_COUNTERS_CC: FunctionCode = 1;
+
+/// The event IDs a [`CommandTable`] uses for the error events it sends.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CommandEventIds {
+    /// Event ID for a command with a function code not in the table.
+    pub invalid_code: u16,
+
+    /// Event ID for a command of the wrong length for its function code.
+    pub invalid_length: u16,
+
+    /// Event ID for a command whose handler returned an error.
+    pub failed: u16,
+}
+
+/// The standard commands a [`CommandTable`] can handle itself,
+/// following cFS conventions:
+///
+/// * The no-op command counts as accepted, and sends an
+///   [`Information`](EventType::Information) event with the application's version.
+/// * The reset-counters command zeroes the [command counters](HkCounters)
+///   (and so isn't counted itself), calls `on_reset_counters` (if given)
+///   to reset the application's own counters, and sends a [`Debug`](EventType::Debug) event.
+///
+/// Both commands must have no payload;
+/// otherwise they're rejected as with any command of the wrong length.
+pub struct StandardCommands<S> {
+    /// The message ID of the commands.
+    pub msg_id: MsgId_Atom,
+
+    /// The function code of the no-op command (usually [`NOOP_CC`]).
+    pub noop_code: FunctionCode,
+
+    /// The function code of the reset-counters command (usually [`RESET_COUNTERS_CC`]).
+    pub reset_counters_code: FunctionCode,
+
+    /// Event ID for the no-op command's event.
+    pub noop_event: u16,
+
+    /// Event ID for the reset-counters command's event.
+    pub reset_counters_event: u16,
+
+    /// The application's version (major, minor, revision), reported by the no-op command.
+    pub version: [u32; 3],
+
+    /// Called on the application state by the reset-counters command.
+    pub on_reset_counters: Option<fn(&mut S)>,
+}
+
+impl<S> Clone for StandardCommands<S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S> Copy for StandardCommands<S> {}
+
+/// One entry of a [`CommandTable`].
+///
+/// These are generally created using [`command_table!`](crate::command_table).
+pub struct CommandEntry<S> {
+    msg_id:   MsgId_Atom,
+    fcn_code: FunctionCode,
+    size:     usize,
+    handler:  fn(&mut S, &Message) -> Result<(), Status>,
+}
+
+impl<S> CommandEntry<S> {
+    /// Creates an entry for commands with message ID `msg_id` and function code `fcn_code`,
+    /// which must be the size of a [`Command<T>`].
+    ///
+    /// `handler` is called on such commands;
+    /// it should [cast](Message::try_cast_cmd) the message to a `Command<T>`.
+    #[inline]
+    pub const fn new<T: Copy>(
+        msg_id: MsgId_Atom,
+        fcn_code: FunctionCode,
+        handler: fn(&mut S, &Message) -> Result<(), Status>,
+    ) -> Self {
+        CommandEntry {
+            msg_id,
+            fcn_code,
+            size: core::mem::size_of::<Command<T>>(),
+            handler,
+        }
+    }
+}
+
+/// A table of command handlers, keyed by (message ID, function code).
+///
+/// `S` is the type of the application state passed to handlers.
+pub struct CommandTable<S: 'static> {
+    event_ids: CommandEventIds,
+    entries:   &'static [CommandEntry<S>],
+    standard:  Option<StandardCommands<S>>,
+}
+
+impl<S> CommandTable<S> {
+    /// Creates a command table from its entries.
+    #[inline]
+    pub const fn new(event_ids: CommandEventIds, entries: &'static [CommandEntry<S>]) -> Self {
+        CommandTable {
+            event_ids,
+            entries,
+            standard: None,
+        }
+    }
+
+    /// Returns the table, but also handling the [standard commands](StandardCommands).
+    #[inline]
+    pub const fn with_standard_commands(self, standard: StandardCommands<S>) -> Self {
+        CommandTable {
+            event_ids: self.event_ids,
+            entries:   self.entries,
+            standard:  Some(standard),
+        }
+    }
+
+    /// Returns whether any commands in the table have message ID `msg_id`.
+    #[inline]
+    pub fn handles(&self, msg_id: MsgId_Atom) -> bool {
+        self.standard.map_or(false, |standard| standard.msg_id == msg_id)
+            || self.entries.iter().any(|e| e.msg_id == msg_id)
+    }
+
+    /// Returns an iterator over the distinct message IDs of the commands in the table
+    /// (e.g., for subscribing to them).
+    pub fn msg_ids(&self) -> impl Iterator<Item = MsgId_Atom> + '_ {
+        let entries = self.entries;
+        let standard = self
+            .standard
+            .map(|standard| standard.msg_id)
+            .filter(|&msg_id| !entries.iter().any(|e| e.msg_id == msg_id));
+        entries
+            .iter()
+            .enumerate()
+            .filter(move |&(i, e)| !entries[..i].iter().any(|prev| prev.msg_id == e.msg_id))
+            .map(|(_, e)| e.msg_id)
+            .chain(standard)
+    }
+
+    /// If `msg`'s message ID is in the table, processes it as a command and returns `Ok(true)`;
+    /// otherwise returns `Ok(false)`, leaving `msg` for the caller to handle.
+    ///
+    /// Processing a command means finding the entry for its function code,
+    /// checking the command's length, and calling the entry's handler on `state` and the command.
+    /// If all of that succeeds, the command is counted as accepted in `counters`;
+    /// otherwise, it is counted as rejected, and an error event is sent using `events`.
+    ///
+    /// An `Err` is only returned if `msg`'s header couldn't be read.
+    ///
+    /// Wraps `CFE_MSG_GetMsgId`, `CFE_MSG_GetFcnCode`, `CFE_MSG_GetSize`, and `CFE_EVS_SendEvent`.
+    #[doc(alias("CFE_MSG_GetMsgId", "CFE_MSG_GetFcnCode", "CFE_MSG_GetSize"))]
+    #[doc(alias = "CFE_EVS_SendEvent")]
+    pub fn dispatch(
+        &self,
+        state: &mut S,
+        msg: &Message,
+        counters: &mut HkCounters,
+        events: &EventSender,
+    ) -> Result<bool, Status> {
+        let msg_id = MsgId_Atom::from(msg.msgid()?);
+        if !self.handles(msg_id) {
+            return Ok(false);
+        }
+
+        let fcn_code = msg.fcn_code()?;
+
+        if let Some(standard) = self.standard.filter(|standard| standard.msg_id == msg_id) {
+            if fcn_code == standard.noop_code || fcn_code == standard.reset_counters_code {
+                let size = msg.size()?;
+                if size != core::mem::size_of::<Command<()>>() {
+                    self.reject_length(
+                        msg_id,
+                        fcn_code,
+                        size,
+                        core::mem::size_of::<Command<()>>(),
+                        counters,
+                        events,
+                    );
+                } else if fcn_code == standard.noop_code {
+                    counters.command_accepted();
+                    let _ = events.send_event(
+                        standard.noop_event,
+                        EventType::Information,
+                        NOOP_FMT,
+                        (standard.version[0], standard.version[1], standard.version[2]),
+                    );
+                } else {
+                    *counters = HkCounters::default();
+                    if let Some(on_reset_counters) = standard.on_reset_counters {
+                        on_reset_counters(state);
+                    }
+                    let _ = events.send_event_str(
+                        standard.reset_counters_event,
+                        EventType::Debug,
+                        "Reset counters command",
+                    );
+                }
+                return Ok(true);
+            }
+        }
+
+        let entry = self.entries.iter().find(|e| e.msg_id == msg_id && e.fcn_code == fcn_code);
+
+        let entry = match entry {
+            Some(entry) => entry,
+            None => {
+                counters.command_rejected();
+                let _ = events.send_event(
+                    self.event_ids.invalid_code,
+                    EventType::Error,
+                    INVALID_CODE_FMT,
+                    (msg_id, fcn_code as u32),
+                );
+                return Ok(true);
+            }
+        };
+
+        let size = msg.size()?;
+        if size != entry.size {
+            self.reject_length(msg_id, fcn_code, size, entry.size, counters, events);
+            return Ok(true);
+        }
+
+        match (entry.handler)(state, msg) {
+            Ok(()) => counters.command_accepted(),
+            Err(status) => {
+                counters.command_rejected();
+                let _ = events.send_event(
+                    self.event_ids.failed,
+                    EventType::Error,
+                    FAILED_FMT,
+                    (msg_id, fcn_code as u32, status.as_num()),
+                );
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Counts a command of the wrong length as rejected and sends an event about it.
+    fn reject_length(
+        &self,
+        msg_id: MsgId_Atom,
+        fcn_code: FunctionCode,
+        size: usize,
+        expected: usize,
+        counters: &mut HkCounters,
+        events: &EventSender,
+    ) {
+        counters.command_rejected();
+        let _ = events.send_event(
+            self.event_ids.invalid_length,
+            EventType::Error,
+            INVALID_LENGTH_FMT,
+            (msg_id, fcn_code as u32, size as u32, expected as u32),
+        );
+    }
+}
+
+/// Casts `msg` to a [`Command<T>`] and calls `handler` on it;
+/// used by [`command_table!`](crate::command_table).
+#[doc(hidden)]
+#[inline]
+pub fn invoke<S, T: Copy>(
+    state: &mut S,
+    msg: &Message,
+    handler: fn(&mut S, &Command<T>) -> Result<(), Status>,
+) -> Result<(), Status> {
+    handler(state, msg.try_cast_cmd::<T>()?)
+}
+
+/// Declares a [`CommandTable`](crate::cfe::cmd::CommandTable).
+///
+/// Each command is given as a tuple of
+/// (message ID as a [`MsgId_Atom`](crate::cfe::sb::MsgId_Atom), function code,
+/// payload type, handler),
+/// where the handler is a `fn(&mut State, &Command<PayloadType>) -> Result<(), Status>`.
+///
+/// Optionally, `standard: <StandardCommands>,` may follow `events`,
+/// for the table to handle the [standard commands](crate::cfe::cmd::StandardCommands) itself.
+///
+/// See the [`cmd`](crate::cfe::cmd) module documentation for an example.
+#[macro_export]
+macro_rules! command_table {
+    (
+        state: $state:ty,
+        events: $events:expr,
+        standard: $standard:expr,
+        commands: [ $( ($mid:expr, $cc:expr, $t:ty, $handler:expr) ),* $(,)? ] $(,)?
+    ) => {
+        $crate::command_table! {
+            state: $state,
+            events: $events,
+            commands: [ $( ($mid, $cc, $t, $handler) ),* ],
+        }
+        .with_standard_commands($standard)
+    };
+    (
+        state: $state:ty,
+        events: $events:expr,
+        commands: [ $( ($mid:expr, $cc:expr, $t:ty, $handler:expr) ),* $(,)? ] $(,)?
+    ) => {
+        $crate::cfe::cmd::CommandTable::<$state>::new(
+            $events,
+            &[ $(
+                $crate::cfe::cmd::CommandEntry::<$state>::new::<$t>(
+                    $mid,
+                    $cc,
+                    |state, msg| $crate::cfe::cmd::invoke::<$state, $t>(state, msg, $handler),
+                )
+            ),* ],
+        )
+    };
+}
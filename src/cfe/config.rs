@@ -0,0 +1,128 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Configuration registry.
+//!
+//! Newer versions of cFE make the mission's configuration values
+//! (mission name, version strings, and the like) available at run time,
+//! so applications can read them without being compiled against the configuration headers:
+//!
+//! ```rust,ignore
+//! use n2o4::cfe::config::ConfigId;
+//!
+//! if let Some(id) = ConfigId::find_by_name(c"MISSION_NAME") {
+//!     let name: Option<CStrBuf<32>> = id.string();
+//! }
+//!
+//! n2o4::cfe::config::iterate_all(|id, name| { /* ... */ });
+//! ```
+//!
+//! This module is only available if the cFE headers `n2o4` was built against
+//! declare the `CFE_Config_*` functions.
+
+use super::ResourceId;
+use crate::sys::*;
+use crate::utils::CStrBuf;
+use core::ffi::{c_char, c_void, CStr};
+
+/// An identifier for a configuration value.
+///
+/// Wraps `CFE_ConfigId_t`.
+#[doc(alias = "CFE_ConfigId_t")]
+#[derive(Clone, Copy, Debug)]
+pub struct ConfigId {
+    pub(crate) id: CFE_ConfigId_t,
+}
+
+impl ConfigId {
+    /// Returns the ID of the configuration value named `name`, if there is one.
+    ///
+    /// Wraps `CFE_Config_GetIdByName`.
+    #[doc(alias = "CFE_Config_GetIdByName")]
+    #[inline]
+    pub fn find_by_name<S: AsRef<CStr> + ?Sized>(name: &S) -> Option<Self> {
+        let id = unsafe { CFE_Config_GetIdByName(name.as_ref().as_ptr()) };
+        ResourceId { id }.is_defined().then_some(ConfigId { id })
+    }
+
+    /// Returns the integer value of the configuration value.
+    ///
+    /// cFE returns 0 if the ID is undefined or doesn't refer to an integer value.
+    ///
+    /// Wraps `CFE_Config_GetValue`.
+    #[doc(alias = "CFE_Config_GetValue")]
+    #[inline]
+    pub fn value(self) -> u32 {
+        unsafe { CFE_Config_GetValue(self.id) }
+    }
+
+    /// Returns a copy of the string value of the configuration value,
+    /// truncated to fit in `SIZE` bytes (including the terminating NUL),
+    /// or `None` if cFE doesn't return a string.
+    ///
+    /// Wraps `CFE_Config_GetString`.
+    #[doc(alias = "CFE_Config_GetString")]
+    #[inline]
+    pub fn string<const SIZE: usize>(self) -> Option<CStrBuf<SIZE>> {
+        let s = unsafe { CFE_Config_GetString(self.id) };
+        unsafe { cstr_from_ptr(s) }.map(CStrBuf::from_cstr)
+    }
+
+    /// Returns a pointer to the object value of the configuration value,
+    /// or a null pointer if there isn't one.
+    ///
+    /// What the pointer points to depends on the configuration value;
+    /// dereferencing it is up to the caller.
+    ///
+    /// Wraps `CFE_Config_GetObjPointer`.
+    #[doc(alias = "CFE_Config_GetObjPointer")]
+    #[inline]
+    pub fn obj_pointer(self) -> *const c_void {
+        unsafe { CFE_Config_GetObjPointer(self.id) }
+    }
+
+    /// Returns the name of the configuration value, if it has one.
+    ///
+    /// Wraps `CFE_Config_GetName`.
+    #[doc(alias = "CFE_Config_GetName")]
+    #[inline]
+    pub fn name(self) -> Option<&'static CStr> {
+        unsafe { cstr_from_ptr(CFE_Config_GetName(self.id)) }
+    }
+}
+
+/// Calls `closure` with the ID and name of each configuration value.
+///
+/// Wraps `CFE_Config_IterateAll`.
+#[doc(alias = "CFE_Config_IterateAll")]
+#[inline]
+pub fn iterate_all<F: FnMut(ConfigId, &CStr)>(mut closure: F) {
+    unsafe extern "C" fn callback<F: FnMut(ConfigId, &CStr)>(
+        arg: *mut c_void,
+        id: CFE_ConfigId_t,
+        name: *const c_char,
+    ) {
+        let closure = &mut *(arg as *mut F);
+        if let Some(name) = cstr_from_ptr(name) {
+            closure(ConfigId { id }, name);
+        }
+    }
+
+    unsafe {
+        CFE_Config_IterateAll(&mut closure as *mut F as *mut c_void, Some(callback::<F>));
+    }
+}
+
+/// Converts a possibly-null pointer from cFE into a `CStr`.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a NUL-terminated string that lives forever.
+#[inline]
+unsafe fn cstr_from_ptr(ptr: *const c_char) -> Option<&'static CStr> {
+    if ptr.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(ptr))
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Host-side decoding of captured software bus messages.
+//!
+//! Ground-support tools and golden-file tests often have messages as raw bytes
+//! (from a telemetry capture, a log file, or `n2o4::mock::sb::take_sent`)
+//! rather than as buffers received from a live software bus.
+//! [`RawMessage`] reads the CCSDS headers of such bytes directly, without calling into cFE,
+//! and copies them out as the same [`Command<T>`]/[`Telemetry<T>`] types the flight software uses:
+//!
+//! ```rust,ignore
+//! let msg = RawMessage::new(&captured_bytes)?;
+//! if msg.msg_id() == MsgId::from(SAMPLE_HK_TLM_MID) {
+//!     let hk: Telemetry<SampleHk> = msg.read_tlm()?;
+//!     println!("{} commands accepted", hk.payload.command_count);
+//! }
+//! ```
+//!
+//! Message IDs are derived using cFE's default message ID mapping,
+//! in which a message's ID is the first 16 bits of its CCSDS primary header.
+
+use super::msg::{Command, FunctionCode, MsgType, Telemetry};
+use super::sb::MsgId;
+use super::Status;
+use crate::sys::*;
+use core::mem;
+
+/// Length of the CCSDS primary header, in bytes.
+const PRIMARY_HEADER_LEN: usize = 6;
+
+/// A software bus message held as raw bytes, headers included.
+#[derive(Clone, Copy, Debug)]
+pub struct RawMessage<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> RawMessage<'a> {
+    /// Checks that `bytes` holds a complete message
+    /// (as long as its CCSDS primary header says it is),
+    /// and if so, wraps it for decoding.
+    ///
+    /// Any bytes past the end of the message are ignored.
+    #[inline]
+    pub fn new(bytes: &'a [u8]) -> Result<Self, Status> {
+        if bytes.len() < PRIMARY_HEADER_LEN {
+            return Err(Status::STATUS_WRONG_MSG_LENGTH);
+        }
+
+        let msg = RawMessage { bytes };
+        let size = msg.size();
+        if bytes.len() < size {
+            return Err(Status::STATUS_WRONG_MSG_LENGTH);
+        }
+
+        Ok(RawMessage { bytes: &bytes[..size] })
+    }
+
+    /// Reads the big-endian 16-bit field at byte `offset`.
+    #[inline]
+    fn read_be16(&self, offset: usize) -> u16 {
+        u16::from_be_bytes([self.bytes[offset], self.bytes[offset + 1]])
+    }
+
+    /// Returns the message's bytes, headers included.
+    #[inline]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Returns the message ID.
+    #[inline]
+    pub fn msg_id(&self) -> MsgId {
+        MsgId {
+            id: crate::shims::CFE_SB_ValueToMsgId(self.read_be16(0) as CFE_SB_MsgId_Atom_t),
+        }
+    }
+
+    /// Returns whether the message is a command or telemetry.
+    #[inline]
+    pub fn msg_type(&self) -> MsgType {
+        if self.read_be16(0) & 0x1000 != 0 {
+            MsgType::Cmd
+        } else {
+            MsgType::Tlm
+        }
+    }
+
+    /// Returns whether the message has a secondary header.
+    #[inline]
+    pub fn has_secondary_header(&self) -> bool {
+        self.read_be16(0) & 0x0800 != 0
+    }
+
+    /// Returns the message's application process identifier (APID).
+    #[inline]
+    pub fn apid(&self) -> u16 {
+        self.read_be16(0) & 0x07ff
+    }
+
+    /// Returns the message's sequence count.
+    #[inline]
+    pub fn sequence_count(&self) -> u16 {
+        self.read_be16(2) & 0x3fff
+    }
+
+    /// Returns the total size of the message, headers included,
+    /// according to its CCSDS primary header.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.read_be16(4) as usize + PRIMARY_HEADER_LEN + 1
+    }
+
+    /// Returns the message's function code,
+    /// if it's a command with a secondary header.
+    #[inline]
+    pub fn fcn_code(&self) -> Option<FunctionCode> {
+        match (self.msg_type(), self.has_secondary_header()) {
+            (MsgType::Cmd, true) if self.bytes.len() > PRIMARY_HEADER_LEN => {
+                Some((self.bytes[PRIMARY_HEADER_LEN] & 0x7f) as FunctionCode)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the message's payload (everything after the headers).
+    #[inline]
+    pub fn payload(&self) -> Result<&'a [u8], Status> {
+        let header_length = match self.msg_type() {
+            MsgType::Cmd => mem::size_of::<CFE_MSG_CommandHeader_t>(),
+            _ => mem::size_of::<CFE_MSG_TelemetryHeader_t>(),
+        };
+
+        self.bytes.get(header_length..).ok_or(Status::STATUS_WRONG_MSG_LENGTH)
+    }
+
+    /// The backend of [`read_cmd`](Self::read_cmd) and [`read_tlm`](Self::read_tlm).
+    #[inline]
+    fn read_msg<T: Sized>(&self, msg_type: MsgType) -> Result<T, Status> {
+        if self.msg_type() != msg_type {
+            return Err(Status::MSG_WRONG_MSG_TYPE);
+        }
+
+        if self.bytes.len() != mem::size_of::<T>() {
+            return Err(Status::STATUS_WRONG_MSG_LENGTH);
+        }
+
+        Ok(unsafe { (self.bytes.as_ptr() as *const T).read_unaligned() })
+    }
+
+    /// If the message is a command of the right size, returns a copy of it as a [`Command<T>`].
+    #[inline]
+    pub fn read_cmd<T: Copy + Sized>(&self) -> Result<Command<T>, Status> {
+        self.read_msg::<Command<T>>(MsgType::Cmd)
+    }
+
+    /// If the message is telemetry of the right size, returns a copy of it as a [`Telemetry<T>`].
+    #[inline]
+    pub fn read_tlm<T: Copy + Sized>(&self) -> Result<Telemetry<T>, Status> {
+        self.read_msg::<Telemetry<T>>(MsgType::Tlm)
+    }
+}
+
+/// Splits a stream of concatenated messages (e.g., a capture file)
+/// into [`RawMessage`]s, using each message's CCSDS length field.
+///
+/// Iteration stops after the first incomplete message,
+/// which is returned as an error.
+#[derive(Clone, Debug)]
+pub struct RawMessages<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> RawMessages<'a> {
+    /// Iterates over the messages in `bytes`.
+    #[inline]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        RawMessages { bytes }
+    }
+}
+
+impl<'a> Iterator for RawMessages<'a> {
+    type Item = Result<RawMessage<'a>, Status>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        match RawMessage::new(self.bytes) {
+            Ok(msg) => {
+                self.bytes = &self.bytes[msg.size()..];
+                Some(Ok(msg))
+            }
+            Err(e) => {
+                self.bytes = &[];
+                Some(Err(e))
+            }
+        }
+    }
+}
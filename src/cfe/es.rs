@@ -3,13 +3,17 @@
 
 //! Executive Services system.
 
-use super::{ResourceId, Status};
-use crate::osal::OsalError;
+use super::{PrintfArgs, ResourceId, Status};
+use crate::handoff::{Handoff, HandshakeSync};
+use crate::osal::task::Task;
+use crate::osal::{ObjectId, ObjectTypeConvertError, OsalError};
 use crate::sys::*;
 use crate::utils::CStrBuf;
-use core::ffi::{c_char, c_void, CStr};
+use core::cell::UnsafeCell;
+use core::ffi::{c_char, c_ulong, c_void, CStr};
 use core::marker::PhantomData;
-use printf_wrap::{PrintfArgument, PrintfFmt};
+use core::sync::atomic::{AtomicU32, AtomicU8, Ordering};
+use printf_wrap::{null_str, NullString, PrintfArgument, PrintfFmt};
 
 /// The status (or requested status) of a cFE application.
 #[doc(alias = "CFE_ES_RunStatus")]
@@ -133,47 +137,44 @@ pub fn perf_log_exit(marker: u32) {
     perf_log_add(marker, 1);
 }
 
-/// Internal macro to generate _n_-adic wrappers around `CFE_ES_WriteToSysLog`.
+/// Writes a message to the cFE System Log using a format string and its arguments,
+/// the latter as a tuple:
+///
+/// ```rust,ignore
+/// write_to_syslog(PrintfFmt::new_or_panic("%s: %u errors\n\0"), (&name, count));
+/// ```
+///
+/// Wraps `CFE_ES_WriteToSysLog`.
+#[doc(alias = "CFE_ES_WriteToSysLog")]
+#[inline]
+pub fn write_to_syslog<T: PrintfArgs>(fmt: PrintfFmt<T>, args: T) -> Status {
+    unsafe { args.write_to_syslog(fmt.as_ptr()) }.into()
+}
+
+/// Internal macro to generate the deprecated _n_-adic forms of [`write_to_syslog`].
 macro_rules! wtsl_impl {
-    (@ $doc_args:expr, $name:ident, ( $($t:ident),* ), ( $($var:ident),* )) => {
-        #[doc = concat!(
-            "Writes a message to the cFE System Log using a format string and ",
-            $doc_args, ".\n",
-            "\n",
-            "Wraps `CFE_ES_WriteToSysLog`.\n",
-        )]
-        #[doc(alias = "CFE_ES_WriteToSysLog")]
+    ($name:ident, ( $($t:ident),* ), ( $($var:ident),* )) => {
+        /// Deprecated _n_-adic form of [`write_to_syslog`].
+        #[doc(hidden)]
+        #[deprecated(note = "use `write_to_syslog`, with the format arguments as a tuple")]
         #[inline]
         pub fn $name<$($t),*>(fmt: PrintfFmt<($($t,)*)>, $($var: $t),*) -> Status
             where $($t: PrintfArgument),* {
 
-            unsafe {
-                CFE_ES_WriteToSysLog(fmt.as_ptr() $(, $var.as_c_val())*)
-            }.into()
+            write_to_syslog(fmt, ($($var,)*))
         }
     };
-    ($num:expr, $name:ident, ( $($t:ident),* ), ( $($var:ident),* )) => {
-        wtsl_impl!(@ concat!(stringify!($num), " format arguments"),
-            $name, ( $($t),* ), ( $($var),* )
-        );
-    };
-    ($name:ident, ( $($t:ident),* ), ( $($var:ident),* )) => {
-        wtsl_impl!(@ "1 format argument",
-            $name, ( $($t),* ), ( $($var),* )
-        );
-    };
 }
 
-wtsl_impl!(0, write_to_syslog0, (), ());
-#[rustfmt::skip]
-wtsl_impl!(   write_to_syslog1, (A), (a));
-wtsl_impl!(2, write_to_syslog2, (A, B), (a, b));
-wtsl_impl!(3, write_to_syslog3, (A, B, C), (a, b, c));
-wtsl_impl!(4, write_to_syslog4, (A, B, C, D), (a, b, c, d));
-wtsl_impl!(5, write_to_syslog5, (A, B, C, D, E), (a, b, c, d, e));
-wtsl_impl!(6, write_to_syslog6, (A, B, C, D, E, F), (a, b, c, d, e, f));
-wtsl_impl!(7, write_to_syslog7, (A, B, C, D, E, F, G), (a, b, c, d, e, f, g));
-wtsl_impl!(8, write_to_syslog8, (A, B, C, D, E, F, G, H), (a, b, c, d, e, f, g, h));
+wtsl_impl!(write_to_syslog0, (), ());
+wtsl_impl!(write_to_syslog1, (A), (a));
+wtsl_impl!(write_to_syslog2, (A, B), (a, b));
+wtsl_impl!(write_to_syslog3, (A, B, C), (a, b, c));
+wtsl_impl!(write_to_syslog4, (A, B, C, D), (a, b, c, d));
+wtsl_impl!(write_to_syslog5, (A, B, C, D, E), (a, b, c, d, e));
+wtsl_impl!(write_to_syslog6, (A, B, C, D, E, F), (a, b, c, d, e, f));
+wtsl_impl!(write_to_syslog7, (A, B, C, D, E, F, G), (a, b, c, d, e, f, g));
+wtsl_impl!(write_to_syslog8, (A, B, C, D, E, F, G, H), (a, b, c, d, e, f, g, h));
 
 /// Writes the contents of a [`str`] to the cFE System Log.
 ///
@@ -190,6 +191,143 @@ pub fn write_to_syslog_str(msg: &str) -> Status {
     .into()
 }
 
+/// Format string for a syslog message tagged with an application name.
+const TAGGED_STR_FMT: NullString = null_str!("%s: %.*s");
+
+/// The calling application's name, as cached by [`with_app_name`].
+struct AppNameCache {
+    state:  AtomicU8,
+    app_id: UnsafeCell<CFE_ES_AppId_t>,
+    name:   UnsafeCell<CStrBuf<API_NAME_BUF_LEN>>,
+}
+
+/// `app_id` and `name` are only written once,
+/// by the task that moves `state` from `EMPTY` to `FILLING`,
+/// and only read after `state` is `READY`.
+unsafe impl Sync for AppNameCache {}
+
+impl AppNameCache {
+    const EMPTY: u8 = 0;
+    const FILLING: u8 = 1;
+    const READY: u8 = 2;
+}
+
+static APP_NAME_CACHE: AppNameCache = AppNameCache {
+    state:  AtomicU8::new(AppNameCache::EMPTY),
+    app_id: UnsafeCell::new(X_CFE_ES_APPID_UNDEFINED),
+    name:   UnsafeCell::new(CStrBuf::new_u8(b"")),
+};
+
+/// Runs `f` on the calling application's ID and name,
+/// the latter of which is fetched from cFE on first use and cached after that.
+///
+/// The cache is only used by the application that filled it,
+/// in case this crate is shared between applications.
+pub(super) fn with_app_name<T, F: FnOnce(AppId, &CStrBuf<API_NAME_BUF_LEN>) -> T>(
+    f: F,
+) -> Result<T, Status> {
+    use core::sync::atomic::Ordering::{Acquire, Relaxed, Release};
+
+    let app_id = get_app_id()?;
+    let cache = &APP_NAME_CACHE;
+
+    if cache.state.load(Acquire) == AppNameCache::READY {
+        // Safety: READY means the cache has been written, and it won't be written again.
+        let (cached_id, name) = unsafe { (*cache.app_id.get(), &*cache.name.get()) };
+        if ResourceId::from(app_id) == (ResourceId { id: cached_id }) {
+            return Ok(f(app_id, name));
+        }
+    }
+
+    let name = app_id.name()?;
+    if cache
+        .state
+        .compare_exchange(AppNameCache::EMPTY, AppNameCache::FILLING, Acquire, Relaxed)
+        .is_ok()
+    {
+        // Safety: we're the only task that got to move the state to FILLING,
+        // and no task reads the cache until it's READY.
+        unsafe {
+            *cache.app_id.get() = app_id.id;
+            *cache.name.get() = name;
+        }
+        cache.state.store(AppNameCache::READY, Release);
+    }
+
+    Ok(f(app_id, &name))
+}
+
+/// Writes the contents of a [`str`] to the cFE System Log,
+/// prefixed with the calling application's name and `": "`,
+/// following the usual cFS convention for log messages.
+///
+/// The application name is fetched from cFE on the first call, then cached.
+/// If it can't be fetched, the message is written without the prefix.
+///
+/// As with [`write_to_syslog_str`], any embedded null characters
+/// and anything after them will not get put into the log message.
+///
+/// Wraps `CFE_ES_WriteToSysLog`.
+#[doc(alias = "CFE_ES_WriteToSysLog")]
+#[inline]
+pub fn write_to_syslog_tagged(msg: &str) -> Status {
+    let tagged = with_app_name(|_, name| {
+        let s: Status = unsafe {
+            CFE_ES_WriteToSysLog(
+                TAGGED_STR_FMT.as_ptr(),
+                name.as_ptr(),
+                msg.len(),
+                msg.as_ptr() as *const c_char,
+            )
+        }
+        .into();
+        s
+    });
+
+    match tagged {
+        Ok(s) => s,
+        Err(_) => write_to_syslog_str(msg),
+    }
+}
+
+/// Writes a message, formatted using Rust's formatting machinery, to the cFE System Log.
+///
+/// This is usually invoked through the [`syslog_println`](crate::syslog_println) macro.
+///
+/// Wraps `CFE_ES_WriteToSysLog`.
+#[cfg(feature = "std")]
+#[doc(alias = "CFE_ES_WriteToSysLog")]
+#[inline]
+pub fn write_to_syslog_fmt(args: core::fmt::Arguments<'_>) -> Status {
+    write_to_syslog_str(&std::fmt::format(args))
+}
+
+/// Like [`write_to_syslog_fmt`], but with the message
+/// prefixed with the calling application's name
+/// as in [`write_to_syslog_tagged`].
+///
+/// Wraps `CFE_ES_WriteToSysLog`.
+#[cfg(feature = "std")]
+#[doc(alias = "CFE_ES_WriteToSysLog")]
+#[inline]
+pub fn write_to_syslog_fmt_tagged(args: core::fmt::Arguments<'_>) -> Status {
+    write_to_syslog_tagged(&std::fmt::format(args))
+}
+
+/// Writes a line to the cFE System Log, with the same syntax as [`println`].
+///
+/// Handy for debugging when running against a POSIX-hosted cFE.
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! syslog_println {
+    () => {
+        $crate::cfe::es::write_to_syslog_str("\n")
+    };
+    ($($arg:tt)*) => {
+        $crate::cfe::es::write_to_syslog_fmt(::core::format_args!("{}\n", ::core::format_args!($($arg)*)))
+    };
+}
+
 /// Immediately resets the cFE core and all cFE applications.
 ///
 /// Wraps `CFE_ES_ResetCFE`.
@@ -240,6 +378,105 @@ pub fn run_loop(run_status: Option<RunStatus>) -> bool {
     unsafe { CFE_ES_RunLoop(p) }
 }
 
+/// An application's request to exit, made by [`request_app_exit`].
+pub(crate) struct ExitRequest {
+    /// The ID (as an integer) of the application that made the request.
+    app_id: AtomicU32,
+
+    /// The status requested,
+    /// or `CFE_ES_RunStatus_UNDEFINED` if there's no request.
+    status: AtomicU32,
+}
+
+impl ExitRequest {
+    /// Withdraws the request, if any.
+    pub(crate) fn clear(&self) {
+        self.status.store(CFE_ES_RunStatus_CFE_ES_RunStatus_UNDEFINED, Ordering::Relaxed);
+    }
+}
+
+/// Exit requests, by [application index](AppId::to_index).
+///
+/// Each entry records which application made its request,
+/// so that, in case this crate is shared between applications,
+/// each only sees its own requests
+/// (and not those of an earlier application with the same index).
+pub(crate) static REQUESTED_EXITS: [ExitRequest; CFE_PLATFORM_ES_MAX_APPLICATIONS as usize] = {
+    #[allow(clippy::declare_interior_mutable_const)]
+    const NONE: ExitRequest = ExitRequest {
+        app_id: AtomicU32::new(0),
+        status: AtomicU32::new(CFE_ES_RunStatus_CFE_ES_RunStatus_UNDEFINED),
+    };
+    [NONE; CFE_PLATFORM_ES_MAX_APPLICATIONS as usize]
+};
+
+/// Returns the calling application's entry in [`REQUESTED_EXITS`]
+/// and its ID as an integer.
+fn exit_request() -> Result<(&'static ExitRequest, u32), Status> {
+    let app_id = get_app_id()?;
+    let request =
+        REQUESTED_EXITS.get(app_id.to_index()?).ok_or(Status::ES_ERR_RESOURCEID_NOT_VALID)?;
+    Ok((request, crate::shims::CFE_ResourceId_ToInteger(app_id.id) as u32))
+}
+
+/// Requests that the calling application exit with `exit_status`
+/// (typically [`AppExit`](RunStatus::AppExit) or [`AppError`](RunStatus::AppError)),
+/// without exiting right away as [`exit_app`] does.
+///
+/// This lets library code ask for shutdown
+/// while leaving the application's main loop in charge of when and how it happens:
+/// the request is passed to cFE by the next call to [`run_loop`]
+/// made by a [`cfe_app!`](crate::cfe_app)-generated main loop
+/// (or by any main loop that passes [`requested_app_exit()`] to [`run_loop`]),
+/// and the application then exits with `exit_status`.
+///
+/// A later request replaces an earlier one.
+/// Requests are kept per application,
+/// so one application's request doesn't affect any other application
+/// even if this crate is shared between them.
+///
+/// Wraps `CFE_ES_GetAppID` and `CFE_ES_AppID_ToIndex`.
+#[doc(alias("CFE_ES_GetAppID", "CFE_ES_AppID_ToIndex"))]
+#[inline]
+pub fn request_app_exit(exit_status: RunStatus) -> Result<(), Status> {
+    let (request, app_id) = exit_request()?;
+
+    request.clear();
+    request.app_id.store(app_id, Ordering::Relaxed);
+    request.status.store(exit_status as u32, Ordering::Release);
+    Ok(())
+}
+
+/// Returns the status passed to the calling application's last call to [`request_app_exit`],
+/// if any.
+///
+/// Wraps `CFE_ES_GetAppID` and `CFE_ES_AppID_ToIndex`.
+#[doc(alias("CFE_ES_GetAppID", "CFE_ES_AppID_ToIndex"))]
+#[inline]
+pub fn requested_app_exit() -> Option<RunStatus> {
+    use RunStatus::*;
+
+    let (request, app_id) = exit_request().ok()?;
+    let status = request.status.load(Ordering::Acquire);
+    if request.app_id.load(Ordering::Relaxed) != app_id {
+        return None;
+    }
+
+    [
+        AppError,
+        AppExit,
+        AppRun,
+        CoreAppInitError,
+        CoreAppRuntimeError,
+        SysDelete,
+        SysException,
+        SysReload,
+        SysRestart,
+    ]
+    .into_iter()
+    .find(|&s| s as u32 == status)
+}
+
 /// An identifier for cFE applications.
 ///
 /// Wraps `CFE_ES_AppId_t`.
@@ -249,6 +486,202 @@ pub struct AppId {
     pub(crate) id: CFE_ES_AppId_t,
 }
 
+impl AppId {
+    /// Returns a zero-based index for the application,
+    /// suitable for indexing arrays of per-application data
+    /// with `CFE_PLATFORM_ES_MAX_APPLICATIONS` entries.
+    ///
+    /// Wraps `CFE_ES_AppID_ToIndex`.
+    #[doc(alias = "CFE_ES_AppID_ToIndex")]
+    #[inline]
+    pub fn to_index(self) -> Result<usize, Status> {
+        let mut idx: u32 = 0;
+        let s: Status = unsafe { CFE_ES_AppID_ToIndex(self.id, &mut idx) }.into();
+        s.as_result(|| idx as usize)
+    }
+
+    /// Retrieves the name of the application.
+    ///
+    /// Wraps `CFE_ES_GetAppName`.
+    #[doc(alias = "CFE_ES_GetAppName")]
+    #[inline]
+    pub fn name(self) -> Result<CStrBuf<API_NAME_BUF_LEN>, Status> {
+        let mut name = [b'\0' as c_char; API_NAME_BUF_LEN];
+        let s: Status =
+            unsafe { CFE_ES_GetAppName(name.as_mut_ptr(), self.id, API_NAME_BUF_LEN) }.into();
+        s.as_result(|| CStrBuf::new_into(name))
+    }
+
+    /// Returns (if successful) the ID of the application named `app_name`.
+    ///
+    /// Returns [`ES_ERR_NAME_NOT_FOUND`](Status::ES_ERR_NAME_NOT_FOUND)
+    /// if no such application is loaded.
+    ///
+    /// Wraps `CFE_ES_GetAppIDByName`.
+    #[doc(alias = "CFE_ES_GetAppIDByName")]
+    #[inline]
+    pub fn find_by_name<S: AsRef<CStr> + ?Sized>(app_name: &S) -> Result<Self, Status> {
+        let mut app_id = AppId { id: X_CFE_ES_APPID_UNDEFINED };
+        let s: Status =
+            unsafe { CFE_ES_GetAppIDByName(&mut app_id.id, app_name.as_ref().as_ptr()) }.into();
+        s.as_result(|| app_id)
+    }
+
+    /// Retrieves information about the application.
+    ///
+    /// Wraps `CFE_ES_GetAppInfo`.
+    #[doc(alias = "CFE_ES_GetAppInfo")]
+    #[inline]
+    pub fn info(self) -> Result<AppInfo, Status> {
+        let mut info = core::mem::MaybeUninit::<CFE_ES_AppInfo_t>::zeroed();
+
+        let s: Status = unsafe { CFE_ES_GetAppInfo(info.as_mut_ptr(), self.id) }.into();
+        s.as_result(|| ())?;
+
+        // Safety: CFE_ES_AppInfo_t is plain data, so the all-zero bit pattern is valid.
+        let info = unsafe { info.assume_init() };
+        Ok(AppInfo {
+            app_id: AppId { id: info.ResourceId },
+            name: CStrBuf::new(&info.Name),
+            entry_point: CStrBuf::new(&info.EntryPoint),
+            file_name: CStrBuf::new(&info.FileName),
+            stack_size: info.StackSize as usize,
+            addresses: ModuleAddresses::from_info(&info),
+            priority: TaskPriority { prio: info.Priority },
+            main_task_id: TaskId { id: info.MainTaskId },
+            main_task_name: CStrBuf::new(&info.MainTaskName),
+            execution_counter: info.ExecutionCounter,
+            num_child_tasks: info.NumOfChildTasks,
+            app_type: AppType::from_raw(info.Type),
+            exception_action: ExceptionAction::from_raw(info.ExceptionAction),
+        })
+    }
+}
+
+/// Information about a cFE application, as returned by [`AppId::info`].
+///
+/// Substitutes for `CFE_ES_AppInfo_t`.
+#[doc(alias = "CFE_ES_AppInfo_t")]
+#[derive(Clone, Debug)]
+pub struct AppInfo {
+    /// The application's ID.
+    pub app_id: AppId,
+
+    /// The application's name.
+    pub name: CStrBuf<API_NAME_BUF_LEN>,
+
+    /// The name of the application's entry point.
+    pub entry_point: CStrBuf<API_NAME_BUF_LEN>,
+
+    /// The file the application was loaded from.
+    pub file_name: CStrBuf<PATH_BUF_LEN>,
+
+    /// The stack size of the application's main task, in bytes.
+    pub stack_size: usize,
+
+    /// Where the application was loaded in memory, if the system reports it.
+    pub addresses: Option<ModuleAddresses>,
+
+    /// The priority of the application's main task.
+    pub priority: TaskPriority,
+
+    /// The ID of the application's main task.
+    pub main_task_id: TaskId,
+
+    /// The name of the application's main task.
+    pub main_task_name: CStrBuf<API_NAME_BUF_LEN>,
+
+    /// The number of times the application's main task has gone through [`run_loop`]
+    /// or called [`increment_task_counter`].
+    ///
+    /// This stays at 0 until the application has finished initializing,
+    /// so it's a handy indication that the application is up and running.
+    pub execution_counter: u32,
+
+    /// The number of child tasks the application has.
+    pub num_child_tasks: u32,
+
+    /// Whether the application is part of cFE itself or was loaded separately,
+    /// or `None` if cFE reported a type these bindings don't recognize.
+    pub app_type: Option<AppType>,
+
+    /// What cFE does if the application causes an exception,
+    /// or `None` if cFE reported an action these bindings don't recognize.
+    pub exception_action: Option<ExceptionAction>,
+}
+
+impl AppInfo {
+    /// Returns whether the application has finished initializing
+    /// and is going through its main loop
+    /// (i.e., whether [`execution_counter`](Self::execution_counter) is nonzero).
+    ///
+    /// `CFE_ES_AppInfo_t` doesn't include the application's run state,
+    /// so this is the closest indication available.
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        self.execution_counter != 0
+    }
+
+    /// Returns whether cFE restarts just the application (rather than the whole processor)
+    /// if the application causes an exception.
+    #[inline]
+    pub fn restarts_on_exception(&self) -> bool {
+        self.exception_action == Some(ExceptionAction::RestartApp)
+    }
+}
+
+/// The type of a cFE application.
+#[doc(alias = "CFE_ES_AppType")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum AppType {
+    /// One of cFE's core applications.
+    #[doc(alias = "CFE_ES_AppType_CORE")]
+    Core     = CFE_ES_AppType_CFE_ES_AppType_CORE,
+
+    /// An application loaded separately from cFE.
+    #[doc(alias = "CFE_ES_AppType_EXTERNAL")]
+    External = CFE_ES_AppType_CFE_ES_AppType_EXTERNAL,
+
+    /// A library.
+    #[doc(alias = "CFE_ES_AppType_LIBRARY")]
+    Library  = CFE_ES_AppType_CFE_ES_AppType_LIBRARY,
+}
+
+impl AppType {
+    /// Converts the raw value `raw`, if it's one of the known application types.
+    #[inline]
+    fn from_raw(raw: u32) -> Option<Self> {
+        [AppType::Core, AppType::External, AppType::Library].into_iter().find(|&t| t as u32 == raw)
+    }
+}
+
+/// What cFE does when an application causes an exception.
+#[doc(alias = "CFE_ES_ExceptionAction")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum ExceptionAction {
+    /// Restart just the application.
+    #[doc(alias = "CFE_ES_ExceptionAction_RESTART_APP")]
+    RestartApp = CFE_ES_ExceptionAction_CFE_ES_ExceptionAction_RESTART_APP,
+
+    /// Restart the whole processor.
+    #[doc(alias = "CFE_ES_ExceptionAction_PROC_RESTART")]
+    ProcessorRestart = CFE_ES_ExceptionAction_CFE_ES_ExceptionAction_PROC_RESTART,
+}
+
+impl ExceptionAction {
+    /// Converts the raw value `raw`, if it's one of the known exception actions.
+    #[inline]
+    fn from_raw(raw: u8) -> Option<Self> {
+        [ExceptionAction::RestartApp, ExceptionAction::ProcessorRestart]
+            .into_iter()
+            .find(|&a| a as u32 == raw as u32)
+    }
+}
+
 impl From<AppId> for ResourceId {
     #[inline]
     fn from(app_id: AppId) -> Self {
@@ -312,6 +745,34 @@ pub fn delete_app(app_id: AppId) -> Result<(), Status> {
     s.as_result(|| ())
 }
 
+/// Asks cFE to restart the calling application.
+///
+/// This is [`restart_app`] applied to [`get_app_id()`].
+/// The restart doesn't happen immediately:
+/// once cFE has processed the request, [`run_loop`] returns `false`,
+/// at which point the application should clean up and call [`exit_app`]
+/// (as it would for any other shutdown request);
+/// cFE then starts the application afresh from the same file.
+///
+/// Wraps `CFE_ES_GetAppID` and `CFE_ES_RestartApp`.
+#[doc(alias("CFE_ES_GetAppID", "CFE_ES_RestartApp"))]
+#[inline]
+pub fn request_self_restart() -> Result<(), Status> {
+    restart_app(get_app_id()?)
+}
+
+/// Asks cFE to reload the calling application from `app_file_name`.
+///
+/// This is [`reload_app`] applied to [`get_app_id()`],
+/// and interacts with [`run_loop`] as [`request_self_restart`] does.
+///
+/// Wraps `CFE_ES_GetAppID` and `CFE_ES_ReloadApp`.
+#[doc(alias("CFE_ES_GetAppID", "CFE_ES_ReloadApp"))]
+#[inline]
+pub fn request_self_reload<S: AsRef<CStr> + ?Sized>(app_file_name: &S) -> Result<(), Status> {
+    reload_app(get_app_id()?, app_file_name)
+}
+
 /// Waits for a minimum state of the overall cFS system,
 /// or a timeout (in milliseconds), whichever comes first.
 ///
@@ -333,6 +794,21 @@ pub struct TaskId {
     pub(crate) id: CFE_ES_TaskId_t,
 }
 
+impl TaskId {
+    /// Returns a zero-based index for the task,
+    /// suitable for indexing arrays of per-task data
+    /// with `OS_MAX_TASKS` entries.
+    ///
+    /// Wraps `CFE_ES_TaskID_ToIndex`.
+    #[doc(alias = "CFE_ES_TaskID_ToIndex")]
+    #[inline]
+    pub fn to_index(self) -> Result<usize, Status> {
+        let mut idx: u32 = 0;
+        let s: Status = unsafe { CFE_ES_TaskID_ToIndex(self.id, &mut idx) }.into();
+        s.as_result(|| idx as usize)
+    }
+}
+
 impl From<TaskId> for ResourceId {
     #[inline]
     fn from(app_id: TaskId) -> Self {
@@ -340,8 +816,219 @@ impl From<TaskId> for ResourceId {
     }
 }
 
+// cFE task IDs are OSAL task IDs in disguise
+// (cf. cFE's `CFE_ES_TaskId_ToOSAL` and `CFE_ES_TaskId_FromOSAL`):
+// the integer value carried across, XORed with `CFE_RESOURCEID_MARK`
+// (which is nonzero if cFE is built with strict resource IDs).
+
+/// Equivalent to cFE's `CFE_ES_TaskId_ToOSAL`.
+impl From<TaskId> for ObjectId {
+    #[doc(alias = "CFE_ES_TaskId_ToOSAL")]
+    #[inline]
+    fn from(task_id: TaskId) -> Self {
+        let val = crate::shims::CFE_ResourceId_ToInteger(task_id.id);
+        ObjectId::from(val ^ CFE_RESOURCEID_MARK as c_ulong)
+    }
+}
+
+/// Equivalent to cFE's `CFE_ES_TaskId_FromOSAL`,
+/// but fails if the object ID isn't that of an OSAL task.
+impl TryFrom<ObjectId> for TaskId {
+    type Error = ObjectTypeConvertError;
+
+    #[doc(alias = "CFE_ES_TaskId_FromOSAL")]
+    #[inline]
+    fn try_from(oid: ObjectId) -> Result<Self, Self::Error> {
+        Task::try_from(oid).map(|task| TaskId::from(&task))
+    }
+}
+
+/// Equivalent to cFE's `CFE_ES_TaskId_FromOSAL`.
+impl From<&Task> for TaskId {
+    #[doc(alias = "CFE_ES_TaskId_FromOSAL")]
+    #[inline]
+    fn from(task: &Task) -> Self {
+        let val = c_ulong::from(task.as_id()) ^ CFE_RESOURCEID_MARK as c_ulong;
+        TaskId {
+            id: crate::shims::CFE_ResourceId_FromInteger(val),
+        }
+    }
+}
+
+/// Equivalent to cFE's `CFE_ES_TaskId_ToOSAL`,
+/// but fails if the result isn't the ID of an OSAL task
+/// (e.g., if the task ID is undefined).
+impl TryFrom<TaskId> for Task {
+    type Error = ObjectTypeConvertError;
+
+    #[doc(alias = "CFE_ES_TaskId_ToOSAL")]
+    #[inline]
+    fn try_from(task_id: TaskId) -> Result<Self, Self::Error> {
+        Task::try_from(ObjectId::from(task_id))
+    }
+}
+
+/// The size of the buffers used for cFE application and library names,
+/// including the null terminator.
+pub(crate) const API_NAME_BUF_LEN: usize = CFE_MISSION_MAX_API_LEN as usize;
+
+/// The size of the buffers used for file names in cFE,
+/// including the null terminator.
+const PATH_BUF_LEN: usize = CFE_MISSION_MAX_PATH_LEN as usize;
+
+/// An identifier for cFE libraries.
+///
+/// Wraps `CFE_ES_LibId_t`.
+#[doc(alias = "CFE_ES_LibId_t")]
+#[derive(Clone, Copy, Debug)]
+pub struct LibId {
+    pub(crate) id: CFE_ES_LibId_t,
+}
+
+impl LibId {
+    /// Returns (if successful) the ID of the loaded library named `lib_name`.
+    ///
+    /// Returns [`ES_ERR_NAME_NOT_FOUND`](Status::ES_ERR_NAME_NOT_FOUND)
+    /// if no such library is loaded.
+    ///
+    /// Wraps `CFE_ES_GetLibIDByName`.
+    #[doc(alias = "CFE_ES_GetLibIDByName")]
+    #[inline]
+    pub fn find_by_name<S: AsRef<CStr> + ?Sized>(lib_name: &S) -> Result<Self, Status> {
+        let mut lib_id = LibId { id: X_CFE_ES_LIBID_UNDEFINED };
+        let s: Status =
+            unsafe { CFE_ES_GetLibIDByName(&mut lib_id.id, lib_name.as_ref().as_ptr()) }.into();
+        s.as_result(|| lib_id)
+    }
+
+    /// Retrieves the name of the library.
+    ///
+    /// Wraps `CFE_ES_GetLibName`.
+    #[doc(alias = "CFE_ES_GetLibName")]
+    #[inline]
+    pub fn name(self) -> Result<CStrBuf<API_NAME_BUF_LEN>, Status> {
+        let mut name = [b'\0' as c_char; API_NAME_BUF_LEN];
+        let s: Status =
+            unsafe { CFE_ES_GetLibName(name.as_mut_ptr(), self.id, API_NAME_BUF_LEN) }.into();
+        s.as_result(|| CStrBuf::new_into(name))
+    }
+
+    /// Retrieves information about the library.
+    ///
+    /// Wraps `CFE_ES_GetLibInfo`.
+    #[doc(alias = "CFE_ES_GetLibInfo")]
+    #[inline]
+    pub fn info(self) -> Result<LibInfo, Status> {
+        let mut info = core::mem::MaybeUninit::<CFE_ES_AppInfo_t>::zeroed();
+
+        let s: Status = unsafe { CFE_ES_GetLibInfo(info.as_mut_ptr(), self.id) }.into();
+        s.as_result(|| ())?;
+
+        // Safety: CFE_ES_AppInfo_t is plain data, so the all-zero bit pattern is valid.
+        let info = unsafe { info.assume_init() };
+        Ok(LibInfo {
+            lib_id:      LibId { id: info.ResourceId },
+            name:        CStrBuf::new(&info.Name),
+            entry_point: CStrBuf::new(&info.EntryPoint),
+            file_name:   CStrBuf::new(&info.FileName),
+            addresses:   ModuleAddresses::from_info(&info),
+        })
+    }
+
+    /// Returns a zero-based index for the library,
+    /// suitable for indexing arrays of per-library data
+    /// with `CFE_PLATFORM_ES_MAX_LIBRARIES` entries.
+    ///
+    /// Wraps `CFE_ES_LibID_ToIndex`.
+    #[doc(alias = "CFE_ES_LibID_ToIndex")]
+    #[inline]
+    pub fn to_index(self) -> Result<usize, Status> {
+        let mut idx: u32 = 0;
+        let s: Status = unsafe { CFE_ES_LibID_ToIndex(self.id, &mut idx) }.into();
+        s.as_result(|| idx as usize)
+    }
+}
+
+impl From<LibId> for ResourceId {
+    #[inline]
+    fn from(lib_id: LibId) -> Self {
+        ResourceId { id: lib_id.id }
+    }
+}
+
+/// Information about a loaded cFE library, as returned by [`LibId::info`].
+///
+/// Substitutes for `CFE_ES_AppInfo_t`.
+#[doc(alias = "CFE_ES_AppInfo_t")]
+#[derive(Clone, Debug)]
+pub struct LibInfo {
+    /// The library's ID.
+    pub lib_id: LibId,
+
+    /// The library's name.
+    pub name: CStrBuf<API_NAME_BUF_LEN>,
+
+    /// The name of the library's initialization function.
+    pub entry_point: CStrBuf<API_NAME_BUF_LEN>,
+
+    /// The file the library was loaded from.
+    pub file_name: CStrBuf<PATH_BUF_LEN>,
+
+    /// Where the library was loaded in memory, if the system reports it.
+    pub addresses: Option<ModuleAddresses>,
+}
+
+/// The memory locations of a loaded module (application or library).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ModuleAddresses {
+    /// The address of the module's code segment.
+    pub code_address: usize,
+
+    /// The size of the module's code segment, in bytes.
+    pub code_size: usize,
+
+    /// The address of the module's data segment.
+    pub data_address: usize,
+
+    /// The size of the module's data segment, in bytes.
+    pub data_size: usize,
+
+    /// The address of the module's BSS segment.
+    pub bss_address: usize,
+
+    /// The size of the module's BSS segment, in bytes.
+    pub bss_size: usize,
+}
+
+impl ModuleAddresses {
+    /// Extracts the addresses from `info`, if they're marked as valid.
+    #[inline]
+    fn from_info(info: &CFE_ES_AppInfo_t) -> Option<Self> {
+        if info.AddressesAreValid == 0 {
+            return None;
+        }
+
+        Some(ModuleAddresses {
+            code_address: info.CodeAddress as usize,
+            code_size:    info.CodeSize as usize,
+            data_address: info.DataAddress as usize,
+            data_size:    info.DataSize as usize,
+            bss_address:  info.BSSAddress as usize,
+            bss_size:     info.BSSSize as usize,
+        })
+    }
+}
+
 /// A task priority; used for task scheduling.
 ///
+/// As with OSAL priorities, this is in reverse numeric order,
+/// so 0 is the highest priority and 255 the lowest.
+/// (Note that the derived [`Ord`] implementation compares the numeric values.)
+///
+/// This converts to and from [`osal::task::TaskPriority`](crate::osal::task::TaskPriority),
+/// so the same values can be used for cFE child tasks
+/// and with [`Task::set_priority`](crate::osal::task::Task::set_priority).
+///
 /// Wraps `CFE_ES_TaskPriority_Atom_t`.
 #[doc(alias = "CFE_ES_TaskPriority_Atom_t")]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -351,9 +1038,26 @@ pub struct TaskPriority {
 }
 
 impl TaskPriority {
+    /// The highest priority (0).
+    pub const HIGHEST: Self = Self::new(0);
+
+    /// A priority above those of the cFE core services
+    /// (60 to 70 in the default platform configuration), for time-critical tasks (40).
+    pub const CRITICAL: Self = Self::new(40);
+
+    /// A priority for ordinary application tasks,
+    /// below the cFE core services (100).
+    pub const NORMAL: Self = Self::new(100);
+
+    /// A priority for background work, below that of ordinary application tasks (200).
+    pub const BACKGROUND: Self = Self::new(200);
+
+    /// The lowest priority (255).
+    pub const LOWEST: Self = Self::new(255);
+
     /// Creates a new [`TaskPriority`] with the given numerical priority.
     #[inline]
-    pub fn new(priority: u8) -> Self {
+    pub const fn new(priority: u8) -> Self {
         // Per the Users Guide, only values 0-255 are allowed for the priority, hence the u8 argument.
         Self {
             prio: priority as CFE_ES_TaskPriority_Atom_t,
@@ -362,9 +1066,30 @@ impl TaskPriority {
 
     /// Returns the numeric value of this [`TaskPriority`].
     #[inline]
-    pub fn val(self) -> u8 {
+    pub const fn val(self) -> u8 {
         self.prio as u8
     }
+
+    /// Returns whether `self` is a higher priority than `other`
+    /// (i.e., has a lower numeric value).
+    #[inline]
+    pub const fn is_higher_than(self, other: Self) -> bool {
+        self.prio < other.prio
+    }
+}
+
+impl From<crate::osal::task::TaskPriority> for TaskPriority {
+    #[inline]
+    fn from(priority: crate::osal::task::TaskPriority) -> Self {
+        Self::new(priority)
+    }
+}
+
+impl From<TaskPriority> for crate::osal::task::TaskPriority {
+    #[inline]
+    fn from(priority: TaskPriority) -> Self {
+        priority.val()
+    }
 }
 
 /// Flags for task creation, as used by [`create_child_task`].
@@ -397,36 +1122,108 @@ impl From<TaskFlags> for u32 {
     }
 }
 
-/// A pointer used for cross-task transfer of data
-/// by [`create_child_task`] and [`task_main_func`].
-static mut TASK_FUNC_PTR: *const c_void = core::ptr::null();
+/// The slot through which [`create_child_task`] passes closures
+/// to [`task_main_func`].
+#[cfg(not(loom))]
+static CHILD_HANDOFF: Handoff = Handoff::new();
+
+#[cfg(loom)]
+loom::lazy_static! {
+    static ref CHILD_HANDOFF: Handoff = Handoff::new();
+}
+
+/// The OSAL semaphores serializing and signaling [`CHILD_HANDOFF`] handoffs.
+struct ChildHandshake {
+    mutex:  crate::osal::sync::MutSem,
+    signal: crate::osal::sync::BinSem,
+}
+
+impl ChildHandshake {
+    /// Gets handles to the semaphores, creating them if need be.
+    fn get() -> Result<Self, Status> {
+        Ok(ChildHandshake {
+            mutex:  child_mutex()?,
+            signal: child_signal_sem()?,
+        })
+    }
+}
+
+impl HandshakeSync for ChildHandshake {
+    type Error = Status;
+
+    #[inline]
+    fn locked<T, F: FnOnce() -> T>(&self, closure: F) -> Result<T, Status> {
+        self.mutex.lock(closure).map_err(|_| Status::STATUS_EXTERNAL_RESOURCE_FAIL)
+    }
+
+    #[inline]
+    fn signal(&self) {
+        if self.signal.give().is_err() {
+            crate::ffi_errors::record(crate::ffi_errors::Subsystem::Osal);
+        }
+    }
+
+    #[inline]
+    fn wait(&self) {
+        if self.signal.take().is_err() {
+            crate::ffi_errors::record(crate::ffi_errors::Subsystem::Osal);
+        }
+    }
+}
+
+/// Proof that the code holding it is running in a child task
+/// (rather than an application's main task),
+/// required by the APIs that may only be called from child tasks.
+///
+/// [`create_child_task`] hands one to the function it runs in the child task.
+/// It can't be sent to another task.
+#[derive(Debug)]
+pub struct ChildTaskContext {
+    _not_send: PhantomData<*const ()>,
+}
+
+impl ChildTaskContext {
+    /// Creates a context token for the current task.
+    ///
+    /// # Safety
+    ///
+    /// The current task must be a child task,
+    /// e.g., one started by [`create_child_task_c`].
+    #[inline]
+    pub unsafe fn new_unchecked() -> Self {
+        ChildTaskContext { _not_send: PhantomData }
+    }
+
+    /// Causes the child task to exit and be deleted by cFE.
+    ///
+    /// See [`exit_child_task`].
+    ///
+    /// Wraps `CFE_ES_ExitChildTask`.
+    #[doc(alias = "CFE_ES_ExitChildTask")]
+    #[inline]
+    pub fn exit(&self) -> Result<crate::utils::Unconstructable, Status> {
+        exit_child_task(self)
+    }
+}
 
 /// Wrapper for a Rust [`FnOnce`] to run said function in a new task.
 ///
 /// Handles the calling of `CFE_ES_ExitChildTask` so you don't have to!
 #[doc(alias = "CFE_ES_ExitChildTask")]
-extern "C" fn task_main_func<F: FnOnce() + Send + Sized + 'static>() {
-    use core::ptr::read_volatile;
-    use core::sync::atomic;
-
-    let copy_completed_semaphore = match child_signal_sem() {
-        Ok(sem) => sem,
+extern "C" fn task_main_func<F: FnOnce(ChildTaskContext) + Send + Sized + 'static>() {
+    let handshake = match ChildHandshake::get() {
+        Ok(handshake) => handshake,
         Err(_) => {
-            unreachable!("The semaphore should have been created already!");
+            unreachable!("The semaphores should have been created already!");
         }
     };
 
-    // Before the parent task called us, it acquired a lock to use TASK_FUNC_PTR
-    // and stored a pointer to the closure there. We copy it over:
-    atomic::fence(atomic::Ordering::Acquire);
-    let f: F = unsafe { read_volatile(TASK_FUNC_PTR as *const F) };
-
-    // The parent task has been blocking in order to allow us to copy over `f`.
-    // Now that we've completed that, we signal for it to continue.
-    let _ = copy_completed_semaphore.give();
+    // The parent task started us from within `CHILD_HANDOFF.send`,
+    // and is blocking until we've copied over the closure:
+    let f: F = unsafe { CHILD_HANDOFF.receive(&handshake) };
 
     // And, now that all that has been completed:
-    f();
+    f(ChildTaskContext { _not_send: PhantomData });
 
     // The thread closure has finished executing, so clean up:
     unsafe {
@@ -439,74 +1236,79 @@ extern "C" fn task_main_func<F: FnOnce() + Send + Sized + 'static>() {
 /// Tries to create a new child task.
 /// If successful, runs `function` in the child task and returns the child task's ID.
 ///
+/// `function` is passed a [`ChildTaskContext`],
+/// for use with the APIs that may only be called from child tasks.
+///
 /// The child task will have name `task_name`, run on a stack with `stack_size` bytes,
 /// run with priority `priority`, and have task flags `flags`.
 ///
 /// Wraps `CFE_ES_CreateChildTask` (and `CFE_ES_ExitChildTask` in the child task).
 #[doc(alias("CFE_ES_CreateChildTask", "CFE_ES_ExitChildTask"))]
 #[inline]
-pub fn create_child_task<F: FnOnce() + Send + Sized + 'static, S: AsRef<CStr> + ?Sized>(
+pub fn create_child_task<
+    F: FnOnce(ChildTaskContext) + Send + Sized + 'static,
+    S: AsRef<CStr> + ?Sized,
+>(
     function: F,
     task_name: &S,
     stack_size: usize,
     priority: TaskPriority,
     flags: TaskFlags,
 ) -> Result<TaskId, Status> {
-    use core::sync::atomic;
-
     let mut task_id = TaskId { id: X_CFE_RESOURCEID_UNDEFINED };
-    let fptr: &F = &function;
 
-    let copy_completed_semaphore = child_signal_sem()?;
+    let handshake = ChildHandshake::get()?;
 
-    let s = child_mutex()?
-        .lock(|| {
-            // OK, we have the lock. Time to write a pointer to the closure into the shared space:
-            unsafe {
-                TASK_FUNC_PTR = (fptr as *const F) as *const c_void;
-            }
-            atomic::fence(atomic::Ordering::Release);
-
-            let s: Status = unsafe {
-                CFE_ES_CreateChildTask(
-                    &mut task_id.id,
-                    task_name.as_ref().as_ptr(),
-                    Some(task_main_func::<F>),
-                    X_CFE_ES_TASK_STACK_ALLOCATE,
-                    stack_size,
-                    priority.prio,
-                    flags.into(),
-                )
-            }
-            .into();
-
-            if s.severity() != super::StatusSeverity::Success {
-                return s;
-            }
-
-            // Wait for the child task to finish copying the closure, then return the status:
-            let _ = copy_completed_semaphore.take();
-            s
-        })
-        .map_err(|_| Status::STATUS_EXTERNAL_RESOURCE_FAIL)?;
+    CHILD_HANDOFF.send(&handshake, function, || {
+        let s: Status = unsafe {
+            CFE_ES_CreateChildTask(
+                &mut task_id.id,
+                task_name.as_ref().as_ptr(),
+                Some(task_main_func::<F>),
+                X_CFE_ES_TASK_STACK_ALLOCATE,
+                stack_size,
+                priority.prio,
+                flags.into(),
+            )
+        }
+        .into();
 
-    s.as_result(|| ())?;
+        match s.severity() {
+            super::StatusSeverity::Success => Ok(()),
+            _ => Err(s),
+        }
+    })?;
 
+    // If we get here, the child task was successfully created
+    // and has taken ownership of the closure.
     if task_id.id == X_CFE_RESOURCEID_UNDEFINED {
         return Err(Status::ES_ERR_RESOURCEID_NOT_VALID);
     }
 
-    // If (and only if) we get here, the child task was successfully created
-    // and has copied over the closure. As it has been logically moved over to
-    // the new thread, we do *not* want to drop it here. As such:
-    core::mem::forget(function);
-
     Ok(task_id)
 }
 
 type AtomicOsalId = <osal_id_t as crate::utils::AtomicVersion>::Atomic;
 const BASE32_SYMBOLS: &[u8; 32] = b"0123456789abcdfghjklmnpqrstvwxyz";
 
+/// Bumped on each attempt to name a shared semaphore,
+/// so that tasks racing to create one don't keep picking the same name.
+static SEM_NAME_COUNTER: core::sync::atomic::AtomicUsize = core::sync::atomic::AtomicUsize::new(0);
+
+/// Returns a seed for a semaphore name, derived from the calling task's ID,
+/// the current time, and [`SEM_NAME_COUNTER`].
+fn sem_name_seed() -> usize {
+    use core::sync::atomic::Ordering::Relaxed;
+
+    let task = crate::shims::OS_ObjectIdToInteger(unsafe { OS_TaskGetId() }) as usize;
+    let now = super::time::get_time();
+
+    task.rotate_left(7)
+        .wrapping_add(now.seconds() as usize)
+        .wrapping_add(now.subseconds().rotate_right(4) as usize)
+        .wrapping_add(SEM_NAME_COUNTER.fetch_add(1, Relaxed).wrapping_mul(0x9e37_79b9))
+}
+
 /// Creates an atomic variable to hold an OSAL ID for some semaphore type
 /// and a wrapper function for getting a handle to said semaphore.
 macro_rules! get_shared_sem {
@@ -531,16 +1333,11 @@ macro_rules! get_shared_sem {
             // First off, start work on a name:
             let mut name: [c_char; MAX_NAME_LEN] = [b'\0' as c_char; MAX_NAME_LEN];
             b"n2o4-".into_iter().enumerate().for_each(|(i, val)| name[i] = *val as c_char);
-            let sp = psm::stack_pointer() as usize;
             let mut num_iter: usize = $initial_iter_value;
 
             let sem = loop {
                 // Generate a name likely to be unique:
-                let now = super::time::get_time();
-                let mut pseudo_hash = sp
-                    .wrapping_add(now.seconds() as usize)
-                    .wrapping_add(now.subseconds().rotate_right(4) as usize)
-                    .wrapping_add(num_iter);
+                let mut pseudo_hash = sem_name_seed().wrapping_add(num_iter);
 
                 for i in 5..(MAX_NAME_LEN - 1) {
                     name[i] = BASE32_SYMBOLS[pseudo_hash % 32] as c_char;
@@ -561,7 +1358,9 @@ macro_rules! get_shared_sem {
                 Err(first_sem_id) => {
                     // Someone beat us to writing a semaphore ID.
                     // We should use that one instead:
-                    let _ = sem.delete();
+                    if sem.delete().is_err() {
+                        crate::ffi_errors::record(crate::ffi_errors::Subsystem::Osal);
+                    }
                     Sem { id: first_sem_id }
                 }
             })
@@ -577,7 +1376,8 @@ get_shared_sem!(child_signal_sem, crate::osal::sync::BinSem, CHILD_SIGNAL_SEM_ID
 /// This is a little faster than [`create_child_task`] and uses less resources,
 /// but unlike [`create_child_task`], this does not accept Rust-style closures as values of `function`.
 ///
-/// `function` should call `CFE_ES_ExitChildTask` (or [`exit_child_task`] if written in Rust)
+/// `function` should call `CFE_ES_ExitChildTask` (or [`exit_child_task`] if written in Rust,
+/// with a context from [`ChildTaskContext::new_unchecked`])
 /// at the end of its execution.
 ///
 /// Wraps `CFE_ES_CreateChildTask`.
@@ -614,7 +1414,7 @@ pub fn create_child_task_c<S: AsRef<CStr> + ?Sized>(
     }
 }
 
-/// When called from a child task, causes the child task to exit and be deleted by cFE.
+/// Causes the child task `_ctx` is for (the current task) to exit and be deleted by cFE.
 ///
 /// Unless an error occurs, this does not return.
 ///
@@ -625,7 +1425,7 @@ pub fn create_child_task_c<S: AsRef<CStr> + ?Sized>(
 /// Wraps `CFE_ES_ExitChildTask`.
 #[doc(alias = "CFE_ES_ExitChildTask")]
 #[inline]
-pub fn exit_child_task() -> Result<crate::utils::Unconstructable, Status> {
+pub fn exit_child_task(_ctx: &ChildTaskContext) -> Result<crate::utils::Unconstructable, Status> {
     unsafe {
         CFE_ES_ExitChildTask();
     }
@@ -648,6 +1448,282 @@ pub fn increment_task_counter() {
     }
 }
 
+/// A flag for asking [worker tasks](spawn_worker) to stop.
+///
+/// Workers check it through [`WorkerContext::should_continue`].
+/// As it must outlive the workers, it's usually a `static`:
+///
+/// ```rust,ignore
+/// static STOP_WORKERS: StopFlag = StopFlag::new();
+/// ```
+#[derive(Debug)]
+pub struct StopFlag {
+    stop: core::sync::atomic::AtomicBool,
+}
+
+impl StopFlag {
+    /// Creates a flag with no stop requested.
+    #[inline]
+    pub const fn new() -> Self {
+        StopFlag {
+            stop: core::sync::atomic::AtomicBool::new(false),
+        }
+    }
+
+    /// Asks the workers checking this flag to stop.
+    #[inline]
+    pub fn request_stop(&self) {
+        self.stop.store(true, core::sync::atomic::Ordering::Release);
+    }
+
+    /// Returns whether a stop has been requested.
+    #[inline]
+    pub fn is_stop_requested(&self) -> bool {
+        self.stop.load(core::sync::atomic::Ordering::Acquire)
+    }
+
+    /// Clears any stop request (e.g., before starting new workers).
+    #[inline]
+    pub fn reset(&self) {
+        self.stop.store(false, core::sync::atomic::Ordering::Release);
+    }
+}
+
+impl Default for StopFlag {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// What a [worker task](spawn_worker)'s function is given
+/// to follow the conventions for child tasks.
+#[derive(Debug)]
+pub struct WorkerContext {
+    stop:  Option<&'static StopFlag>,
+    child: ChildTaskContext,
+}
+
+impl WorkerContext {
+    /// Returns whether the worker should go around its loop again
+    /// (that is, whether no stop has been requested through its [`StopFlag`], if any),
+    /// after [incrementing](increment_task_counter) the task's execution counter
+    /// to show it's still alive.
+    ///
+    /// This is meant to be called once per iteration, as the main task does with [`run_loop`]:
+    ///
+    /// ```rust,ignore
+    /// while ctx.should_continue() {
+    ///     // ...
+    /// }
+    /// ```
+    ///
+    /// Wraps `CFE_ES_IncrementTaskCounter`.
+    #[doc(alias = "CFE_ES_IncrementTaskCounter")]
+    #[inline]
+    pub fn should_continue(&mut self) -> bool {
+        increment_task_counter();
+        !self.stop_requested()
+    }
+
+    /// Returns whether a stop has been requested through the worker's [`StopFlag`], if any.
+    #[inline]
+    pub fn stop_requested(&self) -> bool {
+        self.stop.map_or(false, StopFlag::is_stop_requested)
+    }
+
+    /// Increments the task's execution counter, as shown in ES's task info,
+    /// without checking for a stop request.
+    ///
+    /// Wraps `CFE_ES_IncrementTaskCounter`.
+    #[doc(alias = "CFE_ES_IncrementTaskCounter")]
+    #[inline]
+    pub fn increment_task_counter(&self) {
+        increment_task_counter();
+    }
+
+    /// Returns the worker's [`ChildTaskContext`].
+    #[inline]
+    pub fn child_task(&self) -> &ChildTaskContext {
+        &self.child
+    }
+}
+
+/// Format string for the event about a [`Worker`]'s function failing.
+const WORKER_FAILED_EVENT_FMT: PrintfFmt<(&CStr, u32)> =
+    crate::printf_fmt!(&CStr, u32; "Worker task %s failed: 0x%08X");
+
+/// Format string for the system-log message about a [`Worker`]'s function failing.
+const WORKER_FAILED_SYSLOG_FMT: PrintfFmt<(&CStr, u32)> =
+    crate::printf_fmt!(&CStr, u32; "Worker task %s failed: 0x%08X\n");
+
+/// A child task to be started by [`spawn`](Self::spawn),
+/// running a function according to the conventions for child tasks:
+/// checking a [`StopFlag`] and incrementing the task's execution counter
+/// through a [`WorkerContext`],
+/// and reporting the function's returning an error (as an event or in the System Log).
+///
+/// [`spawn_worker`] covers the common case.
+#[derive(Debug)]
+pub struct Worker<'a, S: AsRef<CStr> + ?Sized> {
+    task_name:     &'a S,
+    stack_size:    usize,
+    priority:      TaskPriority,
+    flags:         TaskFlags,
+    stop:          Option<&'static StopFlag>,
+    failure_event: Option<(super::evs::EventSender, u16)>,
+}
+
+impl<'a, S: AsRef<CStr> + ?Sized> Worker<'a, S> {
+    /// Describes a worker task with name `task_name`, `stack_size` bytes of stack,
+    /// and priority `priority`.
+    #[inline]
+    pub fn new(task_name: &'a S, stack_size: usize, priority: TaskPriority) -> Self {
+        Worker {
+            task_name,
+            stack_size,
+            priority,
+            flags: TaskFlags::new_empty(),
+            stop: None,
+            failure_event: None,
+        }
+    }
+
+    /// Sets the worker's task flags.
+    #[inline]
+    pub fn flags(mut self, flags: TaskFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Has the worker check `stop` in [`WorkerContext::should_continue`].
+    #[inline]
+    pub fn stop_flag(mut self, stop: &'static StopFlag) -> Self {
+        self.stop = Some(stop);
+        self
+    }
+
+    /// Has the worker report its function's returning an error
+    /// as an error event with ID `event_id`, rather than in the System Log.
+    #[inline]
+    pub fn failure_event(mut self, sender: &super::evs::EventSender, event_id: u16) -> Self {
+        self.failure_event = Some((sender.clone(), event_id));
+        self
+    }
+
+    /// Tries to start the worker task, running `function` in it,
+    /// and returns the task's ID.
+    ///
+    /// If `function` returns an error, it's reported (along with the task's name),
+    /// after which the task exits.
+    ///
+    /// Wraps `CFE_ES_CreateChildTask` (and, in the child task,
+    /// `CFE_ES_IncrementTaskCounter`, `CFE_EVS_SendEvent` or `CFE_ES_WriteToSysLog`,
+    /// and `CFE_ES_ExitChildTask`).
+    #[doc(alias = "CFE_ES_CreateChildTask")]
+    #[inline]
+    pub fn spawn<F, E>(self, function: F) -> Result<TaskId, Status>
+    where
+        F: FnOnce(&mut WorkerContext) -> Result<(), E> + Send + 'static,
+        E: Into<crate::error::Error>,
+    {
+        let name = self.task_name.as_ref().to_bytes_with_nul();
+        let name: CStrBuf<API_NAME_BUF_LEN> = CStrBuf::new_u8(name);
+        let stop = self.stop;
+        let failure_event = self.failure_event;
+
+        let worker = move |child| {
+            let mut ctx = WorkerContext { stop, child };
+            if let Err(e) = function(&mut ctx) {
+                let code = e.into().as_i32() as u32;
+                match failure_event {
+                    Some((sender, event_id)) => {
+                        let _ = sender.send_event(
+                            event_id,
+                            super::evs::EventType::Error,
+                            WORKER_FAILED_EVENT_FMT,
+                            (name.as_ref(), code),
+                        );
+                    }
+                    None => {
+                        let status =
+                            write_to_syslog(WORKER_FAILED_SYSLOG_FMT, (name.as_ref(), code));
+                        crate::ffi_errors::check_cfe(
+                            crate::ffi_errors::Subsystem::Es,
+                            status.status,
+                        );
+                    }
+                }
+            }
+        };
+
+        create_child_task(worker, self.task_name, self.stack_size, self.priority, self.flags)
+    }
+}
+
+/// Tries to start a worker task (with name `task_name`, `stack_size` bytes of stack,
+/// and priority `priority`) running `function`, and returns the task's ID.
+///
+/// `function` should loop on [`WorkerContext::should_continue`];
+/// if it returns an error, that's reported in the System Log.
+/// For a stop flag or event reporting, use [`Worker`].
+///
+/// ```rust,ignore
+/// spawn_worker(c"SAMPLE_WORKER", 16384, TaskPriority::new(120), |ctx| {
+///     while ctx.should_continue() {
+///         poll_device()?;
+///         delay(100)?;
+///     }
+///     Ok::<(), Error>(())
+/// })?;
+/// ```
+///
+/// Wraps `CFE_ES_CreateChildTask`.
+#[doc(alias = "CFE_ES_CreateChildTask")]
+#[inline]
+pub fn spawn_worker<F, E, S>(
+    task_name: &S,
+    stack_size: usize,
+    priority: TaskPriority,
+    function: F,
+) -> Result<TaskId, Status>
+where
+    F: FnOnce(&mut WorkerContext) -> Result<(), E> + Send + 'static,
+    E: Into<crate::error::Error>,
+    S: AsRef<CStr> + ?Sized,
+{
+    Worker::new(task_name, stack_size, priority).spawn(function)
+}
+
+/// A type of cyclic redundancy check (CRC) [`calculate_crc`] can compute.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum CrcType {
+    /// A 16-bit CRC (CRC-16/ARC), the only type cFE currently implements.
+    #[doc(alias = "CFE_MISSION_ES_CRC_16")]
+    Crc16 = CFE_MISSION_ES_CRC_16,
+}
+
+impl CrcType {
+    /// The mission's default CRC type.
+    #[doc(alias = "CFE_MISSION_ES_DEFAULT_CRC")]
+    pub const DEFAULT: CrcType = CrcType::Crc16;
+}
+
+/// Calculates a CRC of type `crc_type` over `data`,
+/// starting from `input_crc` (typically 0, or the result of an earlier call
+/// to continue the calculation over more data).
+///
+/// Wraps `CFE_ES_CalculateCRC`.
+#[doc(alias = "CFE_ES_CalculateCRC")]
+#[inline]
+pub fn calculate_crc(data: &[u8], input_crc: u32, crc_type: CrcType) -> u32 {
+    unsafe {
+        CFE_ES_CalculateCRC(data.as_ptr() as *const c_void, data.len(), input_crc, crc_type as u32)
+    }
+}
+
 /// A handle to a block in the Critical Data Store (CDS).
 ///
 /// Wraps `CFE_ES_CDSHandle_t`.
@@ -3,21 +3,35 @@
 
 //! Event system.
 
-use super::Status;
+use super::{PrintfArgs, Status};
 use crate::cfe::{es::AppId, time::SysTime};
 use crate::sealed_traits;
 use crate::sys::*;
+use crate::time_source::{CfsClock, TimeSource};
 use core::convert::TryFrom;
-use core::ffi::{c_char, c_void};
+use core::ffi::{c_char, c_void, CStr};
 use core::marker::PhantomData;
+use core::sync::atomic::{AtomicBool, Ordering};
 use printf_wrap::{PrintfArgument, PrintfFmt};
 
 /// A marker type to ensure you [`register`] before sending events.
+///
+/// It's zero-sized, so cloning it is free;
+/// use [`get_or_register`](Self::get_or_register) to get one
+/// wherever it's needed without registering more than once.
 #[derive(Clone, Debug)]
 pub struct EventSender {
     _x: PhantomData<u8>,
 }
 
+/// Event Services identifies the application sending an event from the calling task,
+/// and child tasks belong to the application that created them,
+/// so an `EventSender` may be sent to any of an application's tasks.
+unsafe impl Send for EventSender {}
+
+/// Event Services may be called concurrently from multiple tasks.
+unsafe impl Sync for EventSender {}
+
 /// Event-message filter definition for the binary filter scheme.
 ///
 /// `EventID` is an event ID as supplied to [`send_event_*`](`EventSender::send_event_str`).
@@ -102,11 +116,27 @@ impl FilterScheme for BinFilter {
     const SCHEME_ID: u16 = CFE_EVS_EventFilter_CFE_EVS_EventFilter_BINARY as u16;
 }
 
+/// Whether this application has registered with event services
+/// (through [`register`] or [`EventSender::assume_registered`]).
+///
+/// As each application links its own copy of `n2o4`, this is per-application.
+static REGISTERED: AtomicBool = AtomicBool::new(false);
+
+/// Forgets any registration, along with the fake cFE's.
+#[cfg(feature = "mock")]
+pub(crate) fn reset_registration() {
+    REGISTERED.store(false, Ordering::Release);
+}
+
 /// Registers the application with event services.
 ///
 /// This needs to be called before sending event messages, so "send an event"
 /// operations are implemented as methods on [`EventSender`],
-/// which is provided only by this function.
+/// which is provided only by this function
+/// (and [`EventSender::get_or_register`], which only calls it the first time).
+///
+/// Registering again replaces the application's filters,
+/// resetting their counters.
 ///
 /// Wraps `CFE_EVS_Register`.
 #[doc(alias = "CFE_EVS_Register")]
@@ -122,14 +152,20 @@ pub fn register<T: FilterScheme>(filters: &[T]) -> Result<EventSender, Status> {
     let s: Status =
         unsafe { CFE_EVS_Register(filters.as_ptr() as *const c_void, num_filters, T::SCHEME_ID) }
             .into();
-    s.as_result(|| EventSender { _x: PhantomData })
+    s.as_result(|| {
+        REGISTERED.store(true, Ordering::Release);
+        EventSender { _x: PhantomData }
+    })
 }
 
 /// The classification of an event message, analogous to the
 /// [syslog](https://en.wikipedia.org/wiki/Syslog)
 /// severity level.
+///
+/// Event types are ordered by severity,
+/// from [`Debug`](Self::Debug) (least severe) to [`Critical`](Self::Critical) (most).
 #[doc(alias = "CFE_EVS_EventType")]
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
 #[repr(u16)]
 #[non_exhaustive]
 pub enum EventType {
@@ -150,99 +186,164 @@ pub enum EventType {
     Critical    = CFE_EVS_EventType_CFE_EVS_EventType_CRITICAL as u16,
 }
 
-/// Internal macro for generating _n_-adic wrappers around `CFE_EVS_Send*Event*`.
+impl EventSender {
+    /// Returns an `EventSender`, first [registering](register) the application
+    /// with event services (with the filters `filters`) if that hasn't been done yet.
+    ///
+    /// If the application has already registered, `filters` is ignored.
+    /// (Tasks racing to register first may each register,
+    /// in which case the filters of the last to do so apply.)
+    ///
+    /// Wraps `CFE_EVS_Register`.
+    #[doc(alias = "CFE_EVS_Register")]
+    #[inline]
+    pub fn get_or_register<T: FilterScheme>(filters: &[T]) -> Result<EventSender, Status> {
+        if Self::is_registered() {
+            Ok(EventSender { _x: PhantomData })
+        } else {
+            register(filters)
+        }
+    }
+
+    /// Returns an `EventSender` without registering,
+    /// for when the application registered with event services some other way
+    /// (e.g., in C startup code calling `CFE_EVS_Register`).
+    ///
+    /// From then on, [`get_or_register`](Self::get_or_register) won't register either.
+    /// If the application hasn't in fact registered,
+    /// events are sent or rejected as cFE sees fit for unregistered applications
+    /// (e.g., with [`EVS_APP_NOT_REGISTERED`](Status::EVS_APP_NOT_REGISTERED)).
+    #[inline]
+    pub fn assume_registered() -> EventSender {
+        REGISTERED.store(true, Ordering::Release);
+        EventSender { _x: PhantomData }
+    }
+
+    /// Returns whether the application has registered with event services
+    /// through [`register`] or [`assume_registered`](Self::assume_registered).
+    #[inline]
+    pub fn is_registered() -> bool {
+        REGISTERED.load(Ordering::Acquire)
+    }
+
+    /// Returns an `EventSender` if the application
+    /// [has registered](Self::is_registered), without registering it otherwise.
+    #[inline]
+    pub(crate) fn if_registered() -> Option<EventSender> {
+        Self::is_registered().then_some(EventSender { _x: PhantomData })
+    }
+
+    /// Generates a software event using a format string and its arguments,
+    /// the latter as a tuple:
+    ///
+    /// ```rust,ignore
+    /// events.send_event(BAD_CMD_EID, EventType::Error, PrintfFmt::new_or_panic("bad code %u\0"), (cc,));
+    /// ```
+    ///
+    /// Wraps `CFE_EVS_SendEvent`.
+    #[doc(alias = "CFE_EVS_SendEvent")]
+    #[inline]
+    pub fn send_event<T: PrintfArgs>(
+        &self,
+        event_id: u16,
+        event_type: EventType,
+        fmt: PrintfFmt<T>,
+        args: T,
+    ) -> Status {
+        unsafe { args.send_event(event_id, event_type as u16, fmt.as_ptr()) }.into()
+    }
+
+    /// Generates a software event (with the specified Application ID)
+    /// using a format string and its arguments (as a tuple).
+    ///
+    /// Wraps `CFE_EVS_SendEventWithAppID`.
+    #[doc(alias = "CFE_EVS_SendEventWithAppID")]
+    #[inline]
+    pub fn send_event_with_app_id<T: PrintfArgs>(
+        &self,
+        event_id: u16,
+        event_type: EventType,
+        app_id: AppId,
+        fmt: PrintfFmt<T>,
+        args: T,
+    ) -> Status {
+        unsafe { args.send_event_with_app_id(event_id, event_type as u16, app_id.id, fmt.as_ptr()) }
+            .into()
+    }
+
+    /// Generates a software event (with a specific time tag)
+    /// using a format string and its arguments (as a tuple).
+    ///
+    /// Wraps `CFE_EVS_SendTimedEvent`.
+    #[doc(alias = "CFE_EVS_SendTimedEvent")]
+    #[inline]
+    pub fn send_timed_event<T: PrintfArgs>(
+        &self,
+        time: SysTime,
+        event_id: u16,
+        event_type: EventType,
+        fmt: PrintfFmt<T>,
+        args: T,
+    ) -> Status {
+        unsafe { args.send_timed_event(time.tm, event_id, event_type as u16, fmt.as_ptr()) }.into()
+    }
+}
+
+/// Internal macro for generating the deprecated _n_-adic forms
+/// of [`send_event`](EventSender::send_event) and friends.
 macro_rules! send_impl {
-    (@ $doc_args:expr, $se:ident, $sewai:ident, $ste:ident, ( $($t:ident),* ), ( $($var:ident),* )) => {
-        #[doc = concat!(
-            "Generates a software event using a format string and ",
-            $doc_args, ".\n",
-            "\n",
-            "Wraps `CFE_EVS_SendEvent`.\n",
-        )]
-        #[doc(alias = "CFE_EVS_SendEvent")]
+    ($se:ident, $sewai:ident, $ste:ident, ( $($t:ident),* ), ( $($var:ident),* )) => {
+        /// Deprecated _n_-adic form of [`send_event`](Self::send_event).
+        #[doc(hidden)]
+        #[deprecated(note = "use `send_event`, with the format arguments as a tuple")]
         #[inline]
         pub fn $se<$($t),*>(&self, event_id: u16, event_type: EventType, fmt: PrintfFmt<($($t,)*)>, $($var: $t),*) -> Status
             where $($t: PrintfArgument),* {
 
-            unsafe {
-                CFE_EVS_SendEvent(
-                    event_id, event_type as u16, fmt.as_ptr()
-                    $(, $var.as_c_val() )*
-                )
-            }.into()
+            self.send_event(event_id, event_type, fmt, ($($var,)*))
         }
 
-        #[doc = concat!(
-            "Generates a software event (with the specified Application ID) ",
-            "using a format string and ",
-            $doc_args, ".\n",
-            "\n",
-            "Wraps `CFE_EVS_SendEventWithAppID`.\n",
-        )]
-        #[doc(alias = "CFE_EVS_SendEventWithAppID")]
+        /// Deprecated _n_-adic form of [`send_event_with_app_id`](Self::send_event_with_app_id).
+        #[doc(hidden)]
+        #[deprecated(note = "use `send_event_with_app_id`, with the format arguments as a tuple")]
         #[inline]
         pub fn $sewai<$($t),*>(&self, event_id: u16, event_type: EventType, app_id: AppId, fmt: PrintfFmt<($($t,)*)>, $($var: $t),*) -> Status
             where $($t: PrintfArgument),* {
 
-            unsafe {
-                CFE_EVS_SendEventWithAppID(
-                    event_id, event_type as u16, app_id.id, fmt.as_ptr()
-                    $(, $var.as_c_val() )*
-                )
-            }.into()
+            self.send_event_with_app_id(event_id, event_type, app_id, fmt, ($($var,)*))
         }
 
-        #[doc = concat!(
-            "Generates a software event (with a specific time tag) ",
-            "using a format string and ",
-            $doc_args, ".\n",
-            "\n",
-            "Wraps `CFE_EVS_SendTimedEvent`.\n",
-        )]
-        #[doc(alias = "CFE_EVS_SendTimedEvent")]
+        /// Deprecated _n_-adic form of [`send_timed_event`](Self::send_timed_event).
+        #[doc(hidden)]
+        #[deprecated(note = "use `send_timed_event`, with the format arguments as a tuple")]
         #[inline]
         pub fn $ste<$($t),*>(&self, time: SysTime, event_id: u16, event_type: EventType, fmt: PrintfFmt<($($t,)*)>, $($var: $t),*) -> Status
             where $($t: PrintfArgument),* {
 
-            unsafe {
-                CFE_EVS_SendTimedEvent(
-                    time.tm, event_id, event_type as u16, fmt.as_ptr()
-                    $(, $var.as_c_val() )*
-                )
-            }.into()
+            self.send_timed_event(time, event_id, event_type, fmt, ($($var,)*))
         }
     };
-    ($num:expr, $se:ident, $sewai:ident, $ste:ident, ( $($t:ident),* ), ( $($var:ident),* )) => {
-        send_impl!(@ concat!(stringify!($num), " format arguments"),
-            $se, $sewai, $ste, ( $($t),* ), ( $($var),* )
-        );
-    };
-    ($se:ident, $sewai:ident, $ste:ident, ( $($t:ident),* ), ( $($var:ident),* )) => {
-        send_impl!(@ "1 format argument",
-            $se, $sewai, $ste, ( $($t),* ), ( $($var),* )
-        );
-    };
 }
 
 #[rustfmt::skip]
 impl EventSender {
-    send_impl!(0, send_event0, send_event_with_app_id0, send_timed_event0,
+    send_impl!(send_event0, send_event_with_app_id0, send_timed_event0,
                (), ());
-    send_impl!(   send_event1, send_event_with_app_id1, send_timed_event1,
+    send_impl!(send_event1, send_event_with_app_id1, send_timed_event1,
                (A), (a));
-    send_impl!(2, send_event2, send_event_with_app_id2, send_timed_event2,
+    send_impl!(send_event2, send_event_with_app_id2, send_timed_event2,
                (A, B), (a, b));
-    send_impl!(3, send_event3, send_event_with_app_id3, send_timed_event3,
+    send_impl!(send_event3, send_event_with_app_id3, send_timed_event3,
                (A, B, C), (a, b, c));
-    send_impl!(4, send_event4, send_event_with_app_id4, send_timed_event4,
+    send_impl!(send_event4, send_event_with_app_id4, send_timed_event4,
                (A, B, C, D), (a, b, c, d));
-    send_impl!(5, send_event5, send_event_with_app_id5, send_timed_event5,
+    send_impl!(send_event5, send_event_with_app_id5, send_timed_event5,
                (A, B, C, D, E), (a, b, c, d, e));
-    send_impl!(6, send_event6, send_event_with_app_id6, send_timed_event6,
+    send_impl!(send_event6, send_event_with_app_id6, send_timed_event6,
                (A, B, C, D, E, F), (a, b, c, d, e, f));
-    send_impl!(7, send_event7, send_event_with_app_id7, send_timed_event7,
+    send_impl!(send_event7, send_event_with_app_id7, send_timed_event7,
                (A, B, C, D, E, F, G), (a, b, c, d, e, f, g));
-    send_impl!(8, send_event8, send_event_with_app_id8, send_timed_event8,
+    send_impl!(send_event8, send_event_with_app_id8, send_timed_event8,
                (A, B, C, D, E, F, G, H), (a, b, c, d, e, f, g, h));
 }
 
@@ -268,6 +369,23 @@ impl EventSender {
         .into()
     }
 
+    /// Generates a software event with a message
+    /// formatted using Rust's formatting machinery
+    /// (e.g., `format_args!("bad value {}", x)`).
+    ///
+    /// Wraps `CFE_EVS_SendEvent`.
+    #[cfg(feature = "std")]
+    #[doc(alias = "CFE_EVS_SendEvent")]
+    #[inline]
+    pub fn send_event_fmt(
+        &self,
+        event_id: u16,
+        event_type: EventType,
+        args: core::fmt::Arguments<'_>,
+    ) -> Status {
+        self.send_event_str(event_id, event_type, &std::fmt::format(args))
+    }
+
     /// Generates a software event with the specified Application ID
     /// using a [`str`] as the message.
     ///
@@ -326,3 +444,524 @@ impl EventSender {
         .into()
     }
 }
+
+impl EventSender {
+    /// Returns a wrapper around this sender that only sends events
+    /// at least as severe as `min_severity`.
+    #[inline]
+    pub fn with_min_severity(&self, min_severity: EventType) -> SeverityFilter {
+        SeverityFilter {
+            events: self.clone(),
+            min_severity,
+        }
+    }
+}
+
+/// Sends an event with a format string and arguments given inline,
+/// returning the result of `send_event`:
+///
+/// ```rust,ignore
+/// cfe_event!(events, BAD_CMD_EID, Error, u16; "bad code %hu", cc);
+/// cfe_event!(events, STARTED_EID, Information; "started");
+/// ```
+///
+/// `events` can be an [`EventSender`] or anything else with a
+/// [`send_event`](EventSender::send_event) method, such as a [`SeverityFilter`];
+/// the event type is the name of an [`EventType`] variant;
+/// the types of the arguments come next, as for [`printf_fmt!`](crate::printf_fmt);
+/// and the format string is a string literal (without a terminating NUL).
+/// As with [`printf_fmt!`](crate::printf_fmt), the format string is checked against
+/// the argument types at compile time.
+///
+/// With `; location` after the arguments,
+/// the source file and line of the macro invocation get appended to the message
+/// (as in `"state 3 [src/lib.rs:120]"`),
+/// which is handy for tracing execution with debug events during integration:
+///
+/// ```rust,ignore
+/// cfe_event!(events, TRACE_EID, Debug, u32; "state %u", state; location);
+/// ```
+///
+/// As with any event message, Event Services truncates the result
+/// to `CFE_MISSION_EVS_MAX_MESSAGE_LENGTH` characters.
+#[macro_export]
+macro_rules! cfe_event {
+    ($events:expr, $event_id:expr, $event_type:ident $(, $t:ty)* ; $fmt:literal $(, $arg:expr)* ; location) => {{
+        const FMT: $crate::prelude::PrintfFmt<($($t,)* &::core::ffi::CStr, u32)> =
+            $crate::prelude::PrintfFmt::new_or_panic(::core::concat!($fmt, " [%s:%u]\0"));
+        const FILE: &::core::ffi::CStr =
+            $crate::cfe::evs::location_file(::core::concat!(::core::file!(), "\0"));
+        $events.send_event(
+            $event_id,
+            $crate::cfe::evs::EventType::$event_type,
+            FMT,
+            ($($arg,)* FILE, ::core::line!()),
+        )
+    }};
+    ($events:expr, $event_id:expr, $event_type:ident $(, $t:ty)* ; $fmt:literal $(, $arg:expr)* $(,)?) => {
+        $events.send_event(
+            $event_id,
+            $crate::cfe::evs::EventType::$event_type,
+            $crate::printf_fmt!($($t),* ; $fmt),
+            ($($arg,)*),
+        )
+    };
+}
+
+/// Converts `file`, which must end in its only null byte, to a [`CStr`] at compile time;
+/// used by [`cfe_event!`](crate::cfe_event).
+#[doc(hidden)]
+pub const fn location_file(file: &'static str) -> &'static CStr {
+    let bytes = file.as_bytes();
+    if bytes.is_empty() || bytes[bytes.len() - 1] != b'\0' {
+        panic!("file name not null-terminated");
+    }
+
+    let mut i = 0;
+    while i < bytes.len() - 1 {
+        if bytes[i] == b'\0' {
+            panic!("file name contains a null byte");
+        }
+        i += 1;
+    }
+
+    unsafe { CStr::from_bytes_with_nul_unchecked(bytes) }
+}
+
+/// Creates a [`PrintfFmt`] from a format string literal (without a terminating NUL)
+/// and the types of its arguments,
+/// checking the format string against the types at compile time:
+///
+/// ```rust,ignore
+/// const TEMP_FMT: PrintfFmt<(u16, i32)> = printf_fmt!(u16, i32; "sensor %hu: %d C");
+///
+/// events.send_event(TEMP_EID, EventType::Information, printf_fmt!(u32; "count %u"), (count,));
+/// ```
+///
+/// A mismatch between the format string and the types
+/// (e.g., `"%s"` with a `u32`) is a compile error,
+/// rather than a panic (or worse) when the event is sent.
+#[macro_export]
+macro_rules! printf_fmt {
+    ($($t:ty),* ; $fmt:literal) => {{
+        const FMT: $crate::prelude::PrintfFmt<($($t,)*)> =
+            $crate::prelude::PrintfFmt::new_or_panic(::core::concat!($fmt, "\0"));
+        FMT
+    }};
+}
+
+/// `"%s"`: a string.
+pub const FMT_STR: PrintfFmt<(&'static CStr,)> = printf_fmt!(&'static CStr; "%s");
+
+/// `"%u"`: an unsigned integer.
+pub const FMT_U32: PrintfFmt<(u32,)> = printf_fmt!(u32; "%u");
+
+/// `"%u %u"`: two unsigned integers.
+pub const FMT_2_U32: PrintfFmt<(u32, u32)> = printf_fmt!(u32, u32; "%u %u");
+
+/// `"%u %u %u"`: three unsigned integers.
+pub const FMT_3_U32: PrintfFmt<(u32, u32, u32)> = printf_fmt!(u32, u32, u32; "%u %u %u");
+
+/// `"%d"`: a signed integer.
+pub const FMT_I32: PrintfFmt<(i32,)> = printf_fmt!(i32; "%d");
+
+/// `"0x%08X"`: an unsigned integer in hexadecimal, as status codes are usually shown.
+pub const FMT_HEX_U32: PrintfFmt<(u32,)> = printf_fmt!(u32; "0x%08X");
+
+/// `"%s: %u"`: a string (e.g., a name) and an unsigned integer.
+pub const FMT_STR_U32: PrintfFmt<(&'static CStr, u32)> = printf_fmt!(&'static CStr, u32; "%s: %u");
+
+/// `"%s: 0x%08X"`: a string (e.g., what failed) and an unsigned integer in hexadecimal
+/// (e.g., the status it failed with).
+pub const FMT_STR_HEX_U32: PrintfFmt<(&'static CStr, u32)> =
+    printf_fmt!(&'static CStr, u32; "%s: 0x%08X");
+
+/// Format string for [`Status::log_if_error`] with a status that has a name.
+const NAMED_STATUS_FMT: PrintfFmt<(&CStr, u32)> = printf_fmt!(&CStr, u32; "%s (0x%08X)");
+
+/// Format string for [`Status::log_if_error`] with a status that doesn't have a name.
+const UNNAMED_STATUS_FMT: PrintfFmt<(u32,)> = printf_fmt!(u32; "cFE status 0x%08X");
+
+impl Status {
+    /// If `self` isn't a success, sends an event with ID `event_id` reporting it
+    /// under its cFE name (e.g., "CFE_SB_BAD_ARGUMENT (0xCA000003)"),
+    /// as an [`Error`](EventType::Error) event for
+    /// [errors](super::StatusSeverity::Error) and an
+    /// [`Information`](EventType::Information) event otherwise.
+    ///
+    /// Returns `self`, so this can be chained.
+    ///
+    /// Wraps `CFE_EVS_SendEvent`.
+    #[doc(alias = "CFE_EVS_SendEvent")]
+    #[inline]
+    pub fn log_if_error(self, events: &EventSender, event_id: u16) -> Self {
+        let event_type = match self.severity() {
+            super::StatusSeverity::Success => return self,
+            super::StatusSeverity::Informational => EventType::Information,
+            super::StatusSeverity::Error => EventType::Error,
+        };
+
+        let name =
+            self.name_with_nul().and_then(|name| CStr::from_bytes_with_nul(name.as_bytes()).ok());
+
+        let _ = match name {
+            Some(name) => {
+                events.send_event(event_id, event_type, NAMED_STATUS_FMT, (name, self.as_num()))
+            }
+            None => events.send_event(event_id, event_type, UNNAMED_STATUS_FMT, (self.as_num(),)),
+        };
+        self
+    }
+}
+
+/// Error reporting for the results of cFE calls:
+///
+/// ```rust,ignore
+/// pipe.subscribe(msg_id).log_if_error(&events, SUBSCRIBE_ERR_EID)?;
+/// ```
+///
+/// This is a [sealed trait](https://rust-lang.github.io/api-guidelines/future-proofing.html#c-sealed):
+/// it's only implemented for `Result<T, Status>`.
+pub trait StatusResultExt: sealed_traits::StatusResultExtSealed {
+    /// If `self` is an `Err`, reports the status as with [`Status::log_if_error`].
+    ///
+    /// Returns `self`, so this can be followed by `?`.
+    fn log_if_error(self, events: &EventSender, event_id: u16) -> Self;
+}
+
+impl<T> sealed_traits::StatusResultExtSealed for Result<T, Status> {}
+
+impl<T> StatusResultExt for Result<T, Status> {
+    #[inline]
+    fn log_if_error(self, events: &EventSender, event_id: u16) -> Self {
+        self.map_err(|status| status.log_if_error(events, event_id))
+    }
+}
+
+/// Wraps an [`EventSender`], dropping events less severe than a threshold
+/// before they reach Event Services.
+///
+/// This is handy for noisy debug instrumentation that's left in flight builds:
+///
+/// ```rust,ignore
+/// let mut debug_events = events.with_min_severity(EventType::Information);
+///
+/// // not sent:
+/// debug_events.send_event_str(TRACE_EID, EventType::Debug, "entering control loop");
+///
+/// // on a ground command to turn on debugging:
+/// debug_events.set_min_severity(EventType::Debug);
+/// ```
+///
+/// Unlike filtering by Event Services, the dropped events cost almost nothing,
+/// but the threshold can only be changed by the application itself.
+#[derive(Clone, Debug)]
+pub struct SeverityFilter {
+    events:       EventSender,
+    min_severity: EventType,
+}
+
+impl SeverityFilter {
+    /// Returns the wrapped [`EventSender`], for sending events regardless of severity.
+    #[inline]
+    pub fn sender(&self) -> &EventSender {
+        &self.events
+    }
+
+    /// Returns the current threshold.
+    #[inline]
+    pub fn min_severity(&self) -> EventType {
+        self.min_severity
+    }
+
+    /// Changes the threshold.
+    #[inline]
+    pub fn set_min_severity(&mut self, min_severity: EventType) {
+        self.min_severity = min_severity;
+    }
+
+    /// Returns whether an event of type `event_type` would be sent.
+    #[inline]
+    pub fn allows(&self, event_type: EventType) -> bool {
+        event_type >= self.min_severity
+    }
+
+    /// Like [`EventSender::send_event`], but only sends the event
+    /// if it's [severe enough](Self::allows).
+    ///
+    /// Returns `None` if the event was dropped.
+    ///
+    /// Wraps `CFE_EVS_SendEvent`.
+    #[doc(alias = "CFE_EVS_SendEvent")]
+    #[inline]
+    pub fn send_event<T: PrintfArgs>(
+        &self,
+        event_id: u16,
+        event_type: EventType,
+        fmt: PrintfFmt<T>,
+        args: T,
+    ) -> Option<Status> {
+        if !self.allows(event_type) {
+            return None;
+        }
+        Some(self.events.send_event(event_id, event_type, fmt, args))
+    }
+
+    /// Like [`EventSender::send_event_str`], but only sends the event
+    /// if it's [severe enough](Self::allows).
+    ///
+    /// Returns `None` if the event was dropped.
+    ///
+    /// Wraps `CFE_EVS_SendEvent`.
+    #[doc(alias = "CFE_EVS_SendEvent")]
+    #[inline]
+    pub fn send_event_str(
+        &self,
+        event_id: u16,
+        event_type: EventType,
+        msg: &str,
+    ) -> Option<Status> {
+        if !self.allows(event_type) {
+            return None;
+        }
+        Some(self.events.send_event_str(event_id, event_type, msg))
+    }
+
+    /// Like [`EventSender::send_event_fmt`], but only formats and sends the event
+    /// if it's [severe enough](Self::allows).
+    ///
+    /// Returns `None` if the event was dropped.
+    ///
+    /// Wraps `CFE_EVS_SendEvent`.
+    #[cfg(feature = "std")]
+    #[doc(alias = "CFE_EVS_SendEvent")]
+    #[inline]
+    pub fn send_event_fmt(
+        &self,
+        event_id: u16,
+        event_type: EventType,
+        args: core::fmt::Arguments<'_>,
+    ) -> Option<Status> {
+        if !self.allows(event_type) {
+            return None;
+        }
+        Some(self.events.send_event_fmt(event_id, event_type, args))
+    }
+}
+
+/// A rate limit for one event ID, enforced by an [`EventLimiter`]
+/// as a token bucket:
+/// up to `burst` events may be sent at once,
+/// after which events may be sent at an average of `per_second` per second.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct RateLimit {
+    /// The event ID the limit applies to.
+    pub event_id: u16,
+
+    /// The long-term maximum rate, in events per second.
+    pub per_second: u32,
+
+    /// The maximum number of events that may be sent in a burst.
+    pub burst: u32,
+}
+
+/// The state of one [`RateLimit`]'s token bucket.
+#[derive(Clone, Copy, Debug)]
+struct LimitState {
+    limit:        RateLimit,
+    /// Available tokens, in millionths of a token.
+    micro_tokens: u64,
+    last_refill:  SysTime,
+    suppressed:   u32,
+    last_summary: SysTime,
+}
+
+/// Format string for an [`EventLimiter`]'s summary events.
+const SUPPRESSED_FMT: PrintfFmt<(u32, u32)> =
+    printf_fmt!(u32, u32; "%u events with ID %u suppressed");
+
+/// Wraps an [`EventSender`], enforcing per-event-ID [rate limits](RateLimit)
+/// (which [`BinFilter`]s can't express).
+///
+/// Events over the limit aren't sent;
+/// instead, for each limited event ID, an informational summary event
+/// saying how many events were suppressed is sent at most once every `summary_interval` seconds
+/// (on a later send attempt or call to [`report_suppressed`](Self::report_suppressed)).
+///
+/// ```rust,ignore
+/// let mut limiter = EventLimiter::new(
+///     events.clone(),
+///     [RateLimit { event_id: SENSOR_ERR_EID, per_second: 1, burst: 5 }],
+///     EVENTS_SUPPRESSED_EID,
+///     60,
+/// );
+///
+/// limiter.send_event_str(SENSOR_ERR_EID, EventType::Error, "sensor read failed");
+/// ```
+///
+/// Event IDs without a limit are always sent.
+///
+/// Rates are measured by the spacecraft time, as read through a [`TimeSource`];
+/// see [`with_time_source`](Self::with_time_source).
+#[derive(Debug)]
+pub struct EventLimiter<const N: usize, S = CfsClock> {
+    events:           EventSender,
+    limits:           [LimitState; N],
+    summary_event_id: u16,
+    summary_interval: u32,
+    clock:            S,
+}
+
+impl<const N: usize> EventLimiter<N> {
+    /// Creates a limiter sending events with `events`, enforcing `limits`,
+    /// and sending summaries of suppressed events with event ID `summary_event_id`
+    /// at most every `summary_interval` seconds.
+    ///
+    /// Each limit starts with a full bucket.
+    #[inline]
+    pub fn new(
+        events: EventSender,
+        limits: [RateLimit; N],
+        summary_event_id: u16,
+        summary_interval: u32,
+    ) -> Self {
+        Self::with_time_source(events, limits, summary_event_id, summary_interval, CfsClock)
+    }
+}
+
+impl<const N: usize, S: TimeSource> EventLimiter<N, S> {
+    /// Like [`new`](EventLimiter::new), but reading the time through `clock`.
+    #[inline]
+    pub fn with_time_source(
+        events: EventSender,
+        limits: [RateLimit; N],
+        summary_event_id: u16,
+        summary_interval: u32,
+        clock: S,
+    ) -> Self {
+        let now = clock.spacecraft_time();
+        let limits = limits.map(|limit| LimitState {
+            limit,
+            micro_tokens: limit.burst as u64 * 1_000_000,
+            last_refill: now,
+            suppressed: 0,
+            last_summary: now,
+        });
+
+        EventLimiter {
+            events,
+            limits,
+            summary_event_id,
+            summary_interval,
+            clock,
+        }
+    }
+
+    /// Returns the wrapped [`EventSender`], for sending events without any limits.
+    #[inline]
+    pub fn sender(&self) -> &EventSender {
+        &self.events
+    }
+
+    /// Reports whether an event with ID `event_id` may be sent now,
+    /// using up one of its tokens if so
+    /// (and counting it as suppressed if not).
+    #[inline]
+    pub fn allow(&mut self, event_id: u16) -> bool {
+        let now = self.clock.spacecraft_time();
+        self.report_suppressed_at(now);
+
+        let state = match self.limits.iter_mut().find(|s| s.limit.event_id == event_id) {
+            Some(state) => state,
+            None => return true,
+        };
+
+        if now < state.last_refill {
+            // The clock went backwards; start measuring afresh.
+            state.last_refill = now;
+        }
+        let elapsed = now - state.last_refill;
+        let elapsed_us = elapsed.seconds() as u64 * 1_000_000 + elapsed.microseconds() as u64;
+        let capacity = state.limit.burst as u64 * 1_000_000;
+        state.micro_tokens = state
+            .micro_tokens
+            .saturating_add(elapsed_us.saturating_mul(state.limit.per_second as u64))
+            .min(capacity);
+        state.last_refill = now;
+
+        if state.micro_tokens >= 1_000_000 {
+            state.micro_tokens -= 1_000_000;
+            true
+        } else {
+            state.suppressed = state.suppressed.saturating_add(1);
+            false
+        }
+    }
+
+    /// Sends summary events for any event IDs with suppressed events
+    /// whose summary interval has passed.
+    ///
+    /// Summaries are also sent on send attempts,
+    /// but an application which stops sending limited events
+    /// should call this periodically (e.g., on housekeeping requests)
+    /// so the last summaries still go out.
+    #[inline]
+    pub fn report_suppressed(&mut self) {
+        self.report_suppressed_at(self.clock.spacecraft_time());
+    }
+
+    fn report_suppressed_at(&mut self, now: SysTime) {
+        for state in self.limits.iter_mut() {
+            if state.suppressed == 0 {
+                continue;
+            }
+            if now >= state.last_summary
+                && (now - state.last_summary).seconds() < self.summary_interval
+            {
+                continue;
+            }
+
+            let _ = self.events.send_event(
+                self.summary_event_id,
+                EventType::Information,
+                SUPPRESSED_FMT,
+                (state.suppressed, state.limit.event_id as u32),
+            );
+            state.suppressed = 0;
+            state.last_summary = now;
+        }
+    }
+
+    /// Like [`EventSender::send_event`], but subject to the rate limits;
+    /// returns `None` if the event was suppressed.
+    #[inline]
+    pub fn send_event<T: PrintfArgs>(
+        &mut self,
+        event_id: u16,
+        event_type: EventType,
+        fmt: PrintfFmt<T>,
+        args: T,
+    ) -> Option<Status> {
+        if self.allow(event_id) {
+            Some(self.events.send_event(event_id, event_type, fmt, args))
+        } else {
+            None
+        }
+    }
+
+    /// Like [`EventSender::send_event_str`], but subject to the rate limits;
+    /// returns `None` if the event was suppressed.
+    #[inline]
+    pub fn send_event_str(
+        &mut self,
+        event_id: u16,
+        event_type: EventType,
+        msg: &str,
+    ) -> Option<Status> {
+        if self.allow(event_id) {
+            Some(self.events.send_event_str(event_id, event_type, msg))
+        } else {
+            None
+        }
+    }
+}
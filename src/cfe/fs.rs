@@ -2,3 +2,134 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! File and filesystem utilities.
+
+use super::time::SysTime;
+use super::Status;
+use crate::osal::file::File;
+use crate::sys::*;
+use crate::utils::CStrBuf;
+use core::ffi::CStr;
+
+/// The length of the description in a [`FsHeader`], including null terminator.
+#[doc(alias = "CFE_FS_HDR_DESC_MAX_LEN")]
+pub const HDR_DESC_MAX_LEN: usize = CFE_FS_HDR_DESC_MAX_LEN as usize;
+
+/// A header with all fields zeroed, to be filled in by cFE.
+const EMPTY_HEADER: CFE_FS_Header_t = CFE_FS_Header_t {
+    ContentType:    0,
+    SubType:        0,
+    Length:         0,
+    SpacecraftID:   0,
+    ProcessorID:    0,
+    ApplicationID:  0,
+    TimeSeconds:    0,
+    TimeSubSeconds: 0,
+    Description:    [0; HDR_DESC_MAX_LEN],
+};
+
+/// The standard header at the start of files written by cFS applications.
+///
+/// Wraps `CFE_FS_Header_t`.
+#[doc(alias = "CFE_FS_Header_t")]
+#[derive(Clone, Copy, Debug)]
+pub struct FsHeader {
+    pub(crate) hdr: CFE_FS_Header_t,
+}
+
+impl FsHeader {
+    /// Creates a header with description `description`
+    /// (truncated if need be) and file subtype `sub_type`.
+    ///
+    /// The remaining fields are filled in when the header is [written](Self::write_to).
+    ///
+    /// Wraps `CFE_FS_InitHeader`.
+    #[doc(alias = "CFE_FS_InitHeader")]
+    #[inline]
+    pub fn new<S: AsRef<CStr> + ?Sized>(description: &S, sub_type: u32) -> Self {
+        let mut hdr = EMPTY_HEADER;
+
+        unsafe { CFE_FS_InitHeader(&mut hdr, description.as_ref().as_ptr(), sub_type) };
+
+        FsHeader { hdr }
+    }
+
+    /// Reads the header at the start of `file`,
+    /// leaving the file position just after it.
+    ///
+    /// Wraps `CFE_FS_ReadHeader`.
+    #[doc(alias = "CFE_FS_ReadHeader")]
+    #[inline]
+    pub fn read_from(file: &File) -> Result<Self, Status> {
+        let mut header = FsHeader { hdr: EMPTY_HEADER };
+
+        let s: Status = unsafe { CFE_FS_ReadHeader(&mut header.hdr, file.id) }.into();
+        if s.status >= 0 && s.status as usize != core::mem::size_of::<CFE_FS_Header_t>() {
+            return Err(Status::STATUS_WRONG_MSG_LENGTH);
+        }
+
+        s.as_result(|| header)
+    }
+
+    /// Fills in the spacecraft, processor, and application IDs and the current time,
+    /// then writes the header at the start of `file`,
+    /// leaving the file position just after it.
+    ///
+    /// Wraps `CFE_FS_WriteHeader`.
+    #[doc(alias = "CFE_FS_WriteHeader")]
+    #[inline]
+    pub fn write_to(&mut self, file: &File) -> Result<(), Status> {
+        let s: Status = unsafe { CFE_FS_WriteHeader(file.id, &mut self.hdr) }.into();
+        if s.status >= 0 && s.status as usize != core::mem::size_of::<CFE_FS_Header_t>() {
+            return Err(Status::STATUS_EXTERNAL_RESOURCE_FAIL);
+        }
+
+        s.as_result(|| ())
+    }
+
+    /// Returns the file subtype.
+    #[inline]
+    pub fn sub_type(&self) -> u32 {
+        self.hdr.SubType
+    }
+
+    /// Returns the ID of the spacecraft the file was written on.
+    #[inline]
+    pub fn spacecraft_id(&self) -> u32 {
+        self.hdr.SpacecraftID
+    }
+
+    /// Returns the ID of the processor the file was written on.
+    #[inline]
+    pub fn processor_id(&self) -> u32 {
+        self.hdr.ProcessorID
+    }
+
+    /// Returns the ID of the application that wrote the file.
+    #[inline]
+    pub fn application_id(&self) -> u32 {
+        self.hdr.ApplicationID
+    }
+
+    /// Returns the time the header was written.
+    #[inline]
+    pub fn time(&self) -> SysTime {
+        SysTime::new(self.hdr.TimeSeconds, self.hdr.TimeSubSeconds)
+    }
+
+    /// Returns the file's description.
+    #[inline]
+    pub fn description(&self) -> CStrBuf<HDR_DESC_MAX_LEN> {
+        CStrBuf::new(&self.hdr.Description)
+    }
+}
+
+/// Sets the time recorded in the header at the start of `file` to `time`.
+///
+/// Wraps `CFE_FS_SetTimestamp`.
+#[doc(alias = "CFE_FS_SetTimestamp")]
+#[inline]
+pub fn set_timestamp(file: &File, time: SysTime) -> Result<(), Status> {
+    let s: Status = unsafe { CFE_FS_SetTimestamp(file.id, time.tm) }.into();
+
+    s.as_result(|| ())
+}
@@ -0,0 +1,183 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Housekeeping telemetry.
+//!
+//! Nearly every cFS application has a housekeeping (HK) telemetry packet,
+//! sent whenever a "send HK" request command arrives (typically from the scheduler app),
+//! that reports (among other things) how many commands the app has accepted and rejected.
+//! [`Housekeeping`] takes care of the boilerplate:
+//!
+//! ```rust,ignore
+//! let mut hk = Housekeeping::new(MY_APP_HK_TLM_MID, MY_APP_SEND_HK_MID, MyHkPayload::default())?;
+//! hk.subscribe(&mut pipe)?;
+//!
+//! loop {
+//!     pipe.receive_buffer(TimeOut::PendForever, |msg| {
+//!         let msg = msg?;
+//!         if hk.handle(msg)? {
+//!             return Ok(());
+//!         }
+//!
+//!         match process_ground_command(msg) {
+//!             Ok(()) => hk.command_accepted(),
+//!             Err(_) => hk.command_rejected(),
+//!         }
+//!         Ok(())
+//!     })?;
+//! }
+//! ```
+
+use core::mem;
+
+use super::msg::{Message, Telemetry};
+use super::sb::{MsgId, Pipe};
+use super::Status;
+use crate::sys::*;
+
+/// The command counters an application reports in its housekeeping telemetry.
+///
+/// As is conventional for cFS applications, both counters wrap around at 256.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct HkCounters {
+    /// The number of commands accepted.
+    pub command_count: u8,
+
+    /// The number of commands rejected.
+    pub command_error_count: u8,
+}
+
+impl HkCounters {
+    /// Counts a command as accepted.
+    #[inline]
+    pub fn command_accepted(&mut self) {
+        self.command_count = self.command_count.wrapping_add(1);
+    }
+
+    /// Counts a command as rejected.
+    #[inline]
+    pub fn command_rejected(&mut self) {
+        self.command_error_count = self.command_error_count.wrapping_add(1);
+    }
+}
+
+/// A housekeeping telemetry payload.
+pub trait HkPayload: Copy {
+    /// Updates the payload just before it's sent,
+    /// e.g., copying in the current command counters.
+    fn update(&mut self, counters: &HkCounters);
+}
+
+/// An application's housekeeping telemetry packet and command counters,
+/// along with the message ID of the command that requests the packet be sent.
+pub struct Housekeeping<T: HkPayload> {
+    tlm:        Telemetry<T>,
+    request_id: MsgId,
+    counters:   HkCounters,
+}
+
+impl<T: HkPayload> Housekeeping<T> {
+    /// Creates the housekeeping telemetry packet,
+    /// with message ID `tlm_id` and initial payload `payload`,
+    /// to be sent in response to commands with message ID `request_id`.
+    ///
+    /// Wraps `CFE_MSG_Init`.
+    #[doc(alias = "CFE_MSG_Init")]
+    #[inline]
+    pub fn new(tlm_id: MsgId, request_id: MsgId, payload: T) -> Result<Self, Status> {
+        Ok(Housekeeping {
+            tlm: Telemetry::new(tlm_id, payload)?,
+            request_id,
+            counters: HkCounters::default(),
+        })
+    }
+
+    /// Subscribes `pipe` to the housekeeping request command.
+    ///
+    /// Wraps `CFE_SB_Subscribe`.
+    #[doc(alias = "CFE_SB_Subscribe")]
+    #[inline]
+    pub fn subscribe(&self, pipe: &mut Pipe) -> Result<(), Status> {
+        pipe.subscribe(self.request_id)
+    }
+
+    /// If `msg` is a housekeeping request, handles it and returns `Ok(true)`;
+    /// otherwise, returns `Ok(false)`, leaving `msg` for the caller to handle.
+    ///
+    /// Handling a request means sending the housekeeping packet
+    /// (see [`send`](Self::send)), or, if the request is the wrong length,
+    /// counting it as a [rejected command](Self::command_rejected).
+    ///
+    /// Wraps `CFE_MSG_GetMsgId`, `CFE_MSG_GetSize`, `CFE_SB_TimeStampMsg`, and `CFE_SB_TransmitMsg`.
+    #[doc(alias("CFE_MSG_GetMsgId", "CFE_MSG_GetSize"))]
+    #[doc(alias("CFE_SB_TimeStampMsg", "CFE_SB_TransmitMsg"))]
+    pub fn handle(&mut self, msg: &Message) -> Result<bool, Status> {
+        if msg.msgid()? != self.request_id {
+            return Ok(false);
+        }
+
+        if msg.size()? != mem::size_of::<CFE_MSG_CommandHeader_t>() as _ {
+            self.command_rejected();
+            return Ok(true);
+        }
+
+        self.send()?;
+        Ok(true)
+    }
+
+    /// Updates the payload with the current counters, time-stamps the packet,
+    /// and sends it.
+    ///
+    /// Wraps `CFE_SB_TimeStampMsg` and `CFE_SB_TransmitMsg`.
+    #[doc(alias("CFE_SB_TimeStampMsg", "CFE_SB_TransmitMsg"))]
+    #[inline]
+    pub fn send(&mut self) -> Result<(), Status> {
+        let counters = self.counters;
+        self.tlm.payload.update(&counters);
+        self.tlm.time_stamp();
+        self.tlm.transmit(true)
+    }
+
+    /// Counts a command as accepted.
+    #[inline]
+    pub fn command_accepted(&mut self) {
+        self.counters.command_accepted();
+    }
+
+    /// Counts a command as rejected.
+    #[inline]
+    pub fn command_rejected(&mut self) {
+        self.counters.command_rejected();
+    }
+
+    /// Zeroes the command counters (as for a "reset counters" command).
+    #[inline]
+    pub fn reset_counters(&mut self) {
+        self.counters = HkCounters::default();
+    }
+
+    /// Returns the current command counters.
+    #[inline]
+    pub fn counters(&self) -> HkCounters {
+        self.counters
+    }
+
+    /// Returns the command counters for modification
+    /// (e.g., for use with [`CommandTable::dispatch`](super::cmd::CommandTable::dispatch)).
+    #[inline]
+    pub fn counters_mut(&mut self) -> &mut HkCounters {
+        &mut self.counters
+    }
+
+    /// Returns the housekeeping payload.
+    #[inline]
+    pub fn payload(&self) -> &T {
+        &self.tlm.payload
+    }
+
+    /// Returns the housekeeping payload for modification.
+    #[inline]
+    pub fn payload_mut(&mut self) -> &mut T {
+        &mut self.tlm.payload
+    }
+}
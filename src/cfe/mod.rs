@@ -6,17 +6,36 @@
 use crate::sys::*;
 use core::ffi::c_ulong;
 
+pub mod app;
+pub mod catalog;
+pub mod cmd;
+#[cfg(n2o4_cfe_config)]
+pub mod config;
+#[cfg(feature = "std")]
+pub mod decode;
 pub mod es;
 pub mod evs;
 pub mod fs;
+pub mod hk;
 pub mod msg;
+pub mod pacing;
+pub mod params;
+pub mod perf;
+pub mod psp;
+pub mod recorder;
+pub mod route;
 pub mod sb;
+pub mod startup;
 pub mod tbl;
 pub mod time;
+pub mod wait;
 
 mod status_consts;
 
-use printf_wrap::{null_str, NullString};
+use crate::sealed_traits::PrintfArgsSealed;
+use crate::utils::CStrBuf;
+use core::ffi::c_char;
+use printf_wrap::{null_str, NullString, PrintfArgument};
 
 /// An ID to identify cFE-managed resources.
 ///
@@ -34,7 +53,25 @@ impl ResourceId {
     #[doc(alias = "CFE_ResourceId_IsDefined")]
     #[inline]
     pub fn is_defined(&self) -> bool {
-        unsafe { SHIM_CFE_ResourceId_IsDefined(self.id) }
+        crate::shims::CFE_ResourceId_IsDefined(self.id)
+    }
+
+    /// Returns a zero-based index for the resource,
+    /// suitable for indexing arrays of `table_size` entries,
+    /// given the base value `base_value` for resources of its type
+    /// (e.g., `CFE_ES_APPID_BASE`).
+    ///
+    /// Returns [`ES_ERR_RESOURCEID_NOT_VALID`](Status::ES_ERR_RESOURCEID_NOT_VALID)
+    /// if the resource isn't of the type with base value `base_value`.
+    ///
+    /// Wraps `CFE_ResourceId_ToIndex`.
+    #[doc(alias = "CFE_ResourceId_ToIndex")]
+    #[inline]
+    pub fn to_index(&self, base_value: u32, table_size: u32) -> Result<usize, Status> {
+        let mut idx: u32 = 0;
+        let s: Status =
+            unsafe { CFE_ResourceId_ToIndex(self.id, base_value, table_size, &mut idx) }.into();
+        s.as_result(|| idx as usize)
     }
 
     /// A value that represents an undefined/unused resource.
@@ -55,7 +92,7 @@ impl PartialEq<ResourceId> for ResourceId {
     #[doc(alias = "CFE_ResourceId_Equal")]
     #[inline]
     fn eq(&self, other: &ResourceId) -> bool {
-        unsafe { SHIM_CFE_ResourceId_Equal(self.id, other.id) }
+        crate::shims::CFE_ResourceId_Equal(self.id, other.id)
     }
 }
 
@@ -66,7 +103,7 @@ impl From<c_ulong> for ResourceId {
     #[doc(alias = "CFE_ResourceId_FromInteger")]
     #[inline]
     fn from(val: c_ulong) -> ResourceId {
-        let rid = unsafe { SHIM_CFE_ResourceId_FromInteger(val) };
+        let rid = crate::shims::CFE_ResourceId_FromInteger(val);
         ResourceId { id: rid }
     }
 }
@@ -76,10 +113,52 @@ impl From<ResourceId> for c_ulong {
     #[doc(alias = "CFE_ResourceId_ToInteger")]
     #[inline]
     fn from(id: ResourceId) -> c_ulong {
-        unsafe { SHIM_CFE_ResourceId_ToInteger(id.id) }
+        crate::shims::CFE_ResourceId_ToInteger(id.id)
     }
 }
 
+/// The calling application's identity, as returned by [`context`].
+#[derive(Clone, Debug)]
+pub struct AppContext {
+    /// The application's ID.
+    pub app_id: es::AppId,
+
+    /// The application's name.
+    pub name: CStrBuf<{ es::API_NAME_BUF_LEN }>,
+
+    /// An event sender for the application,
+    /// or `None` if the application hasn't registered with event services yet.
+    pub events: Option<evs::EventSender>,
+}
+
+/// Returns the calling application's ID, name, and (once it has registered
+/// with event services) [`EventSender`](evs::EventSender),
+/// so that library code can send events attributed to the application
+/// without these being passed through every function:
+///
+/// ```rust,ignore
+/// if let Some(events) = n2o4::cfe::context()?.events {
+///     events.send_event_str(LIB_ERR_EID, EventType::Error, "checksum mismatch");
+/// }
+/// ```
+///
+/// The application's name is fetched from cFE on the first call, then cached.
+/// This never registers the application with event services itself,
+/// so it's safe to call before the application has done so
+/// (or if it registers from C code, in which case it should call
+/// [`EventSender::assume_registered`](evs::EventSender::assume_registered)).
+///
+/// Wraps `CFE_ES_GetAppID` and `CFE_ES_GetAppName`.
+#[doc(alias("CFE_ES_GetAppID", "CFE_ES_GetAppName"))]
+#[inline]
+pub fn context() -> Result<AppContext, Status> {
+    es::with_app_name(|app_id, name| AppContext {
+        app_id,
+        name: *name,
+        events: evs::EventSender::if_registered(),
+    })
+}
+
 /// The numeric type corresponding to a [`Status`].
 ///
 /// This is the same as `CFE_Status_t`.
@@ -92,6 +171,7 @@ pub use crate::sys::CFE_Status_t as CFE_Status;
 /// Wraps `CFE_Status_t`.
 #[doc(alias = "CFE_Status_t")]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
 pub struct Status {
     pub(crate) status: CFE_Status_t,
 }
@@ -110,6 +190,15 @@ impl From<Status> for CFE_Status {
     }
 }
 
+impl core::fmt::Display for Status {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "cFE status {:#010x}", self.status as u32)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Status {}
+
 /// The severity part of a [`Status`].
 #[doc(alias = "CFE_SEVERITY_BITMASK")]
 #[repr(u32)]
@@ -241,6 +330,32 @@ impl Status {
         }
     }
 
+    /// Converts `self` into a `Result`, treating only statuses of severity
+    /// [`Success`](`StatusSeverity::Success`) as `Ok`.
+    ///
+    /// This is the policy used throughout this crate's own wrappers.
+    /// Note that [`Informational`](`StatusSeverity::Informational`) statuses
+    /// (such as [`Status::TBL_INFO_UPDATED`]) end up as `Err` under this policy;
+    /// see [`as_result_allow_info`](Self::as_result_allow_info) for an alternative.
+    #[inline]
+    pub fn as_result_strict(&self) -> Result<(), Status> {
+        self.as_result(|| ())
+    }
+
+    /// Converts `self` into a `Result`, treating statuses of severity
+    /// [`Success`](`StatusSeverity::Success`) or
+    /// [`Informational`](`StatusSeverity::Informational`) as `Ok`.
+    ///
+    /// The `Ok` variant contains `self`,
+    /// so the caller can still tell which informational status was returned.
+    #[inline]
+    pub fn as_result_allow_info(&self) -> Result<Status, Status> {
+        match self.severity() {
+            StatusSeverity::Success | StatusSeverity::Informational => Ok(*self),
+            StatusSeverity::Error => Err(*self),
+        }
+    }
+
     /// Returns the status as a 32-bit number.
     #[inline]
     pub fn as_num(&self) -> u32 {
@@ -248,6 +363,75 @@ impl Status {
     }
 }
 
+/// The arguments for a [`PrintfFmt`](printf_wrap::PrintfFmt) format string, as a tuple,
+/// for use with [`write_to_syslog`](es::write_to_syslog)
+/// and [`EventSender::send_event`](evs::EventSender::send_event) and friends.
+///
+/// This is implemented for tuples of up to 8 [`PrintfArgument`]s, including `()`.
+/// Note that a single argument `a` is passed as the one-element tuple `(a,)`.
+///
+/// This is a [sealed trait](https://rust-lang.github.io/api-guidelines/future-proofing.html#c-sealed):
+/// it cannot be implemented outside of `n2o4`.
+pub trait PrintfArgs: printf_wrap::PrintfArgs + PrintfArgsSealed {}
+
+/// Internal macro to implement [`PrintfArgs`] for tuples.
+macro_rules! printf_args_impl {
+    ($( ( $($t:ident),* ), ( $($var:ident),* ); )*) => {$(
+        impl<$($t: PrintfArgument),*> PrintfArgs for ($($t,)*) {}
+
+        impl<$($t: PrintfArgument),*> PrintfArgsSealed for ($($t,)*) {
+            #[inline]
+            unsafe fn write_to_syslog(self, fmt: *const c_char) -> CFE_Status_t {
+                let ($($var,)*) = self;
+                CFE_ES_WriteToSysLog(fmt $(, $var.as_c_val())*)
+            }
+
+            #[inline]
+            unsafe fn send_event(self, event_id: u16, event_type: u16, fmt: *const c_char) -> CFE_Status_t {
+                let ($($var,)*) = self;
+                CFE_EVS_SendEvent(event_id, event_type, fmt $(, $var.as_c_val())*)
+            }
+
+            #[inline]
+            unsafe fn send_event_with_app_id(
+                self,
+                event_id: u16,
+                event_type: u16,
+                app_id: CFE_ES_AppId_t,
+                fmt: *const c_char,
+            ) -> CFE_Status_t {
+                let ($($var,)*) = self;
+                CFE_EVS_SendEventWithAppID(event_id, event_type, app_id, fmt $(, $var.as_c_val())*)
+            }
+
+            #[inline]
+            unsafe fn send_timed_event(
+                self,
+                time: CFE_TIME_SysTime_t,
+                event_id: u16,
+                event_type: u16,
+                fmt: *const c_char,
+            ) -> CFE_Status_t {
+                let ($($var,)*) = self;
+                CFE_EVS_SendTimedEvent(time, event_id, event_type, fmt $(, $var.as_c_val())*)
+            }
+        }
+    )*};
+}
+
+#[rustfmt::skip]
+printf_args_impl! {
+    (), ();
+    (A), (a);
+    (A, B), (a, b);
+    (A, B, C), (a, b, c);
+    (A, B, C, D), (a, b, c, d);
+    (A, B, C, D, E), (a, b, c, d, e);
+    (A, B, C, D, E, F), (a, b, c, d, e, f);
+    (A, B, C, D, E, F, G), (a, b, c, d, e, f, g);
+    (A, B, C, D, E, F, G, H), (a, b, c, d, e, f, g, h);
+}
+
 /// Format string for using a Rust [`str`] in
 /// [`printf(3)`](https://www.freebsd.org/cgi/man.cgi?printf%283%29)-style C functions.
-const RUST_STR_FMT: NullString = null_str!("%.*s");
+pub(crate) const RUST_STR_FMT: NullString = null_str!("%.*s");
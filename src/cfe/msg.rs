@@ -9,7 +9,9 @@ use core::ops::{Deref, DerefMut};
 
 use super::sb::MsgId;
 use super::Status;
+use crate::sealed_traits::TypedMessageSealed;
 use crate::sys::*;
+use crate::utils::{AnyBitPattern, NoPadding};
 
 /// Returns the number of items in array field `$field` of `$type`.
 ///
@@ -118,7 +120,7 @@ impl Message {
     /// Convenience function for creating a higher-level wrapper from the
     /// [`cfs_sys`]-provided type.
     #[inline]
-    pub(super) fn from_cfe(m: &CFE_MSG_Message_t) -> &Message {
+    pub(crate) fn from_cfe(m: &CFE_MSG_Message_t) -> &Message {
         let p = m as *const CFE_MSG_Message_t as *const Message;
         unsafe { &*p }
     }
@@ -211,6 +213,58 @@ impl Message {
         s.as_result(|| ())
     }
 
+    /// Returns the message type recorded in the message's header.
+    ///
+    /// Wraps `CFE_MSG_GetType`.
+    #[doc(alias = "CFE_MSG_GetType")]
+    #[inline]
+    pub fn msg_type(&self) -> Result<MsgType, Status> {
+        let mut t: CFE_MSG_Type_t = CFE_MSG_Type_CFE_MSG_Type_Invalid;
+        let s: Status = unsafe { CFE_MSG_GetType(&self.msg, &mut t) }.into();
+
+        s.as_result(|| MsgType::from_cfe(t))
+    }
+
+    /// Checks that the message's headers are consistent,
+    /// as a gatekeeper before the message is dispatched;
+    /// if `check_checksum` is set, the checksum of a command message is checked too.
+    ///
+    /// Returns `Err` only if a check couldn't be made at all;
+    /// failed checks are recorded in the returned [`MsgValidation`].
+    ///
+    /// Wraps `CFE_MSG_GetMsgId`, `CFE_SB_IsValidMsgId`, `CFE_MSG_GetType`,
+    /// `CFE_MSG_GetTypeFromMsgId`, `CFE_MSG_GetSize`, and `CFE_MSG_ValidateChecksum`.
+    #[doc(alias("CFE_MSG_ValidateChecksum", "CFE_MSG_GetType"))]
+    #[inline]
+    pub fn validate(&self, check_checksum: bool) -> Result<MsgValidation, Status> {
+        let msg_id = self.msgid()?;
+        let msg_id_valid = msg_id.is_valid();
+        let header_type = self.msg_type()?;
+
+        let msg_type_ok = msg_id_valid && msg_id.msg_type()? == header_type;
+
+        let header_length = match header_type {
+            MsgType::Cmd => mem::size_of::<CFE_MSG_CommandHeader_t>(),
+            _ => mem::size_of::<CFE_MSG_TelemetryHeader_t>(),
+        };
+        let size_ok = self.size()? >= header_length;
+
+        let checksum_ok = if check_checksum && header_type == MsgType::Cmd && size_ok {
+            let mut is_valid = false;
+            let s: Status = unsafe { CFE_MSG_ValidateChecksum(&self.msg, &mut is_valid) }.into();
+            Some(s.as_result(|| is_valid)?)
+        } else {
+            None
+        };
+
+        Ok(MsgValidation {
+            msg_id_valid,
+            msg_type_ok,
+            size_ok,
+            checksum_ok,
+        })
+    }
+
     /// The backend of [`try_cast_cmd`](`Self::try_cast_cmd`)
     /// and [`try_cast_tlm`](`Self::try_cast_tlm`).
     #[inline]
@@ -251,6 +305,9 @@ impl Message {
     /// Returns the payload of the message as a byte slice.
     ///
     /// This can be useful when the payload isn't a C structure.
+    ///
+    /// Returns [`STATUS_WRONG_MSG_LENGTH`](Status::STATUS_WRONG_MSG_LENGTH)
+    /// if the message is too short to hold its own headers.
     #[inline]
     pub fn payload(&self) -> Result<&[u8], Status> {
         let size = self.size()? as usize;
@@ -261,6 +318,9 @@ impl Message {
                 return Err(Status::MSG_WRONG_MSG_TYPE);
             }
         };
+        if size < header_length {
+            return Err(Status::STATUS_WRONG_MSG_LENGTH);
+        }
 
         let slice: Option<&[u8]> = unsafe {
             let base: *const u8 =
@@ -283,21 +343,139 @@ impl Message {
         unsafe { CFE_SB_TimeStampMsg(&mut self.msg) }
     }
 
+    /// Sets the checksum field of a command message
+    /// so that the message passes checksum validation.
+    ///
+    /// This must be done after the rest of the message has been filled in.
+    ///
+    /// Wraps `CFE_MSG_GenerateChecksum`.
+    #[doc(alias = "CFE_MSG_GenerateChecksum")]
+    #[inline]
+    pub fn generate_checksum(&mut self) -> Result<(), Status> {
+        let s: Status = unsafe { CFE_MSG_GenerateChecksum(&mut self.msg) }.into();
+        s.as_result(|| ())
+    }
+
     /// Transmits onto the software bus the message this [`Message`] is a header for.
     ///
     /// The software bus makes a copy of the message,
     /// so the current instance of the message may be freely modified after
     /// calling this method.
     ///
+    /// `increment_sequence_count` is what cFE calls `IsOrigination`:
+    /// if `true`, the software bus treats this application as the message's originator,
+    /// setting the sequence count (and, depending on the mission's configuration,
+    /// other header fields such as the time stamp) on the copy it sends;
+    /// if `false`, the header is sent as it is.
+    ///
     /// Wraps `CFE_SB_TransmitMsg`.
     #[doc(alias = "CFE_SB_TransmitMsg")]
     #[inline]
     pub fn transmit(&mut self, increment_sequence_count: bool) -> Result<(), Status> {
-        let s: Status =
-            unsafe { CFE_SB_TransmitMsg(&mut self.msg, increment_sequence_count) }.into();
+        let s: Status = super::perf::timed(super::perf::SB_TRANSMIT_MSG, || unsafe {
+            CFE_SB_TransmitMsg(&mut self.msg, increment_sequence_count)
+        })
+        .into();
+
+        s.as_result(|| ())
+    }
+
+    /// Transmits onto the software bus a copy of the message this [`Message`] is a header for,
+    /// leaving its header (sequence count, time stamp, etc.) as it is,
+    /// as a bent-pipe application forwarding messages from elsewhere must.
+    ///
+    /// Unlike [`transmit`](Self::transmit), this doesn't need a mutable reference,
+    /// so a message can be forwarded straight from the buffer it was received in:
+    ///
+    /// ```rust,ignore
+    /// pipe.receive_buffer(TimeOut::PendForever, |msg| msg?.forward())?;
+    /// ```
+    ///
+    /// Wraps `CFE_SB_TransmitMsg` (with `IsOrigination` set to `false`).
+    #[doc(alias = "CFE_SB_TransmitMsg")]
+    #[inline]
+    pub fn forward(&self) -> Result<(), Status> {
+        let s: Status = super::perf::timed(super::perf::SB_TRANSMIT_MSG, || unsafe {
+            CFE_SB_TransmitMsg(&self.msg, false)
+        })
+        .into();
+
+        s.as_result(|| ())
+    }
+
+    // The helpers below hold the parts of the `Command<T>` and `Telemetry<T>` methods
+    // that don't depend on `T`, so they aren't duplicated for every payload type.
+    // (Hence also the lack of `#[inline]`.)
+
+    /// Checks that `msg_id` is for a message of type `msg_type`,
+    /// then initializes this message (`size` bytes long) with `msg_id`.
+    ///
+    /// Safety: as for [`init`](Self::init);
+    /// also, this must be the header of a message at least `size` bytes long.
+    unsafe fn init_typed(
+        &mut self,
+        msg_id: MsgId,
+        msg_type: MsgType,
+        size: Size,
+    ) -> Result<(), Status> {
+        if msg_id.msg_type() != Ok(msg_type) {
+            return Err(Status::SB_BAD_ARGUMENT);
+        }
+
+        self.init(msg_id, size)
+    }
+
+    /// Sets the function code of this message, which must be a command message.
+    fn set_fcn_code_raw(&mut self, fcn_code: FunctionCode) -> Result<(), Status> {
+        let s: Status = unsafe { CFE_MSG_SetFcnCode(&mut self.msg, fcn_code) }.into();
 
         s.as_result(|| ())
     }
+
+    /// Returns the first `full_size` bytes of the message at `msg` (headers included),
+    /// or fewer if the size declared in its header is smaller.
+    ///
+    /// This takes a pointer rather than `&self` so that the slice
+    /// can be derived from a pointer to the whole typed message,
+    /// rather than one to just its [`Message`] header.
+    ///
+    /// Safety: `msg` must point to a message at least `full_size` bytes long,
+    /// with none of those bytes uninitialized, that is valid for `'a`.
+    unsafe fn bytes_within<'a>(msg: *const Message, full_size: usize) -> &'a [u8] {
+        let len = (*msg).size().map_or(full_size, |size| size.min(full_size));
+        core::slice::from_raw_parts(msg as *const u8, len)
+    }
+
+    /// As with [`bytes_within`](Self::bytes_within), but mutable.
+    ///
+    /// Safety: as for `bytes_within`; also, `msg` must be valid for writes,
+    /// and the caller is responsible for what's written to the slice.
+    unsafe fn bytes_within_mut<'a>(msg: *mut Message, full_size: usize) -> &'a mut [u8] {
+        let len = (*msg).size().map_or(full_size, |size| size.min(full_size));
+        core::slice::from_raw_parts_mut(msg as *mut u8, len)
+    }
+
+    /// Transmits the first `payload_offset + len * elem_size` bytes of this message,
+    /// then sets the message's size back to `full_size`.
+    ///
+    /// Safety: the message must be at least `full_size` bytes long,
+    /// and `payload_offset + len * elem_size` must be no more than that.
+    unsafe fn transmit_partial_raw(
+        &mut self,
+        increment_sequence_count: bool,
+        payload_offset: usize,
+        elem_size: usize,
+        len: usize,
+        full_size: usize,
+    ) -> Result<(), Status> {
+        let sz = (payload_offset + (len * elem_size)) as Size;
+
+        self.set_size(sz)?;
+        let ret_val = self.transmit(increment_sequence_count);
+        let _ = self.set_size(full_size as Size);
+
+        ret_val
+    }
 }
 
 impl<T: Copy + Sized> Command<T> {
@@ -323,13 +501,9 @@ impl<T: Copy + Sized> Command<T> {
         };
         let sz: Size = mem::size_of::<Self>() as Size;
 
-        if msg_id.msg_type() != Ok(MsgType::Cmd) {
-            return Err(Status::SB_BAD_ARGUMENT);
-        }
-
-        unsafe { Message::from_cfe_mut(&mut cmd.header.Msg).init(msg_id, sz) }?;
-
-        cmd.set_fcn_code(fcn_code)?;
+        let msg = Message::from_cfe_mut(&mut cmd.header.Msg);
+        unsafe { msg.init_typed(msg_id, MsgType::Cmd, sz) }?;
+        msg.set_fcn_code_raw(fcn_code)?;
 
         // Set the payload again, as it might have gotten nuked by one of the API calls.
         // Safe due to payload being Copy.
@@ -356,9 +530,108 @@ impl<T: Copy + Sized> Command<T> {
     #[doc(alias = "CFG_MSG_SetFcnCode")]
     #[inline]
     pub fn set_fcn_code(&mut self, fcn_code: FunctionCode) -> Result<(), Status> {
-        let s: Status = unsafe { CFE_MSG_SetFcnCode(&mut self.header.Msg, fcn_code) }.into();
+        Message::from_cfe_mut(&mut self.header.Msg).set_fcn_code_raw(fcn_code)
+    }
 
-        s.as_result(|| ())
+    /// Transmits the command onto the software bus,
+    /// first setting its checksum so that its recipient can validate it.
+    ///
+    /// The checksum covers the whole message, sequence count included,
+    /// so the software bus is told to leave the sequence count as it is
+    /// (as with [`transmit(false)`](Message::transmit)).
+    ///
+    /// Wraps `CFE_MSG_GenerateChecksum` and `CFE_SB_TransmitMsg`.
+    #[doc(alias("CFE_MSG_GenerateChecksum", "CFE_SB_TransmitMsg"))]
+    #[inline]
+    pub fn transmit_checksummed(&mut self) -> Result<(), Status> {
+        let msg = Message::from_cfe_mut(&mut self.header.Msg);
+        msg.generate_checksum()?;
+        msg.transmit(false)
+    }
+
+    /// Returns the message (headers included) as a byte slice,
+    /// up to the size declared in its header
+    /// (or the size of the whole `Command<T>`, if that's smaller).
+    ///
+    /// This is [`as_bytes`](Self::as_bytes) for payloads that may have padding.
+    ///
+    /// Wraps `CFE_MSG_GetSize`.
+    ///
+    /// # Safety
+    ///
+    /// Padding bytes (within `T`, or between the header and the payload)
+    /// may be uninitialized, and reading them as `u8`s is undefined behavior.
+    /// The caller must ensure that the message has no padding
+    /// within the returned length.
+    #[doc(alias = "CFE_MSG_GetSize")]
+    #[inline]
+    pub unsafe fn as_bytes_unchecked(&self) -> &[u8] {
+        Message::bytes_within(self as *const Self as *const Message, mem::size_of::<Self>())
+    }
+
+    /// Returns the message (headers included) as a mutable byte slice,
+    /// up to the size declared in its header
+    /// (or the size of the whole `Command<T>`, if that's smaller).
+    ///
+    /// This is [`as_bytes_mut`](Self::as_bytes_mut) for payloads
+    /// that may have padding or invalid bit patterns.
+    ///
+    /// Wraps `CFE_MSG_GetSize`.
+    ///
+    /// # Safety
+    ///
+    /// Whatever is written to the slice must leave [`payload`](Self::payload)
+    /// a valid instance of `T`,
+    /// and, as with [`as_bytes_unchecked`](Self::as_bytes_unchecked),
+    /// padding bytes mustn't be read before being written to.
+    #[doc(alias = "CFE_MSG_GetSize")]
+    #[inline]
+    pub unsafe fn as_bytes_mut_unchecked(&mut self) -> &mut [u8] {
+        Message::bytes_within_mut(self as *mut Self as *mut Message, mem::size_of::<Self>())
+    }
+}
+
+impl<T: NoPadding> Command<T> {
+    /// Fails to compile (when used) unless the payload directly follows the header
+    /// and fills out the rest of the `Command<T>`,
+    /// as otherwise there's padding around it.
+    const NO_GAPS: () = assert!(
+        mem::size_of::<Self>() == mem::size_of::<CFE_MSG_CommandHeader_t>() + mem::size_of::<T>(),
+        "padding between the message header and the payload"
+    );
+
+    /// Returns the message (headers included) as a byte slice,
+    /// up to the size declared in its header
+    /// (or the size of the whole `Command<T>`, if that's smaller).
+    ///
+    /// Fails to compile if the payload's alignment
+    /// leaves padding between it and the header.
+    ///
+    /// Wraps `CFE_MSG_GetSize`.
+    #[doc(alias = "CFE_MSG_GetSize")]
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NO_GAPS;
+        unsafe { self.as_bytes_unchecked() }
+    }
+}
+
+impl<T: NoPadding + AnyBitPattern> Command<T> {
+    /// Returns the message (headers included) as a mutable byte slice,
+    /// up to the size declared in its header
+    /// (or the size of the whole `Command<T>`, if that's smaller).
+    ///
+    /// Fails to compile if the payload's alignment
+    /// leaves padding between it and the header.
+    ///
+    /// Wraps `CFE_MSG_GetSize`.
+    #[doc(alias = "CFE_MSG_GetSize")]
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NO_GAPS;
+        unsafe { self.as_bytes_mut_unchecked() }
     }
 }
 
@@ -378,14 +651,17 @@ impl<T: Copy + Sized, const SIZE: usize> Command<[T; SIZE]> {
         increment_sequence_count: bool,
         len: usize,
     ) -> Result<(), Status> {
-        let len = len.min(SIZE);
-        let sz = (offset_of!(self, payload) + (len * mem::size_of::<T>())) as Size;
-
-        unsafe { self.set_size(sz) }?;
-        let ret_val = self.transmit(increment_sequence_count);
-        let _ = unsafe { self.set_size(mem::size_of::<Self>() as Size) };
+        let payload_offset = offset_of!(self, payload);
 
-        ret_val
+        unsafe {
+            (**self).transmit_partial_raw(
+                increment_sequence_count,
+                payload_offset,
+                mem::size_of::<T>(),
+                len.min(SIZE),
+                mem::size_of::<Self>(),
+            )
+        }
     }
 }
 
@@ -428,11 +704,7 @@ impl<T: Copy + Sized> Telemetry<T> {
         };
         let sz: Size = mem::size_of::<Self>() as Size;
 
-        if msg_id.msg_type() != Ok(MsgType::Tlm) {
-            return Err(Status::SB_BAD_ARGUMENT);
-        }
-
-        unsafe { Message::from_cfe_mut(&mut tlm.header.Msg).init(msg_id, sz) }?;
+        unsafe { Message::from_cfe_mut(&mut tlm.header.Msg).init_typed(msg_id, MsgType::Tlm, sz) }?;
 
         // Set the payload again, as it might have gotten nuked by the API calls.
         // Safe due to payload being Copy.
@@ -442,6 +714,91 @@ impl<T: Copy + Sized> Telemetry<T> {
 
         Ok(tlm)
     }
+
+    /// Returns the message (headers included) as a byte slice,
+    /// up to the size declared in its header
+    /// (or the size of the whole `Telemetry<T>`, if that's smaller).
+    ///
+    /// This is [`as_bytes`](Self::as_bytes) for payloads that may have padding.
+    ///
+    /// Wraps `CFE_MSG_GetSize`.
+    ///
+    /// # Safety
+    ///
+    /// Padding bytes (within `T`, or between the header and the payload)
+    /// may be uninitialized, and reading them as `u8`s is undefined behavior.
+    /// The caller must ensure that the message has no padding
+    /// within the returned length.
+    #[doc(alias = "CFE_MSG_GetSize")]
+    #[inline]
+    pub unsafe fn as_bytes_unchecked(&self) -> &[u8] {
+        Message::bytes_within(self as *const Self as *const Message, mem::size_of::<Self>())
+    }
+
+    /// Returns the message (headers included) as a mutable byte slice,
+    /// up to the size declared in its header
+    /// (or the size of the whole `Telemetry<T>`, if that's smaller).
+    ///
+    /// This is [`as_bytes_mut`](Self::as_bytes_mut) for payloads
+    /// that may have padding or invalid bit patterns.
+    ///
+    /// Wraps `CFE_MSG_GetSize`.
+    ///
+    /// # Safety
+    ///
+    /// Whatever is written to the slice must leave [`payload`](Self::payload)
+    /// a valid instance of `T`,
+    /// and, as with [`as_bytes_unchecked`](Self::as_bytes_unchecked),
+    /// padding bytes mustn't be read before being written to.
+    #[doc(alias = "CFE_MSG_GetSize")]
+    #[inline]
+    pub unsafe fn as_bytes_mut_unchecked(&mut self) -> &mut [u8] {
+        Message::bytes_within_mut(self as *mut Self as *mut Message, mem::size_of::<Self>())
+    }
+}
+
+impl<T: NoPadding> Telemetry<T> {
+    /// Fails to compile (when used) unless the payload directly follows the header
+    /// and fills out the rest of the `Telemetry<T>`,
+    /// as otherwise there's padding around it.
+    const NO_GAPS: () = assert!(
+        mem::size_of::<Self>() == mem::size_of::<CFE_MSG_TelemetryHeader_t>() + mem::size_of::<T>(),
+        "padding between the message header and the payload"
+    );
+
+    /// Returns the message (headers included) as a byte slice,
+    /// up to the size declared in its header
+    /// (or the size of the whole `Telemetry<T>`, if that's smaller).
+    ///
+    /// Fails to compile if the payload's alignment
+    /// leaves padding between it and the header.
+    ///
+    /// Wraps `CFE_MSG_GetSize`.
+    #[doc(alias = "CFE_MSG_GetSize")]
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NO_GAPS;
+        unsafe { self.as_bytes_unchecked() }
+    }
+}
+
+impl<T: NoPadding + AnyBitPattern> Telemetry<T> {
+    /// Returns the message (headers included) as a mutable byte slice,
+    /// up to the size declared in its header
+    /// (or the size of the whole `Telemetry<T>`, if that's smaller).
+    ///
+    /// Fails to compile if the payload's alignment
+    /// leaves padding between it and the header.
+    ///
+    /// Wraps `CFE_MSG_GetSize`.
+    #[doc(alias = "CFE_MSG_GetSize")]
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NO_GAPS;
+        unsafe { self.as_bytes_mut_unchecked() }
+    }
 }
 
 impl<T: Copy + Sized + Default> Telemetry<T> {
@@ -468,14 +825,17 @@ impl<T: Copy + Sized, const SIZE: usize> Telemetry<[T; SIZE]> {
         increment_sequence_count: bool,
         len: usize,
     ) -> Result<(), Status> {
-        let len = len.min(SIZE);
-        let sz = (offset_of!(self, payload) + (len * mem::size_of::<T>())) as Size;
+        let payload_offset = offset_of!(self, payload);
 
-        unsafe { self.set_size(sz) }?;
-        let ret_val = self.transmit(increment_sequence_count);
-        let _ = unsafe { self.set_size(mem::size_of::<Self>() as Size) };
-
-        ret_val
+        unsafe {
+            (**self).transmit_partial_raw(
+                increment_sequence_count,
+                payload_offset,
+                mem::size_of::<T>(),
+                len.min(SIZE),
+                mem::size_of::<Self>(),
+            )
+        }
     }
 }
 
@@ -495,6 +855,54 @@ impl<T: Copy> DerefMut for Telemetry<T> {
     }
 }
 
+/// A message type with a fixed layout ([`Command<T>`] or [`Telemetry<T>`])
+/// that a received [`Message`] can be checked against and copied into,
+/// as by [`Pipe::receive_owned`](super::sb::Pipe::receive_owned).
+pub trait TypedMessage: TypedMessageSealed {}
+
+impl<T: Copy + Sized> TypedMessageSealed for Command<T> {
+    #[inline]
+    fn try_cast_from(msg: &Message) -> Result<&Self, Status> {
+        msg.try_cast_cmd::<T>()
+    }
+}
+
+impl<T: Copy + Sized> TypedMessageSealed for Telemetry<T> {
+    #[inline]
+    fn try_cast_from(msg: &Message) -> Result<&Self, Status> {
+        msg.try_cast_tlm::<T>()
+    }
+}
+
+impl<T: Copy + Sized> TypedMessage for Command<T> {}
+impl<T: Copy + Sized> TypedMessage for Telemetry<T> {}
+
+/// The results of the checks made by [`Message::validate`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MsgValidation {
+    /// Whether the message ID is valid.
+    pub msg_id_valid: bool,
+
+    /// Whether the message type in the header matches the type the message ID maps to.
+    pub msg_type_ok: bool,
+
+    /// Whether the message's declared size is enough to hold the headers for its type.
+    pub size_ok: bool,
+
+    /// Whether the message's checksum is correct,
+    /// or `None` if it wasn't checked
+    /// (because it wasn't asked for, the message isn't a command, or the message is too short).
+    pub checksum_ok: Option<bool>,
+}
+
+impl MsgValidation {
+    /// Returns whether all the checks that were made passed.
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        self.msg_id_valid && self.msg_type_ok && self.size_ok && self.checksum_ok != Some(false)
+    }
+}
+
 /// The type of a message.
 #[doc(alias = "CFG_MSG_Type")]
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
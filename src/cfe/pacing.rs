@@ -0,0 +1,186 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pacing telemetry output.
+//!
+//! An application sending several telemetry packets at different rates
+//! usually wants to spread them out over each second,
+//! rather than sending them all at once (and bunching up the downlink).
+//! A [`TlmSchedule`] divides each second into a number of slots,
+//! gives each packet a phase offset within its period,
+//! and reports (or publishes) the packets due in each slot:
+//!
+//! ```rust,ignore
+//! // 10 slots per second; the HK packet at 1 Hz, the attitude packet at 5 Hz,
+//! // and the diagnostics packet every 5 seconds:
+//! let mut schedule = TlmSchedule::new(10, [10, 2, 50]);
+//!
+//! loop {
+//!     // on each 100 ms wakeup (from the scheduler app, a timer, etc.):
+//!     schedule.publish_due([&mut hk, &mut attitude_tlm, &mut diag_tlm])?;
+//! }
+//! ```
+
+use super::hk::{HkPayload, Housekeeping};
+use super::msg::Telemetry;
+use super::Status;
+
+/// Something that can be sent on request, such as a telemetry packet.
+pub trait Publisher {
+    /// Sends the item.
+    fn publish(&mut self) -> Result<(), Status>;
+}
+
+impl<T: Copy> Publisher for Telemetry<T> {
+    /// Time-stamps the packet and sends it, incrementing the sequence count.
+    ///
+    /// Wraps `CFE_SB_TimeStampMsg` and `CFE_SB_TransmitMsg`.
+    #[inline]
+    fn publish(&mut self) -> Result<(), Status> {
+        self.time_stamp();
+        self.transmit(true)
+    }
+}
+
+impl<T: HkPayload> Publisher for Housekeeping<T> {
+    /// Equivalent to [`Housekeeping::send`].
+    #[inline]
+    fn publish(&mut self) -> Result<(), Status> {
+        self.send()
+    }
+}
+
+/// A schedule for sending `N` packets at fixed rates,
+/// staggered across each second.
+///
+/// Time is counted in slots, of which there are a fixed number per second;
+/// the schedule advances one slot per call to [`tick`](Self::tick)
+/// (or [`publish_due`](Self::publish_due)), so those should be called at that rate.
+/// Each packet has a period, in slots, and a phase within that period;
+/// it's due in slot `s` if `s % period == phase`.
+#[derive(Clone, Debug)]
+pub struct TlmSchedule<const N: usize> {
+    periods: [u32; N],
+    phases:  [u32; N],
+
+    /// The number of the next slot.
+    slot: u64,
+}
+
+impl<const N: usize> TlmSchedule<N> {
+    /// Creates a schedule with `slots_per_second` slots per second,
+    /// where packet `i` is sent once every `periods[i]` slots
+    /// (or never, if `periods[i]` is 0).
+    ///
+    /// Each packet in turn is given the phase that shares the fewest slots
+    /// with the packets before it, preferring phases spread evenly across the first second.
+    #[inline]
+    pub fn new(slots_per_second: u32, periods: [u32; N]) -> Self {
+        let slots_per_second = slots_per_second.max(1) as u64;
+
+        // Collisions are counted over one second, or the longest period if that's longer.
+        let window = periods.iter().fold(slots_per_second, |w, &p| w.max(p as u64));
+
+        let mut phases = [0; N];
+        for i in 0..N {
+            let period = periods[i] as u64;
+            if period == 0 {
+                continue;
+            }
+
+            let preferred = (i as u64 * slots_per_second / N as u64) % period;
+            let mut best = (u64::MAX, preferred);
+            for k in 0..period {
+                let candidate = (preferred + k) % period;
+                let collisions = (candidate..window)
+                    .step_by(period as usize)
+                    .map(|slot| {
+                        (0..i)
+                            .filter(|&j| {
+                                periods[j] != 0 && slot % periods[j] as u64 == phases[j] as u64
+                            })
+                            .count() as u64
+                    })
+                    .sum::<u64>();
+                if collisions < best.0 {
+                    best = (collisions, candidate);
+                }
+                if collisions == 0 {
+                    break;
+                }
+            }
+            phases[i] = best.1 as u32;
+        }
+
+        TlmSchedule { periods, phases, slot: 0 }
+    }
+
+    /// Returns packet `index`'s period, in slots (0 meaning the packet is never sent).
+    ///
+    /// Panics if `index >= N`.
+    #[inline]
+    pub fn period(&self, index: usize) -> u32 {
+        self.periods[index]
+    }
+
+    /// Returns packet `index`'s phase within its period, in slots.
+    ///
+    /// Panics if `index >= N`.
+    #[inline]
+    pub fn phase(&self, index: usize) -> u32 {
+        self.phases[index]
+    }
+
+    /// Changes packet `index`'s period and phase
+    /// (0 meaning the packet is never sent).
+    ///
+    /// The phase is reduced modulo the period.
+    /// Panics if `index >= N`.
+    #[inline]
+    pub fn set_rate(&mut self, index: usize, period: u32, phase: u32) {
+        self.periods[index] = period;
+        self.phases[index] = if period == 0 { 0 } else { phase % period };
+    }
+
+    /// Advances the schedule by one slot,
+    /// returning which packets are due in the slot just begun.
+    #[inline]
+    pub fn tick(&mut self) -> [bool; N] {
+        let slot = self.slot;
+        self.slot = self.slot.wrapping_add(1);
+
+        let mut due = [false; N];
+        for (i, d) in due.iter_mut().enumerate() {
+            let period = self.periods[i] as u64;
+            *d = period != 0 && slot % period == self.phases[i] as u64;
+        }
+        due
+    }
+
+    /// Advances the schedule by one slot,
+    /// and publishes the packets (from `publishers`, in the same order as the periods)
+    /// that are due in the slot just begun.
+    ///
+    /// All due packets are published even if some fail;
+    /// the first error, if any, is returned.
+    #[inline]
+    pub fn publish_due(&mut self, publishers: [&mut dyn Publisher; N]) -> Result<(), Status> {
+        let mut result = Ok(());
+        for (publisher, due) in publishers.into_iter().zip(self.tick()) {
+            if due {
+                let r = publisher.publish();
+                if result.is_ok() {
+                    result = r;
+                }
+            }
+        }
+        result
+    }
+
+    /// Restarts the schedule, so that the next call to [`tick`](Self::tick)
+    /// begins slot 0 (the start of a second).
+    #[inline]
+    pub fn restart(&mut self) {
+        self.slot = 0;
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tunable parameters kept in a table.
+//!
+//! Many cFS applications keep their tunable parameters in a loadable table,
+//! and want a consistent copy of them to work from
+//! that only changes at well-defined points.
+//! [`Params`] takes care of the boilerplate:
+//!
+//! ```rust,ignore
+//! let (th, _) = TblHandle::<MyParams>::register(c"MyParams", TblOptions::default(), None)?;
+//! let mut params = Params::new(th, ParamsEventIds { updated: 20, rejected: 21 })
+//!     .with_check(|p| p.gain <= MAX_GAIN);
+//!
+//! loop {
+//!     // once per cycle (e.g., on a "send HK" request):
+//!     params.refresh(&events)?;
+//!
+//!     if let Some(snapshot) = params.get() {
+//!         run_controller(&snapshot.value);
+//!     }
+//! }
+//! ```
+
+use super::evs::{EventSender, EventType};
+use super::tbl::{TableType, TblHandle};
+use super::Status;
+use printf_wrap::PrintfFmt;
+
+/// Format string for the event about parameters failing their check.
+const REJECTED_FMT: PrintfFmt<(u32,)> =
+    crate::printf_fmt!(u32; "Parameters rejected; keeping generation %u");
+
+/// Format string for the event about parameters being taken up.
+const UPDATED_FMT: PrintfFmt<(u32,)> =
+    crate::printf_fmt!(u32; "Parameters updated to generation %u");
+
+/// The event IDs a [`Params`] uses for the events it sends.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParamsEventIds {
+    /// Event ID for the (informational) event sent when a new set of parameters is taken up.
+    pub updated: u16,
+
+    /// Event ID for the error event sent when a new set of parameters fails its check.
+    pub rejected: u16,
+}
+
+/// A copy of a set of parameters,
+/// along with the generation it belongs to.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParamsSnapshot<T> {
+    /// The parameters.
+    pub value: T,
+
+    /// The number of times (wrapping around at 2<sup>32</sup>) the parameters have been taken up,
+    /// counting this one; the first set of parameters is generation 1.
+    pub generation: u32,
+}
+
+/// An application's tunable parameters, kept in a loadable table.
+///
+/// The parameters are only read from the table on a call to [`refresh`](Self::refresh),
+/// so the snapshot returned by [`get`](Self::get) stays the same between refreshes.
+pub struct Params<T: TableType> {
+    th:        TblHandle<T>,
+    current:   Option<ParamsSnapshot<T>>,
+    check:     Option<fn(&T) -> bool>,
+    event_ids: ParamsEventIds,
+
+    /// Whether the table's current contents were rejected,
+    /// so that they aren't checked (and reported) again until they change.
+    rejected: bool,
+}
+
+impl<T: TableType> Params<T> {
+    /// Creates a set of parameters kept in the table with handle `th`,
+    /// sending events with the IDs in `event_ids`.
+    ///
+    /// No parameters are available until the first successful [`refresh`](Self::refresh).
+    #[inline]
+    pub fn new(th: TblHandle<T>, event_ids: ParamsEventIds) -> Self {
+        Params {
+            th,
+            current: None,
+            check: None,
+            event_ids,
+            rejected: false,
+        }
+    }
+
+    /// Sets a check which new table contents must pass before they're taken up,
+    /// in addition to any validation function registered with the table.
+    #[inline]
+    pub fn with_check(mut self, check: fn(&T) -> bool) -> Self {
+        self.check = Some(check);
+        self
+    }
+
+    /// Performs the standard table maintenance and, if the table contents have changed
+    /// (or no parameters have been taken up yet), takes up the new contents
+    /// if they pass the check set with [`with_check`](Self::with_check).
+    ///
+    /// Sends an event when new parameters are taken up or rejected.
+    /// Returns whether new parameters were taken up;
+    /// if the table has never been loaded, returns `Ok(false)`.
+    ///
+    /// Wraps `CFE_TBL_Manage`, `CFE_TBL_GetAddress`, and `CFE_TBL_ReleaseAddress`.
+    #[doc(alias("CFE_TBL_Manage", "CFE_TBL_GetAddress"))]
+    #[inline]
+    pub fn refresh(&mut self, events: &EventSender) -> Result<bool, Status> {
+        let managed_update = self.th.manage()?;
+
+        let (value, updated) = match self.th.get_ref(|tbl, updated| (*tbl, updated)) {
+            Ok(x) => x,
+            Err(Status::TBL_ERR_NEVER_LOADED) => return Ok(false),
+            Err(status) => return Err(status),
+        };
+
+        if !(updated || managed_update || (self.current.is_none() && !self.rejected)) {
+            return Ok(false);
+        }
+
+        let generation = self.generation();
+
+        if let Some(check) = self.check {
+            if !check(&value) {
+                let _ = events.send_event(
+                    self.event_ids.rejected,
+                    EventType::Error,
+                    REJECTED_FMT,
+                    (generation,),
+                );
+                self.rejected = true;
+                return Ok(false);
+            }
+        }
+
+        let generation = generation.wrapping_add(1);
+        self.current = Some(ParamsSnapshot { value, generation });
+        self.rejected = false;
+
+        let _ = events.send_event(
+            self.event_ids.updated,
+            EventType::Information,
+            UPDATED_FMT,
+            (generation,),
+        );
+        Ok(true)
+    }
+
+    /// Returns a copy of the current parameters, if any have been taken up.
+    #[inline]
+    pub fn get(&self) -> Option<ParamsSnapshot<T>> {
+        self.current
+    }
+
+    /// Returns the generation of the current parameters (0 if none have been taken up yet).
+    #[inline]
+    pub fn generation(&self) -> u32 {
+        match self.current {
+            Some(ref snapshot) => snapshot.generation,
+            None => 0,
+        }
+    }
+
+    /// Returns the underlying table handle,
+    /// e.g., for [loading](TblHandle::load) default parameters.
+    #[inline]
+    pub fn table(&mut self) -> &mut TblHandle<T> {
+        &mut self.th
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Performance-log IDs for `n2o4`'s own operations.
+//!
+//! With the `instrument-perf` feature enabled,
+//! some of the wrappers in this crate log
+//! [entry and exit markers](super::es::perf_log_add) around the cFE calls they make,
+//! so that traces viewed with
+//! [the Software Performance Analysis tool](https://github.com/nasa/perfutils-java)
+//! show the time spent in those calls without any markers in the application itself.
+//!
+//! The IDs used are the last few below `CFE_MISSION_ES_PERF_MAX_IDS`;
+//! when the feature is enabled, applications shouldn't use these IDs for their own markers.
+//! Without the feature, nothing is logged, and the IDs are free for other use.
+
+use crate::sys::*;
+
+/// The lowest performance-log ID reserved by `n2o4`.
+pub const PERF_ID_BASE: u32 = CFE_MISSION_ES_PERF_MAX_IDS - 4;
+
+/// Marks calls to `CFE_SB_ReceiveBuffer`
+/// (from [`Pipe::receive_buffer`](super::sb::Pipe::receive_buffer)).
+#[doc(alias = "CFE_SB_ReceiveBuffer")]
+pub const SB_RECEIVE_BUFFER: u32 = PERF_ID_BASE;
+
+/// Marks calls to `CFE_SB_TransmitMsg`
+/// (from [`Message::transmit`](super::msg::Message::transmit) and its relatives).
+#[doc(alias = "CFE_SB_TransmitMsg")]
+pub const SB_TRANSMIT_MSG: u32 = PERF_ID_BASE + 1;
+
+/// Marks calls to `CFE_TBL_GetAddress`
+/// (from [`TblHandle::get_ref`](super::tbl::TblHandle::get_ref) and its relatives).
+#[doc(alias = "CFE_TBL_GetAddress")]
+pub const TBL_GET_ADDRESS: u32 = PERF_ID_BASE + 2;
+
+/// Marks calls to `CFE_TBL_Manage`
+/// (from [`TblHandle::manage`](super::tbl::TblHandle::manage)).
+#[doc(alias = "CFE_TBL_Manage")]
+pub const TBL_MANAGE: u32 = PERF_ID_BASE + 3;
+
+const _: () = assert!(TBL_MANAGE < CFE_MISSION_ES_PERF_MAX_IDS);
+
+/// Runs `f`, bracketed by entry and exit markers for `marker`
+/// if the `instrument-perf` feature is enabled.
+#[cfg(feature = "instrument-perf")]
+#[inline(always)]
+pub(crate) fn timed<T, F: FnOnce() -> T>(marker: u32, f: F) -> T {
+    unsafe { CFE_ES_PerfLogAdd(marker, 0) };
+    let result = f();
+    unsafe { CFE_ES_PerfLogAdd(marker, 1) };
+    result
+}
+
+/// Runs `f`, bracketed by entry and exit markers for `marker`
+/// if the `instrument-perf` feature is enabled.
+#[cfg(not(feature = "instrument-perf"))]
+#[inline(always)]
+pub(crate) fn timed<T, F: FnOnce() -> T>(_marker: u32, f: F) -> T {
+    f()
+}
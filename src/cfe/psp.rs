@@ -0,0 +1,542 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Platform Support Package (PSP) APIs.
+
+use crate::sys::*;
+use crate::utils::NegativeI32;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// An error code, as returned by many PSP API functions.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct PspError {
+    /// Numeric error code from the PSP.
+    pub code: NegativeI32,
+}
+
+macro_rules! psp_err_consts {
+    ($($error_code:ident , $doc:expr),+ $(,)?) => {
+        impl PspError {
+            $(
+                #[doc = concat!($doc, ".\n\nWraps `", stringify!($error_code), "`.")]
+                pub const $error_code: Self = PspError { code: NegativeI32::new_or_panic($error_code) };
+            )+
+        }
+    };
+}
+
+psp_err_consts! {
+    CFE_PSP_ERROR, "Failed execution",
+    CFE_PSP_INVALID_POINTER, "Invalid pointer",
+    CFE_PSP_ERROR_ADDRESS_MISALIGNED, "Address misalignment",
+    CFE_PSP_ERROR_TIMEOUT, "Error timeout",
+    CFE_PSP_INVALID_MEM_ADDR, "Invalid memory address",
+    CFE_PSP_INVALID_MEM_TYPE, "Invalid memory type",
+    CFE_PSP_INVALID_MEM_RANGE, "Invalid memory range",
+    CFE_PSP_INVALID_MEM_WORDSIZE, "Invalid memory word size",
+    CFE_PSP_INVALID_MEM_SIZE, "Invalid memory size",
+    CFE_PSP_INVALID_MEM_ATTR, "Invalid memory attributes",
+    CFE_PSP_ERROR_NOT_IMPLEMENTED, "Not implemented",
+}
+
+impl core::fmt::Display for PspError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PSP error {}", self.code.as_i32())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PspError {}
+
+/// Treats `code` as a PSP status code,
+/// returning `Err` if it's an error.
+#[inline]
+fn psp_status(code: i32) -> Result<(), PspError> {
+    match NegativeI32::new(code) {
+        Some(code) => Err(PspError { code }),
+        None => Ok(()),
+    }
+}
+
+/// Whether a [`Watchdog`] currently exists (from this copy of the crate).
+static WATCHDOG_TAKEN: AtomicBool = AtomicBool::new(false);
+
+/// Exclusive access to the platform's watchdog timer.
+///
+/// Only one `Watchdog` may exist at a time,
+/// so that servicing the watchdog is the responsibility of a single owner,
+/// such as a health-monitoring application.
+/// This is only enforced among the users of one copy of this crate:
+/// applications that each link their own copy of `n2o4`
+/// can each take a `Watchdog`,
+/// so which application services the watchdog must still be agreed on.
+///
+/// ```rust,ignore
+/// let mut wd = Watchdog::take().ok_or(Status::STATUS_REQUEST_ALREADY_PENDING)?;
+/// wd.set_timeout_ms(5000);
+/// wd.enable();
+///
+/// loop {
+///     // ...check that everything is healthy...
+///     wd.service();
+/// }
+/// ```
+///
+/// Dropping the `Watchdog` leaves the watchdog timer in whatever state it was,
+/// and allows it to be taken again.
+#[derive(Debug)]
+pub struct Watchdog {
+    _private: (),
+}
+
+impl Watchdog {
+    /// Returns the `Watchdog`, or `None` if it's already held elsewhere.
+    #[inline]
+    pub fn take() -> Option<Watchdog> {
+        if WATCHDOG_TAKEN.swap(true, Ordering::Acquire) {
+            None
+        } else {
+            Some(Watchdog { _private: () })
+        }
+    }
+
+    /// Starts the watchdog timer;
+    /// once it's running, it must be [serviced](Self::service) within each timeout period
+    /// to avoid a processor reset.
+    ///
+    /// Wraps `CFE_PSP_WatchdogEnable`.
+    #[doc(alias = "CFE_PSP_WatchdogEnable")]
+    #[inline]
+    pub fn enable(&mut self) {
+        unsafe { CFE_PSP_WatchdogEnable() };
+    }
+
+    /// Stops the watchdog timer.
+    ///
+    /// Wraps `CFE_PSP_WatchdogDisable`.
+    #[doc(alias = "CFE_PSP_WatchdogDisable")]
+    #[inline]
+    pub fn disable(&mut self) {
+        unsafe { CFE_PSP_WatchdogDisable() };
+    }
+
+    /// Restarts the watchdog timer's countdown.
+    ///
+    /// Wraps `CFE_PSP_WatchdogService`.
+    #[doc(alias = "CFE_PSP_WatchdogService")]
+    #[inline]
+    pub fn service(&mut self) {
+        unsafe { CFE_PSP_WatchdogService() };
+    }
+
+    /// Returns the watchdog timer's timeout period, in milliseconds.
+    ///
+    /// Wraps `CFE_PSP_WatchdogGet`.
+    #[doc(alias = "CFE_PSP_WatchdogGet")]
+    #[inline]
+    pub fn timeout_ms(&self) -> u32 {
+        unsafe { CFE_PSP_WatchdogGet() }
+    }
+
+    /// Sets the watchdog timer's timeout period to `timeout_ms` milliseconds.
+    ///
+    /// Wraps `CFE_PSP_WatchdogSet`.
+    #[doc(alias = "CFE_PSP_WatchdogSet")]
+    #[inline]
+    pub fn set_timeout_ms(&mut self, timeout_ms: u32) {
+        unsafe { CFE_PSP_WatchdogSet(timeout_ms) };
+    }
+}
+
+impl Drop for Watchdog {
+    #[inline]
+    fn drop(&mut self) {
+        WATCHDOG_TAKEN.store(false, Ordering::Release);
+    }
+}
+
+/// A kind of memory in the PSP's memory table.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+#[non_exhaustive]
+pub enum MemoryType {
+    /// Ordinary RAM.
+    #[doc(alias = "CFE_PSP_MEM_RAM")]
+    Ram    = CFE_PSP_MEM_RAM,
+
+    /// EEPROM.
+    #[doc(alias = "CFE_PSP_MEM_EEPROM")]
+    Eeprom = CFE_PSP_MEM_EEPROM,
+
+    /// Either RAM or EEPROM.
+    #[doc(alias = "CFE_PSP_MEM_ANY")]
+    Any    = CFE_PSP_MEM_ANY,
+}
+
+/// Checks that the `size` bytes starting at address `address`
+/// lie within a range of type `mem_type` in the PSP's memory table.
+///
+/// Wraps `CFE_PSP_MemValidateRange`.
+#[doc(alias = "CFE_PSP_MemValidateRange")]
+#[inline]
+pub fn validate_range(address: usize, size: usize, mem_type: MemoryType) -> Result<(), PspError> {
+    psp_status(unsafe { CFE_PSP_MemValidateRange(address, size, mem_type as u32) })
+}
+
+/// Reads a `u8` from address `address`.
+///
+/// # Safety
+///
+/// `address` must be readable, and reading from it must not have side effects
+/// that other code isn't prepared for.
+/// [`ValidatedRegion`] provides reads checked against the PSP's memory table instead.
+///
+/// Wraps `CFE_PSP_MemRead8`.
+#[doc(alias = "CFE_PSP_MemRead8")]
+#[inline]
+pub unsafe fn mem_read8(address: usize) -> Result<u8, PspError> {
+    let mut value: u8 = 0;
+    psp_status(CFE_PSP_MemRead8(address, &mut value))?;
+    Ok(value)
+}
+
+/// Writes `value` to address `address`.
+///
+/// # Safety
+///
+/// `address` must be writable, and no other code may rely on the memory there
+/// staying unchanged.
+///
+/// Wraps `CFE_PSP_MemWrite8`.
+#[doc(alias = "CFE_PSP_MemWrite8")]
+#[inline]
+pub unsafe fn mem_write8(address: usize, value: u8) -> Result<(), PspError> {
+    psp_status(CFE_PSP_MemWrite8(address, value))
+}
+
+/// Reads a `u16` from address `address`.
+///
+/// # Safety
+///
+/// `address` must be readable, and reading from it must not have side effects
+/// that other code isn't prepared for.
+/// [`ValidatedRegion`] provides reads checked against the PSP's memory table instead.
+///
+/// Wraps `CFE_PSP_MemRead16`.
+#[doc(alias = "CFE_PSP_MemRead16")]
+#[inline]
+pub unsafe fn mem_read16(address: usize) -> Result<u16, PspError> {
+    let mut value: u16 = 0;
+    psp_status(CFE_PSP_MemRead16(address, &mut value))?;
+    Ok(value)
+}
+
+/// Writes `value` to address `address`.
+///
+/// # Safety
+///
+/// `address` must be writable, and no other code may rely on the memory there
+/// staying unchanged.
+///
+/// Wraps `CFE_PSP_MemWrite16`.
+#[doc(alias = "CFE_PSP_MemWrite16")]
+#[inline]
+pub unsafe fn mem_write16(address: usize, value: u16) -> Result<(), PspError> {
+    psp_status(CFE_PSP_MemWrite16(address, value))
+}
+
+/// Reads a `u32` from address `address`.
+///
+/// # Safety
+///
+/// `address` must be readable, and reading from it must not have side effects
+/// that other code isn't prepared for.
+/// [`ValidatedRegion`] provides reads checked against the PSP's memory table instead.
+///
+/// Wraps `CFE_PSP_MemRead32`.
+#[doc(alias = "CFE_PSP_MemRead32")]
+#[inline]
+pub unsafe fn mem_read32(address: usize) -> Result<u32, PspError> {
+    let mut value: u32 = 0;
+    psp_status(CFE_PSP_MemRead32(address, &mut value))?;
+    Ok(value)
+}
+
+/// Writes `value` to address `address`.
+///
+/// # Safety
+///
+/// `address` must be writable, and no other code may rely on the memory there
+/// staying unchanged.
+///
+/// Wraps `CFE_PSP_MemWrite32`.
+#[doc(alias = "CFE_PSP_MemWrite32")]
+#[inline]
+pub unsafe fn mem_write32(address: usize, value: u32) -> Result<(), PspError> {
+    psp_status(CFE_PSP_MemWrite32(address, value))
+}
+
+/// A range of memory which has been checked against the PSP's memory table,
+/// and so may be read from without further checks.
+///
+/// Reads and writes are bounds-checked against the region,
+/// failing with [`CFE_PSP_INVALID_MEM_RANGE`](PspError::CFE_PSP_INVALID_MEM_RANGE)
+/// if they would go outside it:
+///
+/// ```rust,ignore
+/// let region = ValidatedRegion::new(dwell_addr, 64, MemoryType::Any)?;
+/// let sample = region.read32(offset)?;
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ValidatedRegion {
+    start:    usize,
+    size:     usize,
+    mem_type: MemoryType,
+}
+
+impl ValidatedRegion {
+    /// Checks that the `size` bytes starting at address `start`
+    /// lie within a range of type `mem_type` in the PSP's memory table
+    /// and, if so, returns the region they make up.
+    ///
+    /// Wraps `CFE_PSP_MemValidateRange`.
+    #[doc(alias = "CFE_PSP_MemValidateRange")]
+    #[inline]
+    pub fn new(start: usize, size: usize, mem_type: MemoryType) -> Result<Self, PspError> {
+        validate_range(start, size, mem_type)?;
+
+        Ok(ValidatedRegion { start, size, mem_type })
+    }
+
+    /// Returns the region's starting address.
+    #[inline]
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Returns the region's size, in bytes.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the type of memory the region was checked against.
+    #[inline]
+    pub fn mem_type(&self) -> MemoryType {
+        self.mem_type
+    }
+
+    /// Returns the address `offset` bytes into the region,
+    /// if a `T` there lies entirely within the region.
+    #[inline]
+    fn address_of<T>(&self, offset: usize) -> Result<usize, PspError> {
+        match offset.checked_add(core::mem::size_of::<T>()) {
+            Some(end) if end <= self.size => Ok(self.start + offset),
+            _ => Err(PspError::CFE_PSP_INVALID_MEM_RANGE),
+        }
+    }
+
+    /// Reads a `u8` from `offset` bytes into the region.
+    ///
+    /// Wraps `CFE_PSP_MemRead8`.
+    #[doc(alias = "CFE_PSP_MemRead8")]
+    #[inline]
+    pub fn read8(&self, offset: usize) -> Result<u8, PspError> {
+        let address = self.address_of::<u8>(offset)?;
+
+        // Safety: the address was checked against the PSP's memory table.
+        unsafe { mem_read8(address) }
+    }
+
+    /// Reads a `u16` from `offset` bytes into the region.
+    ///
+    /// Wraps `CFE_PSP_MemRead16`.
+    #[doc(alias = "CFE_PSP_MemRead16")]
+    #[inline]
+    pub fn read16(&self, offset: usize) -> Result<u16, PspError> {
+        let address = self.address_of::<u16>(offset)?;
+
+        // Safety: the address was checked against the PSP's memory table.
+        unsafe { mem_read16(address) }
+    }
+
+    /// Reads a `u32` from `offset` bytes into the region.
+    ///
+    /// Wraps `CFE_PSP_MemRead32`.
+    #[doc(alias = "CFE_PSP_MemRead32")]
+    #[inline]
+    pub fn read32(&self, offset: usize) -> Result<u32, PspError> {
+        let address = self.address_of::<u32>(offset)?;
+
+        // Safety: the address was checked against the PSP's memory table.
+        unsafe { mem_read32(address) }
+    }
+
+    /// Writes `value` at `offset` bytes into the region.
+    ///
+    /// # Safety
+    ///
+    /// No other code may rely on the memory written to staying unchanged.
+    ///
+    /// Wraps `CFE_PSP_MemWrite8`.
+    #[doc(alias = "CFE_PSP_MemWrite8")]
+    #[inline]
+    pub unsafe fn write8(&self, offset: usize, value: u8) -> Result<(), PspError> {
+        let address = self.address_of::<u8>(offset)?;
+        mem_write8(address, value)
+    }
+
+    /// Writes `value` at `offset` bytes into the region.
+    ///
+    /// # Safety
+    ///
+    /// No other code may rely on the memory written to staying unchanged.
+    ///
+    /// Wraps `CFE_PSP_MemWrite16`.
+    #[doc(alias = "CFE_PSP_MemWrite16")]
+    #[inline]
+    pub unsafe fn write16(&self, offset: usize, value: u16) -> Result<(), PspError> {
+        let address = self.address_of::<u16>(offset)?;
+        mem_write16(address, value)
+    }
+
+    /// Writes `value` at `offset` bytes into the region.
+    ///
+    /// # Safety
+    ///
+    /// No other code may rely on the memory written to staying unchanged.
+    ///
+    /// Wraps `CFE_PSP_MemWrite32`.
+    #[doc(alias = "CFE_PSP_MemWrite32")]
+    #[inline]
+    pub unsafe fn write32(&self, offset: usize, value: u32) -> Result<(), PspError> {
+        let address = self.address_of::<u32>(offset)?;
+        mem_write32(address, value)
+    }
+}
+
+/// Allows writes to EEPROM bank `bank`.
+///
+/// Wraps `CFE_PSP_EepromWriteEnable`.
+#[doc(alias = "CFE_PSP_EepromWriteEnable")]
+#[inline]
+pub fn eeprom_write_enable(bank: u32) -> Result<(), PspError> {
+    psp_status(unsafe { CFE_PSP_EepromWriteEnable(bank) })
+}
+
+/// Disallows writes to EEPROM bank `bank`.
+///
+/// Wraps `CFE_PSP_EepromWriteDisable`.
+#[doc(alias = "CFE_PSP_EepromWriteDisable")]
+#[inline]
+pub fn eeprom_write_disable(bank: u32) -> Result<(), PspError> {
+    psp_status(unsafe { CFE_PSP_EepromWriteDisable(bank) })
+}
+
+/// Writes `value` to EEPROM address `address`.
+///
+/// # Safety
+///
+/// `address` must be in EEPROM, and no other code may rely on the memory there
+/// staying unchanged.
+///
+/// Wraps `CFE_PSP_EepromWrite8`.
+#[doc(alias = "CFE_PSP_EepromWrite8")]
+#[inline]
+pub unsafe fn eeprom_write8(address: usize, value: u8) -> Result<(), PspError> {
+    psp_status(CFE_PSP_EepromWrite8(address, value))
+}
+
+/// Writes `value` to EEPROM address `address`.
+///
+/// # Safety
+///
+/// `address` must be in EEPROM, and no other code may rely on the memory there
+/// staying unchanged.
+///
+/// Wraps `CFE_PSP_EepromWrite16`.
+#[doc(alias = "CFE_PSP_EepromWrite16")]
+#[inline]
+pub unsafe fn eeprom_write16(address: usize, value: u16) -> Result<(), PspError> {
+    psp_status(CFE_PSP_EepromWrite16(address, value))
+}
+
+/// Writes `value` to EEPROM address `address`.
+///
+/// # Safety
+///
+/// `address` must be in EEPROM, and no other code may rely on the memory there
+/// staying unchanged.
+///
+/// Wraps `CFE_PSP_EepromWrite32`.
+#[doc(alias = "CFE_PSP_EepromWrite32")]
+#[inline]
+pub unsafe fn eeprom_write32(address: usize, value: u32) -> Result<(), PspError> {
+    psp_status(CFE_PSP_EepromWrite32(address, value))
+}
+
+/// A platform-reserved area of memory, such as the reset area.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MemoryArea {
+    /// The area's starting address.
+    pub start: usize,
+
+    /// The area's size, in bytes.
+    pub size: usize,
+}
+
+impl MemoryArea {
+    /// Returns the area's contents.
+    ///
+    /// # Safety
+    ///
+    /// Nothing may write to the area while the returned slice exists.
+    #[inline]
+    pub unsafe fn as_slice(&self) -> &'static [u8] {
+        core::slice::from_raw_parts(self.start as *const u8, self.size)
+    }
+
+    /// Returns the area's contents, for modification.
+    ///
+    /// # Safety
+    ///
+    /// Nothing else may access the area while the returned slice exists.
+    #[inline]
+    pub unsafe fn as_slice_mut(&self) -> &'static mut [u8] {
+        core::slice::from_raw_parts_mut(self.start as *mut u8, self.size)
+    }
+}
+
+/// Obtains the location and size of an area with `getter`.
+#[inline]
+fn get_area(
+    getter: unsafe extern "C" fn(*mut cpuaddr, *mut u32) -> i32,
+) -> Result<MemoryArea, PspError> {
+    let mut start: cpuaddr = 0;
+    let mut size: u32 = 0;
+
+    psp_status(unsafe { getter(&mut start, &mut size) })?;
+
+    Ok(MemoryArea { start, size: size as usize })
+}
+
+/// Returns the reset area,
+/// which is preserved across processor resets (but not power-on resets).
+///
+/// Wraps `CFE_PSP_GetResetArea`.
+#[doc(alias = "CFE_PSP_GetResetArea")]
+#[inline]
+pub fn reset_area() -> Result<MemoryArea, PspError> {
+    get_area(CFE_PSP_GetResetArea)
+}
+
+/// Returns the user-reserved area,
+/// which is preserved across processor resets (but not power-on resets)
+/// and is set aside for mission use.
+///
+/// Wraps `CFE_PSP_GetUserReservedArea`.
+#[doc(alias = "CFE_PSP_GetUserReservedArea")]
+#[inline]
+pub fn user_reserved_area() -> Result<MemoryArea, PspError> {
+    get_area(CFE_PSP_GetUserReservedArea)
+}
@@ -0,0 +1,243 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Recording software-bus messages to files.
+//!
+//! A [`Recorder`] subscribes a pipe to a set of message IDs
+//! and writes each message received to a file with a standard [`FsHeader`],
+//! starting a new file once the current one gets too big or too old,
+//! in the manner of the Data Storage (DS) application:
+//!
+//! ```rust,ignore
+//! let config = RecorderConfig {
+//!     dir:           OsPath::new("/ram/rec")?,
+//!     prefix:        "hk",
+//!     extension:     "dat",
+//!     description:   c"HK recording",
+//!     sub_type:      0,
+//!     max_file_size: 64 * 1024,
+//!     max_file_age:  600,
+//! };
+//! let mut recorder = Recorder::new(Pipe::new(32, c"REC_PIPE")?, &[HK_TLM_MID], config)?;
+//!
+//! loop {
+//!     recorder.process(TimeOut::PendForever)?;
+//! }
+//! ```
+
+use core::fmt::Write;
+
+use super::fs::FsHeader;
+use super::msg::Message;
+use super::sb::{MsgId, Pipe, TimeOut};
+use super::time::SysTime;
+use super::Status;
+use crate::error::Error;
+use crate::osal::file::{AccessMode, FileFlags, OwnedFile};
+use crate::osal::fs::{OsPath, MAX_FILE_NAME};
+use crate::osal::OsalError;
+use crate::time_source::{CfsClock, TimeSource};
+use crate::utils::CStrBuf;
+use core::ffi::CStr;
+
+/// Where and how a [`Recorder`] writes its files.
+#[derive(Clone, Copy, Debug)]
+pub struct RecorderConfig {
+    /// The directory files are written to.
+    pub dir: OsPath,
+
+    /// The start of each file's name, which is followed by an eight-digit sequence number.
+    pub prefix: &'static str,
+
+    /// The extension given to each file (without the `.`), or `""` for none.
+    pub extension: &'static str,
+
+    /// The description written in each file's header.
+    pub description: &'static CStr,
+
+    /// The file subtype written in each file's header.
+    pub sub_type: u32,
+
+    /// The size, in bytes (header included), past which a new file is started,
+    /// or 0 for no limit.
+    pub max_file_size: u32,
+
+    /// The age, in seconds, past which a new file is started,
+    /// or 0 for no limit.
+    pub max_file_age: u32,
+}
+
+/// The state of a [`Recorder`]'s files.
+struct Files<S> {
+    config:    RecorderConfig,
+    current:   Option<OwnedFile>,
+    path:      Option<OsPath>,
+    size:      u32,
+    opened_at: SysTime,
+    next_seq:  u32,
+    clock:     S,
+}
+
+/// Records the messages arriving on a pipe to a series of files.
+///
+/// Files are named `<prefix><sequence number>.<extension>`,
+/// with sequence numbers starting from 0;
+/// any existing file with the same name is overwritten.
+///
+/// File ages are measured by the spacecraft time, as read through a [`TimeSource`];
+/// see [`with_time_source`](Self::with_time_source).
+pub struct Recorder<S = CfsClock> {
+    pipe:  Pipe,
+    files: Files<S>,
+}
+
+impl Recorder {
+    /// Creates a recorder taking messages from `pipe`,
+    /// which it subscribes to each of `msg_ids`.
+    ///
+    /// No file is opened until the first message arrives.
+    #[inline]
+    pub fn new(pipe: Pipe, msg_ids: &[MsgId], config: RecorderConfig) -> Result<Self, Status> {
+        Self::with_time_source(pipe, msg_ids, config, CfsClock)
+    }
+}
+
+impl<S: TimeSource> Recorder<S> {
+    /// Like [`new`](Recorder::new), but reading the time through `clock`.
+    #[inline]
+    pub fn with_time_source(
+        mut pipe: Pipe,
+        msg_ids: &[MsgId],
+        config: RecorderConfig,
+        clock: S,
+    ) -> Result<Self, Status> {
+        for &msg_id in msg_ids {
+            pipe.subscribe(msg_id)?;
+        }
+
+        Ok(Recorder {
+            pipe,
+            files: Files {
+                config,
+                current: None,
+                path: None,
+                size: 0,
+                opened_at: SysTime::new(0, 0),
+                next_seq: 0,
+                clock,
+            },
+        })
+    }
+
+    /// Waits up to `time_out` for a message to arrive on the pipe and records it.
+    ///
+    /// Returns whether a message was recorded
+    /// (`Ok(false)` means no message arrived in time).
+    #[inline]
+    pub fn process(&mut self, time_out: TimeOut) -> Result<bool, Error> {
+        let files = &mut self.files;
+
+        self.pipe.receive_buffer(time_out, |msg| match msg {
+            Ok(msg) => files.record(msg).map(|()| true),
+            Err(Status::SB_TIME_OUT) | Err(Status::SB_NO_MESSAGE) => Ok(false),
+            Err(status) => Err(status.into()),
+        })
+    }
+
+    /// Records `msg` (which needn't have come from the recorder's pipe).
+    #[inline]
+    pub fn record(&mut self, msg: &Message) -> Result<(), Error> {
+        self.files.record(msg)
+    }
+
+    /// Closes the current file, if any,
+    /// so that the next message recorded starts a new one.
+    #[inline]
+    pub fn rollover(&mut self) {
+        self.files.close();
+    }
+
+    /// Returns the path of the file currently being written, if any.
+    #[inline]
+    pub fn current_path(&self) -> Option<&OsPath> {
+        self.files.path.as_ref()
+    }
+
+    /// Returns the pipe messages are taken from, e.g., to subscribe it to more message IDs.
+    #[inline]
+    pub fn pipe(&mut self) -> &mut Pipe {
+        &mut self.pipe
+    }
+}
+
+impl<S: TimeSource> Files<S> {
+    fn record(&mut self, msg: &Message) -> Result<(), Error> {
+        let len = msg.size()?;
+        let now = self.clock.spacecraft_time();
+
+        if self.current.is_some() && self.is_full(len, now) {
+            self.close();
+        }
+
+        let file = match self.current {
+            Some(ref mut file) => file,
+            None => self.open_next(now)?,
+        };
+
+        // `msg` may be the header of a typed message whose payload has padding,
+        // so its bytes are handed straight to OSAL rather than read here as a `&[u8]`.
+        let written = unsafe { file.write_raw(msg as *const Message as *const u8, len) }?;
+        if written != len {
+            return Err(OsalError::OS_ERROR.into());
+        }
+        self.size = self.size.saturating_add(written as u32);
+
+        Ok(())
+    }
+
+    /// Returns whether the current file is due to be replaced
+    /// before writing `len` more bytes at time `now`.
+    fn is_full(&self, len: usize, now: SysTime) -> bool {
+        let max_size = self.config.max_file_size;
+        let max_age = self.config.max_file_age;
+
+        let too_big = max_size != 0 && self.size as usize + len > max_size as usize;
+        let too_old =
+            max_age != 0 && (now < self.opened_at || (now - self.opened_at).seconds() >= max_age);
+
+        too_big || too_old
+    }
+
+    fn open_next(&mut self, now: SysTime) -> Result<&mut OwnedFile, Error> {
+        let mut name: CStrBuf<MAX_FILE_NAME> = CStrBuf::new_u8(b"");
+        let ext_sep = if self.config.extension.is_empty() { "" } else { "." };
+        write!(
+            name,
+            "{}{:08}{}{}",
+            self.config.prefix, self.next_seq, ext_sep, self.config.extension
+        )
+        .map_err(|_| OsalError::OS_FS_ERR_NAME_TOO_LONG)?;
+        let path = self.config.dir.join(name.as_str())?;
+
+        let file = OwnedFile::open_create(
+            &path,
+            FileFlags::CREATE | FileFlags::TRUNCATE,
+            AccessMode::WriteOnly,
+        )?;
+        let mut header = FsHeader::new(self.config.description, self.config.sub_type);
+        header.write_to(&file)?;
+
+        self.next_seq = self.next_seq.wrapping_add(1);
+        self.path = Some(path);
+        self.size = core::mem::size_of::<crate::sys::CFE_FS_Header_t>() as u32;
+        self.opened_at = now;
+
+        Ok(self.current.insert(file))
+    }
+
+    fn close(&mut self) {
+        self.current = None;
+        self.path = None;
+        self.size = 0;
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Routing messages by message ID.
+//!
+//! A [`MsgRoute`] maps message IDs to small indices
+//! (into an array of handlers, a `match`, etc.),
+//! looked up by binary search rather than by checking each message ID in turn.
+//! It's built at compile time, sorting its entries
+//! and rejecting duplicate message IDs with a compile error:
+//!
+//! ```rust,ignore
+//! const WAKEUP: usize = 0;
+//! const COMMAND: usize = 1;
+//! const SEND_HK: usize = 2;
+//!
+//! const ROUTES: MsgRoute<3> = MsgRoute::new([
+//!     (MY_APP_CMD_MID, COMMAND),
+//!     (MY_APP_WAKEUP_MID, WAKEUP),
+//!     (MY_APP_SEND_HK_MID, SEND_HK),
+//! ]);
+//!
+//! for msg_id in ROUTES.msg_ids() {
+//!     pipe.subscribe(MsgId::from(msg_id))?;
+//! }
+//!
+//! // then, in the main loop:
+//! match ROUTES.route(msg)? {
+//!     Some(WAKEUP) => app.do_periodic_work(),
+//!     Some(COMMAND) => app.handle_command(msg),
+//!     Some(SEND_HK) => app.send_hk(),
+//!     _ => app.report_unexpected(msg),
+//! }
+//! ```
+
+use super::msg::Message;
+use super::sb::{MsgId, MsgId_Atom};
+use super::Status;
+
+/// A table mapping `N` message IDs to indices,
+/// sorted by message ID for lookup by binary search.
+///
+/// See [the module documentation](self) for an example.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MsgRoute<const N: usize> {
+    entries: [(MsgId_Atom, usize); N],
+}
+
+impl<const N: usize> MsgRoute<N> {
+    /// Creates a table from `(message ID, index)` pairs, in any order.
+    ///
+    /// # Panics
+    ///
+    /// Panics (at compile time, if used to initialize a `const` or `static`)
+    /// if a message ID appears more than once.
+    pub const fn new(routes: [(MsgId_Atom, usize); N]) -> Self {
+        let mut entries = routes;
+
+        // Insertion sort, as the more efficient sorts in `core` aren't `const`:
+        let mut i = 1;
+        while i < N {
+            let mut j = i;
+            while j > 0 && entries[j - 1].0 > entries[j].0 {
+                let tmp = entries[j - 1];
+                entries[j - 1] = entries[j];
+                entries[j] = tmp;
+                j -= 1;
+            }
+            i += 1;
+        }
+
+        let mut i = 1;
+        while i < N {
+            if entries[i - 1].0 == entries[i].0 {
+                panic!("duplicate message ID in MsgRoute");
+            }
+            i += 1;
+        }
+
+        MsgRoute { entries }
+    }
+
+    /// Returns the index for the numeric message ID `msg_id`, if it's in the table.
+    #[inline]
+    pub fn lookup_value(&self, msg_id: MsgId_Atom) -> Option<usize> {
+        self.entries.binary_search_by_key(&msg_id, |&(id, _)| id).ok().map(|i| self.entries[i].1)
+    }
+
+    /// Returns the index for `msg_id`, if it's in the table.
+    #[inline]
+    pub fn lookup(&self, msg_id: MsgId) -> Option<usize> {
+        self.lookup_value(MsgId_Atom::from(msg_id))
+    }
+
+    /// Returns the index for the message ID of `msg`, if it's in the table.
+    ///
+    /// Wraps `CFE_MSG_GetMsgId`.
+    #[doc(alias = "CFE_MSG_GetMsgId")]
+    #[inline]
+    pub fn route(&self, msg: &Message) -> Result<Option<usize>, Status> {
+        Ok(self.lookup(msg.msgid()?))
+    }
+
+    /// Returns an iterator over the message IDs in the table, in ascending order
+    /// (e.g., for subscribing to them).
+    #[inline]
+    pub fn msg_ids(&self) -> impl Iterator<Item = MsgId_Atom> + '_ {
+        self.entries.iter().map(|&(id, _)| id)
+    }
+}
@@ -3,10 +3,14 @@
 
 //! Software Bus system.
 
-use core::ffi::CStr;
+use core::ffi::{c_void, CStr};
+use core::fmt;
 use core::marker::PhantomData;
+use core::mem;
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
 
-use super::msg::{Message, MsgType};
+use super::msg::{Message, MsgType, TypedMessage};
 use super::Status;
 use crate::sys::*;
 
@@ -24,7 +28,7 @@ pub use crate::sys::CFE_SB_MsgId_Atom_t as MsgId_Atom;
 ///
 /// Wraps `CFE_SB_MsgId_t`.
 #[doc(alias = "CFG_SB_MsgId_t")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
 pub struct MsgId {
     pub(crate) id: CFE_SB_MsgId_t,
 }
@@ -39,6 +43,26 @@ impl MsgId {
         unsafe { CFE_SB_IsValidMsgId(self.id) }
     }
 
+    /// Converts the numeric message ID `val` to a [`MsgId`],
+    /// checking that the result is valid.
+    ///
+    /// Returns [`SB_BAD_ARGUMENT`](Status::SB_BAD_ARGUMENT)
+    /// (the status SB itself gives for invalid message IDs) if it isn't.
+    /// Use this rather than the [`From`] conversion for message IDs from outside sources,
+    /// such as ground commands, so that invalid IDs are rejected up front.
+    ///
+    /// Wraps `CFE_SB_ValueToMsgId` and `CFE_SB_IsValidMsgId`.
+    #[doc(alias("CFE_SB_ValueToMsgId", "CFE_SB_IsValidMsgId"))]
+    #[inline]
+    pub fn try_from_value(val: MsgId_Atom) -> Result<MsgId, Status> {
+        let msg_id = MsgId::from(val);
+        if msg_id.is_valid() {
+            Ok(msg_id)
+        } else {
+            Err(Status::SB_BAD_ARGUMENT)
+        }
+    }
+
     /// Returns the message type this message ID corresponds to.
     ///
     /// Wraps `CFE_MSG_GetTypeFromMsgId`.
@@ -69,18 +93,23 @@ impl PartialEq<MsgId> for MsgId {
     #[doc(alias = "CFG_SB_MsgId_Equal")]
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        unsafe { SHIM_CFE_SB_MsgId_Equal(self.id, other.id) }
+        crate::shims::CFE_SB_MsgId_Equal(self.id, other.id)
     }
 }
 
 impl Eq for MsgId {}
 
+/// This conversion doesn't check that the result is [valid](MsgId::is_valid);
+/// an invalid message ID is only rejected once it's used
+/// (e.g., when subscribing to it or transmitting a message with it).
+/// [`MsgId::try_from_value`] checks up front.
+///
 /// Wraps `CFE_SB_ValueToMsgId`.
 impl From<MsgId_Atom> for MsgId {
     #[doc(alias = "CFG_SB_ValueToMsgId")]
     #[inline]
     fn from(val: MsgId_Atom) -> Self {
-        let msg_id = unsafe { SHIM_CFE_SB_ValueToMsgId(val) };
+        let msg_id = crate::shims::CFE_SB_ValueToMsgId(val);
         MsgId { id: msg_id }
     }
 }
@@ -90,7 +119,52 @@ impl From<MsgId> for MsgId_Atom {
     #[doc(alias = "CFG_SB_MsgIdToValue")]
     #[inline]
     fn from(id: MsgId) -> Self {
-        unsafe { SHIM_CFE_SB_MsgIdToValue(id.id) }
+        crate::shims::CFE_SB_MsgIdToValue(id.id)
+    }
+}
+
+/// Shows the numeric value in hex, as mission ICDs usually do (e.g., `0x1880`).
+///
+/// Wraps `CFE_SB_MsgIdToValue`.
+impl fmt::Display for MsgId {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{:04X}", MsgId_Atom::from(*self))
+    }
+}
+
+/// Shows the numeric value in hex, and whether the message ID is valid
+/// (e.g., `MsgId(0x1880)`, or `MsgId(0xFFFFFFFF, invalid)`).
+///
+/// Wraps `CFE_SB_MsgIdToValue` and `CFE_SB_IsValidMsgId`.
+impl fmt::Debug for MsgId {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_valid() {
+            write!(f, "MsgId({})", self)
+        } else {
+            write!(f, "MsgId({}, invalid)", self)
+        }
+    }
+}
+
+/// Formats the numeric value.
+///
+/// Wraps `CFE_SB_MsgIdToValue`.
+impl fmt::LowerHex for MsgId {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(&MsgId_Atom::from(*self), f)
+    }
+}
+
+/// Formats the numeric value.
+///
+/// Wraps `CFE_SB_MsgIdToValue`.
+impl fmt::UpperHex for MsgId {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(&MsgId_Atom::from(*self), f)
     }
 }
 
@@ -332,7 +406,10 @@ impl Pipe {
     {
         let mut buf: *mut CFE_SB_Buffer_t = core::ptr::null_mut();
 
-        let s: Status = unsafe { CFE_SB_ReceiveBuffer(&mut buf, self.id, time_out.into()) }.into();
+        let s: Status = super::perf::timed(super::perf::SB_RECEIVE_BUFFER, || unsafe {
+            CFE_SB_ReceiveBuffer(&mut buf, self.id, time_out.into())
+        })
+        .into();
 
         let result: Result<&Message, Status>;
         result = if s.severity() == super::StatusSeverity::Error {
@@ -346,4 +423,574 @@ impl Pipe {
 
         closure(result)
     }
+
+    /// Receives a message from the pipe and, if it's an `M`
+    /// (as checked by [`Message::try_cast_cmd`] or [`Message::try_cast_tlm`]),
+    /// returns a copy of it.
+    ///
+    /// Unlike with [`receive_buffer`](Self::receive_buffer),
+    /// the returned message isn't tied to the software bus buffer,
+    /// so it can be kept for as long as needed (e.g., for store-and-forward).
+    ///
+    /// Wraps `CFE_SB_ReceiveBuffer`.
+    #[doc(alias = "CFE_SB_ReceiveBuffer")]
+    #[inline]
+    pub fn receive_owned<M: TypedMessage>(&mut self, time_out: TimeOut) -> Result<M, Status> {
+        self.receive_buffer(time_out, |msg| {
+            let m = M::try_cast_from(msg?)?;
+
+            // SAFETY: `Command<T>` and `Telemetry<T>` consist only of `Copy` fields.
+            Ok(unsafe { core::ptr::read(m) })
+        })
+    }
+
+    /// Like [`receive_owned`](Self::receive_owned),
+    /// but copies the message into `dest` instead of returning it.
+    ///
+    /// If reception or the check fails, `dest` is left unchanged.
+    ///
+    /// Wraps `CFE_SB_ReceiveBuffer`.
+    #[doc(alias = "CFE_SB_ReceiveBuffer")]
+    #[inline]
+    pub fn receive_into<M: TypedMessage>(
+        &mut self,
+        time_out: TimeOut,
+        dest: &mut M,
+    ) -> Result<(), Status> {
+        self.receive_buffer(time_out, |msg| {
+            let m = M::try_cast_from(msg?)?;
+
+            // SAFETY: `Command<T>` and `Telemetry<T>` consist only of `Copy` fields,
+            // and `m` (in the software bus buffer) can't overlap `dest`.
+            unsafe { core::ptr::copy_nonoverlapping(m, dest, 1) };
+            Ok(())
+        })
+    }
+}
+
+/// A set of pipes to be serviced together, such as a command pipe and a wakeup pipe.
+///
+/// cFE can't wait on more than one pipe at once, so [`poll`](Self::poll)
+/// first checks each pipe in turn without blocking,
+/// starting after the pipe that last had a message (so that no pipe gets starved),
+/// and only if they're all empty waits on the *primary* pipe, the first in the set.
+/// While waiting, messages arriving on the other pipes wait too,
+/// so the primary pipe should be one that receives messages regularly,
+/// such as the pipe subscribed to the scheduler's wakeup message:
+///
+/// ```rust,ignore
+/// let mut pipes = PipeSet::new([&mut wakeup_pipe, &mut cmd_pipe]);
+/// loop {
+///     pipes.poll(TimeOut::Millis(1000), |index, msg| match (index, msg) {
+///         (0, Ok(msg)) => do_periodic_work(msg),
+///         (1, Ok(msg)) => handle_command(msg),
+///         (_, Err(status)) => handle_error(status),
+///     });
+/// }
+/// ```
+#[derive(Debug)]
+pub struct PipeSet<'p, const N: usize> {
+    pipes: [&'p mut Pipe; N],
+    next:  usize,
+}
+
+impl<'p, const N: usize> PipeSet<'p, N> {
+    /// Creates a set of the pipes `pipes`, the first of which is the primary pipe.
+    #[inline]
+    pub fn new(pipes: [&'p mut Pipe; N]) -> Self {
+        PipeSet { pipes, next: 0 }
+    }
+
+    /// Receives a message from one of the pipes in the set,
+    /// waiting on the primary pipe as specified by `time_out` if they're all empty.
+    ///
+    /// `closure` gets called with the index (in the set) of the pipe
+    /// the message was received from, and the result of the reception attempt
+    /// (as with [`Pipe::receive_buffer`]).
+    /// If all the pipes are empty and the wait times out,
+    /// `closure` is called with index 0 and
+    /// [`SB_TIME_OUT`](Status::SB_TIME_OUT)
+    /// (or [`SB_NO_MESSAGE`](Status::SB_NO_MESSAGE) if `time_out` is [`TimeOut::Poll`]).
+    ///
+    /// Wraps `CFE_SB_ReceiveBuffer`.
+    #[doc(alias = "CFG_SB_ReceiveBuffer")]
+    #[inline]
+    pub fn poll<T, F>(&mut self, time_out: TimeOut, closure: F) -> T
+    where
+        F: for<'a> FnOnce(usize, Result<&'a Message, Status>) -> T,
+    {
+        let mut closure = Some(closure);
+
+        for k in 0..N {
+            let index = (self.next + k) % N;
+            let result = self.pipes[index].receive_buffer(TimeOut::Poll, |msg| match msg {
+                Err(Status::SB_NO_MESSAGE) => None,
+                msg => closure.take().map(|f| f(index, msg)),
+            });
+
+            if let Some(result) = result {
+                self.next = (index + 1) % N;
+                return result;
+            }
+        }
+
+        let closure = match closure {
+            Some(closure) => closure,
+            None => unreachable!("PipeSet::poll closure called without returning"),
+        };
+
+        if N == 0 {
+            return closure(0, Err(Status::SB_BAD_ARGUMENT));
+        }
+
+        self.next = 1 % N;
+        self.pipes[0].receive_buffer(time_out, |msg| closure(0, msg))
+    }
+}
+
+/// Counts of the outcomes of reception attempts on a [`StatPipe`],
+/// suitable for reporting in housekeeping telemetry.
+///
+/// The counts wrap around on overflow.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct PipeStats {
+    /// The number of messages received.
+    pub received: u32,
+
+    /// The number of polls ([`TimeOut::Poll`]) that found the pipe empty
+    /// ([`SB_NO_MESSAGE`](Status::SB_NO_MESSAGE)).
+    pub no_message: u32,
+
+    /// The number of waits that timed out
+    /// ([`SB_TIME_OUT`](Status::SB_TIME_OUT)).
+    pub timed_out: u32,
+
+    /// The number of pipe read errors
+    /// ([`SB_PIPE_RD_ERR`](Status::SB_PIPE_RD_ERR)),
+    /// which usually mean the underlying queue failed.
+    pub read_errors: u32,
+
+    /// The number of other errors.
+    pub other_errors: u32,
+
+    /// The most recent error other than
+    /// [`SB_NO_MESSAGE`](Status::SB_NO_MESSAGE) or [`SB_TIME_OUT`](Status::SB_TIME_OUT),
+    /// if any.
+    pub last_error: Option<Status>,
+}
+
+impl PipeStats {
+    /// Returns the total number of errors
+    /// (not counting empty polls or timeouts).
+    #[inline]
+    pub fn errors(&self) -> u32 {
+        self.read_errors.wrapping_add(self.other_errors)
+    }
+
+    /// Updates the counts for a reception attempt with the result `result`.
+    #[inline]
+    fn record(&mut self, result: Result<&Message, Status>) {
+        match result {
+            Ok(_) => self.received = self.received.wrapping_add(1),
+            Err(Status::SB_NO_MESSAGE) => self.no_message = self.no_message.wrapping_add(1),
+            Err(Status::SB_TIME_OUT) => self.timed_out = self.timed_out.wrapping_add(1),
+            Err(s) => {
+                if s == Status::SB_PIPE_RD_ERR {
+                    self.read_errors = self.read_errors.wrapping_add(1);
+                } else {
+                    self.other_errors = self.other_errors.wrapping_add(1);
+                }
+                self.last_error = Some(s);
+            }
+        }
+    }
+}
+
+/// A [`Pipe`] that keeps [statistics](PipeStats) on its reception attempts,
+/// so that lost or failed receptions can be reported in housekeeping telemetry:
+///
+/// ```rust,ignore
+/// let mut pipe = StatPipe::new(Pipe::new(16, c"CMD_PIPE")?);
+/// pipe.receive_buffer(TimeOut::Millis(1000), |msg| handle(msg));
+///
+/// let stats = pipe.stats();
+/// hk.pipe_read_errors = stats.read_errors;
+/// ```
+#[derive(Debug)]
+pub struct StatPipe {
+    pipe:  Pipe,
+    stats: PipeStats,
+}
+
+impl StatPipe {
+    /// Wraps `pipe`, with all counts zero.
+    #[inline]
+    pub fn new(pipe: Pipe) -> Self {
+        StatPipe {
+            pipe,
+            stats: PipeStats::default(),
+        }
+    }
+
+    /// Returns the current statistics.
+    #[inline]
+    pub fn stats(&self) -> PipeStats {
+        self.stats
+    }
+
+    /// Resets all counts to zero (e.g., on a reset-counters command).
+    #[inline]
+    pub fn reset_stats(&mut self) {
+        self.stats = PipeStats::default();
+    }
+
+    /// Returns a reference to the underlying pipe.
+    #[inline]
+    pub fn pipe(&self) -> &Pipe {
+        &self.pipe
+    }
+
+    /// Returns a mutable reference to the underlying pipe.
+    ///
+    /// Receptions through this reference aren't counted.
+    #[inline]
+    pub fn pipe_mut(&mut self) -> &mut Pipe {
+        &mut self.pipe
+    }
+
+    /// Returns the underlying pipe, discarding the statistics.
+    #[inline]
+    pub fn into_inner(self) -> Pipe {
+        self.pipe
+    }
+
+    /// Receives a message from the pipe, as with [`Pipe::receive_buffer`],
+    /// and counts the outcome.
+    ///
+    /// Wraps `CFE_SB_ReceiveBuffer`.
+    #[doc(alias = "CFG_SB_ReceiveBuffer")]
+    #[inline]
+    pub fn receive_buffer<T, F>(&mut self, time_out: TimeOut, closure: F) -> T
+    where
+        F: for<'a> FnOnce(Result<&'a Message, Status>) -> T,
+    {
+        let stats = &mut self.stats;
+        self.pipe.receive_buffer(time_out, |msg| {
+            stats.record(msg);
+            closure(msg)
+        })
+    }
+}
+
+/// A message buffer allocated from the software bus's own memory,
+/// which can be transmitted without the software bus copying it (*zero-copy* transmission).
+///
+/// The buffer goes back to the software bus when transmitted or dropped.
+/// [`copy_of`](Self::copy_of) allows forwarding a received message, headers and all,
+/// with a single copy:
+///
+/// ```rust,ignore
+/// pipe.receive_buffer(TimeOut::PendForever, |msg| -> Result<(), Status> {
+///     SendBuffer::copy_of(msg?)?.transmit(false)
+/// })?;
+/// ```
+///
+/// Wraps `CFE_SB_Buffer_t`.
+#[doc(alias = "CFE_SB_Buffer_t")]
+#[derive(Debug)]
+pub struct SendBuffer {
+    buf:  NonNull<CFE_SB_Buffer_t>,
+    size: usize,
+}
+
+impl SendBuffer {
+    /// Allocates a zero-filled buffer for a message `size` bytes long.
+    ///
+    /// Returns [`SB_BAD_ARGUMENT`](Status::SB_BAD_ARGUMENT)
+    /// if `size` is too small to hold a message header,
+    /// or [`SB_BUF_ALOC_ERR`](Status::SB_BUF_ALOC_ERR)
+    /// if the software bus couldn't allocate the buffer.
+    ///
+    /// Wraps `CFE_SB_AllocateMessageBuffer`.
+    #[doc(alias = "CFE_SB_AllocateMessageBuffer")]
+    #[inline]
+    pub fn new(size: usize) -> Result<Self, Status> {
+        if size < mem::size_of::<CFE_MSG_Message_t>() {
+            return Err(Status::SB_BAD_ARGUMENT);
+        }
+
+        let buf = NonNull::new(unsafe { CFE_SB_AllocateMessageBuffer(size) })
+            .ok_or(Status::SB_BUF_ALOC_ERR)?;
+        unsafe { core::ptr::write_bytes(buf.as_ptr() as *mut u8, 0, size) };
+
+        Ok(SendBuffer { buf, size })
+    }
+
+    /// Allocates a buffer holding a copy of `msg` (headers included),
+    /// such as a message just received from a pipe.
+    ///
+    /// Wraps `CFE_MSG_GetSize`, `CFE_SB_AllocateMessageBuffer` and `CFE_PSP_MemCpy`.
+    #[doc(alias("CFE_SB_AllocateMessageBuffer", "CFE_PSP_MemCpy"))]
+    #[inline]
+    pub fn copy_of(msg: &Message) -> Result<Self, Status> {
+        let size = msg.size()?;
+        let buf = Self::new(size)?;
+
+        // `msg` may be the header of a typed message whose payload has padding,
+        // whose bytes can't be read as `u8`s here, so the copy is left to the PSP.
+        // (What the PSP writes to the buffer is then as initialized as anything else C writes.)
+        let s = unsafe {
+            CFE_PSP_MemCpy(
+                buf.buf.as_ptr() as *mut c_void,
+                msg as *const Message as *const c_void,
+                size as u32,
+            )
+        };
+        if s != CFE_PSP_SUCCESS as i32 {
+            return Err(Status::STATUS_EXTERNAL_RESOURCE_FAIL);
+        }
+
+        Ok(buf)
+    }
+
+    /// Returns the whole buffer as a byte slice.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { core::slice::from_raw_parts(self.buf.as_ptr() as *const u8, self.size) }
+    }
+
+    /// Returns the whole buffer as a mutable byte slice.
+    #[inline]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe { core::slice::from_raw_parts_mut(self.buf.as_ptr() as *mut u8, self.size) }
+    }
+
+    /// Transmits the message in the buffer onto the software bus,
+    /// handing the buffer over to the software bus.
+    ///
+    /// If transmission fails, the buffer is released.
+    ///
+    /// Wraps `CFE_SB_TransmitBuffer`.
+    #[doc(alias = "CFE_SB_TransmitBuffer")]
+    #[inline]
+    pub fn transmit(self, increment_sequence_count: bool) -> Result<(), Status> {
+        let s: Status = super::perf::timed(super::perf::SB_TRANSMIT_MSG, || unsafe {
+            CFE_SB_TransmitBuffer(self.buf.as_ptr(), increment_sequence_count)
+        })
+        .into();
+
+        // On success, the software bus owns the buffer now:
+        if s.severity() != super::StatusSeverity::Error {
+            mem::forget(self);
+        }
+
+        s.as_result(|| ())
+    }
+}
+
+impl Deref for SendBuffer {
+    type Target = Message;
+
+    #[inline]
+    fn deref(&self) -> &Message {
+        Message::from_cfe(unsafe { &self.buf.as_ref().Msg })
+    }
+}
+
+impl DerefMut for SendBuffer {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Message {
+        Message::from_cfe_mut(unsafe { &mut self.buf.as_mut().Msg })
+    }
+}
+
+/// Wraps `CFE_SB_ReleaseMessageBuffer`.
+impl Drop for SendBuffer {
+    #[inline]
+    fn drop(&mut self) {
+        let status = unsafe { CFE_SB_ReleaseMessageBuffer(self.buf.as_ptr()) };
+        crate::ffi_errors::check_cfe(crate::ffi_errors::Subsystem::Sb, status);
+    }
+}
+
+/// Wraps `CFE_SB_PipeId_t`.
+impl From<&Pipe> for super::ResourceId {
+    #[inline]
+    fn from(pipe: &Pipe) -> Self {
+        super::ResourceId { id: pipe.id }
+    }
+}
+
+/// Sends the software bus a command with function code `fcn_code`
+/// on the subscription-reporting control message ID.
+fn send_sub_report_cmd(fcn_code: u32) -> Result<(), Status> {
+    let msg_id = MsgId::from(CFE_SB_SUB_RPT_CTRL_MID as MsgId_Atom);
+    super::msg::Command::new(msg_id, fcn_code as super::msg::FunctionCode, ())?
+        .transmit_checksummed()
+}
+
+/// Asks the software bus to report each subscription and unsubscription from now on
+/// as a [`SubscriptionReport`] message.
+///
+/// Sends `CFE_SB_ENABLE_SUB_REPORTING_CC`.
+#[doc(alias = "CFE_SB_ENABLE_SUB_REPORTING_CC")]
+#[inline]
+pub fn enable_subscription_reporting() -> Result<(), Status> {
+    send_sub_report_cmd(CFE_SB_ENABLE_SUB_REPORTING_CC)
+}
+
+/// Asks the software bus to stop reporting subscriptions and unsubscriptions.
+///
+/// Sends `CFE_SB_DISABLE_SUB_REPORTING_CC`.
+#[doc(alias = "CFE_SB_DISABLE_SUB_REPORTING_CC")]
+#[inline]
+pub fn disable_subscription_reporting() -> Result<(), Status> {
+    send_sub_report_cmd(CFE_SB_DISABLE_SUB_REPORTING_CC)
+}
+
+/// Asks the software bus to send a list of all current subscriptions,
+/// as a series of [`SubscriptionsSegment`] messages.
+///
+/// Sends `CFE_SB_SEND_PREV_SUBS_CC`.
+#[doc(alias = "CFE_SB_SEND_PREV_SUBS_CC")]
+#[inline]
+pub fn request_subscriptions() -> Result<(), Status> {
+    send_sub_report_cmd(CFE_SB_SEND_PREV_SUBS_CC)
+}
+
+/// A subscription of a pipe to a message ID.
+#[derive(Clone, Copy, Debug)]
+pub struct Subscription {
+    /// The message ID subscribed to.
+    pub msg_id: MsgId,
+
+    /// The quality of service requested.
+    pub qos: Qos,
+
+    /// The ID of the subscribing pipe.
+    pub pipe: super::ResourceId,
+}
+
+impl Subscription {
+    #[inline]
+    fn from_cfe(msg_id: CFE_SB_MsgId_t, qos: CFE_SB_Qos_t, pipe: CFE_SB_PipeId_t) -> Self {
+        Subscription {
+            msg_id: MsgId { id: msg_id },
+            qos:    Qos { qos },
+            pipe:   super::ResourceId { id: pipe },
+        }
+    }
+}
+
+/// Whether a [`SubscriptionReport`] is of a subscription or an unsubscription.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SubscriptionChange {
+    /// A pipe subscribed to a message ID.
+    #[doc(alias = "CFE_SB_SUBSCRIPTION")]
+    Subscribed,
+
+    /// A pipe unsubscribed from a message ID.
+    #[doc(alias = "CFE_SB_UNSUBSCRIPTION")]
+    Unsubscribed,
+}
+
+/// A report of a single subscription or unsubscription,
+/// as sent by the software bus (when [enabled](enable_subscription_reporting))
+/// with message ID `CFE_SB_ONESUB_TLM_MID`.
+///
+/// Substitutes for `CFE_SB_SingleSubscriptionTlm_t`.
+#[doc(alias("CFE_SB_SingleSubscriptionTlm_t", "CFE_SB_ONESUB_TLM_MID"))]
+#[derive(Clone, Copy, Debug)]
+pub struct SubscriptionReport {
+    /// Whether the subscription was made or removed.
+    pub change: SubscriptionChange,
+
+    /// The subscription.
+    pub subscription: Subscription,
+}
+
+impl SubscriptionReport {
+    /// The message ID of subscription reports.
+    #[doc(alias = "CFE_SB_ONESUB_TLM_MID")]
+    pub const MSG_ID: MsgId_Atom = CFE_SB_ONESUB_TLM_MID as MsgId_Atom;
+
+    /// Decodes `msg` as a subscription report.
+    ///
+    /// Returns [`MSG_WRONG_MSG_TYPE`](Status::MSG_WRONG_MSG_TYPE)
+    /// if `msg` doesn't have the [message ID](Self::MSG_ID) of subscription reports,
+    /// or another error if it's malformed.
+    #[inline]
+    pub fn from_message(msg: &Message) -> Result<Self, Status> {
+        if msg.msgid()? != MsgId::from(Self::MSG_ID) {
+            return Err(Status::MSG_WRONG_MSG_TYPE);
+        }
+
+        let p = &msg.try_cast_tlm::<CFE_SB_SingleSubscriptionTlm_Payload_t>()?.payload;
+        let change = match p.SubType as u32 {
+            CFE_SB_SUBSCRIPTION => SubscriptionChange::Subscribed,
+            CFE_SB_UNSUBSCRIPTION => SubscriptionChange::Unsubscribed,
+            _ => return Err(Status::SB_BAD_ARGUMENT),
+        };
+
+        Ok(SubscriptionReport {
+            change,
+            subscription: Subscription::from_cfe(p.MsgId, p.Qos, p.Pipe),
+        })
+    }
+}
+
+/// One of the messages listing all current subscriptions,
+/// as sent by the software bus (on [request](request_subscriptions))
+/// with message ID `CFE_SB_ALLSUBS_TLM_MID`.
+///
+/// Substitutes for `CFE_SB_AllSubscriptionsTlm_t`.
+#[doc(alias("CFE_SB_AllSubscriptionsTlm_t", "CFE_SB_ALLSUBS_TLM_MID"))]
+#[derive(Clone, Copy, Debug)]
+pub struct SubscriptionsSegment {
+    payload: CFE_SB_AllSubscriptionsTlm_Payload_t,
+}
+
+impl SubscriptionsSegment {
+    /// The message ID of subscription-list segments.
+    #[doc(alias = "CFE_SB_ALLSUBS_TLM_MID")]
+    pub const MSG_ID: MsgId_Atom = CFE_SB_ALLSUBS_TLM_MID as MsgId_Atom;
+
+    /// Decodes `msg` as a segment of the subscription list.
+    ///
+    /// Returns [`MSG_WRONG_MSG_TYPE`](Status::MSG_WRONG_MSG_TYPE)
+    /// if `msg` doesn't have the [message ID](Self::MSG_ID) of subscription-list segments,
+    /// or another error if it's malformed.
+    #[inline]
+    pub fn from_message(msg: &Message) -> Result<Self, Status> {
+        if msg.msgid()? != MsgId::from(Self::MSG_ID) {
+            return Err(Status::MSG_WRONG_MSG_TYPE);
+        }
+
+        let payload = msg.try_cast_tlm::<CFE_SB_AllSubscriptionsTlm_Payload_t>()?.payload;
+        if payload.Entries as usize > payload.Entry.len() {
+            return Err(Status::STATUS_WRONG_MSG_LENGTH);
+        }
+
+        Ok(SubscriptionsSegment { payload })
+    }
+
+    /// Returns which segment of the list this is (counting from 1).
+    #[inline]
+    pub fn segment(&self) -> u32 {
+        self.payload.PktSegment
+    }
+
+    /// Returns the number of segments in the whole list.
+    #[inline]
+    pub fn total_segments(&self) -> u32 {
+        self.payload.TotalSegments
+    }
+
+    /// Returns the subscriptions listed in this segment.
+    #[inline]
+    pub fn subscriptions(&self) -> impl ExactSizeIterator<Item = Subscription> + '_ {
+        self.payload.Entry[..self.payload.Entries as usize]
+            .iter()
+            .map(|e| Subscription::from_cfe(e.MsgId, e.Qos, e.Pipe))
+    }
 }
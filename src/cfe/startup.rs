@@ -0,0 +1,170 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Waiting for other applications and libraries during startup.
+//!
+//! An application that depends on others (e.g., one that uses a library's API,
+//! or expects another application to have created a pipe or registered a table)
+//! can use [`wait_for_dependencies`] at the end of its initialization
+//! to wait until they're up, rather than racing them:
+//!
+//! ```rust,ignore
+//! const DEPS: &[Dependency<'static>] = &[
+//!     Dependency::Lib(c"SAMPLE_LIB"),
+//!     Dependency::App(c"TO_LAB"),
+//! ];
+//!
+//! let progress = StartupEventIds { waiting: 30, ready: 31, timed_out: 32 };
+//! if let Err(missing) = wait_for_dependencies(DEPS, 5000, 100, Some((&events, progress))) {
+//!     // carry on without `missing`, or give up
+//! }
+//! ```
+
+use super::es::{wait_for_system_state, AppId, LibId, SystemState};
+use super::evs::{EventSender, EventType};
+use super::Status;
+use crate::osal::{as_millis, local_time, task};
+use core::ffi::CStr;
+use printf_wrap::PrintfFmt;
+
+/// Format string for the event about starting to wait for a dependency.
+const WAITING_FMT: PrintfFmt<(&CStr,)> = crate::printf_fmt!(&CStr; "Waiting for %s");
+
+/// Format string for the event about giving up on a dependency.
+const TIMED_OUT_FMT: PrintfFmt<(u32, &CStr)> =
+    crate::printf_fmt!(u32, &CStr; "Timed out after %u ms waiting for %s");
+
+/// Format string for the event about a dependency becoming ready.
+const READY_FMT: PrintfFmt<(&CStr, u32)> = crate::printf_fmt!(&CStr, u32; "%s ready after %u ms");
+
+/// Something an application needs to be up before it can operate.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Dependency<'a> {
+    /// The cFE application with the given name.
+    ///
+    /// An application counts as ready once its main task
+    /// has gone through its run loop at least once.
+    App(&'a CStr),
+
+    /// The cFE library with the given name.
+    ///
+    /// A library counts as ready once it's loaded
+    /// (cFE runs a library's initialization function as part of loading it).
+    Lib(&'a CStr),
+}
+
+impl<'a> Dependency<'a> {
+    /// Returns the name of the application or library.
+    #[inline]
+    pub fn name(&self) -> &'a CStr {
+        match *self {
+            Dependency::App(name) => name,
+            Dependency::Lib(name) => name,
+        }
+    }
+
+    /// Returns whether the application or library is ready.
+    ///
+    /// Returns `Ok(false)` if it isn't loaded (yet).
+    ///
+    /// Wraps `CFE_ES_GetAppIDByName` and `CFE_ES_GetAppInfo`, or `CFE_ES_GetLibIDByName`.
+    #[doc(alias("CFE_ES_GetAppIDByName", "CFE_ES_GetAppInfo", "CFE_ES_GetLibIDByName"))]
+    #[inline]
+    pub fn is_ready(&self) -> Result<bool, Status> {
+        let found = match *self {
+            Dependency::App(name) => AppId::find_by_name(name)
+                .and_then(AppId::info)
+                .map(|info| info.execution_counter > 0),
+            Dependency::Lib(name) => LibId::find_by_name(name).map(|_| true),
+        };
+
+        match found {
+            Err(Status::ES_ERR_NAME_NOT_FOUND) => Ok(false),
+            x => x,
+        }
+    }
+}
+
+/// The event IDs [`wait_for_dependencies`] uses for its progress events.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StartupEventIds {
+    /// Event ID for the (informational) event sent when starting to wait for a dependency.
+    pub waiting: u16,
+
+    /// Event ID for the (informational) event sent when a dependency waited for becomes ready.
+    pub ready: u16,
+
+    /// Event ID for the error event sent when a dependency isn't ready in time.
+    pub timed_out: u16,
+}
+
+/// Waits, for up to `timeout_ms` milliseconds in total,
+/// for each of `deps` in turn to become [ready](Dependency::is_ready).
+///
+/// This first waits for the cFE core to be [ready](SystemState::CoreReady),
+/// then checks each dependency every `poll_interval_ms` milliseconds until it's ready.
+/// If `events` is provided, progress events are sent with the given IDs
+/// for each dependency that isn't ready at the first check.
+///
+/// Returns the first dependency that wasn't ready before the timeout, if any.
+///
+/// Wraps `CFE_ES_WaitForSystemState`, `CFE_ES_GetAppIDByName`, `CFE_ES_GetAppInfo`,
+/// `CFE_ES_GetLibIDByName`, and `OS_TaskDelay`.
+#[doc(alias("CFE_ES_WaitForSystemState", "CFE_ES_GetAppIDByName", "CFE_ES_GetAppInfo"))]
+pub fn wait_for_dependencies<'a>(
+    deps: &[Dependency<'a>],
+    timeout_ms: u32,
+    poll_interval_ms: u32,
+    events: Option<(&EventSender, StartupEventIds)>,
+) -> Result<(), Dependency<'a>> {
+    let start = local_time().ok();
+    let elapsed_ms = || match (start, local_time()) {
+        (Some(start), Ok(now)) => as_millis(now - start),
+        _ => 0,
+    };
+
+    let _ = wait_for_system_state(SystemState::CoreReady, timeout_ms);
+
+    for dep in deps {
+        if dep.is_ready() == Ok(true) {
+            continue;
+        }
+
+        if let Some((sender, ids)) = events {
+            let _ =
+                sender.send_event(ids.waiting, EventType::Information, WAITING_FMT, (dep.name(),));
+        }
+
+        loop {
+            let elapsed = elapsed_ms();
+            if elapsed >= timeout_ms {
+                if let Some((sender, ids)) = events {
+                    let _ = sender.send_event(
+                        ids.timed_out,
+                        EventType::Error,
+                        TIMED_OUT_FMT,
+                        (elapsed, dep.name()),
+                    );
+                }
+                return Err(*dep);
+            }
+
+            let _ = task::delay(poll_interval_ms.max(1).min(timeout_ms - elapsed));
+
+            if dep.is_ready() == Ok(true) {
+                break;
+            }
+        }
+
+        if let Some((sender, ids)) = events {
+            let _ = sender.send_event(
+                ids.ready,
+                EventType::Information,
+                READY_FMT,
+                (dep.name(), elapsed_ms()),
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -12,385 +12,168 @@ const fn mk_status(n: CFE_Status_t) -> Status {
     Status { status: n }
 }
 
-impl Status {
-    #[doc(alias = "CFE_SUCCESS")]
-    pub const SUCCESS: Status = mk_status(S_CFE_SUCCESS);
-
-    #[doc(alias = "CFE_STATUS_NO_COUNTER_INCREMENT")]
-    pub const STATUS_NO_COUNTER_INCREMENT: Status = mk_status(S_CFE_STATUS_NO_COUNTER_INCREMENT);
-
-    #[doc(alias = "CFE_STATUS_WRONG_MSG_LENGTH")]
-    pub const STATUS_WRONG_MSG_LENGTH: Status = mk_status(S_CFE_STATUS_WRONG_MSG_LENGTH);
-
-    #[doc(alias = "CFE_STATUS_UNKNOWN_MSG_ID")]
-    pub const STATUS_UNKNOWN_MSG_ID: Status = mk_status(S_CFE_STATUS_UNKNOWN_MSG_ID);
-
-    #[doc(alias = "CFE_STATUS_BAD_COMMAND_CODE")]
-    pub const STATUS_BAD_COMMAND_CODE: Status = mk_status(S_CFE_STATUS_BAD_COMMAND_CODE);
-
-    #[doc(alias = "CFE_STATUS_EXTERNAL_RESOURCE_FAIL")]
-    pub const STATUS_EXTERNAL_RESOURCE_FAIL: Status =
-        mk_status(S_CFE_STATUS_EXTERNAL_RESOURCE_FAIL);
-
-    #[doc(alias = "CFE_STATUS_REQUEST_ALREADY_PENDING")]
-    pub const STATUS_REQUEST_ALREADY_PENDING: Status =
-        mk_status(S_CFE_STATUS_REQUEST_ALREADY_PENDING);
-
-    #[doc(alias = "CFE_STATUS_NOT_IMPLEMENTED")]
-    pub const STATUS_NOT_IMPLEMENTED: Status = mk_status(S_CFE_STATUS_NOT_IMPLEMENTED);
-
-    #[doc(alias = "CFE_EVS_UNKNOWN_FILTER")]
-    pub const EVS_UNKNOWN_FILTER: Status = mk_status(S_CFE_EVS_UNKNOWN_FILTER);
-
-    #[doc(alias = "CFE_EVS_APP_NOT_REGISTERED")]
-    pub const EVS_APP_NOT_REGISTERED: Status = mk_status(S_CFE_EVS_APP_NOT_REGISTERED);
-
-    #[doc(alias = "CFE_EVS_APP_ILLEGAL_APP_ID")]
-    pub const EVS_APP_ILLEGAL_APP_ID: Status = mk_status(S_CFE_EVS_APP_ILLEGAL_APP_ID);
-
-    #[doc(alias = "CFE_EVS_APP_FILTER_OVERLOAD")]
-    pub const EVS_APP_FILTER_OVERLOAD: Status = mk_status(S_CFE_EVS_APP_FILTER_OVERLOAD);
-
-    #[doc(alias = "CFE_EVS_RESET_AREA_POINTER")]
-    pub const EVS_RESET_AREA_POINTER: Status = mk_status(S_CFE_EVS_RESET_AREA_POINTER);
-
-    #[doc(alias = "CFE_EVS_EVT_NOT_REGISTERED")]
-    pub const EVS_EVT_NOT_REGISTERED: Status = mk_status(S_CFE_EVS_EVT_NOT_REGISTERED);
-
-    #[doc(alias = "CFE_EVS_FILE_WRITE_ERROR")]
-    pub const EVS_FILE_WRITE_ERROR: Status = mk_status(S_CFE_EVS_FILE_WRITE_ERROR);
-
-    #[doc(alias = "CFE_EVS_INVALID_PARAMETER")]
-    pub const EVS_INVALID_PARAMETER: Status = mk_status(S_CFE_EVS_INVALID_PARAMETER);
-
-    #[doc(alias = "CFE_EVS_NOT_IMPLEMENTED")]
-    pub const EVS_NOT_IMPLEMENTED: Status = mk_status(S_CFE_EVS_NOT_IMPLEMENTED);
-
-    #[doc(alias = "CFE_ES_ERR_RESOURCEID_NOT_VALID")]
-    pub const ES_ERR_RESOURCEID_NOT_VALID: Status = mk_status(S_CFE_ES_ERR_RESOURCEID_NOT_VALID);
-
-    #[doc(alias = "CFE_ES_ERR_NAME_NOT_FOUND")]
-    pub const ES_ERR_NAME_NOT_FOUND: Status = mk_status(S_CFE_ES_ERR_NAME_NOT_FOUND);
-
-    #[doc(alias = "CFE_ES_ERR_APP_CREATE")]
-    pub const ES_ERR_APP_CREATE: Status = mk_status(S_CFE_ES_ERR_APP_CREATE);
-
-    #[doc(alias = "CFE_ES_ERR_CHILD_TASK_CREATE")]
-    pub const ES_ERR_CHILD_TASK_CREATE: Status = mk_status(S_CFE_ES_ERR_CHILD_TASK_CREATE);
-
-    #[doc(alias = "CFE_ES_ERR_SYS_LOG_FULL")]
-    pub const ES_ERR_SYS_LOG_FULL: Status = mk_status(S_CFE_ES_ERR_SYS_LOG_FULL);
-
-    #[doc(alias = "CFE_ES_ERR_MEM_BLOCK_SIZE")]
-    pub const ES_ERR_MEM_BLOCK_SIZE: Status = mk_status(S_CFE_ES_ERR_MEM_BLOCK_SIZE);
-
-    #[doc(alias = "CFE_ES_ERR_LOAD_LIB")]
-    pub const ES_ERR_LOAD_LIB: Status = mk_status(S_CFE_ES_ERR_LOAD_LIB);
-
-    #[doc(alias = "CFE_ES_BAD_ARGUMENT")]
-    pub const ES_BAD_ARGUMENT: Status = mk_status(S_CFE_ES_BAD_ARGUMENT);
-
-    #[doc(alias = "CFE_ES_ERR_CHILD_TASK_REGISTER")]
-    pub const ES_ERR_CHILD_TASK_REGISTER: Status = mk_status(S_CFE_ES_ERR_CHILD_TASK_REGISTER);
-
-    #[doc(alias = "CFE_ES_CDS_ALREADY_EXISTS")]
-    pub const ES_CDS_ALREADY_EXISTS: Status = mk_status(S_CFE_ES_CDS_ALREADY_EXISTS);
-
-    #[doc(alias = "CFE_ES_CDS_INSUFFICIENT_MEMORY")]
-    pub const ES_CDS_INSUFFICIENT_MEMORY: Status = mk_status(S_CFE_ES_CDS_INSUFFICIENT_MEMORY);
-
-    #[doc(alias = "CFE_ES_CDS_INVALID_NAME")]
-    pub const ES_CDS_INVALID_NAME: Status = mk_status(S_CFE_ES_CDS_INVALID_NAME);
-
-    #[doc(alias = "CFE_ES_CDS_INVALID_SIZE")]
-    pub const ES_CDS_INVALID_SIZE: Status = mk_status(S_CFE_ES_CDS_INVALID_SIZE);
-
-    #[doc(alias = "CFE_ES_CDS_INVALID")]
-    pub const ES_CDS_INVALID: Status = mk_status(S_CFE_ES_CDS_INVALID);
-
-    #[doc(alias = "CFE_ES_CDS_ACCESS_ERROR")]
-    pub const ES_CDS_ACCESS_ERROR: Status = mk_status(S_CFE_ES_CDS_ACCESS_ERROR);
-
-    #[doc(alias = "CFE_ES_FILE_IO_ERR")]
-    pub const ES_FILE_IO_ERR: Status = mk_status(S_CFE_ES_FILE_IO_ERR);
-
-    #[doc(alias = "CFE_ES_RST_ACCESS_ERR")]
-    pub const ES_RST_ACCESS_ERR: Status = mk_status(S_CFE_ES_RST_ACCESS_ERR);
-
-    #[doc(alias = "CFE_ES_ERR_APP_REGISTER")]
-    pub const ES_ERR_APP_REGISTER: Status = mk_status(S_CFE_ES_ERR_APP_REGISTER);
-
-    #[doc(alias = "CFE_ES_ERR_CHILD_TASK_DELETE")]
-    pub const ES_ERR_CHILD_TASK_DELETE: Status = mk_status(S_CFE_ES_ERR_CHILD_TASK_DELETE);
-
-    #[doc(alias = "CFE_ES_ERR_CHILD_TASK_DELETE_MAIN_TASK")]
-    pub const ES_ERR_CHILD_TASK_DELETE_MAIN_TASK: Status =
-        mk_status(S_CFE_ES_ERR_CHILD_TASK_DELETE_MAIN_TASK);
-
-    #[doc(alias = "CFE_ES_CDS_BLOCK_CRC_ERR")]
-    pub const ES_CDS_BLOCK_CRC_ERR: Status = mk_status(S_CFE_ES_CDS_BLOCK_CRC_ERR);
-
-    #[doc(alias = "CFE_ES_MUT_SEM_DELETE_ERR")]
-    pub const ES_MUT_SEM_DELETE_ERR: Status = mk_status(S_CFE_ES_MUT_SEM_DELETE_ERR);
-
-    #[doc(alias = "CFE_ES_BIN_SEM_DELETE_ERR")]
-    pub const ES_BIN_SEM_DELETE_ERR: Status = mk_status(S_CFE_ES_BIN_SEM_DELETE_ERR);
-
-    #[doc(alias = "CFE_ES_COUNT_SEM_DELETE_ERR")]
-    pub const ES_COUNT_SEM_DELETE_ERR: Status = mk_status(S_CFE_ES_COUNT_SEM_DELETE_ERR);
-
-    #[doc(alias = "CFE_ES_QUEUE_DELETE_ERR")]
-    pub const ES_QUEUE_DELETE_ERR: Status = mk_status(S_CFE_ES_QUEUE_DELETE_ERR);
-
-    #[doc(alias = "CFE_ES_FILE_CLOSE_ERR")]
-    pub const ES_FILE_CLOSE_ERR: Status = mk_status(S_CFE_ES_FILE_CLOSE_ERR);
-
-    #[doc(alias = "CFE_ES_CDS_WRONG_TYPE_ERR")]
-    pub const ES_CDS_WRONG_TYPE_ERR: Status = mk_status(S_CFE_ES_CDS_WRONG_TYPE_ERR);
-
-    #[doc(alias = "CFE_ES_CDS_OWNER_ACTIVE_ERR")]
-    pub const ES_CDS_OWNER_ACTIVE_ERR: Status = mk_status(S_CFE_ES_CDS_OWNER_ACTIVE_ERR);
-
-    #[doc(alias = "CFE_ES_APP_CLEANUP_ERR")]
-    pub const ES_APP_CLEANUP_ERR: Status = mk_status(S_CFE_ES_APP_CLEANUP_ERR);
-
-    #[doc(alias = "CFE_ES_TIMER_DELETE_ERR")]
-    pub const ES_TIMER_DELETE_ERR: Status = mk_status(S_CFE_ES_TIMER_DELETE_ERR);
-
-    #[doc(alias = "CFE_ES_BUFFER_NOT_IN_POOL")]
-    pub const ES_BUFFER_NOT_IN_POOL: Status = mk_status(S_CFE_ES_BUFFER_NOT_IN_POOL);
-
-    #[doc(alias = "CFE_ES_TASK_DELETE_ERR")]
-    pub const ES_TASK_DELETE_ERR: Status = mk_status(S_CFE_ES_TASK_DELETE_ERR);
-
-    #[doc(alias = "CFE_ES_OPERATION_TIMED_OUT")]
-    pub const ES_OPERATION_TIMED_OUT: Status = mk_status(S_CFE_ES_OPERATION_TIMED_OUT);
-
-    #[doc(alias = "CFE_ES_LIB_ALREADY_LOADED")]
-    pub const ES_LIB_ALREADY_LOADED: Status = mk_status(S_CFE_ES_LIB_ALREADY_LOADED);
-
-    #[doc(alias = "CFE_ES_ERR_SYS_LOG_TRUNCATED")]
-    pub const ES_ERR_SYS_LOG_TRUNCATED: Status = mk_status(S_CFE_ES_ERR_SYS_LOG_TRUNCATED);
-
-    #[doc(alias = "CFE_ES_NO_RESOURCE_IDS_AVAILABLE")]
-    pub const ES_NO_RESOURCE_IDS_AVAILABLE: Status = mk_status(S_CFE_ES_NO_RESOURCE_IDS_AVAILABLE);
-
-    #[doc(alias = "CFE_ES_POOL_BLOCK_INVALID")]
-    pub const ES_POOL_BLOCK_INVALID: Status = mk_status(S_CFE_ES_POOL_BLOCK_INVALID);
-
-    #[doc(alias = "CFE_ES_ERR_DUPLICATE_NAME")]
-    pub const ES_ERR_DUPLICATE_NAME: Status = mk_status(S_CFE_ES_ERR_DUPLICATE_NAME);
-
-    #[doc(alias = "CFE_ES_NOT_IMPLEMENTED")]
-    pub const ES_NOT_IMPLEMENTED: Status = mk_status(S_CFE_ES_NOT_IMPLEMENTED);
-
-    #[doc(alias = "CFE_FS_BAD_ARGUMENT")]
-    pub const FS_BAD_ARGUMENT: Status = mk_status(S_CFE_FS_BAD_ARGUMENT);
-
-    #[doc(alias = "CFE_FS_INVALID_PATH")]
-    pub const FS_INVALID_PATH: Status = mk_status(S_CFE_FS_INVALID_PATH);
-
-    #[doc(alias = "CFE_FS_FNAME_TOO_LONG")]
-    pub const FS_FNAME_TOO_LONG: Status = mk_status(S_CFE_FS_FNAME_TOO_LONG);
-
-    #[doc(alias = "CFE_FS_NOT_IMPLEMENTED")]
-    pub const FS_NOT_IMPLEMENTED: Status = mk_status(S_CFE_FS_NOT_IMPLEMENTED);
-
-    #[doc(alias = "CFE_MSG_WRONG_MSG_TYPE")]
-    pub const MSG_WRONG_MSG_TYPE: Status = mk_status(S_CFE_MSG_WRONG_MSG_TYPE);
-
-    #[doc(alias = "CFE_SB_TIME_OUT")]
-    pub const SB_TIME_OUT: Status = mk_status(S_CFE_SB_TIME_OUT);
-
-    #[doc(alias = "CFE_SB_NO_MESSAGE")]
-    pub const SB_NO_MESSAGE: Status = mk_status(S_CFE_SB_NO_MESSAGE);
-
-    #[doc(alias = "CFE_SB_BAD_ARGUMENT")]
-    pub const SB_BAD_ARGUMENT: Status = mk_status(S_CFE_SB_BAD_ARGUMENT);
-
-    #[doc(alias = "CFE_SB_MAX_PIPES_MET")]
-    pub const SB_MAX_PIPES_MET: Status = mk_status(S_CFE_SB_MAX_PIPES_MET);
-
-    #[doc(alias = "CFE_SB_PIPE_CR_ERR")]
-    pub const SB_PIPE_CR_ERR: Status = mk_status(S_CFE_SB_PIPE_CR_ERR);
-
-    #[doc(alias = "CFE_SB_PIPE_RD_ERR")]
-    pub const SB_PIPE_RD_ERR: Status = mk_status(S_CFE_SB_PIPE_RD_ERR);
-
-    #[doc(alias = "CFE_SB_MSG_TOO_BIG")]
-    pub const SB_MSG_TOO_BIG: Status = mk_status(S_CFE_SB_MSG_TOO_BIG);
-
-    #[doc(alias = "CFE_SB_BUF_ALOC_ERR")]
-    pub const SB_BUF_ALOC_ERR: Status = mk_status(S_CFE_SB_BUF_ALOC_ERR);
-
-    #[doc(alias = "CFE_SB_MAX_MSGS_MET")]
-    pub const SB_MAX_MSGS_MET: Status = mk_status(S_CFE_SB_MAX_MSGS_MET);
-
-    #[doc(alias = "CFE_SB_MAX_DESTS_MET")]
-    pub const SB_MAX_DESTS_MET: Status = mk_status(S_CFE_SB_MAX_DESTS_MET);
-
-    #[doc(alias = "CFE_SB_INTERNAL_ERR")]
-    pub const SB_INTERNAL_ERR: Status = mk_status(S_CFE_SB_INTERNAL_ERR);
-
-    #[doc(alias = "CFE_SB_WRONG_MSG_TYPE")]
-    pub const SB_WRONG_MSG_TYPE: Status = mk_status(S_CFE_SB_WRONG_MSG_TYPE);
-
-    #[doc(alias = "CFE_SB_BUFFER_INVALID")]
-    pub const SB_BUFFER_INVALID: Status = mk_status(S_CFE_SB_BUFFER_INVALID);
-
-    #[doc(alias = "CFE_SB_NOT_IMPLEMENTED")]
-    pub const SB_NOT_IMPLEMENTED: Status = mk_status(S_CFE_SB_NOT_IMPLEMENTED);
-
-    #[doc(alias = "CFE_TBL_ERR_INVALID_HANDLE")]
-    pub const TBL_ERR_INVALID_HANDLE: Status = mk_status(S_CFE_TBL_ERR_INVALID_HANDLE);
-
-    #[doc(alias = "CFE_TBL_ERR_INVALID_NAME")]
-    pub const TBL_ERR_INVALID_NAME: Status = mk_status(S_CFE_TBL_ERR_INVALID_NAME);
-
-    #[doc(alias = "CFE_TBL_ERR_INVALID_SIZE")]
-    pub const TBL_ERR_INVALID_SIZE: Status = mk_status(S_CFE_TBL_ERR_INVALID_SIZE);
-
-    #[doc(alias = "CFE_TBL_INFO_UPDATE_PENDING")]
-    pub const TBL_INFO_UPDATE_PENDING: Status = mk_status(S_CFE_TBL_INFO_UPDATE_PENDING);
-
-    #[doc(alias = "CFE_TBL_ERR_NEVER_LOADED")]
-    pub const TBL_ERR_NEVER_LOADED: Status = mk_status(S_CFE_TBL_ERR_NEVER_LOADED);
-
-    #[doc(alias = "CFE_TBL_ERR_REGISTRY_FULL")]
-    pub const TBL_ERR_REGISTRY_FULL: Status = mk_status(S_CFE_TBL_ERR_REGISTRY_FULL);
-
-    #[doc(alias = "CFE_TBL_WARN_DUPLICATE")]
-    pub const TBL_WARN_DUPLICATE: Status = mk_status(S_CFE_TBL_WARN_DUPLICATE);
-
-    #[doc(alias = "CFE_TBL_ERR_NO_ACCESS")]
-    pub const TBL_ERR_NO_ACCESS: Status = mk_status(S_CFE_TBL_ERR_NO_ACCESS);
-
-    #[doc(alias = "CFE_TBL_ERR_UNREGISTERED")]
-    pub const TBL_ERR_UNREGISTERED: Status = mk_status(S_CFE_TBL_ERR_UNREGISTERED);
-
-    #[doc(alias = "CFE_TBL_ERR_HANDLES_FULL")]
-    pub const TBL_ERR_HANDLES_FULL: Status = mk_status(S_CFE_TBL_ERR_HANDLES_FULL);
-
-    #[doc(alias = "CFE_TBL_ERR_DUPLICATE_DIFF_SIZE")]
-    pub const TBL_ERR_DUPLICATE_DIFF_SIZE: Status = mk_status(S_CFE_TBL_ERR_DUPLICATE_DIFF_SIZE);
-
-    #[doc(alias = "CFE_TBL_ERR_DUPLICATE_NOT_OWNED")]
-    pub const TBL_ERR_DUPLICATE_NOT_OWNED: Status = mk_status(S_CFE_TBL_ERR_DUPLICATE_NOT_OWNED);
-
-    #[doc(alias = "CFE_TBL_INFO_UPDATED")]
-    pub const TBL_INFO_UPDATED: Status = mk_status(S_CFE_TBL_INFO_UPDATED);
-
-    #[doc(alias = "CFE_TBL_ERR_NO_BUFFER_AVAIL")]
-    pub const TBL_ERR_NO_BUFFER_AVAIL: Status = mk_status(S_CFE_TBL_ERR_NO_BUFFER_AVAIL);
-
-    #[doc(alias = "CFE_TBL_ERR_DUMP_ONLY")]
-    pub const TBL_ERR_DUMP_ONLY: Status = mk_status(S_CFE_TBL_ERR_DUMP_ONLY);
-
-    #[doc(alias = "CFE_TBL_ERR_ILLEGAL_SRC_TYPE")]
-    pub const TBL_ERR_ILLEGAL_SRC_TYPE: Status = mk_status(S_CFE_TBL_ERR_ILLEGAL_SRC_TYPE);
-
-    #[doc(alias = "CFE_TBL_ERR_LOAD_IN_PROGRESS")]
-    pub const TBL_ERR_LOAD_IN_PROGRESS: Status = mk_status(S_CFE_TBL_ERR_LOAD_IN_PROGRESS);
-
-    #[doc(alias = "CFE_TBL_ERR_FILE_TOO_LARGE")]
-    pub const TBL_ERR_FILE_TOO_LARGE: Status = mk_status(S_CFE_TBL_ERR_FILE_TOO_LARGE);
-
-    #[doc(alias = "CFE_TBL_WARN_SHORT_FILE")]
-    pub const TBL_WARN_SHORT_FILE: Status = mk_status(S_CFE_TBL_WARN_SHORT_FILE);
-
-    #[doc(alias = "CFE_TBL_ERR_BAD_CONTENT_ID")]
-    pub const TBL_ERR_BAD_CONTENT_ID: Status = mk_status(S_CFE_TBL_ERR_BAD_CONTENT_ID);
-
-    #[doc(alias = "CFE_TBL_INFO_NO_UPDATE_PENDING")]
-    pub const TBL_INFO_NO_UPDATE_PENDING: Status = mk_status(S_CFE_TBL_INFO_NO_UPDATE_PENDING);
-
-    #[doc(alias = "CFE_TBL_INFO_TABLE_LOCKED")]
-    pub const TBL_INFO_TABLE_LOCKED: Status = mk_status(S_CFE_TBL_INFO_TABLE_LOCKED);
-
-    #[doc(alias = "CFE_TBL_INFO_VALIDATION_PENDING")]
-    pub const TBL_INFO_VALIDATION_PENDING: Status = mk_status(S_CFE_TBL_INFO_VALIDATION_PENDING);
-
-    #[doc(alias = "CFE_TBL_INFO_NO_VALIDATION_PENDING")]
-    pub const TBL_INFO_NO_VALIDATION_PENDING: Status =
-        mk_status(S_CFE_TBL_INFO_NO_VALIDATION_PENDING);
-
-    #[doc(alias = "CFE_TBL_ERR_BAD_SUBTYPE_ID")]
-    pub const TBL_ERR_BAD_SUBTYPE_ID: Status = mk_status(S_CFE_TBL_ERR_BAD_SUBTYPE_ID);
-
-    #[doc(alias = "CFE_TBL_ERR_FILE_SIZE_INCONSISTENT")]
-    pub const TBL_ERR_FILE_SIZE_INCONSISTENT: Status =
-        mk_status(S_CFE_TBL_ERR_FILE_SIZE_INCONSISTENT);
-
-    #[doc(alias = "CFE_TBL_ERR_NO_STD_HEADER")]
-    pub const TBL_ERR_NO_STD_HEADER: Status = mk_status(S_CFE_TBL_ERR_NO_STD_HEADER);
-
-    #[doc(alias = "CFE_TBL_ERR_NO_TBL_HEADER")]
-    pub const TBL_ERR_NO_TBL_HEADER: Status = mk_status(S_CFE_TBL_ERR_NO_TBL_HEADER);
-
-    #[doc(alias = "CFE_TBL_ERR_FILENAME_TOO_LONG")]
-    pub const TBL_ERR_FILENAME_TOO_LONG: Status = mk_status(S_CFE_TBL_ERR_FILENAME_TOO_LONG);
-
-    #[doc(alias = "CFE_TBL_ERR_FILE_FOR_WRONG_TABLE")]
-    pub const TBL_ERR_FILE_FOR_WRONG_TABLE: Status = mk_status(S_CFE_TBL_ERR_FILE_FOR_WRONG_TABLE);
-
-    #[doc(alias = "CFE_TBL_ERR_LOAD_INCOMPLETE")]
-    pub const TBL_ERR_LOAD_INCOMPLETE: Status = mk_status(S_CFE_TBL_ERR_LOAD_INCOMPLETE);
-
-    #[doc(alias = "CFE_TBL_WARN_PARTIAL_LOAD")]
-    pub const TBL_WARN_PARTIAL_LOAD: Status = mk_status(S_CFE_TBL_WARN_PARTIAL_LOAD);
-
-    #[doc(alias = "CFE_TBL_ERR_PARTIAL_LOAD")]
-    pub const TBL_ERR_PARTIAL_LOAD: Status = mk_status(S_CFE_TBL_ERR_PARTIAL_LOAD);
-
-    #[doc(alias = "CFE_TBL_INFO_DUMP_PENDING")]
-    pub const TBL_INFO_DUMP_PENDING: Status = mk_status(S_CFE_TBL_INFO_DUMP_PENDING);
-
-    #[doc(alias = "CFE_TBL_ERR_INVALID_OPTIONS")]
-    pub const TBL_ERR_INVALID_OPTIONS: Status = mk_status(S_CFE_TBL_ERR_INVALID_OPTIONS);
-
-    #[doc(alias = "CFE_TBL_WARN_NOT_CRITICAL")]
-    pub const TBL_WARN_NOT_CRITICAL: Status = mk_status(S_CFE_TBL_WARN_NOT_CRITICAL);
-
-    #[doc(alias = "CFE_TBL_INFO_RECOVERED_TBL")]
-    pub const TBL_INFO_RECOVERED_TBL: Status = mk_status(S_CFE_TBL_INFO_RECOVERED_TBL);
-
-    #[doc(alias = "CFE_TBL_ERR_BAD_SPACECRAFT_ID")]
-    pub const TBL_ERR_BAD_SPACECRAFT_ID: Status = mk_status(S_CFE_TBL_ERR_BAD_SPACECRAFT_ID);
-
-    #[doc(alias = "CFE_TBL_ERR_BAD_PROCESSOR_ID")]
-    pub const TBL_ERR_BAD_PROCESSOR_ID: Status = mk_status(S_CFE_TBL_ERR_BAD_PROCESSOR_ID);
-
-    #[doc(alias = "CFE_TBL_MESSAGE_ERROR")]
-    pub const TBL_MESSAGE_ERROR: Status = mk_status(S_CFE_TBL_MESSAGE_ERROR);
-
-    #[doc(alias = "CFE_TBL_ERR_SHORT_FILE")]
-    pub const TBL_ERR_SHORT_FILE: Status = mk_status(S_CFE_TBL_ERR_SHORT_FILE);
-
-    #[doc(alias = "CFE_TBL_ERR_ACCESS")]
-    pub const TBL_ERR_ACCESS: Status = mk_status(S_CFE_TBL_ERR_ACCESS);
-
-    #[doc(alias = "CFE_TBL_BAD_ARGUMENT")]
-    pub const TBL_BAD_ARGUMENT: Status = mk_status(S_CFE_TBL_BAD_ARGUMENT);
-
-    #[doc(alias = "CFE_TBL_NOT_IMPLEMENTED")]
-    pub const TBL_NOT_IMPLEMENTED: Status = mk_status(S_CFE_TBL_NOT_IMPLEMENTED);
-
-    #[doc(alias = "CFE_TIME_NOT_IMPLEMENTED")]
-    pub const TIME_NOT_IMPLEMENTED: Status = mk_status(S_CFE_TIME_NOT_IMPLEMENTED);
-
-    #[doc(alias = "CFE_TIME_INTERNAL_ONLY")]
-    pub const TIME_INTERNAL_ONLY: Status = mk_status(S_CFE_TIME_INTERNAL_ONLY);
-
-    #[doc(alias = "CFE_TIME_OUT_OF_RANGE")]
-    pub const TIME_OUT_OF_RANGE: Status = mk_status(S_CFE_TIME_OUT_OF_RANGE);
-
-    #[doc(alias = "CFE_TIME_TOO_MANY_SYNCH_CALLBACKS")]
-    pub const TIME_TOO_MANY_SYNCH_CALLBACKS: Status =
-        mk_status(S_CFE_TIME_TOO_MANY_SYNCH_CALLBACKS);
-
-    #[doc(alias = "CFE_TIME_CALLBACK_NOT_REGISTERED")]
-    pub const TIME_CALLBACK_NOT_REGISTERED: Status = mk_status(S_CFE_TIME_CALLBACK_NOT_REGISTERED);
+/// Defines an associated constant on [`Status`] for each listed status,
+/// along with [`Status::name`], which maps the values back to their C names.
+macro_rules! status_consts {
+    ($($c_name:literal $name:ident = $value:ident;)*) => {
+        impl Status {
+            $(
+                #[doc(alias = $c_name)]
+                pub const $name: Status = mk_status($value);
+            )*
+
+            /// Returns the name cFE gives the status (e.g., `"CFE_SB_BAD_ARGUMENT"`),
+            /// or `None` if it isn't one of the statuses defined by cFE.
+            pub fn name(&self) -> Option<&'static str> {
+                self.name_with_nul().map(|s| &s[..s.len() - 1])
+            }
+
+            /// Like [`name`](Self::name), but with a terminating NUL character,
+            /// for passing to C.
+            pub(crate) fn name_with_nul(&self) -> Option<&'static str> {
+                $(
+                    if self.status == $value {
+                        return Some(concat!($c_name, "\0"));
+                    }
+                )*
+                None
+            }
+        }
+    };
+}
 
-    #[doc(alias = "CFE_TIME_BAD_ARGUMENT")]
-    pub const TIME_BAD_ARGUMENT: Status = mk_status(S_CFE_TIME_BAD_ARGUMENT);
+status_consts! {
+    "CFE_SUCCESS" SUCCESS = S_CFE_SUCCESS;
+
+    "CFE_STATUS_NO_COUNTER_INCREMENT" STATUS_NO_COUNTER_INCREMENT = S_CFE_STATUS_NO_COUNTER_INCREMENT;
+    "CFE_STATUS_WRONG_MSG_LENGTH" STATUS_WRONG_MSG_LENGTH = S_CFE_STATUS_WRONG_MSG_LENGTH;
+    "CFE_STATUS_UNKNOWN_MSG_ID" STATUS_UNKNOWN_MSG_ID = S_CFE_STATUS_UNKNOWN_MSG_ID;
+    "CFE_STATUS_BAD_COMMAND_CODE" STATUS_BAD_COMMAND_CODE = S_CFE_STATUS_BAD_COMMAND_CODE;
+    "CFE_STATUS_EXTERNAL_RESOURCE_FAIL" STATUS_EXTERNAL_RESOURCE_FAIL = S_CFE_STATUS_EXTERNAL_RESOURCE_FAIL;
+    "CFE_STATUS_REQUEST_ALREADY_PENDING" STATUS_REQUEST_ALREADY_PENDING = S_CFE_STATUS_REQUEST_ALREADY_PENDING;
+    "CFE_STATUS_NOT_IMPLEMENTED" STATUS_NOT_IMPLEMENTED = S_CFE_STATUS_NOT_IMPLEMENTED;
+
+    "CFE_EVS_UNKNOWN_FILTER" EVS_UNKNOWN_FILTER = S_CFE_EVS_UNKNOWN_FILTER;
+    "CFE_EVS_APP_NOT_REGISTERED" EVS_APP_NOT_REGISTERED = S_CFE_EVS_APP_NOT_REGISTERED;
+    "CFE_EVS_APP_ILLEGAL_APP_ID" EVS_APP_ILLEGAL_APP_ID = S_CFE_EVS_APP_ILLEGAL_APP_ID;
+    "CFE_EVS_APP_FILTER_OVERLOAD" EVS_APP_FILTER_OVERLOAD = S_CFE_EVS_APP_FILTER_OVERLOAD;
+    "CFE_EVS_RESET_AREA_POINTER" EVS_RESET_AREA_POINTER = S_CFE_EVS_RESET_AREA_POINTER;
+    "CFE_EVS_EVT_NOT_REGISTERED" EVS_EVT_NOT_REGISTERED = S_CFE_EVS_EVT_NOT_REGISTERED;
+    "CFE_EVS_FILE_WRITE_ERROR" EVS_FILE_WRITE_ERROR = S_CFE_EVS_FILE_WRITE_ERROR;
+    "CFE_EVS_INVALID_PARAMETER" EVS_INVALID_PARAMETER = S_CFE_EVS_INVALID_PARAMETER;
+    "CFE_EVS_NOT_IMPLEMENTED" EVS_NOT_IMPLEMENTED = S_CFE_EVS_NOT_IMPLEMENTED;
+
+    "CFE_ES_ERR_RESOURCEID_NOT_VALID" ES_ERR_RESOURCEID_NOT_VALID = S_CFE_ES_ERR_RESOURCEID_NOT_VALID;
+    "CFE_ES_ERR_NAME_NOT_FOUND" ES_ERR_NAME_NOT_FOUND = S_CFE_ES_ERR_NAME_NOT_FOUND;
+    "CFE_ES_ERR_APP_CREATE" ES_ERR_APP_CREATE = S_CFE_ES_ERR_APP_CREATE;
+    "CFE_ES_ERR_CHILD_TASK_CREATE" ES_ERR_CHILD_TASK_CREATE = S_CFE_ES_ERR_CHILD_TASK_CREATE;
+    "CFE_ES_ERR_SYS_LOG_FULL" ES_ERR_SYS_LOG_FULL = S_CFE_ES_ERR_SYS_LOG_FULL;
+    "CFE_ES_ERR_MEM_BLOCK_SIZE" ES_ERR_MEM_BLOCK_SIZE = S_CFE_ES_ERR_MEM_BLOCK_SIZE;
+    "CFE_ES_ERR_LOAD_LIB" ES_ERR_LOAD_LIB = S_CFE_ES_ERR_LOAD_LIB;
+    "CFE_ES_BAD_ARGUMENT" ES_BAD_ARGUMENT = S_CFE_ES_BAD_ARGUMENT;
+    "CFE_ES_ERR_CHILD_TASK_REGISTER" ES_ERR_CHILD_TASK_REGISTER = S_CFE_ES_ERR_CHILD_TASK_REGISTER;
+    "CFE_ES_CDS_ALREADY_EXISTS" ES_CDS_ALREADY_EXISTS = S_CFE_ES_CDS_ALREADY_EXISTS;
+    "CFE_ES_CDS_INSUFFICIENT_MEMORY" ES_CDS_INSUFFICIENT_MEMORY = S_CFE_ES_CDS_INSUFFICIENT_MEMORY;
+    "CFE_ES_CDS_INVALID_NAME" ES_CDS_INVALID_NAME = S_CFE_ES_CDS_INVALID_NAME;
+    "CFE_ES_CDS_INVALID_SIZE" ES_CDS_INVALID_SIZE = S_CFE_ES_CDS_INVALID_SIZE;
+    "CFE_ES_CDS_INVALID" ES_CDS_INVALID = S_CFE_ES_CDS_INVALID;
+    "CFE_ES_CDS_ACCESS_ERROR" ES_CDS_ACCESS_ERROR = S_CFE_ES_CDS_ACCESS_ERROR;
+    "CFE_ES_FILE_IO_ERR" ES_FILE_IO_ERR = S_CFE_ES_FILE_IO_ERR;
+    "CFE_ES_RST_ACCESS_ERR" ES_RST_ACCESS_ERR = S_CFE_ES_RST_ACCESS_ERR;
+    "CFE_ES_ERR_APP_REGISTER" ES_ERR_APP_REGISTER = S_CFE_ES_ERR_APP_REGISTER;
+    "CFE_ES_ERR_CHILD_TASK_DELETE" ES_ERR_CHILD_TASK_DELETE = S_CFE_ES_ERR_CHILD_TASK_DELETE;
+    "CFE_ES_ERR_CHILD_TASK_DELETE_MAIN_TASK" ES_ERR_CHILD_TASK_DELETE_MAIN_TASK = S_CFE_ES_ERR_CHILD_TASK_DELETE_MAIN_TASK;
+    "CFE_ES_CDS_BLOCK_CRC_ERR" ES_CDS_BLOCK_CRC_ERR = S_CFE_ES_CDS_BLOCK_CRC_ERR;
+    "CFE_ES_MUT_SEM_DELETE_ERR" ES_MUT_SEM_DELETE_ERR = S_CFE_ES_MUT_SEM_DELETE_ERR;
+    "CFE_ES_BIN_SEM_DELETE_ERR" ES_BIN_SEM_DELETE_ERR = S_CFE_ES_BIN_SEM_DELETE_ERR;
+    "CFE_ES_COUNT_SEM_DELETE_ERR" ES_COUNT_SEM_DELETE_ERR = S_CFE_ES_COUNT_SEM_DELETE_ERR;
+    "CFE_ES_QUEUE_DELETE_ERR" ES_QUEUE_DELETE_ERR = S_CFE_ES_QUEUE_DELETE_ERR;
+    "CFE_ES_FILE_CLOSE_ERR" ES_FILE_CLOSE_ERR = S_CFE_ES_FILE_CLOSE_ERR;
+    "CFE_ES_CDS_WRONG_TYPE_ERR" ES_CDS_WRONG_TYPE_ERR = S_CFE_ES_CDS_WRONG_TYPE_ERR;
+    "CFE_ES_CDS_OWNER_ACTIVE_ERR" ES_CDS_OWNER_ACTIVE_ERR = S_CFE_ES_CDS_OWNER_ACTIVE_ERR;
+    "CFE_ES_APP_CLEANUP_ERR" ES_APP_CLEANUP_ERR = S_CFE_ES_APP_CLEANUP_ERR;
+    "CFE_ES_TIMER_DELETE_ERR" ES_TIMER_DELETE_ERR = S_CFE_ES_TIMER_DELETE_ERR;
+    "CFE_ES_BUFFER_NOT_IN_POOL" ES_BUFFER_NOT_IN_POOL = S_CFE_ES_BUFFER_NOT_IN_POOL;
+    "CFE_ES_TASK_DELETE_ERR" ES_TASK_DELETE_ERR = S_CFE_ES_TASK_DELETE_ERR;
+    "CFE_ES_OPERATION_TIMED_OUT" ES_OPERATION_TIMED_OUT = S_CFE_ES_OPERATION_TIMED_OUT;
+    "CFE_ES_LIB_ALREADY_LOADED" ES_LIB_ALREADY_LOADED = S_CFE_ES_LIB_ALREADY_LOADED;
+    "CFE_ES_ERR_SYS_LOG_TRUNCATED" ES_ERR_SYS_LOG_TRUNCATED = S_CFE_ES_ERR_SYS_LOG_TRUNCATED;
+    "CFE_ES_NO_RESOURCE_IDS_AVAILABLE" ES_NO_RESOURCE_IDS_AVAILABLE = S_CFE_ES_NO_RESOURCE_IDS_AVAILABLE;
+    "CFE_ES_POOL_BLOCK_INVALID" ES_POOL_BLOCK_INVALID = S_CFE_ES_POOL_BLOCK_INVALID;
+    "CFE_ES_ERR_DUPLICATE_NAME" ES_ERR_DUPLICATE_NAME = S_CFE_ES_ERR_DUPLICATE_NAME;
+    "CFE_ES_NOT_IMPLEMENTED" ES_NOT_IMPLEMENTED = S_CFE_ES_NOT_IMPLEMENTED;
+
+    "CFE_FS_BAD_ARGUMENT" FS_BAD_ARGUMENT = S_CFE_FS_BAD_ARGUMENT;
+    "CFE_FS_INVALID_PATH" FS_INVALID_PATH = S_CFE_FS_INVALID_PATH;
+    "CFE_FS_FNAME_TOO_LONG" FS_FNAME_TOO_LONG = S_CFE_FS_FNAME_TOO_LONG;
+    "CFE_FS_NOT_IMPLEMENTED" FS_NOT_IMPLEMENTED = S_CFE_FS_NOT_IMPLEMENTED;
+
+    "CFE_MSG_WRONG_MSG_TYPE" MSG_WRONG_MSG_TYPE = S_CFE_MSG_WRONG_MSG_TYPE;
+
+    "CFE_SB_TIME_OUT" SB_TIME_OUT = S_CFE_SB_TIME_OUT;
+    "CFE_SB_NO_MESSAGE" SB_NO_MESSAGE = S_CFE_SB_NO_MESSAGE;
+    "CFE_SB_BAD_ARGUMENT" SB_BAD_ARGUMENT = S_CFE_SB_BAD_ARGUMENT;
+    "CFE_SB_MAX_PIPES_MET" SB_MAX_PIPES_MET = S_CFE_SB_MAX_PIPES_MET;
+    "CFE_SB_PIPE_CR_ERR" SB_PIPE_CR_ERR = S_CFE_SB_PIPE_CR_ERR;
+    "CFE_SB_PIPE_RD_ERR" SB_PIPE_RD_ERR = S_CFE_SB_PIPE_RD_ERR;
+    "CFE_SB_MSG_TOO_BIG" SB_MSG_TOO_BIG = S_CFE_SB_MSG_TOO_BIG;
+    "CFE_SB_BUF_ALOC_ERR" SB_BUF_ALOC_ERR = S_CFE_SB_BUF_ALOC_ERR;
+    "CFE_SB_MAX_MSGS_MET" SB_MAX_MSGS_MET = S_CFE_SB_MAX_MSGS_MET;
+    "CFE_SB_MAX_DESTS_MET" SB_MAX_DESTS_MET = S_CFE_SB_MAX_DESTS_MET;
+    "CFE_SB_INTERNAL_ERR" SB_INTERNAL_ERR = S_CFE_SB_INTERNAL_ERR;
+    "CFE_SB_WRONG_MSG_TYPE" SB_WRONG_MSG_TYPE = S_CFE_SB_WRONG_MSG_TYPE;
+    "CFE_SB_BUFFER_INVALID" SB_BUFFER_INVALID = S_CFE_SB_BUFFER_INVALID;
+    "CFE_SB_NOT_IMPLEMENTED" SB_NOT_IMPLEMENTED = S_CFE_SB_NOT_IMPLEMENTED;
+
+    "CFE_TBL_ERR_INVALID_HANDLE" TBL_ERR_INVALID_HANDLE = S_CFE_TBL_ERR_INVALID_HANDLE;
+    "CFE_TBL_ERR_INVALID_NAME" TBL_ERR_INVALID_NAME = S_CFE_TBL_ERR_INVALID_NAME;
+    "CFE_TBL_ERR_INVALID_SIZE" TBL_ERR_INVALID_SIZE = S_CFE_TBL_ERR_INVALID_SIZE;
+    "CFE_TBL_INFO_UPDATE_PENDING" TBL_INFO_UPDATE_PENDING = S_CFE_TBL_INFO_UPDATE_PENDING;
+    "CFE_TBL_ERR_NEVER_LOADED" TBL_ERR_NEVER_LOADED = S_CFE_TBL_ERR_NEVER_LOADED;
+    "CFE_TBL_ERR_REGISTRY_FULL" TBL_ERR_REGISTRY_FULL = S_CFE_TBL_ERR_REGISTRY_FULL;
+    "CFE_TBL_WARN_DUPLICATE" TBL_WARN_DUPLICATE = S_CFE_TBL_WARN_DUPLICATE;
+    "CFE_TBL_ERR_NO_ACCESS" TBL_ERR_NO_ACCESS = S_CFE_TBL_ERR_NO_ACCESS;
+    "CFE_TBL_ERR_UNREGISTERED" TBL_ERR_UNREGISTERED = S_CFE_TBL_ERR_UNREGISTERED;
+    "CFE_TBL_ERR_HANDLES_FULL" TBL_ERR_HANDLES_FULL = S_CFE_TBL_ERR_HANDLES_FULL;
+    "CFE_TBL_ERR_DUPLICATE_DIFF_SIZE" TBL_ERR_DUPLICATE_DIFF_SIZE = S_CFE_TBL_ERR_DUPLICATE_DIFF_SIZE;
+    "CFE_TBL_ERR_DUPLICATE_NOT_OWNED" TBL_ERR_DUPLICATE_NOT_OWNED = S_CFE_TBL_ERR_DUPLICATE_NOT_OWNED;
+    "CFE_TBL_INFO_UPDATED" TBL_INFO_UPDATED = S_CFE_TBL_INFO_UPDATED;
+    "CFE_TBL_ERR_NO_BUFFER_AVAIL" TBL_ERR_NO_BUFFER_AVAIL = S_CFE_TBL_ERR_NO_BUFFER_AVAIL;
+    "CFE_TBL_ERR_DUMP_ONLY" TBL_ERR_DUMP_ONLY = S_CFE_TBL_ERR_DUMP_ONLY;
+    "CFE_TBL_ERR_ILLEGAL_SRC_TYPE" TBL_ERR_ILLEGAL_SRC_TYPE = S_CFE_TBL_ERR_ILLEGAL_SRC_TYPE;
+    "CFE_TBL_ERR_LOAD_IN_PROGRESS" TBL_ERR_LOAD_IN_PROGRESS = S_CFE_TBL_ERR_LOAD_IN_PROGRESS;
+    "CFE_TBL_ERR_FILE_TOO_LARGE" TBL_ERR_FILE_TOO_LARGE = S_CFE_TBL_ERR_FILE_TOO_LARGE;
+    "CFE_TBL_WARN_SHORT_FILE" TBL_WARN_SHORT_FILE = S_CFE_TBL_WARN_SHORT_FILE;
+    "CFE_TBL_ERR_BAD_CONTENT_ID" TBL_ERR_BAD_CONTENT_ID = S_CFE_TBL_ERR_BAD_CONTENT_ID;
+    "CFE_TBL_INFO_NO_UPDATE_PENDING" TBL_INFO_NO_UPDATE_PENDING = S_CFE_TBL_INFO_NO_UPDATE_PENDING;
+    "CFE_TBL_INFO_TABLE_LOCKED" TBL_INFO_TABLE_LOCKED = S_CFE_TBL_INFO_TABLE_LOCKED;
+    "CFE_TBL_INFO_VALIDATION_PENDING" TBL_INFO_VALIDATION_PENDING = S_CFE_TBL_INFO_VALIDATION_PENDING;
+    "CFE_TBL_INFO_NO_VALIDATION_PENDING" TBL_INFO_NO_VALIDATION_PENDING = S_CFE_TBL_INFO_NO_VALIDATION_PENDING;
+    "CFE_TBL_ERR_BAD_SUBTYPE_ID" TBL_ERR_BAD_SUBTYPE_ID = S_CFE_TBL_ERR_BAD_SUBTYPE_ID;
+    "CFE_TBL_ERR_FILE_SIZE_INCONSISTENT" TBL_ERR_FILE_SIZE_INCONSISTENT = S_CFE_TBL_ERR_FILE_SIZE_INCONSISTENT;
+    "CFE_TBL_ERR_NO_STD_HEADER" TBL_ERR_NO_STD_HEADER = S_CFE_TBL_ERR_NO_STD_HEADER;
+    "CFE_TBL_ERR_NO_TBL_HEADER" TBL_ERR_NO_TBL_HEADER = S_CFE_TBL_ERR_NO_TBL_HEADER;
+    "CFE_TBL_ERR_FILENAME_TOO_LONG" TBL_ERR_FILENAME_TOO_LONG = S_CFE_TBL_ERR_FILENAME_TOO_LONG;
+    "CFE_TBL_ERR_FILE_FOR_WRONG_TABLE" TBL_ERR_FILE_FOR_WRONG_TABLE = S_CFE_TBL_ERR_FILE_FOR_WRONG_TABLE;
+    "CFE_TBL_ERR_LOAD_INCOMPLETE" TBL_ERR_LOAD_INCOMPLETE = S_CFE_TBL_ERR_LOAD_INCOMPLETE;
+    "CFE_TBL_WARN_PARTIAL_LOAD" TBL_WARN_PARTIAL_LOAD = S_CFE_TBL_WARN_PARTIAL_LOAD;
+    "CFE_TBL_ERR_PARTIAL_LOAD" TBL_ERR_PARTIAL_LOAD = S_CFE_TBL_ERR_PARTIAL_LOAD;
+    "CFE_TBL_INFO_DUMP_PENDING" TBL_INFO_DUMP_PENDING = S_CFE_TBL_INFO_DUMP_PENDING;
+    "CFE_TBL_ERR_INVALID_OPTIONS" TBL_ERR_INVALID_OPTIONS = S_CFE_TBL_ERR_INVALID_OPTIONS;
+    "CFE_TBL_WARN_NOT_CRITICAL" TBL_WARN_NOT_CRITICAL = S_CFE_TBL_WARN_NOT_CRITICAL;
+    "CFE_TBL_INFO_RECOVERED_TBL" TBL_INFO_RECOVERED_TBL = S_CFE_TBL_INFO_RECOVERED_TBL;
+    "CFE_TBL_ERR_BAD_SPACECRAFT_ID" TBL_ERR_BAD_SPACECRAFT_ID = S_CFE_TBL_ERR_BAD_SPACECRAFT_ID;
+    "CFE_TBL_ERR_BAD_PROCESSOR_ID" TBL_ERR_BAD_PROCESSOR_ID = S_CFE_TBL_ERR_BAD_PROCESSOR_ID;
+    "CFE_TBL_MESSAGE_ERROR" TBL_MESSAGE_ERROR = S_CFE_TBL_MESSAGE_ERROR;
+    "CFE_TBL_ERR_SHORT_FILE" TBL_ERR_SHORT_FILE = S_CFE_TBL_ERR_SHORT_FILE;
+    "CFE_TBL_ERR_ACCESS" TBL_ERR_ACCESS = S_CFE_TBL_ERR_ACCESS;
+    "CFE_TBL_BAD_ARGUMENT" TBL_BAD_ARGUMENT = S_CFE_TBL_BAD_ARGUMENT;
+    "CFE_TBL_NOT_IMPLEMENTED" TBL_NOT_IMPLEMENTED = S_CFE_TBL_NOT_IMPLEMENTED;
+
+    "CFE_TIME_NOT_IMPLEMENTED" TIME_NOT_IMPLEMENTED = S_CFE_TIME_NOT_IMPLEMENTED;
+    "CFE_TIME_INTERNAL_ONLY" TIME_INTERNAL_ONLY = S_CFE_TIME_INTERNAL_ONLY;
+    "CFE_TIME_OUT_OF_RANGE" TIME_OUT_OF_RANGE = S_CFE_TIME_OUT_OF_RANGE;
+    "CFE_TIME_TOO_MANY_SYNCH_CALLBACKS" TIME_TOO_MANY_SYNCH_CALLBACKS = S_CFE_TIME_TOO_MANY_SYNCH_CALLBACKS;
+    "CFE_TIME_CALLBACK_NOT_REGISTERED" TIME_CALLBACK_NOT_REGISTERED = S_CFE_TIME_CALLBACK_NOT_REGISTERED;
+    "CFE_TIME_BAD_ARGUMENT" TIME_BAD_ARGUMENT = S_CFE_TIME_BAD_ARGUMENT;
 }
@@ -3,13 +3,19 @@
 
 //! Table system.
 
+use crate::cfe::es::{calculate_crc, CrcType};
 use crate::cfe::time::SysTime;
 use crate::cfe::Status;
+use crate::ffi_errors::{self, Subsystem};
+use crate::osal::sync::OwnedMutSem;
+use crate::osal::OsalError;
 use crate::sys::*;
-use crate::utils::CStrBuf;
+use crate::utils::{AnyBitPattern, CStrBuf};
+use core::cell::UnsafeCell;
 use core::ffi::{c_char, c_void, CStr};
 use core::marker::PhantomData;
 use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicBool, Ordering};
 
 /// A convenience trait for referring to which types can be
 /// used as the contents of cFE tables.
@@ -33,6 +39,10 @@ pub fn info<S: AsRef<CStr> + ?Sized>(table_name: &S) -> Result<TblInfo, Status>
 
 /// A handle to a table.
 ///
+/// A `TblHandle` may be moved to any task of the application that registered the table
+/// (such as a child task), but Table Services doesn't coordinate operations on the same handle
+/// from several tasks; to use a handle from more than one task, wrap it in a [`SyncTblHandle`].
+///
 /// Wraps a `CFE_TBL_Handle_t`.
 #[doc(alias = "CFE_TBL_Handle_t")]
 pub struct TblHandle<T: TableType> {
@@ -40,6 +50,15 @@ pub struct TblHandle<T: TableType> {
     _x:  PhantomData<T>,
 }
 
+/// Table Services identifies the application using a table handle from the calling task,
+/// so a handle may be sent to any task of the application that registered it
+/// (elsewhere, operations on it just fail).
+unsafe impl<T: TableType> Send for TblHandle<T> {}
+
+/// Every operation that accesses the table contents or changes its state takes `&mut self`;
+/// the rest only query Table Services, which serializes access to its registry.
+unsafe impl<T: TableType> Sync for TblHandle<T> {}
+
 impl<T: TableType> TblHandle<T> {
     /// Tries to register a loadable table with cFE,
     /// returning a handle if successful.
@@ -100,7 +119,10 @@ impl<T: TableType> TblHandle<T> {
     {
         let mut tbl_ptr: *mut c_void = core::ptr::null_mut();
 
-        let status: Status = unsafe { CFE_TBL_GetAddress(&mut tbl_ptr, self.hdl) }.into();
+        let status: Status = super::perf::timed(super::perf::TBL_GET_ADDRESS, || unsafe {
+            CFE_TBL_GetAddress(&mut tbl_ptr, self.hdl)
+        })
+        .into();
 
         let updated_recently = match status {
             Status::SUCCESS => false,
@@ -115,7 +137,8 @@ impl<T: TableType> TblHandle<T> {
             Some(tbl_ref) => Ok(closure(tbl_ref, updated_recently)),
         };
 
-        let _ = unsafe { CFE_TBL_ReleaseAddress(self.hdl) };
+        let status = unsafe { CFE_TBL_ReleaseAddress(self.hdl) };
+        ffi_errors::check_cfe(Subsystem::Tbl, status);
 
         return_val
     }
@@ -140,6 +163,82 @@ impl<T: TableType> TblHandle<T> {
         status.as_result(|| ())
     }
 
+    /// Tries to load the table with the contents `bytes`,
+    /// an image of a `T` (e.g., as received from the ground).
+    ///
+    /// Table Services copies `bytes` straight into the table's own working buffer,
+    /// so they needn't be aligned for a `T`, and no `T` is staged on the stack.
+    ///
+    /// Returns [`TBL_ERR_INVALID_SIZE`](Status::TBL_ERR_INVALID_SIZE)
+    /// (without loading anything) if `bytes` isn't exactly `size_of::<T>()` bytes long.
+    /// Overlaying just part of the current contents isn't supported:
+    /// `CFE_TBL_Load` only takes a whole image,
+    /// so merging a part into the current contents would need a full-size copy of the table
+    /// outside Table Services, which is what this avoids
+    /// (tables may well be bigger than a task's stack).
+    ///
+    /// Wraps `CFE_TBL_Load`.
+    #[doc(alias = "CFE_TBL_Load")]
+    #[inline]
+    pub fn load_from_slice(&mut self, bytes: &[u8]) -> Result<(), Status>
+    where
+        T: AnyBitPattern,
+    {
+        if bytes.len() != core::mem::size_of::<T>() {
+            return Err(Status::TBL_ERR_INVALID_SIZE);
+        }
+
+        // Safety: `bytes` is the right length for a `T`, and any bytes are a valid `T`.
+        // Table Services copies them byte by byte, so alignment doesn't matter.
+        let status: Status = unsafe {
+            CFE_TBL_Load(
+                self.hdl,
+                CFE_TBL_SrcEnum_CFE_TBL_SRC_ADDRESS,
+                bytes.as_ptr() as *const c_void,
+            )
+        }
+        .into();
+
+        status.as_result(|| ())
+    }
+
+    /// Loads the table with data from `source` (as with [`load`](Self::load)),
+    /// then reads the contents back and checks that their
+    /// [CRC](calculate_crc) is `expected_crc`.
+    ///
+    /// Note that the new contents are loaded (and visible to other users of the table)
+    /// before they're checked; on a [`CrcMismatch`](VerifiedLoadError::CrcMismatch),
+    /// the caller should treat them as suspect (e.g., by loading known-good contents instead).
+    /// Also, as the contents are read back through [`get_ref`](Self::get_ref),
+    /// the next call to `get_ref` won't report them as updated.
+    ///
+    /// Wraps `CFE_TBL_Load`, `CFE_TBL_GetAddress`, `CFE_ES_CalculateCRC`,
+    /// and `CFE_TBL_ReleaseAddress`.
+    #[doc(alias("CFE_TBL_Load", "CFE_ES_CalculateCRC"))]
+    #[inline]
+    pub fn load_verified(
+        &mut self,
+        source: TblLoadSource<'_, T>,
+        expected_crc: u32,
+    ) -> Result<(), VerifiedLoadError> {
+        self.load(source)?;
+
+        let actual = self.get_ref(|tbl, _| {
+            // Safety: `tbl` refers to a `T`, which is `size_of::<T>()` bytes long.
+            // (Any padding bytes in `T` are included in the CRC, as they are by cFE.)
+            let bytes = unsafe {
+                core::slice::from_raw_parts(tbl as *const T as *const u8, core::mem::size_of::<T>())
+            };
+            calculate_crc(bytes, 0, CrcType::DEFAULT)
+        })?;
+
+        if actual == expected_crc {
+            Ok(())
+        } else {
+            Err(VerifiedLoadError::CrcMismatch { expected: expected_crc, actual })
+        }
+    }
+
     /// Notifies Table Services that this application
     /// has modified the contents of this table.
     ///
@@ -168,7 +267,9 @@ impl<T: TableType> TblHandle<T> {
     #[doc(alias = "CFE_TBL_Manage")]
     #[inline]
     pub fn manage(&mut self) -> Result<bool, Status> {
-        let status: Status = unsafe { CFE_TBL_Manage(self.hdl) }.into();
+        let status: Status =
+            super::perf::timed(super::perf::TBL_MANAGE, || unsafe { CFE_TBL_Manage(self.hdl) })
+                .into();
 
         match status {
             Status::SUCCESS => Ok(false),
@@ -294,6 +395,80 @@ impl<T: TableType> TblHandle<T> {
     }
 }
 
+/// A [`TblHandle`] which may be used from several tasks of the application that registered it.
+///
+/// Access to the handle is serialized by an OSAL mutex:
+///
+/// ```rust,ignore
+/// let shared = SyncTblHandle::new(tbl, c"MY_TBL_MUTEX")?;
+/// let limit = shared.lock(|th| th.get_ref(|tbl, _| tbl.limit))??;
+/// ```
+pub struct SyncTblHandle<T: TableType> {
+    th:     UnsafeCell<TblHandle<T>>,
+    mutex:  OwnedMutSem,
+    in_use: AtomicBool,
+}
+
+/// The handle itself may be sent between tasks of its application.
+unsafe impl<T: TableType> Send for SyncTblHandle<T> {}
+
+/// All access to the inner handle happens in [`lock`](SyncTblHandle::lock),
+/// with the mutex held.
+unsafe impl<T: TableType> Sync for SyncTblHandle<T> {}
+
+impl<T: TableType> SyncTblHandle<T> {
+    /// Wraps `th`, creating a mutex with name `mutex_name` to serialize access to it.
+    ///
+    /// Wraps `OS_MutSemCreate`.
+    #[doc(alias = "OS_MutSemCreate")]
+    #[inline]
+    pub fn new<S: AsRef<CStr> + ?Sized>(
+        th: TblHandle<T>,
+        mutex_name: &S,
+    ) -> Result<Self, OsalError> {
+        Ok(SyncTblHandle {
+            th:     UnsafeCell::new(th),
+            mutex:  OwnedMutSem::new(mutex_name)?,
+            in_use: AtomicBool::new(false),
+        })
+    }
+
+    /// Acquires the mutex, blocking until it does, then runs `closure` on the inner handle.
+    ///
+    /// As OSAL mutexes may be recursive, a call from within `closure` on the same handle
+    /// fails with [`Status::STATUS_REQUEST_ALREADY_PENDING`] rather than handing out a second reference;
+    /// if the mutex can't be acquired, returns [`Status::STATUS_EXTERNAL_RESOURCE_FAIL`].
+    ///
+    /// Wraps `OS_MutSemTake` and `OS_MutSemGive`.
+    #[doc(alias("OS_MutSemTake", "OS_MutSemGive"))]
+    #[inline]
+    pub fn lock<F, V>(&self, closure: F) -> Result<V, Status>
+    where
+        F: FnOnce(&mut TblHandle<T>) -> V,
+    {
+        self.mutex
+            .lock(|| {
+                if self.in_use.swap(true, Ordering::Acquire) {
+                    return Err(Status::STATUS_REQUEST_ALREADY_PENDING);
+                }
+
+                // Safety: we hold the mutex, and the flag shows this isn't a nested call,
+                // so no other reference to the handle exists.
+                let val = closure(unsafe { &mut *self.th.get() });
+
+                self.in_use.store(false, Ordering::Release);
+                Ok(val)
+            })
+            .map_err(|_| Status::STATUS_EXTERNAL_RESOURCE_FAIL)?
+    }
+
+    /// Returns the inner handle, deleting the mutex.
+    #[inline]
+    pub fn into_inner(self) -> TblHandle<T> {
+        self.th.into_inner()
+    }
+}
+
 /// A handle to a dump-only table.
 ///
 /// Wraps a `CFE_TBL_Handle_t`.
@@ -414,7 +589,10 @@ impl<T: TableType> DumpOnlyTblHandle<T> {
         } else {
             let mut tbl_ptr: *mut c_void = core::ptr::null_mut();
 
-            let status: Status = unsafe { CFE_TBL_GetAddress(&mut tbl_ptr, self.th.hdl) }.into();
+            let status: Status = super::perf::timed(super::perf::TBL_GET_ADDRESS, || unsafe {
+                CFE_TBL_GetAddress(&mut tbl_ptr, self.th.hdl)
+            })
+            .into();
 
             match status {
                 Status::SUCCESS | Status::TBL_INFO_UPDATED => (),
@@ -432,12 +610,14 @@ impl<T: TableType> DumpOnlyTblHandle<T> {
                 }
             };
 
-            let _ = unsafe { CFE_TBL_ReleaseAddress(self.th.hdl) };
+            let status = unsafe { CFE_TBL_ReleaseAddress(self.th.hdl) };
+            ffi_errors::check_cfe(Subsystem::Tbl, status);
 
             rv
         };
 
-        let _ = unsafe { CFE_TBL_Modified(self.th.hdl) };
+        let status = unsafe { CFE_TBL_Modified(self.th.hdl) };
+        ffi_errors::check_cfe(Subsystem::Tbl, status);
 
         return return_val;
     }
@@ -535,7 +715,8 @@ impl<T: TableType> Drop for SharedTblHandle<T> {
     #[doc(alias = "CFE_TBL_Unregister")]
     #[inline]
     fn drop(&mut self) {
-        let _ = unsafe { CFE_TBL_Unregister(self.th.hdl) };
+        let status = unsafe { CFE_TBL_Unregister(self.th.hdl) };
+        ffi_errors::check_cfe(Subsystem::Tbl, status);
     }
 }
 
@@ -633,6 +814,43 @@ pub enum TblLoadSource<'a, T> {
     FileName(&'a CStr),
 }
 
+/// Error: a call to [`TblHandle::load_verified`] failed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VerifiedLoadError {
+    /// Loading the table, or reading it back, failed with this status.
+    Status(Status),
+
+    /// The table was loaded, but the CRC of its contents wasn't the one expected.
+    CrcMismatch {
+        /// The CRC expected.
+        expected: u32,
+
+        /// The CRC of the table contents.
+        actual: u32,
+    },
+}
+
+impl From<Status> for VerifiedLoadError {
+    #[inline]
+    fn from(status: Status) -> Self {
+        VerifiedLoadError::Status(status)
+    }
+}
+
+impl core::fmt::Display for VerifiedLoadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            VerifiedLoadError::Status(status) => write!(f, "{}", status),
+            VerifiedLoadError::CrcMismatch { expected, actual } => {
+                write!(f, "table CRC mismatch: expected {:#06x}, got {:#06x}", expected, actual)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VerifiedLoadError {}
+
 /// A pending action for a table.
 #[derive(Clone, Copy, Debug)]
 #[non_exhaustive]
@@ -653,6 +871,7 @@ pub enum PendingAction {
 /// Corresponds to `CFE_TBL_Info_t`.
 #[doc(alias = "CFE_TBL_Info_t")]
 #[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TblInfo {
     /// Size of the table in bytes.
     pub size: usize,
@@ -4,6 +4,7 @@
 //! Time Services system.
 
 use crate::sys::*;
+use crate::utils::bitflags_impl;
 use core::cmp::Ordering;
 use core::ops::{Add, Sub};
 
@@ -14,6 +15,11 @@ macro_rules! cfe_time_type {
         /// Wraps `CFE_TIME_SysTime_t`.
         #[doc(alias = "CFE_TIME_SysTime_t")]
         #[derive(Clone, Copy, Debug)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(serde::Serialize, serde::Deserialize),
+            serde(from = "TimeRepr", into = "TimeRepr")
+        )]
         pub struct $name {
             pub(crate) tm: CFE_TIME_SysTime_t,
         }
@@ -102,9 +108,33 @@ macro_rules! cfe_time_type {
                 <$osal>::from_microseconds(value.seconds() as i64, microseconds)
             }
         }
+
+        #[cfg(feature = "serde")]
+        impl From<TimeRepr> for $name {
+            #[inline]
+            fn from(repr: TimeRepr) -> Self {
+                Self::new(repr.seconds, repr.subseconds)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl From<$name> for TimeRepr {
+            #[inline]
+            fn from(value: $name) -> Self {
+                TimeRepr { seconds: value.seconds(), subseconds: value.subseconds() }
+            }
+        }
     };
 }
 
+/// How [`SysTime`] and [`DeltaTime`] are (de)serialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TimeRepr {
+    seconds:    u32,
+    subseconds: u32,
+}
+
 cfe_time_type!(SysTime:
     "A system time value, represented as seconds/subseconds since some epoch.",
     "since the relevant epoch",
@@ -181,3 +211,234 @@ pub fn get_time() -> SysTime {
     let tm = unsafe { CFE_TIME_GetTime() };
     SysTime { tm }
 }
+
+/// Details about the state of the spacecraft clock,
+/// for judging the quality of the time it reports.
+///
+/// This is a bitfield; elements may be combined using the `|` operator
+/// and checked for with [`contains`](Self::contains).
+///
+/// cFE has no flag for a pending leap-second change;
+/// the leap-second count is applied through the STCF and leap-seconds commands instead.
+///
+/// Substitutes for the value returned by `CFE_TIME_GetClockInfo`.
+#[doc(alias = "CFE_TIME_GetClockInfo")]
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct ClockInfo {
+    flags: u16,
+}
+
+impl ClockInfo {
+    /// The clock has been set.
+    ///
+    /// Wraps `CFE_TIME_FLAG_CLKSET`.
+    #[doc(alias = "CFE_TIME_FLAG_CLKSET")]
+    pub const CLOCK_SET: ClockInfo = Self::flag(CFE_TIME_FLAG_CLKSET);
+
+    /// The clock is flywheeling (running without a time-at-the-tone signal).
+    ///
+    /// Wraps `CFE_TIME_FLAG_FLYING`.
+    #[doc(alias = "CFE_TIME_FLAG_FLYING")]
+    pub const FLYWHEEL: ClockInfo = Self::flag(CFE_TIME_FLAG_FLYING);
+
+    /// The time source is internal (rather than external).
+    ///
+    /// Wraps `CFE_TIME_FLAG_SRCINT`.
+    #[doc(alias = "CFE_TIME_FLAG_SRCINT")]
+    pub const SOURCE_INTERNAL: ClockInfo = Self::flag(CFE_TIME_FLAG_SRCINT);
+
+    /// The tone signal is from the primary (rather than redundant) source.
+    ///
+    /// Wraps `CFE_TIME_FLAG_SIGPRI`.
+    #[doc(alias = "CFE_TIME_FLAG_SIGPRI")]
+    pub const SIGNAL_PRIMARY: ClockInfo = Self::flag(CFE_TIME_FLAG_SIGPRI);
+
+    /// The time server is flywheeling.
+    ///
+    /// Wraps `CFE_TIME_FLAG_SRVFLY`.
+    #[doc(alias = "CFE_TIME_FLAG_SRVFLY")]
+    pub const SERVER_FLYWHEEL: ClockInfo = Self::flag(CFE_TIME_FLAG_SRVFLY);
+
+    /// Flywheel mode has been commanded.
+    ///
+    /// Wraps `CFE_TIME_FLAG_CMDFLY`.
+    #[doc(alias = "CFE_TIME_FLAG_CMDFLY")]
+    pub const COMMANDED_FLYWHEEL: ClockInfo = Self::flag(CFE_TIME_FLAG_CMDFLY);
+
+    /// The one-time STCF adjustment is to be added (rather than subtracted).
+    ///
+    /// Wraps `CFE_TIME_FLAG_ADDADJ`.
+    #[doc(alias = "CFE_TIME_FLAG_ADDADJ")]
+    pub const ADD_ADJUSTMENT: ClockInfo = Self::flag(CFE_TIME_FLAG_ADDADJ);
+
+    /// The 1&nbsp;Hz STCF adjustment is to be added (rather than subtracted).
+    ///
+    /// Wraps `CFE_TIME_FLAG_ADD1HZ`.
+    #[doc(alias = "CFE_TIME_FLAG_ADD1HZ")]
+    pub const ADD_1HZ_ADJUSTMENT: ClockInfo = Self::flag(CFE_TIME_FLAG_ADD1HZ);
+
+    /// The time client's latency is to be added (rather than subtracted).
+    ///
+    /// Wraps `CFE_TIME_FLAG_ADDTCL`.
+    #[doc(alias = "CFE_TIME_FLAG_ADDTCL")]
+    pub const ADD_CLIENT_LATENCY: ClockInfo = Self::flag(CFE_TIME_FLAG_ADDTCL);
+
+    /// This instance of Time Services is a time server (rather than a client).
+    ///
+    /// Wraps `CFE_TIME_FLAG_SERVER`.
+    #[doc(alias = "CFE_TIME_FLAG_SERVER")]
+    pub const SERVER: ClockInfo = Self::flag(CFE_TIME_FLAG_SERVER);
+
+    /// The tone was received and validated.
+    ///
+    /// Wraps `CFE_TIME_FLAG_GDTONE`.
+    #[doc(alias = "CFE_TIME_FLAG_GDTONE")]
+    pub const GOOD_TONE: ClockInfo = Self::flag(CFE_TIME_FLAG_GDTONE);
+
+    /// The time of the last tone didn't match the reference time.
+    ///
+    /// Wraps `CFE_TIME_FLAG_REFERR`.
+    #[doc(alias = "CFE_TIME_FLAG_REFERR")]
+    pub const REFERENCE_ERROR: ClockInfo = Self::flag(CFE_TIME_FLAG_REFERR);
+
+    #[inline]
+    const fn flag(f: u32) -> ClockInfo {
+        ClockInfo { flags: f as u16 }
+    }
+
+    /// Returns the current state of the spacecraft clock.
+    ///
+    /// Wraps `CFE_TIME_GetClockInfo`.
+    #[doc(alias = "CFE_TIME_GetClockInfo")]
+    #[inline]
+    pub fn get() -> ClockInfo {
+        ClockInfo {
+            flags: unsafe { CFE_TIME_GetClockInfo() },
+        }
+    }
+
+    /// Returns whether the clock has been set.
+    #[inline]
+    pub const fn is_clock_set(self) -> bool {
+        self.contains(Self::CLOCK_SET)
+    }
+
+    /// Returns whether the clock is flywheeling.
+    #[inline]
+    pub const fn is_flywheeling(self) -> bool {
+        self.contains(Self::FLYWHEEL)
+    }
+}
+
+bitflags_impl!(ClockInfo, flags: u16);
+
+/// A time in the CCSDS day segmented (CDS) time code format
+/// (CCSDS 301.0-B-4, section 3.3), with a 16-bit day count
+/// and a 16-bit submillisecond field in microseconds.
+///
+/// Days are counted from the mission epoch's day,
+/// so the corresponding P-field (not included) is "agency-defined epoch".
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CdsTime {
+    /// The number of days since the mission epoch's day.
+    pub days: u16,
+
+    /// The milliseconds of the day.
+    pub millis_of_day: u32,
+
+    /// The microseconds of the millisecond.
+    pub micros_of_milli: u16,
+}
+
+#[cfg(feature = "std")]
+impl CdsTime {
+    /// Returns the time code's T-field, as transmitted (big-endian).
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 8] {
+        let mut bytes = [0u8; 8];
+        bytes[0..2].copy_from_slice(&self.days.to_be_bytes());
+        bytes[2..6].copy_from_slice(&self.millis_of_day.to_be_bytes());
+        bytes[6..8].copy_from_slice(&self.micros_of_milli.to_be_bytes());
+        bytes
+    }
+}
+
+#[cfg(feature = "std")]
+impl SysTime {
+    /// The number of seconds between the start of the mission epoch's day and the epoch.
+    const EPOCH_TIME_OF_DAY: i64 = CFE_MISSION_TIME_EPOCH_HOUR as i64 * 3600
+        + CFE_MISSION_TIME_EPOCH_MINUTE as i64 * 60
+        + CFE_MISSION_TIME_EPOCH_SECOND as i64;
+
+    /// Returns the whole seconds and the nanoseconds
+    /// since the start of the mission epoch's day.
+    fn since_epoch_day(self) -> (i64, u32) {
+        let nanos = ((self.subseconds() as u64 * 1_000_000_000) >> 32) as u32;
+        (self.seconds() as i64 + Self::EPOCH_TIME_OF_DAY, nanos)
+    }
+
+    /// Breaks the time down into a calendar date and time of day,
+    /// taking it to be relative to the mission epoch
+    /// (`CFE_MISSION_TIME_EPOCH_YEAR`, etc.).
+    ///
+    /// No leap seconds are applied,
+    /// so the result is on the same time scale (TAI or UTC) as the time itself.
+    #[doc(alias("CFE_MISSION_TIME_EPOCH_YEAR", "CFE_MISSION_TIME_EPOCH_DAY"))]
+    pub fn to_calendar(self) -> crate::osal::CalendarTime {
+        // The epoch's day of the year is 1-based:
+        let epoch_day = days_from_civil(CFE_MISSION_TIME_EPOCH_YEAR as i64)
+            + CFE_MISSION_TIME_EPOCH_DAY as i64
+            - 1;
+        let (secs, nanos) = self.since_epoch_day();
+
+        crate::osal::OSTime::from_nanoseconds(epoch_day * 86_400 + secs, nanos).to_calendar()
+    }
+
+    /// Formats the time (as broken down by [`to_calendar`](Self::to_calendar))
+    /// in ISO 8601 extended format with microseconds, e.g., `2024-03-01T12:34:56.789012Z`.
+    ///
+    /// The `Z` suffix is only accurate if the time is UTC rather than TAI.
+    pub fn to_iso8601(self) -> String {
+        let cal = self.to_calendar();
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:06}Z",
+            cal.year,
+            cal.month,
+            cal.day,
+            cal.hour,
+            cal.minute,
+            cal.second,
+            cal.nanosecond / 1000
+        )
+    }
+
+    /// Converts the time to the CCSDS day segmented time code format,
+    /// with days counted from the mission epoch's day.
+    ///
+    /// Returns `None` if the day count doesn't fit in 16 bits.
+    pub fn to_ccsds_cds(self) -> Option<CdsTime> {
+        let (secs, nanos) = self.since_epoch_day();
+        let days = u16::try_from(secs / 86_400).ok()?;
+
+        Some(CdsTime {
+            days,
+            millis_of_day: (secs % 86_400) as u32 * 1000 + nanos / 1_000_000,
+            micros_of_milli: (nanos / 1000 % 1000) as u16,
+        })
+    }
+}
+
+/// Returns the number of days from 1970-01-01 to January 1 of `year`
+/// (in the proleptic Gregorian calendar),
+/// per Howard Hinnant's `days_from_civil` algorithm.
+#[cfg(feature = "std")]
+fn days_from_civil(year: i64) -> i64 {
+    // Counting years from March, January is in the previous year:
+    let y = year - 1;
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let doy = 306; // January 1, counting from March 1
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
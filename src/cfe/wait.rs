@@ -0,0 +1,275 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A single wait for whichever comes first:
+//! a message on a pipe, the expiry of a local timer, or a request to shut down.
+//!
+//! Scheduler-driven applications typically wait on a pipe subscribed to
+//! a wakeup message, while also needing to do things at their own pace
+//! (retry a command after a delay, time out a handshake, etc.).
+//! A [`WaitSet`] combines a [`Pipe`] with `N` local timers,
+//! and [`wait_any`](WaitSet::wait_any) reports which of them woke the application up:
+//!
+//! ```rust,ignore
+//! let mut waits = WaitSet::<2>::new(&mut pipe);
+//! let five_s = OSTimeInterval::from_milliseconds(5, 0);
+//! waits.arm(RETRY_TIMER, five_s, five_s)?;
+//!
+//! loop {
+//!     let keep_going = waits.wait_any(TimeOut::PendForever, |result| match result {
+//!         Ok(WaitResult::Message(msg)) => { handle_message(msg); true }
+//!         Ok(WaitResult::TimerExpired(RETRY_TIMER)) => { retry(); true }
+//!         Ok(WaitResult::TimerExpired(_)) => true,
+//!         Ok(WaitResult::Shutdown) => false,
+//!         Err(err) => { report(err); true }
+//!     });
+//!     if !keep_going {
+//!         break;
+//!     }
+//! }
+//! ```
+//!
+//! The timers are kept by checking [OSAL's clock](crate::osal::local_time)
+//! rather than by OSAL timers, so they need no callbacks, tasks, or OSAL resources;
+//! they're only checked during calls to [`wait_any`](WaitSet::wait_any),
+//! which limits how long it waits on the pipe to when the next timer is due.
+
+use super::sb::{Pipe, TimeOut};
+use super::{msg::Message, Status};
+use crate::error::Error;
+use crate::osal::{local_time, OSTime, OSTimeInterval};
+
+/// What ended a call to [`WaitSet::wait_any`].
+#[derive(Clone, Copy)]
+pub enum WaitResult<'a> {
+    /// A message arrived on the pipe.
+    Message(&'a Message),
+
+    /// The local timer with this index expired.
+    TimerExpired(usize),
+
+    /// cFE has asked the application to exit
+    /// (i.e., [`run_loop`](super::es::run_loop) returned `false`).
+    Shutdown,
+}
+
+/// A local timer in a [`WaitSet`].
+#[derive(Clone, Copy, Debug)]
+struct LocalTimer {
+    /// When the timer is next due, if it's armed.
+    deadline: Option<OSTime>,
+
+    /// The time between expiries, or zero for a one-shot timer.
+    period: OSTimeInterval,
+}
+
+impl LocalTimer {
+    /// A disarmed timer.
+    const DISARMED: LocalTimer = LocalTimer {
+        deadline: None,
+        period:   OSTimeInterval {
+            int: crate::sys::OS_time_t { ticks: 0 },
+        },
+    };
+}
+
+/// A [`Pipe`] and `N` local timers to be waited on together.
+///
+/// See [the module documentation](self) for an example.
+#[derive(Debug)]
+pub struct WaitSet<'p, const N: usize> {
+    pipe:   &'p mut Pipe,
+    timers: [LocalTimer; N],
+}
+
+impl<'p, const N: usize> WaitSet<'p, N> {
+    /// Creates a wait set for `pipe`, with all `N` timers disarmed.
+    #[inline]
+    pub fn new(pipe: &'p mut Pipe) -> Self {
+        WaitSet {
+            pipe,
+            timers: [LocalTimer::DISARMED; N],
+        }
+    }
+
+    /// Returns the pipe waited on.
+    #[inline]
+    pub fn pipe_mut(&mut self) -> &mut Pipe {
+        self.pipe
+    }
+
+    /// (Re)arms timer `index` to expire `start` from now,
+    /// then every `period` thereafter
+    /// (or only once, if `period` is zero or negative).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` isn't less than `N`.
+    #[inline]
+    pub fn arm(
+        &mut self,
+        index: usize,
+        start: OSTimeInterval,
+        period: OSTimeInterval,
+    ) -> Result<(), Error> {
+        let now = local_time()?;
+        self.timers[index] = LocalTimer {
+            deadline: Some(now + start),
+            period,
+        };
+        Ok(())
+    }
+
+    /// Disarms timer `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` isn't less than `N`.
+    #[inline]
+    pub fn disarm(&mut self, index: usize) {
+        self.timers[index].deadline = None;
+    }
+
+    /// Returns whether timer `index` is armed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` isn't less than `N`.
+    #[inline]
+    pub fn is_armed(&self, index: usize) -> bool {
+        self.timers[index].deadline.is_some()
+    }
+
+    /// Waits for a message on the pipe, the expiry of a timer,
+    /// or cFE's request for the application to exit, whichever comes first,
+    /// then calls `closure` with what happened.
+    ///
+    /// Shutdown requests are checked first, then the timers, then the pipe.
+    /// If more than one timer is due, the one due earliest is reported;
+    /// the others are reported by later calls.
+    ///
+    /// `time_out` limits how long to wait;
+    /// the wait on the pipe ends early when the next timer is due.
+    /// If nothing happens in time, `closure` is called with
+    /// [`SB_TIME_OUT`](Status::SB_TIME_OUT)
+    /// (or [`SB_NO_MESSAGE`](Status::SB_NO_MESSAGE) if `time_out` is [`TimeOut::Poll`]).
+    ///
+    /// Wraps `CFE_ES_RunLoop`, `OS_GetLocalTime`, and `CFE_SB_ReceiveBuffer`.
+    #[doc(alias("CFE_ES_RunLoop", "CFE_SB_ReceiveBuffer"))]
+    #[inline]
+    pub fn wait_any<T, F>(&mut self, time_out: TimeOut, closure: F) -> T
+    where
+        F: for<'a> FnOnce(Result<WaitResult<'a>, Error>) -> T,
+    {
+        if !super::es::run_loop(None) {
+            return closure(Ok(WaitResult::Shutdown));
+        }
+
+        let start = match local_time() {
+            Ok(now) => now,
+            Err(err) => return closure(Err(err.into())),
+        };
+        let mut now = start;
+        let mut closure = Some(closure);
+        let WaitSet { pipe, timers } = self;
+
+        loop {
+            if let Some(index) = take_expired(timers, now) {
+                return call(&mut closure, Ok(WaitResult::TimerExpired(index)));
+            }
+
+            let remaining = match time_out {
+                TimeOut::Millis(ms) => {
+                    let elapsed_ms = (now - start).total_milliseconds();
+                    let remaining_ms = (ms as i64).saturating_sub(elapsed_ms);
+                    if remaining_ms <= 0 && elapsed_ms > 0 {
+                        return call(&mut closure, Err(Status::SB_TIME_OUT.into()));
+                    }
+                    TimeOut::Millis(remaining_ms.max(0) as u32)
+                }
+                other => other,
+            };
+
+            // Wait on the pipe no longer than until the next timer is due;
+            // if that wait times out, go around again to check the timers.
+            let (pipe_time_out, cut_short) = match next_deadline(timers) {
+                None => (remaining, false),
+                Some(deadline) => {
+                    let until_due = deadline - now;
+                    let until_due_ms = (until_due.total_microseconds() + 999) / 1000;
+                    let until_due_ms = until_due_ms.clamp(1, u32::MAX as i64) as u32;
+
+                    match remaining {
+                        TimeOut::Poll => (TimeOut::Poll, false),
+                        TimeOut::Millis(ms) if ms <= until_due_ms => (remaining, false),
+                        _ => (TimeOut::Millis(until_due_ms), true),
+                    }
+                }
+            };
+
+            let result = pipe.receive_buffer(pipe_time_out, |msg| match msg {
+                Ok(msg) => Some(call(&mut closure, Ok(WaitResult::Message(msg)))),
+                Err(Status::SB_TIME_OUT) if cut_short => None,
+                Err(status @ (Status::SB_TIME_OUT | Status::SB_NO_MESSAGE)) => {
+                    // A timer may have come due while we were waiting:
+                    match local_time().map(|now| take_expired(timers, now)) {
+                        Ok(Some(index)) => {
+                            Some(call(&mut closure, Ok(WaitResult::TimerExpired(index))))
+                        }
+                        Ok(None) => Some(call(&mut closure, Err(status.into()))),
+                        Err(err) => Some(call(&mut closure, Err(err.into()))),
+                    }
+                }
+                Err(status) => Some(call(&mut closure, Err(status.into()))),
+            });
+            if let Some(result) = result {
+                return result;
+            }
+
+            now = match local_time() {
+                Ok(now) => now,
+                Err(err) => return call(&mut closure, Err(err.into())),
+            };
+        }
+    }
+}
+
+/// Calls the closure passed to [`WaitSet::wait_any`], which is only ever called once.
+#[inline]
+fn call<T, F>(closure: &mut Option<F>, result: Result<WaitResult<'_>, Error>) -> T
+where
+    F: for<'a> FnOnce(Result<WaitResult<'a>, Error>) -> T,
+{
+    match closure.take() {
+        Some(closure) => closure(result),
+        None => unreachable!("wait_any's closure called twice"),
+    }
+}
+
+/// Returns the deadline of whichever of `timers` is due soonest, if any are armed.
+#[inline]
+fn next_deadline(timers: &[LocalTimer]) -> Option<OSTime> {
+    timers.iter().filter_map(|t| t.deadline).min()
+}
+
+/// If any of `timers` is due as of `now`, returns the index of the one due earliest,
+/// after rearming it (if periodic) or disarming it (if one-shot).
+fn take_expired(timers: &mut [LocalTimer], now: OSTime) -> Option<usize> {
+    let (index, deadline) = timers
+        .iter()
+        .enumerate()
+        .filter_map(|(i, t)| t.deadline.map(|d| (i, d)))
+        .filter(|&(_, d)| d <= now)
+        .min_by_key(|&(_, d)| d)?;
+
+    let timer = &mut timers[index];
+    timer.deadline = if timer.period.total_nanoseconds() > 0 {
+        // Skip any periods missed entirely, rather than reporting them in a burst:
+        let next = deadline + timer.period;
+        Some(if next <= now { now + timer.period } else { next })
+    } else {
+        None
+    };
+
+    Some(index)
+}
@@ -0,0 +1,128 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Limits set by the mission and platform configuration of cFE and OSAL.
+//!
+//! These come from the configuration headers (`osconfig.h`, `cfe_mission_cfg.h`,
+//! `cfe_platform_cfg.h`, etc.) the bindings were generated from,
+//! so they reflect the mission's settings;
+//! see `notes/bindings-generation.md` for how to point the build at mission-specific headers.
+//! They're given here with the Rust types `n2o4`'s APIs use for the corresponding quantities.
+
+use crate::sys::*;
+
+/// The maximum length of an OSAL path name,
+/// including directory name, file name, and terminating NUL character.
+///
+/// Wraps `OS_MAX_PATH_LEN`.
+#[doc(alias = "OS_MAX_PATH_LEN")]
+pub const OS_PATH_LEN: usize = OS_MAX_PATH_LEN as usize;
+
+/// The maximum length of the name of an OSAL object (task, queue, semaphore, etc.),
+/// including the terminating NUL character.
+///
+/// Wraps `OS_MAX_API_NAME`.
+#[doc(alias = "OS_MAX_API_NAME")]
+pub const OS_NAME_LEN: usize = OS_MAX_API_NAME as usize;
+
+/// The maximum number of messages an OSAL [queue](crate::osal::queue::Queue) can hold.
+///
+/// Wraps `OS_QUEUE_MAX_DEPTH`.
+#[doc(alias = "OS_QUEUE_MAX_DEPTH")]
+pub const OS_QUEUE_DEPTH: usize = OS_QUEUE_MAX_DEPTH as usize;
+
+/// The maximum number of OSAL tasks that can exist at once.
+///
+/// Wraps `OS_MAX_TASKS`.
+#[doc(alias = "OS_MAX_TASKS")]
+pub const OS_TASKS: usize = OS_MAX_TASKS as usize;
+
+/// The maximum number of OSAL queues that can exist at once.
+///
+/// Wraps `OS_MAX_QUEUES`.
+#[doc(alias = "OS_MAX_QUEUES")]
+pub const OS_QUEUES: usize = OS_MAX_QUEUES as usize;
+
+/// The maximum number of files that can be open through OSAL at once.
+///
+/// Wraps `OS_MAX_NUM_OPEN_FILES`.
+#[doc(alias = "OS_MAX_NUM_OPEN_FILES")]
+pub const OS_OPEN_FILES: usize = OS_MAX_NUM_OPEN_FILES as usize;
+
+/// The maximum length of a cFE path name, including the terminating NUL character.
+///
+/// Wraps `CFE_MISSION_MAX_PATH_LEN`.
+#[doc(alias = "CFE_MISSION_MAX_PATH_LEN")]
+pub const CFE_PATH_LEN: usize = CFE_MISSION_MAX_PATH_LEN as usize;
+
+/// The maximum length of the name of a cFE application, library, or task,
+/// including the terminating NUL character.
+///
+/// Wraps `CFE_MISSION_MAX_API_LEN`.
+#[doc(alias = "CFE_MISSION_MAX_API_LEN")]
+pub const CFE_NAME_LEN: usize = CFE_MISSION_MAX_API_LEN as usize;
+
+/// The maximum size, in bytes, of a Software Bus message (header included).
+///
+/// Wraps `CFE_MISSION_SB_MAX_SB_MSG_SIZE`.
+#[doc(alias = "CFE_MISSION_SB_MAX_SB_MSG_SIZE")]
+pub const SB_MAX_MSG_SIZE: usize = CFE_MISSION_SB_MAX_SB_MSG_SIZE as usize;
+
+/// The maximum depth of a Software Bus [pipe](crate::cfe::sb::Pipe),
+/// as passed to [`Pipe::new`](crate::cfe::sb::Pipe::new).
+///
+/// cFE limits pipe depths to the OSAL queue depth limit (`OS_QUEUE_MAX_DEPTH`).
+#[doc(alias = "OS_QUEUE_MAX_DEPTH")]
+pub const SB_MAX_PIPE_DEPTH: u16 =
+    if OS_QUEUE_MAX_DEPTH > u16::MAX as u32 { u16::MAX } else { OS_QUEUE_MAX_DEPTH as u16 };
+
+/// The maximum number of Software Bus pipes that can exist at once.
+///
+/// Wraps `CFE_PLATFORM_SB_MAX_PIPES`.
+#[doc(alias = "CFE_PLATFORM_SB_MAX_PIPES")]
+pub const SB_MAX_PIPES: usize = CFE_PLATFORM_SB_MAX_PIPES as usize;
+
+/// The default limit on the number of messages with a given message ID
+/// that can be queued on a pipe at once.
+///
+/// Wraps `CFE_PLATFORM_SB_DEFAULT_MSG_LIMIT`.
+#[doc(alias = "CFE_PLATFORM_SB_DEFAULT_MSG_LIMIT")]
+pub const SB_DEFAULT_MSG_LIMIT: u16 = CFE_PLATFORM_SB_DEFAULT_MSG_LIMIT as u16;
+
+/// The maximum number of event filters an application can register.
+///
+/// Wraps `CFE_PLATFORM_EVS_MAX_EVENT_FILTERS`.
+#[doc(alias = "CFE_PLATFORM_EVS_MAX_EVENT_FILTERS")]
+pub const EVS_MAX_EVENT_FILTERS: usize = CFE_PLATFORM_EVS_MAX_EVENT_FILTERS as usize;
+
+/// The maximum length of an event message, including the terminating NUL character.
+///
+/// Wraps `CFE_MISSION_EVS_MAX_MESSAGE_LENGTH`.
+#[doc(alias = "CFE_MISSION_EVS_MAX_MESSAGE_LENGTH")]
+pub const EVS_MAX_MESSAGE_LEN: usize = CFE_MISSION_EVS_MAX_MESSAGE_LENGTH as usize;
+
+/// The maximum length of a table name (without the application-name prefix),
+/// including the terminating NUL character.
+///
+/// Wraps `CFE_MISSION_TBL_MAX_NAME_LENGTH`.
+#[doc(alias = "CFE_MISSION_TBL_MAX_NAME_LENGTH")]
+pub const TBL_MAX_NAME_LEN: usize = CFE_MISSION_TBL_MAX_NAME_LENGTH as usize;
+
+/// The maximum size, in bytes, of a single-buffered table.
+///
+/// Wraps `CFE_PLATFORM_TBL_MAX_SNGL_TABLE_SIZE`.
+#[doc(alias = "CFE_PLATFORM_TBL_MAX_SNGL_TABLE_SIZE")]
+pub const TBL_MAX_SINGLE_SIZE: usize = CFE_PLATFORM_TBL_MAX_SNGL_TABLE_SIZE as usize;
+
+/// The maximum size, in bytes, of a double-buffered table.
+///
+/// Wraps `CFE_PLATFORM_TBL_MAX_DBL_TABLE_SIZE`.
+#[doc(alias = "CFE_PLATFORM_TBL_MAX_DBL_TABLE_SIZE")]
+pub const TBL_MAX_DOUBLE_SIZE: usize = CFE_PLATFORM_TBL_MAX_DBL_TABLE_SIZE as usize;
+
+/// The maximum length of the name of a critical data store block
+/// (without the application-name prefix), including the terminating NUL character.
+///
+/// Wraps `CFE_MISSION_ES_CDS_MAX_NAME_LENGTH`.
+#[doc(alias = "CFE_MISSION_ES_CDS_MAX_NAME_LENGTH")]
+pub const CDS_MAX_NAME_LEN: usize = CFE_MISSION_ES_CDS_MAX_NAME_LENGTH as usize;
@@ -0,0 +1,115 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A crate-wide error type.
+
+use crate::cfe::{Status, StatusSeverity};
+use crate::osal::OsalError;
+
+/// An error from any of the subsystems wrapped by this crate.
+///
+/// This exists so that the `?` operator can be used across calls
+/// into both cFE and OSAL APIs in the same function.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Error {
+    /// A cFE status code.
+    Cfe(Status),
+
+    /// An OSAL error code.
+    Osal(OsalError),
+
+    /// A raw status code not (yet) attributed to a particular subsystem.
+    Raw(i32),
+}
+
+impl Error {
+    /// If this is a cFE status, returns it.
+    #[inline]
+    pub const fn as_cfe(&self) -> Option<Status> {
+        match *self {
+            Error::Cfe(status) => Some(status),
+            _ => None,
+        }
+    }
+
+    /// If this is an OSAL error, returns it.
+    #[inline]
+    pub const fn as_osal(&self) -> Option<OsalError> {
+        match *self {
+            Error::Osal(err) => Some(err),
+            _ => None,
+        }
+    }
+
+    /// Returns the underlying numeric error code.
+    #[inline]
+    pub const fn as_i32(&self) -> i32 {
+        match *self {
+            Error::Cfe(status) => status.status,
+            Error::Osal(err) => err.code.as_i32(),
+            Error::Raw(n) => n,
+        }
+    }
+
+    /// Returns `true` if this error is a cFE status of
+    /// [`Informational`](StatusSeverity::Informational) severity
+    /// (i.e., not strictly a failure).
+    #[inline]
+    pub fn is_informational(&self) -> bool {
+        matches!(self, Error::Cfe(status) if status.severity() == StatusSeverity::Informational)
+    }
+}
+
+impl From<Status> for Error {
+    #[inline]
+    fn from(status: Status) -> Error {
+        Error::Cfe(status)
+    }
+}
+
+impl From<OsalError> for Error {
+    #[inline]
+    fn from(err: OsalError) -> Error {
+        Error::Osal(err)
+    }
+}
+
+/// PSP errors aren't distinguished from other subsystems' codes,
+/// so they become [`Error::Raw`].
+impl From<crate::cfe::psp::PspError> for Error {
+    #[inline]
+    fn from(err: crate::cfe::psp::PspError) -> Error {
+        Error::Raw(err.code.as_i32())
+    }
+}
+
+impl From<i32> for Error {
+    #[inline]
+    fn from(code: i32) -> Error {
+        Error::Raw(code)
+    }
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Cfe(status) => status.fmt(f),
+            Error::Osal(err) => err.fmt(f),
+            Error::Raw(n) => write!(f, "error code {}", n),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    #[inline]
+    fn from(err: Error) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::Other, err)
+    }
+}
+
+/// A `Result` with [`Error`] as its error type.
+pub type Result<T> = core::result::Result<T, Error>;
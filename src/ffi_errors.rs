@@ -0,0 +1,129 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Counts of cFE and OSAL errors that `n2o4` has no way to report.
+//!
+//! Some calls `n2o4` makes can fail without there being anyone to tell:
+//! closing a file or deleting an OSAL object in a [`Drop`] impl,
+//! releasing a table's address after a closure has already produced its result,
+//! and so on.
+//! Normally, those errors are discarded.
+//!
+//! With the `count-ffi-errors` feature enabled,
+//! each such error instead increments an atomic counter for the [`Subsystem`] that returned it.
+//! The counters can be read with `counts` (e.g., for inclusion in housekeeping telemetry)
+//! and cleared with `reset` (e.g., on a reset-counters command).
+//! Without the feature, nothing is counted, and only [`Subsystem`] is available.
+
+use crate::sys::*;
+
+#[cfg(feature = "count-ffi-errors")]
+use core::sync::atomic::{AtomicU32, Ordering::Relaxed};
+
+/// The library whose errors are being counted.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[repr(usize)]
+pub enum Subsystem {
+    /// cFE Executive Services.
+    Es   = 0,
+
+    /// cFE Software Bus.
+    Sb   = 1,
+
+    /// cFE Table Services.
+    Tbl  = 2,
+
+    /// OSAL.
+    Osal = 3,
+}
+
+impl Subsystem {
+    /// All the subsystems, in the order of their fields in `FfiErrorCounts`.
+    pub const ALL: [Subsystem; 4] = [Subsystem::Es, Subsystem::Sb, Subsystem::Tbl, Subsystem::Osal];
+}
+
+/// A snapshot of the discarded-error counters.
+///
+/// Laid out as four `u32`s so it can be copied as-is into a housekeeping telemetry payload.
+/// Each counter wraps around on overflow.
+#[cfg(feature = "count-ffi-errors")]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default, Debug)]
+#[repr(C)]
+pub struct FfiErrorCounts {
+    /// Discarded errors from cFE Executive Services.
+    pub es: u32,
+
+    /// Discarded errors from the cFE Software Bus.
+    pub sb: u32,
+
+    /// Discarded errors from cFE Table Services.
+    pub tbl: u32,
+
+    /// Discarded errors from OSAL.
+    pub osal: u32,
+}
+
+#[cfg(feature = "count-ffi-errors")]
+static COUNTERS: [AtomicU32; 4] =
+    [AtomicU32::new(0), AtomicU32::new(0), AtomicU32::new(0), AtomicU32::new(0)];
+
+/// Returns the number of discarded errors from `subsystem`
+/// since startup or the last call to [`reset`].
+#[cfg(feature = "count-ffi-errors")]
+#[inline]
+pub fn count(subsystem: Subsystem) -> u32 {
+    COUNTERS[subsystem as usize].load(Relaxed)
+}
+
+/// Returns the number of discarded errors from each subsystem
+/// since startup or the last call to [`reset`].
+#[cfg(feature = "count-ffi-errors")]
+#[inline]
+pub fn counts() -> FfiErrorCounts {
+    FfiErrorCounts {
+        es:   count(Subsystem::Es),
+        sb:   count(Subsystem::Sb),
+        tbl:  count(Subsystem::Tbl),
+        osal: count(Subsystem::Osal),
+    }
+}
+
+/// Sets all the discarded-error counters back to zero.
+#[cfg(feature = "count-ffi-errors")]
+#[inline]
+pub fn reset() {
+    for c in COUNTERS.iter() {
+        c.store(0, Relaxed);
+    }
+}
+
+/// Counts one discarded error from `subsystem`
+/// if the `count-ffi-errors` feature is enabled.
+#[cfg(feature = "count-ffi-errors")]
+#[inline]
+pub(crate) fn record(subsystem: Subsystem) {
+    COUNTERS[subsystem as usize].fetch_add(1, Relaxed);
+}
+
+/// Counts one discarded error from `subsystem`
+/// if the `count-ffi-errors` feature is enabled.
+#[cfg(not(feature = "count-ffi-errors"))]
+#[inline(always)]
+pub(crate) fn record(_subsystem: Subsystem) {}
+
+/// Counts `status` as a discarded error from `subsystem`
+/// if it has error severity.
+#[inline(always)]
+pub(crate) fn check_cfe(subsystem: Subsystem, status: CFE_Status_t) {
+    if status < 0 {
+        record(subsystem);
+    }
+}
+
+/// Counts `code` as a discarded OSAL error if it's negative.
+#[inline(always)]
+pub(crate) fn check_osal(code: i32) {
+    if code < 0 {
+        record(Subsystem::Osal);
+    }
+}
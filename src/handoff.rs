@@ -0,0 +1,224 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! The protocol by which [`create_child_task`](crate::cfe::es::create_child_task)
+//! hands a closure over to the child task it creates.
+//!
+//! The parent task, while holding a lock that serializes handoffs,
+//! publishes a pointer to the closure in a shared slot,
+//! starts the child task, and waits for a signal.
+//! The child task takes the pointer out of the slot,
+//! moves the closure onto its own stack, and gives the signal,
+//! after which the parent forgets its (now moved-out-of) copy.
+//!
+//! The lock and signal are abstracted behind [`HandshakeSync`]
+//! so that the protocol can be model-checked under [loom](https://docs.rs/loom)
+//! (built with `--cfg loom`, which also swaps in loom's atomics here)
+//! rather than only on a running cFS system.
+//! The model in this module's tests implements [`HandshakeSync`] with `loom::sync` primitives,
+//! then runs [`Handoff::send`] and [`Handoff::receive`] on separate `loom::thread`s;
+//! run it with `RUSTFLAGS="--cfg loom" cargo test --release --lib handoff`.
+
+use core::ffi::c_void;
+use core::mem::ManuallyDrop;
+use core::ptr;
+
+#[cfg(not(loom))]
+use core::sync::atomic::{AtomicPtr, Ordering};
+#[cfg(loom)]
+use loom::sync::atomic::{AtomicPtr, Ordering};
+
+/// The synchronization primitives the handoff protocol is built on.
+pub(crate) trait HandshakeSync {
+    /// The error returned when a primitive can't be used.
+    type Error;
+
+    /// Runs `closure` while holding the lock serializing handoffs.
+    fn locked<T, F: FnOnce() -> T>(&self, closure: F) -> Result<T, Self::Error>;
+
+    /// Called by the receiving task to tell the sending task it has taken the value.
+    fn signal(&self);
+
+    /// Called by the sending task to wait for the receiving task's [`signal`](Self::signal).
+    fn wait(&self);
+}
+
+/// A slot through which one task passes a value to a task it starts.
+pub(crate) struct Handoff {
+    slot: AtomicPtr<c_void>,
+}
+
+impl Handoff {
+    /// Creates an empty slot.
+    #[cfg(not(loom))]
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Handoff {
+            slot: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Creates an empty slot.
+    #[cfg(loom)]
+    #[inline]
+    pub(crate) fn new() -> Self {
+        Handoff {
+            slot: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    /// Passes `value` to the task started by `spawn`,
+    /// which must call [`receive`](Self::receive) (with the same `sync` and `T`) exactly once.
+    ///
+    /// If `spawn` succeeds, returns its result once the new task has taken `value`.
+    /// If `spawn` or the lock fails, `value` is dropped and the error returned.
+    pub(crate) fn send<T, R, S: HandshakeSync, F: FnOnce() -> Result<R, S::Error>>(
+        &self,
+        sync: &S,
+        value: T,
+        spawn: F,
+    ) -> Result<R, S::Error> {
+        let value = ManuallyDrop::new(value);
+
+        let outcome = sync.locked(|| {
+            // The Release store pairs with the Acquire swap in `receive`,
+            // so that the receiving task sees `value` fully written:
+            self.slot.store(&*value as *const T as *mut c_void, Ordering::Release);
+
+            let result = spawn();
+            match result {
+                Ok(_) => sync.wait(),
+                Err(_) => self.slot.store(ptr::null_mut(), Ordering::Relaxed),
+            }
+            result
+        });
+
+        match outcome {
+            // `value` now belongs to the new task, so we must not drop it here.
+            Ok(Ok(r)) => Ok(r),
+            Ok(Err(e)) | Err(e) => {
+                drop(ManuallyDrop::into_inner(value));
+                Err(e)
+            }
+        }
+    }
+
+    /// Takes the value passed by [`send`](Self::send), then signals the sending task.
+    ///
+    /// # Safety
+    ///
+    /// Must be called exactly once, with the same `T`,
+    /// from the task started by the `spawn` closure passed to [`send`](Self::send).
+    pub(crate) unsafe fn receive<T, S: HandshakeSync>(&self, sync: &S) -> T {
+        let p = self.slot.swap(ptr::null_mut(), Ordering::Acquire) as *const T;
+        debug_assert!(!p.is_null(), "Handoff::receive called with nothing sent");
+
+        let value = ptr::read(p);
+        sync.signal();
+        value
+    }
+}
+
+#[cfg(all(test, loom))]
+mod tests {
+    use super::{Handoff, HandshakeSync};
+    use loom::sync::atomic::{AtomicUsize, Ordering};
+    use loom::sync::{Arc, Condvar, Mutex};
+    use loom::thread;
+
+    /// Stands in for the OSAL mutex and binary semaphore of `ChildHandshake`.
+    struct LoomSync {
+        lock:   Mutex<()>,
+        taken:  Mutex<bool>,
+        signal: Condvar,
+    }
+
+    impl LoomSync {
+        fn new() -> Self {
+            LoomSync {
+                lock:   Mutex::new(()),
+                taken:  Mutex::new(false),
+                signal: Condvar::new(),
+            }
+        }
+    }
+
+    impl HandshakeSync for LoomSync {
+        type Error = ();
+
+        fn locked<T, F: FnOnce() -> T>(&self, closure: F) -> Result<T, ()> {
+            let _guard = self.lock.lock().unwrap();
+            Ok(closure())
+        }
+
+        fn signal(&self) {
+            *self.taken.lock().unwrap() = true;
+            self.signal.notify_one();
+        }
+
+        fn wait(&self) {
+            let mut taken = self.taken.lock().unwrap();
+            while !*taken {
+                taken = self.signal.wait(taken).unwrap();
+            }
+            *taken = false;
+        }
+    }
+
+    /// A value that counts how many times it's been dropped.
+    struct Payload {
+        value: u32,
+        drops: Arc<AtomicUsize>,
+    }
+
+    impl Drop for Payload {
+        fn drop(&mut self) {
+            self.drops.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn value_is_moved_to_receiver() {
+        loom::model(|| {
+            let handoff = Arc::new(Handoff::new());
+            let sync = Arc::new(LoomSync::new());
+            let drops = Arc::new(AtomicUsize::new(0));
+            let payload = Payload {
+                value: 42,
+                drops: drops.clone(),
+            };
+
+            let mut child = None;
+            let result = handoff.send(&*sync, payload, || {
+                let (handoff, sync) = (handoff.clone(), sync.clone());
+                child = Some(thread::spawn(move || {
+                    let payload: Payload = unsafe { handoff.receive(&*sync) };
+                    payload.value
+                }));
+                Ok(7)
+            });
+
+            assert_eq!(result, Ok(7));
+            assert_eq!(child.unwrap().join().unwrap(), 42);
+            assert_eq!(drops.load(Ordering::Relaxed), 1);
+        });
+    }
+
+    #[test]
+    fn value_is_dropped_if_spawn_fails() {
+        loom::model(|| {
+            let handoff = Handoff::new();
+            let sync = LoomSync::new();
+            let drops = Arc::new(AtomicUsize::new(0));
+            let payload = Payload {
+                value: 42,
+                drops: drops.clone(),
+            };
+
+            let result: Result<(), ()> = handoff.send(&sync, payload, || Err(()));
+
+            assert_eq!(result, Err(()));
+            assert_eq!(drops.load(Ordering::Relaxed), 1);
+        });
+    }
+}
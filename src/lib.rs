@@ -6,16 +6,44 @@
 //! and [OSAL](https://github.com/nasa/osal), the libraries used by
 //! [Core Flight System](https://cfs.gsfc.nasa.gov/) applications.
 
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 #![warn(missing_docs)]
 
+#[cfg(loom)]
+extern crate loom;
 extern crate printf_wrap;
-extern crate psm;
+#[cfg(feature = "serde")]
+extern crate serde;
 
 pub mod sys;
 
 pub mod cfe;
+pub mod config_limits;
+pub mod error;
+pub mod ffi_errors;
+pub mod msgids;
 pub mod osal;
+pub mod perfids;
+pub mod prelude;
+pub mod time_source;
 pub mod utils;
 
+#[cfg(feature = "mock")]
+pub mod mock;
+
+#[cfg(feature = "ut-stubs")]
+pub mod ut;
+
+#[cfg(feature = "async")]
+pub mod asynch;
+
+#[cfg(all(feature = "mock", feature = "ut-stubs"))]
+compile_error!("features `mock` and `ut-stubs` provide conflicting cFE/OSAL implementations");
+
+pub(crate) mod handoff;
+mod markers;
 pub(crate) mod sealed_traits;
+pub(crate) mod shims;
+
+#[doc(inline)]
+pub use error::Error;
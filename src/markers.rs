@@ -0,0 +1,73 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Compile-time checks that handle types are (or aren't) [`Send`] and [`Sync`]
+//! as intended, so that a change in a type's fields can't silently change them.
+//!
+//! See the types' `Send` and `Sync` impls (or marker fields) for the reasoning behind each.
+
+use crate::cfe::evs::EventSender;
+use crate::cfe::sb::Pipe;
+use crate::cfe::tbl::{SyncTblHandle, TblHandle};
+use crate::osal::file::{File, OwnedFile};
+use crate::osal::queue::{OwnedQueue, Queue};
+use crate::osal::socket::{Connected, Datagram, IPv4, Socket, Stream};
+use crate::osal::sync::{BinSem, CountSem, MutSem, OwnedBinSem, OwnedCountSem, OwnedMutSem};
+use crate::osal::task::{OwnedTask, Task};
+use crate::osal::timer::{OwnedTimer, Timer};
+
+/// Fails to compile unless `$t` implements all the listed traits.
+macro_rules! assert_impl {
+    ($t:ty: $($tr:path),+) => {
+        const _: fn() = || {
+            fn check<T: ?Sized $(+ $tr)+>() {}
+            check::<$t>();
+        };
+    };
+}
+
+/// Fails to compile if `$t` implements `$tr`.
+macro_rules! assert_not_impl {
+    ($t:ty: $tr:path) => {
+        const _: fn() = || {
+            // If `$t: $tr`, both impls apply, and the call below is ambiguous.
+            trait AmbiguousIfImpl<A> {
+                fn some_item() {}
+            }
+            impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+
+            struct Invalid;
+            impl<T: ?Sized + $tr> AmbiguousIfImpl<Invalid> for T {}
+
+            let _ = <$t as AmbiguousIfImpl<_>>::some_item;
+        };
+    };
+}
+
+assert_impl!(BinSem: Send, Sync);
+assert_impl!(CountSem: Send, Sync);
+assert_impl!(MutSem: Send, Sync);
+assert_impl!(OwnedBinSem: Send, Sync);
+assert_impl!(OwnedCountSem: Send, Sync);
+assert_impl!(OwnedMutSem: Send, Sync);
+
+assert_impl!(File: Send, Sync);
+assert_impl!(OwnedFile: Send, Sync);
+
+assert_impl!(Queue<u32>: Send, Sync);
+assert_impl!(OwnedQueue<u32>: Send, Sync);
+assert_impl!(Task: Send, Sync);
+assert_impl!(OwnedTask: Send, Sync);
+assert_impl!(Timer: Send, Sync);
+assert_impl!(OwnedTimer: Send, Sync);
+
+assert_impl!(Socket<IPv4, Stream, Connected>: Send);
+assert_impl!(Socket<IPv4, Datagram, Connected>: Send);
+assert_not_impl!(Socket<IPv4, Stream, Connected>: Sync);
+
+assert_impl!(TblHandle<u32>: Send, Sync);
+assert_impl!(SyncTblHandle<u32>: Send, Sync);
+assert_impl!(EventSender: Send, Sync);
+
+assert_not_impl!(Pipe: Send);
+assert_not_impl!(Pipe: Sync);
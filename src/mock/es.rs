@@ -0,0 +1,527 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! The application's lifecycle, the system log, the critical data store,
+//! and (pretend) loaded libraries.
+
+use super::{copy_to_c_array, lock, string_from};
+use crate::sys::*;
+
+use core::ffi::{c_char, c_void};
+use std::string::String;
+use std::sync::Mutex;
+use std::vec::Vec;
+
+/// The application ID the fake reports for the (single) application under test.
+pub(super) const APP_ID: CFE_ES_AppId_t = 0x0001_0001;
+
+/// The name of the application under test, as used in (e.g.) full table names.
+pub(super) const APP_NAME: &str = "MOCK_APP";
+
+/// The base value for CDS handles handed out by the fake.
+const CDS_BASE: CFE_ES_CDSHandle_t = 0x0009_0000;
+
+/// The base value for library IDs handed out by the fake.
+const LIB_BASE: CFE_ES_LibId_t = 0x0002_0000;
+
+struct CdsBlock {
+    name: String,
+    data: Vec<u8>,
+}
+
+/// An application other than the one under test.
+struct OtherApp {
+    name: String,
+    execution_counter: u32,
+}
+
+struct EsState {
+    exit_requested: bool,
+    exit_status:    Option<u32>,
+    syslog:         Vec<String>,
+    cds:            Vec<CdsBlock>,
+    libs:           Vec<String>,
+    apps:           Vec<OtherApp>,
+}
+
+static STATE: Mutex<EsState> = Mutex::new(EsState {
+    exit_requested: false,
+    exit_status:    None,
+    syslog:         Vec::new(),
+    cds:            Vec::new(),
+    libs:           Vec::new(),
+    apps:           Vec::new(),
+});
+
+pub(super) fn reset() {
+    let mut state = lock(&STATE);
+    state.exit_requested = false;
+    state.exit_status = None;
+    for request in crate::cfe::es::REQUESTED_EXITS.iter() {
+        request.clear();
+    }
+    state.syslog.clear();
+    state.cds.clear();
+    state.libs.clear();
+    state.apps.clear();
+}
+
+/// Makes the fake report that a library named `name` is loaded
+/// (if it doesn't already).
+pub fn load_lib(name: &str) {
+    let mut state = lock(&STATE);
+    if !state.libs.iter().any(|lib| lib == name) {
+        state.libs.push(String::from(name));
+    }
+}
+
+/// Makes the fake report that an application named `name` is loaded
+/// (if it doesn't already), with the given execution counter;
+/// a counter of 0 means it hasn't finished initializing.
+pub fn load_app(name: &str, execution_counter: u32) {
+    let mut state = lock(&STATE);
+    match state.apps.iter_mut().find(|app| app.name == name) {
+        Some(app) => app.execution_counter = execution_counter,
+        None => state.apps.push(OtherApp {
+            name: String::from(name),
+            execution_counter,
+        }),
+    }
+}
+
+/// Makes subsequent calls to [`run_loop`](crate::cfe::es::run_loop) return `false`.
+pub fn request_exit() {
+    lock(&STATE).exit_requested = true;
+}
+
+/// Returns the status passed to [`exit_app`](crate::cfe::es::exit_app), if it was called.
+///
+/// Note that with the fake, `exit_app` panics after recording the status,
+/// as there's no way to stop the calling task.
+pub fn exit_status() -> Option<u32> {
+    lock(&STATE).exit_status
+}
+
+/// Returns all system-log messages written since the last call to `take_syslog`
+/// (or [`reset`](super::reset)), and forgets them.
+pub fn take_syslog() -> Vec<String> {
+    core::mem::take(&mut lock(&STATE).syslog)
+}
+
+/// Called by the fake's C implementation of `CFE_ES_WriteToSysLog`,
+/// once it has formatted the message.
+#[no_mangle]
+unsafe extern "C" fn MOCK_CFE_ES_CaptureSysLog(Message: *const c_char) -> CFE_Status_t {
+    lock(&STATE).syslog.push(string_from(Message));
+    S_CFE_SUCCESS
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_GetAppID(AppIdPtr: *mut CFE_ES_AppId_t) -> CFE_Status_t {
+    if AppIdPtr.is_null() {
+        return S_CFE_ES_BAD_ARGUMENT;
+    }
+    *AppIdPtr = APP_ID;
+    S_CFE_SUCCESS
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_GetAppIDByName(
+    AppIdPtr: *mut CFE_ES_AppId_t,
+    AppName: *const c_char,
+) -> CFE_Status_t {
+    if AppIdPtr.is_null() || AppName.is_null() {
+        return S_CFE_ES_BAD_ARGUMENT;
+    }
+
+    let name = string_from(AppName);
+    if name == APP_NAME {
+        *AppIdPtr = APP_ID;
+        return S_CFE_SUCCESS;
+    }
+    match lock(&STATE).apps.iter().position(|app| app.name == name) {
+        Some(i) => {
+            *AppIdPtr = APP_ID + 1 + i as CFE_ES_AppId_t;
+            S_CFE_SUCCESS
+        }
+        None => S_CFE_ES_ERR_NAME_NOT_FOUND,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_GetAppInfo(
+    AppInfo: *mut CFE_ES_AppInfo_t,
+    AppId: CFE_ES_AppId_t,
+) -> CFE_Status_t {
+    if AppInfo.is_null() {
+        return S_CFE_ES_BAD_ARGUMENT;
+    }
+
+    let state = lock(&STATE);
+    let (name, execution_counter) = if AppId == APP_ID {
+        (APP_NAME, 1)
+    } else {
+        let i = match AppId.checked_sub(APP_ID + 1) {
+            Some(i) => i as usize,
+            None => return S_CFE_ES_ERR_RESOURCEID_NOT_VALID,
+        };
+        match state.apps.get(i) {
+            Some(app) => (app.name.as_str(), app.execution_counter),
+            None => return S_CFE_ES_ERR_RESOURCEID_NOT_VALID,
+        }
+    };
+
+    let info = &mut *AppInfo;
+    info.ResourceId = AppId;
+    copy_to_c_array(&mut info.Name, name);
+    copy_to_c_array(&mut info.EntryPoint, &format!("{}_Main", name));
+    copy_to_c_array(&mut info.FileName, &format!("/cf/{}.so", name.to_lowercase()));
+    copy_to_c_array(&mut info.MainTaskName, name);
+    info.Type = CFE_ES_AppType_CFE_ES_AppType_EXTERNAL;
+    info.ExceptionAction = CFE_ES_ExceptionAction_CFE_ES_ExceptionAction_RESTART_APP as u8;
+    info.AddressesAreValid = 0;
+    info.ExecutionCounter = execution_counter;
+    S_CFE_SUCCESS
+}
+
+/// The number of bits of a resource ID holding its serial number
+/// (the rest hold the base value for its type).
+const RESOURCEID_SHIFT: u32 = 16;
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ResourceId_ToIndex(
+    Id: CFE_ResourceId_t,
+    BaseValue: uint32,
+    TableSize: uint32,
+    Idx: *mut uint32,
+) -> int32 {
+    if Idx.is_null() {
+        return S_CFE_ES_BAD_ARGUMENT;
+    }
+    if Id >> RESOURCEID_SHIFT != BaseValue >> RESOURCEID_SHIFT || TableSize == 0 {
+        return S_CFE_ES_ERR_RESOURCEID_NOT_VALID;
+    }
+
+    *Idx = (Id & ((1 << RESOURCEID_SHIFT) - 1)) % TableSize;
+    S_CFE_SUCCESS
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_AppID_ToIndex(AppID: CFE_ES_AppId_t, Idx: *mut uint32) -> CFE_Status_t {
+    CFE_ResourceId_ToIndex(AppID, APP_ID, CFE_PLATFORM_ES_MAX_APPLICATIONS, Idx)
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_TaskID_ToIndex(
+    TaskID: CFE_ES_TaskId_t,
+    Idx: *mut uint32,
+) -> CFE_Status_t {
+    if Idx.is_null() {
+        return S_CFE_ES_BAD_ARGUMENT;
+    }
+
+    // As in cFE, task IDs are OSAL task IDs in disguise:
+    let mut index: osal_index_t = 0;
+    if OS_ConvertToArrayIndex(TaskID as osal_id_t, &mut index) != OS_SUCCESS as int32 {
+        return S_CFE_ES_ERR_RESOURCEID_NOT_VALID;
+    }
+
+    *Idx = index as uint32;
+    S_CFE_SUCCESS
+}
+
+/// Returns the name of the library with ID `lib_id`, if there is one.
+fn lib_name(state: &EsState, lib_id: CFE_ES_LibId_t) -> Option<&str> {
+    let i = lib_id.checked_sub(LIB_BASE)? as usize;
+    state.libs.get(i).map(String::as_str)
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_GetLibIDByName(
+    LibIdPtr: *mut CFE_ES_LibId_t,
+    LibName: *const c_char,
+) -> CFE_Status_t {
+    if LibIdPtr.is_null() || LibName.is_null() {
+        return S_CFE_ES_BAD_ARGUMENT;
+    }
+
+    let name = string_from(LibName);
+    match lock(&STATE).libs.iter().position(|lib| *lib == name) {
+        Some(i) => {
+            *LibIdPtr = LIB_BASE + i as CFE_ES_LibId_t;
+            S_CFE_SUCCESS
+        }
+        None => S_CFE_ES_ERR_NAME_NOT_FOUND,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_GetLibName(
+    LibName: *mut c_char,
+    LibId: CFE_ES_LibId_t,
+    BufferLength: usize,
+) -> CFE_Status_t {
+    if LibName.is_null() || BufferLength == 0 {
+        return S_CFE_ES_BAD_ARGUMENT;
+    }
+
+    let state = lock(&STATE);
+    match lib_name(&state, LibId) {
+        Some(name) => {
+            copy_to_c_array(core::slice::from_raw_parts_mut(LibName, BufferLength), name);
+            S_CFE_SUCCESS
+        }
+        None => S_CFE_ES_ERR_RESOURCEID_NOT_VALID,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_GetLibInfo(
+    LibInfo: *mut CFE_ES_AppInfo_t,
+    LibId: CFE_ES_LibId_t,
+) -> int32 {
+    if LibInfo.is_null() {
+        return S_CFE_ES_BAD_ARGUMENT;
+    }
+
+    let state = lock(&STATE);
+    match lib_name(&state, LibId) {
+        Some(name) => {
+            let info = &mut *LibInfo;
+            info.ResourceId = LibId;
+            copy_to_c_array(&mut info.Name, name);
+            copy_to_c_array(&mut info.EntryPoint, &format!("{}_Init", name));
+            copy_to_c_array(&mut info.FileName, &format!("/cf/{}.so", name.to_lowercase()));
+            info.AddressesAreValid = 0;
+            S_CFE_SUCCESS
+        }
+        None => S_CFE_ES_ERR_RESOURCEID_NOT_VALID,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_LibID_ToIndex(LibId: CFE_ES_LibId_t, Idx: *mut uint32) -> int32 {
+    CFE_ResourceId_ToIndex(LibId, LIB_BASE, CFE_PLATFORM_ES_MAX_LIBRARIES, Idx)
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_GetAppName(
+    AppName: *mut c_char,
+    AppId: CFE_ES_AppId_t,
+    BufferLength: usize,
+) -> CFE_Status_t {
+    if AppName.is_null() || BufferLength == 0 {
+        return S_CFE_ES_BAD_ARGUMENT;
+    }
+    if AppId != APP_ID {
+        return S_CFE_ES_ERR_RESOURCEID_NOT_VALID;
+    }
+
+    copy_to_c_array(core::slice::from_raw_parts_mut(AppName, BufferLength), APP_NAME);
+    S_CFE_SUCCESS
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_RunLoop(RunStatus: *mut uint32) -> bool {
+    let mut state = lock(&STATE);
+    if !RunStatus.is_null() && *RunStatus != CFE_ES_RunStatus_CFE_ES_RunStatus_APP_RUN {
+        state.exit_requested = true;
+    }
+    !state.exit_requested
+}
+
+#[no_mangle]
+extern "C" fn CFE_ES_ExitApp(ExitStatus: uint32) {
+    let mut state = lock(&STATE);
+    state.exit_status = Some(ExitStatus);
+    state.exit_requested = true;
+}
+
+#[no_mangle]
+extern "C" fn CFE_ES_IncrementTaskCounter() {}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_CalculateCRC(
+    DataPtr: *const c_void,
+    DataLength: usize,
+    InputCRC: uint32,
+    TypeCRC: uint32,
+) -> uint32 {
+    if TypeCRC != CFE_MISSION_ES_CRC_16 || DataPtr.is_null() {
+        return 0;
+    }
+
+    // CRC-16/ARC, as cFE computes it (there with a lookup table):
+    let mut crc = InputCRC as u16;
+    for &byte in core::slice::from_raw_parts(DataPtr as *const u8, DataLength) {
+        crc ^= byte as u16;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 { (crc >> 1) ^ 0xA001 } else { crc >> 1 };
+        }
+    }
+    crc as uint32
+}
+
+#[no_mangle]
+extern "C" fn CFE_ES_PerfLogAdd(_Marker: uint32, _EntryExit: uint32) {}
+
+#[no_mangle]
+extern "C" fn CFE_ES_WaitForSystemState(
+    _MinSystemState: uint32,
+    _TimeOutMilliseconds: uint32,
+) -> CFE_Status_t {
+    S_CFE_SUCCESS
+}
+
+#[no_mangle]
+extern "C" fn CFE_ES_ResetCFE(_ResetType: uint32) -> CFE_Status_t {
+    S_CFE_ES_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn CFE_ES_DeleteApp(_AppID: CFE_ES_AppId_t) -> CFE_Status_t {
+    S_CFE_ES_NOT_IMPLEMENTED
+}
+
+/// Records a restart or reload of the application under test:
+/// as in cFE, the request is logged, and [`run_loop`](crate::cfe::es::run_loop)
+/// then reports that the application should exit.
+fn request_restart(app_id: CFE_ES_AppId_t, what: &str) -> CFE_Status_t {
+    if app_id != APP_ID {
+        return S_CFE_ES_ERR_RESOURCEID_NOT_VALID;
+    }
+
+    let mut state = lock(&STATE);
+    state.syslog.push(format!("{} Application {} Initiated\n", what, APP_NAME));
+    state.exit_requested = true;
+    S_CFE_SUCCESS
+}
+
+#[no_mangle]
+extern "C" fn CFE_ES_RestartApp(AppID: CFE_ES_AppId_t) -> CFE_Status_t {
+    request_restart(AppID, "Restart")
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_ReloadApp(
+    AppID: CFE_ES_AppId_t,
+    AppFileName: *const c_char,
+) -> CFE_Status_t {
+    if AppFileName.is_null() {
+        return S_CFE_ES_BAD_ARGUMENT;
+    }
+
+    request_restart(AppID, "Reload")
+}
+
+#[no_mangle]
+extern "C" fn CFE_ES_CreateChildTask(
+    _TaskIdPtr: *mut CFE_ES_TaskId_t,
+    _TaskName: *const c_char,
+    _FunctionPtr: CFE_ES_ChildTaskMainFuncPtr_t,
+    _StackPtr: CFE_ES_StackPointer_t,
+    _StackSize: usize,
+    _Priority: CFE_ES_TaskPriority_Atom_t,
+    _Flags: uint32,
+) -> CFE_Status_t {
+    S_CFE_ES_ERR_CHILD_TASK_CREATE
+}
+
+#[no_mangle]
+extern "C" fn CFE_ES_ExitChildTask() {}
+
+fn cds_index(handle: CFE_ES_CDSHandle_t) -> Option<usize> {
+    handle.checked_sub(CDS_BASE + 1).map(|i| i as usize)
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_RegisterCDS(
+    CDSHandlePtr: *mut CFE_ES_CDSHandle_t,
+    BlockSize: usize,
+    Name: *const c_char,
+) -> CFE_Status_t {
+    if CDSHandlePtr.is_null() || Name.is_null() {
+        return S_CFE_ES_BAD_ARGUMENT;
+    }
+    if BlockSize == 0 {
+        return S_CFE_ES_CDS_INVALID_SIZE;
+    }
+
+    let name = string_from(Name);
+    if name.is_empty() || name.len() >= CFE_MISSION_ES_CDS_MAX_FULL_NAME_LEN as usize {
+        return S_CFE_ES_CDS_INVALID_NAME;
+    }
+
+    let mut state = lock(&STATE);
+    match state.cds.iter().position(|b| b.name == name) {
+        Some(i) if state.cds[i].data.len() == BlockSize => {
+            *CDSHandlePtr = CDS_BASE + 1 + i as u32;
+            S_CFE_ES_CDS_ALREADY_EXISTS
+        }
+        Some(i) => {
+            state.cds[i].data = std::vec![0; BlockSize];
+            *CDSHandlePtr = CDS_BASE + 1 + i as u32;
+            S_CFE_SUCCESS
+        }
+        None => {
+            state.cds.push(CdsBlock {
+                name,
+                data: std::vec![0; BlockSize],
+            });
+            *CDSHandlePtr = CDS_BASE + state.cds.len() as u32;
+            S_CFE_SUCCESS
+        }
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_CopyToCDS(
+    Handle: CFE_ES_CDSHandle_t,
+    DataToCopy: *const c_void,
+) -> CFE_Status_t {
+    let mut state = lock(&STATE);
+    match cds_index(Handle).and_then(|i| state.cds.get_mut(i)) {
+        Some(block) if !DataToCopy.is_null() => {
+            let len = block.data.len();
+            block.data.copy_from_slice(core::slice::from_raw_parts(DataToCopy as *const u8, len));
+            S_CFE_SUCCESS
+        }
+        Some(_) => S_CFE_ES_BAD_ARGUMENT,
+        None => S_CFE_ES_ERR_RESOURCEID_NOT_VALID,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_RestoreFromCDS(
+    RestoreToMemory: *mut c_void,
+    Handle: CFE_ES_CDSHandle_t,
+) -> CFE_Status_t {
+    let state = lock(&STATE);
+    match cds_index(Handle).and_then(|i| state.cds.get(i)) {
+        Some(block) if !RestoreToMemory.is_null() => {
+            let dst = core::slice::from_raw_parts_mut(RestoreToMemory as *mut u8, block.data.len());
+            dst.copy_from_slice(&block.data);
+            S_CFE_SUCCESS
+        }
+        Some(_) => S_CFE_ES_BAD_ARGUMENT,
+        None => S_CFE_ES_ERR_RESOURCEID_NOT_VALID,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_ES_GetCDSBlockName(
+    BlockName: *mut c_char,
+    BlockId: CFE_ES_CDSHandle_t,
+    BufferLength: usize,
+) -> CFE_Status_t {
+    let state = lock(&STATE);
+    match cds_index(BlockId).and_then(|i| state.cds.get(i)) {
+        Some(block) if !BlockName.is_null() => {
+            let dst = core::slice::from_raw_parts_mut(BlockName, BufferLength);
+            copy_to_c_array(dst, &block.name);
+            S_CFE_SUCCESS
+        }
+        Some(_) => S_CFE_ES_BAD_ARGUMENT,
+        None => S_CFE_ES_ERR_RESOURCEID_NOT_VALID,
+    }
+}
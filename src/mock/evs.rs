@@ -0,0 +1,99 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Capture of sent events.
+
+use super::{lock, string_from};
+use crate::cfe::es::AppId;
+use crate::cfe::time::SysTime;
+use crate::sys::*;
+
+use core::ffi::{c_char, c_void};
+use std::string::String;
+use std::sync::Mutex;
+use std::vec::Vec;
+
+/// An event sent through the fake's event service.
+#[derive(Clone, Debug)]
+pub struct Event {
+    /// The event ID.
+    pub event_id: u16,
+
+    /// The event type, as a raw `CFE_EVS_EventType` value.
+    pub event_type: u16,
+
+    /// The application the event was sent on behalf of.
+    pub app_id: AppId,
+
+    /// The time given for the event, if it was sent as a timed event.
+    pub time: Option<SysTime>,
+
+    /// The (fully formatted) event message.
+    pub message: String,
+}
+
+struct EvsState {
+    registered: bool,
+    events:     Vec<Event>,
+}
+
+static STATE: Mutex<EvsState> = Mutex::new(EvsState {
+    registered: false,
+    events:     Vec::new(),
+});
+
+pub(super) fn reset() {
+    let mut state = lock(&STATE);
+    state.registered = false;
+    state.events.clear();
+    crate::cfe::evs::reset_registration();
+}
+
+/// Returns all events captured since the last call to `take_events` (or [`reset`](super::reset)),
+/// and forgets them.
+pub fn take_events() -> Vec<Event> {
+    core::mem::take(&mut lock(&STATE).events)
+}
+
+/// Returns whether the application has registered with the event service.
+pub fn is_registered() -> bool {
+    lock(&STATE).registered
+}
+
+#[no_mangle]
+extern "C" fn CFE_EVS_Register(
+    _Filters: *const c_void,
+    _NumEventFilters: uint16,
+    _FilterScheme: uint16,
+) -> CFE_Status_t {
+    lock(&STATE).registered = true;
+    S_CFE_SUCCESS
+}
+
+/// Called by the fake's C implementations of the `CFE_EVS_Send*Event*` functions,
+/// once they have formatted the event message.
+#[no_mangle]
+unsafe extern "C" fn MOCK_CFE_EVS_CaptureEvent(
+    EventID: uint16,
+    EventType: uint16,
+    AppID: CFE_ES_AppId_t,
+    Time: *const CFE_TIME_SysTime_t,
+    Message: *const c_char,
+) -> CFE_Status_t {
+    let mut state = lock(&STATE);
+    if !state.registered {
+        return S_CFE_EVS_APP_NOT_REGISTERED;
+    }
+
+    let app_id = if AppID == X_CFE_ES_APPID_UNDEFINED { super::es::APP_ID } else { AppID };
+
+    state.events.push(Event {
+        event_id:   EventID,
+        event_type: EventType,
+        app_id:     AppId { id: app_id },
+        time:       Time.as_ref().map(|&tm| SysTime { tm }),
+        message:    string_from(Message),
+    });
+
+    S_CFE_SUCCESS
+}
@@ -0,0 +1,149 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! cFE file headers.
+//!
+//! Headers are written to and read from the fake's in-memory filesystem
+//! in the same big-endian layout cFE uses,
+//! with spacecraft ID [`SPACECRAFT_ID`] and processor ID [`PROCESSOR_ID`].
+
+use crate::sys::*;
+use core::ffi::{c_char, c_void};
+
+/// The spacecraft ID the fake writes into file headers.
+pub const SPACECRAFT_ID: u32 = 0x42;
+
+/// The processor ID the fake writes into file headers.
+pub const PROCESSOR_ID: u32 = 1;
+
+/// The size of a file header, in bytes.
+const HDR_SIZE: usize = core::mem::size_of::<CFE_FS_Header_t>();
+
+/// The offset of the time fields within a file header.
+const TIME_OFFSET: i32 = 24;
+
+/// "cFE1", the content type of cFE files.
+const CONTENT_TYPE: u32 = 0x6346_4531;
+
+fn encode(hdr: &CFE_FS_Header_t) -> [u8; HDR_SIZE] {
+    let mut bytes = [0u8; HDR_SIZE];
+    let fields = [
+        hdr.ContentType,
+        hdr.SubType,
+        hdr.Length,
+        hdr.SpacecraftID,
+        hdr.ProcessorID,
+        hdr.ApplicationID,
+        hdr.TimeSeconds,
+        hdr.TimeSubSeconds,
+    ];
+    for (i, field) in fields.iter().enumerate() {
+        bytes[i * 4..i * 4 + 4].copy_from_slice(&field.to_be_bytes());
+    }
+    for (b, &c) in bytes[32..].iter_mut().zip(hdr.Description.iter()) {
+        *b = c as u8;
+    }
+    bytes
+}
+
+fn decode(bytes: &[u8; HDR_SIZE], hdr: &mut CFE_FS_Header_t) {
+    let field = |i: usize| {
+        u32::from_be_bytes([bytes[i * 4], bytes[i * 4 + 1], bytes[i * 4 + 2], bytes[i * 4 + 3]])
+    };
+    hdr.ContentType = field(0);
+    hdr.SubType = field(1);
+    hdr.Length = field(2);
+    hdr.SpacecraftID = field(3);
+    hdr.ProcessorID = field(4);
+    hdr.ApplicationID = field(5);
+    hdr.TimeSeconds = field(6);
+    hdr.TimeSubSeconds = field(7);
+    for (c, &b) in hdr.Description.iter_mut().zip(bytes[32..].iter()) {
+        *c = b as c_char;
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_FS_InitHeader(
+    Hdr: *mut CFE_FS_Header_t,
+    Description: *const c_char,
+    SubType: uint32,
+) {
+    if Hdr.is_null() {
+        return;
+    }
+    let hdr = &mut *Hdr;
+    *hdr = core::mem::zeroed();
+    hdr.SubType = SubType;
+
+    let desc = super::string_from(Description);
+    let max = hdr.Description.len() - 1;
+    for (c, &b) in hdr.Description.iter_mut().zip(desc.as_bytes().iter().take(max)) {
+        *c = b as c_char;
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_FS_WriteHeader(
+    FileDes: osal_id_t,
+    Hdr: *mut CFE_FS_Header_t,
+) -> CFE_Status_t {
+    if Hdr.is_null() {
+        return S_CFE_FS_BAD_ARGUMENT;
+    }
+    let hdr = &mut *Hdr;
+    let now = super::time::now();
+    hdr.ContentType = CONTENT_TYPE;
+    hdr.Length = HDR_SIZE as u32;
+    hdr.SpacecraftID = SPACECRAFT_ID;
+    hdr.ProcessorID = PROCESSOR_ID;
+    hdr.ApplicationID = super::es::APP_ID;
+    hdr.TimeSeconds = now.seconds();
+    hdr.TimeSubSeconds = now.subseconds();
+
+    if OS_lseek(FileDes, 0, OS_SEEK_SET) < 0 {
+        return S_CFE_STATUS_EXTERNAL_RESOURCE_FAIL;
+    }
+    let bytes = encode(hdr);
+    OS_write(FileDes, bytes.as_ptr() as *const c_void, HDR_SIZE)
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_FS_ReadHeader(
+    Hdr: *mut CFE_FS_Header_t,
+    FileDes: osal_id_t,
+) -> CFE_Status_t {
+    if Hdr.is_null() {
+        return S_CFE_FS_BAD_ARGUMENT;
+    }
+
+    if OS_lseek(FileDes, 0, OS_SEEK_SET) < 0 {
+        return S_CFE_STATUS_EXTERNAL_RESOURCE_FAIL;
+    }
+    let mut bytes = [0u8; HDR_SIZE];
+    let status = OS_read(FileDes, bytes.as_mut_ptr() as *mut c_void, HDR_SIZE);
+    if status == HDR_SIZE as int32 {
+        decode(&bytes, &mut *Hdr);
+    }
+    status
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_FS_SetTimestamp(
+    FileDes: osal_id_t,
+    NewTimestamp: CFE_TIME_SysTime_t,
+) -> CFE_Status_t {
+    let status = OS_lseek(FileDes, TIME_OFFSET, OS_SEEK_SET);
+    if status < 0 {
+        return S_CFE_FS_BAD_ARGUMENT;
+    }
+
+    let mut bytes = [0u8; 8];
+    bytes[..4].copy_from_slice(&NewTimestamp.Seconds.to_be_bytes());
+    bytes[4..].copy_from_slice(&NewTimestamp.Subseconds.to_be_bytes());
+    if OS_write(FileDes, bytes.as_ptr() as *const c_void, bytes.len()) == bytes.len() as int32 {
+        S_CFE_SUCCESS
+    } else {
+        S_CFE_FS_BAD_ARGUMENT
+    }
+}
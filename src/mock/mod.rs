@@ -0,0 +1,111 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! A host-side fake of the cFE and OSAL APIs, for unit-testing applications.
+//!
+//! When the `mock` feature is enabled, this module provides Rust implementations
+//! of the cFE and OSAL functions that `n2o4` calls,
+//! so that code built on `n2o4` can be linked and run
+//! (e.g., with `cargo test`) on a workstation, without a cFS build.
+//! The cFE and OSAL header files (and `libclang`, unless pregenerated bindings are supplied)
+//! are still needed at build time, as for any other build (see `USING.md`),
+//! but none of their libraries are.
+//!
+//! The fake is deliberately simple:
+//!
+//! * the software bus routes messages between in-memory pipes
+//!   (see [`sb`]);
+//! * events and system-log messages are captured for later inspection
+//!   (see [`evs`] and [`es`]);
+//! * time is virtual, and only advances when told to
+//!   (see [`time`]);
+//! * tables are held in memory and may be loaded from addresses
+//!   (see [`tbl`]);
+//! * files live in an in-memory filesystem (see [`osal`]);
+//! * the watchdog timer only records how it's been used,
+//!   and the PSP memory table covers only ranges added to it (see [`psp`]).
+//!
+//! Functionality without a fake
+//! (e.g., sockets and child tasks)
+//! returns a "not implemented" error.
+//!
+//! All state is global, so tests that use the fake should hold the guard
+//! returned by [`exclusive`] for their whole duration;
+//! this serializes such tests and starts each one from a clean slate.
+
+#![allow(non_snake_case)]
+#![allow(non_upper_case_globals)]
+
+use core::ffi::c_char;
+use std::ffi::CStr;
+use std::string::String;
+use std::sync::{Mutex, MutexGuard};
+
+pub mod es;
+pub mod evs;
+pub mod fs;
+pub mod osal;
+pub mod psp;
+pub mod sb;
+pub mod tbl;
+pub mod time;
+
+static EXCLUSIVE: Mutex<()> = Mutex::new(());
+
+/// Resets all of the fake's state
+/// (software bus, captured events, tables, virtual time, filesystem,
+/// [discarded-error counters](crate::ffi_errors), etc.)
+/// to its initial, empty condition.
+pub fn reset() {
+    es::reset();
+    evs::reset();
+    osal::reset();
+    psp::reset();
+    sb::reset();
+    tbl::reset();
+    time::reset();
+
+    #[cfg(feature = "count-ffi-errors")]
+    crate::ffi_errors::reset();
+}
+
+/// Waits until no other caller holds the returned guard,
+/// then [`reset`]s the fake's state.
+///
+/// Tests should hold the guard for as long as they use the fake.
+pub fn exclusive() -> MutexGuard<'static, ()> {
+    let guard = EXCLUSIVE.lock().unwrap_or_else(|e| e.into_inner());
+    reset();
+    guard
+}
+
+/// Locks `m`, ignoring poisoning (a panicking test shouldn't take down the others).
+fn lock<T>(m: &Mutex<T>) -> MutexGuard<'_, T> {
+    m.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Copies the null-terminated string at `s` into a [`String`];
+/// a null `s` produces an empty string.
+///
+/// # Safety
+///
+/// `s` must be null or point to a null-terminated string.
+unsafe fn string_from(s: *const c_char) -> String {
+    if s.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(s).to_string_lossy().into_owned()
+    }
+}
+
+/// Copies `src` into the C character array `dst`, truncating and null-terminating as needed.
+fn copy_to_c_array(dst: &mut [c_char], src: &str) {
+    if dst.is_empty() {
+        return;
+    }
+    let len = src.len().min(dst.len() - 1);
+    for (d, &b) in dst.iter_mut().zip(&src.as_bytes()[..len]) {
+        *d = b as c_char;
+    }
+    dst[len] = b'\0' as c_char;
+}
@@ -0,0 +1,1295 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! OSAL objects and the in-memory filesystem.
+//!
+//! Files live in a flat map from (virtual) path to contents,
+//! on a single volume of 1 MiB (which `OS_FileSysStatVolume` reports for any mount point);
+//! path translation is the identity.
+//! Directories exist implicitly, wherever there's a file below them.
+//!
+//! Semaphores are simple counters: as the fake is single-threaded at heart,
+//! an attempt to take an unavailable semaphore fails with `OS_SEM_FAILURE`
+//! rather than blocking forever,
+//! and a timed wait on one advances the virtual time by the timeout,
+//! then fails with `OS_SEM_TIMEOUT`.
+//! Queues behave likewise when empty,
+//! failing with `OS_QUEUE_EMPTY` or `OS_QUEUE_TIMEOUT`.
+//!
+//! Timers run off the virtual time:
+//! their callbacks are called (on the calling task) whenever the virtual time
+//! is advanced past their expiration times.
+//! Time bases are placeholders, and can't be synchronized externally.
+//!
+//! There is a single task, the one running the application under test.
+//! Sockets are not implemented.
+
+use super::{copy_to_c_array, lock, string_from};
+use crate::sys::*;
+
+use core::ffi::{c_char, c_void};
+use std::collections::{BTreeMap, VecDeque};
+use std::string::String;
+use std::sync::Mutex;
+use std::vec::Vec;
+
+const SUCCESS: int32 = OS_SUCCESS as int32;
+
+/// The name of the application's (only) task.
+const TASK_NAME: &str = "MOCK_APP";
+
+/// The default priority of the application's task.
+const TASK_PRIORITY: osal_priority_t = 100;
+
+enum Kind {
+    Task { priority: osal_priority_t },
+    File { path: String, pos: usize, access: u32 },
+    Dir { entries: Vec<String>, pos: usize },
+    Queue { depth: usize, data_size: usize, items: VecDeque<Vec<u8>> },
+    BinSem { value: u32 },
+    CountSem { value: u32 },
+    Mutex { held: bool },
+    TimeBase,
+    Timer { callback: OS_ArgCallback_t, arg: usize, next_us: Option<u64>, interval: u64 },
+}
+
+impl Kind {
+    fn obj_type(&self) -> osal_objtype_t {
+        match self {
+            Kind::Task { .. } => OS_OBJECT_TYPE_OS_TASK,
+            Kind::File { .. } => OS_OBJECT_TYPE_OS_STREAM,
+            Kind::Dir { .. } => OS_OBJECT_TYPE_OS_DIR,
+            Kind::Queue { .. } => OS_OBJECT_TYPE_OS_QUEUE,
+            Kind::BinSem { .. } => OS_OBJECT_TYPE_OS_BINSEM,
+            Kind::CountSem { .. } => OS_OBJECT_TYPE_OS_COUNTSEM,
+            Kind::Mutex { .. } => OS_OBJECT_TYPE_OS_MUTEX,
+            Kind::TimeBase => OS_OBJECT_TYPE_OS_TIMEBASE,
+            Kind::Timer { .. } => OS_OBJECT_TYPE_OS_TIMECB,
+        }
+    }
+}
+
+struct Object {
+    name: String,
+    kind: Kind,
+}
+
+struct File {
+    data:  Vec<u8>,
+    mtime: int64,
+}
+
+struct OsalState {
+    objects: Vec<Option<Object>>,
+    files:   Option<BTreeMap<String, File>>,
+}
+
+static STATE: Mutex<OsalState> = Mutex::new(OsalState {
+    objects: Vec::new(),
+    files:   None,
+});
+
+/// Returns the filesystem's files, creating the (empty) map on first use.
+fn files(files: &mut Option<BTreeMap<String, File>>) -> &mut BTreeMap<String, File> {
+    files.get_or_insert_with(BTreeMap::new)
+}
+
+pub(super) fn reset() {
+    let mut state = lock(&STATE);
+    state.objects.clear();
+    state.files = None;
+    state.objects.push(Some(Object {
+        name: String::from(TASK_NAME),
+        kind: Kind::Task { priority: TASK_PRIORITY },
+    }));
+}
+
+/// Creates (or replaces) the file at `path` in the in-memory filesystem,
+/// with contents `contents`.
+pub fn write_file(path: &str, contents: &[u8]) {
+    files(&mut lock(&STATE).files).insert(
+        String::from(path),
+        File {
+            data:  contents.to_vec(),
+            mtime: mtime(),
+        },
+    );
+}
+
+/// Returns a copy of the contents of the file at `path` in the in-memory filesystem,
+/// if there is one.
+pub fn read_file(path: &str) -> Option<Vec<u8>> {
+    files(&mut lock(&STATE).files).get(path).map(|f| f.data.clone())
+}
+
+/// The current virtual time, in microseconds.
+fn now_micros() -> u64 {
+    let now = super::time::now().tm;
+    now.Seconds as u64 * 1_000_000 + ((now.Subseconds as u64 * 1_000_000) >> 32)
+}
+
+/// Calls the callbacks of all timers that have expired
+/// (once for each time they've expired).
+pub(super) fn fire_timers() {
+    let now = now_micros();
+    let mut due = Vec::new();
+
+    {
+        let mut state = lock(&STATE);
+        for (i, obj) in state.objects.iter_mut().enumerate() {
+            if let Some(Object {
+                kind:
+                    Kind::Timer {
+                        callback,
+                        arg,
+                        next_us,
+                        interval,
+                    },
+                ..
+            }) = obj
+            {
+                while let Some(next) = next_us.filter(|&next| next <= now) {
+                    due.push((*callback, id_of(OS_OBJECT_TYPE_OS_TIMECB, i), *arg));
+                    *next_us = if *interval > 0 { Some(next + *interval) } else { None };
+                }
+            }
+        }
+    }
+
+    for (callback, id, arg) in due {
+        if let Some(callback) = callback {
+            unsafe { callback(id, arg as *mut c_void) };
+        }
+    }
+}
+
+/// The current virtual time, in OSAL ticks.
+fn mtime() -> int64 {
+    let now = super::time::now().tm;
+    let micros = ((now.Subseconds as u64 * 1_000_000) >> 32) as int64;
+    now.Seconds as int64 * OS_TIME_TICKS_PER_SECOND as int64
+        + micros * (OS_TIME_TICKS_PER_SECOND / 1_000_000) as int64
+}
+
+fn id_of(obj_type: osal_objtype_t, index: usize) -> osal_id_t {
+    (obj_type << OS_OBJECT_TYPE_SHIFT) | index as osal_id_t
+}
+
+fn object_mut(
+    objects: &mut [Option<Object>],
+    id: osal_id_t,
+    obj_type: osal_objtype_t,
+) -> Option<&mut Object> {
+    if id >> OS_OBJECT_TYPE_SHIFT != obj_type {
+        return None;
+    }
+    objects.get_mut((id & OS_OBJECT_INDEX_MASK) as usize)?.as_mut()
+}
+
+impl OsalState {
+    fn get_mut(&mut self, id: osal_id_t, obj_type: osal_objtype_t) -> Option<&mut Object> {
+        object_mut(&mut self.objects, id, obj_type)
+    }
+
+    fn find(&self, name: &str, obj_type: osal_objtype_t) -> Option<osal_id_t> {
+        self.objects.iter().enumerate().find_map(|(i, obj)| match obj {
+            Some(obj) if obj.kind.obj_type() == obj_type && obj.name == name => {
+                Some(id_of(obj_type, i))
+            }
+            _ => None,
+        })
+    }
+
+    fn insert(&mut self, obj: Object) -> osal_id_t {
+        let obj_type = obj.kind.obj_type();
+        let i = match self.objects.iter().position(Option::is_none) {
+            Some(i) => {
+                self.objects[i] = Some(obj);
+                i
+            }
+            None => {
+                self.objects.push(Some(obj));
+                self.objects.len() - 1
+            }
+        };
+        id_of(obj_type, i)
+    }
+
+    fn remove(&mut self, id: osal_id_t, obj_type: osal_objtype_t) -> int32 {
+        if self.get_mut(id, obj_type).is_none() {
+            return OS_ERR_INVALID_ID;
+        }
+        self.objects[(id & OS_OBJECT_INDEX_MASK) as usize] = None;
+        SUCCESS
+    }
+}
+
+/// Creates a named object, after checking the name and its uniqueness.
+unsafe fn create(id_ptr: *mut osal_id_t, name: *const c_char, kind: Kind) -> int32 {
+    if id_ptr.is_null() || name.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    let name = string_from(name);
+    if name.len() >= OS_MAX_API_NAME as usize {
+        return OS_ERR_NAME_TOO_LONG;
+    }
+
+    let mut state = lock(&STATE);
+    if state.find(&name, kind.obj_type()).is_some() {
+        return OS_ERR_NAME_TAKEN;
+    }
+
+    *id_ptr = state.insert(Object { name, kind });
+    SUCCESS
+}
+
+unsafe fn get_id_by_name(
+    id_ptr: *mut osal_id_t,
+    name: *const c_char,
+    obj_type: osal_objtype_t,
+) -> int32 {
+    if id_ptr.is_null() || name.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    match lock(&STATE).find(&string_from(name), obj_type) {
+        Some(id) => {
+            *id_ptr = id;
+            SUCCESS
+        }
+        None => OS_ERR_NAME_NOT_FOUND,
+    }
+}
+
+/// Runs `f` on the object with ID `id` and type `obj_type`,
+/// returning `OS_ERR_INVALID_ID` if there is no such object.
+fn with_object(
+    id: osal_id_t,
+    obj_type: osal_objtype_t,
+    f: impl FnOnce(&mut Object) -> int32,
+) -> int32 {
+    match lock(&STATE).get_mut(id, obj_type) {
+        Some(obj) => f(obj),
+        None => OS_ERR_INVALID_ID,
+    }
+}
+
+#[no_mangle]
+extern "C" fn OS_IdentifyObject(object_id: osal_id_t) -> osal_objtype_t {
+    object_id >> OS_OBJECT_TYPE_SHIFT
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_ConvertToArrayIndex(
+    object_id: osal_id_t,
+    ArrayIndex: *mut osal_index_t,
+) -> int32 {
+    if ArrayIndex.is_null() {
+        return OS_INVALID_POINTER;
+    }
+    if object_id >> OS_OBJECT_TYPE_SHIFT == OS_OBJECT_TYPE_UNDEFINED {
+        return OS_ERR_INVALID_ID;
+    }
+
+    *ArrayIndex = (object_id & OS_OBJECT_INDEX_MASK) as osal_index_t;
+    SUCCESS
+}
+
+// Time:
+
+#[no_mangle]
+unsafe extern "C" fn OS_GetLocalTime(time_struct: *mut OS_time_t) -> int32 {
+    if time_struct.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    (*time_struct).ticks = mtime();
+    SUCCESS
+}
+
+// Time bases and timers:
+
+#[no_mangle]
+unsafe extern "C" fn OS_TimeBaseCreate(
+    timebase_id: *mut osal_id_t,
+    timebase_name: *const c_char,
+    _external_sync: OS_TimerSync_t,
+) -> int32 {
+    create(timebase_id, timebase_name, Kind::TimeBase)
+}
+
+#[no_mangle]
+extern "C" fn OS_TimeBaseSet(
+    timebase_id: osal_id_t,
+    _start_time: uint32,
+    _interval_time: uint32,
+) -> int32 {
+    with_object(timebase_id, OS_OBJECT_TYPE_OS_TIMEBASE, |_| SUCCESS)
+}
+
+#[no_mangle]
+extern "C" fn OS_TimeBaseDelete(timebase_id: osal_id_t) -> int32 {
+    lock(&STATE).remove(timebase_id, OS_OBJECT_TYPE_OS_TIMEBASE)
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_TimerAdd(
+    timer_id: *mut osal_id_t,
+    timer_name: *const c_char,
+    timebase_id: osal_id_t,
+    callback_ptr: OS_ArgCallback_t,
+    callback_arg: *mut c_void,
+) -> int32 {
+    if callback_ptr.is_none() {
+        return OS_INVALID_POINTER;
+    }
+    if lock(&STATE).get_mut(timebase_id, OS_OBJECT_TYPE_OS_TIMEBASE).is_none() {
+        return OS_ERR_INVALID_ID;
+    }
+
+    let kind = Kind::Timer {
+        callback: callback_ptr,
+        arg:      callback_arg as usize,
+        next_us:  None,
+        interval: 0,
+    };
+    create(timer_id, timer_name, kind)
+}
+
+#[no_mangle]
+extern "C" fn OS_TimerSet(timer_id: osal_id_t, start_time: uint32, interval_time: uint32) -> int32 {
+    if start_time == 0 && interval_time == 0 {
+        return OS_TIMER_ERR_INVALID_ARGS;
+    }
+
+    let now = now_micros();
+    with_object(timer_id, OS_OBJECT_TYPE_OS_TIMECB, |obj| match &mut obj.kind {
+        Kind::Timer { next_us, interval, .. } => {
+            // as in OSAL, a start time of 0 leaves the timer disarmed
+            *next_us = if start_time > 0 { Some(now + start_time as u64) } else { None };
+            *interval = interval_time as u64;
+            SUCCESS
+        }
+        _ => OS_ERR_INVALID_ID,
+    })
+}
+
+#[no_mangle]
+extern "C" fn OS_TimerDelete(timer_id: osal_id_t) -> int32 {
+    lock(&STATE).remove(timer_id, OS_OBJECT_TYPE_OS_TIMECB)
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_TimerGetIdByName(
+    timer_id: *mut osal_id_t,
+    timer_name: *const c_char,
+) -> int32 {
+    get_id_by_name(timer_id, timer_name, OS_OBJECT_TYPE_OS_TIMECB)
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_TimerGetInfo(
+    timer_id: osal_id_t,
+    timer_prop: *mut OS_timer_prop_t,
+) -> int32 {
+    if timer_prop.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    let now = now_micros();
+    with_object(timer_id, OS_OBJECT_TYPE_OS_TIMECB, |obj| {
+        let prop = &mut *timer_prop;
+        copy_to_c_array(&mut prop.name, &obj.name);
+        prop.creator = OS_TaskGetId();
+        prop.accuracy = 1;
+        if let Kind::Timer { next_us, interval, .. } = obj.kind {
+            prop.start_time = next_us.map_or(0, |next| next.saturating_sub(now) as uint32);
+            prop.interval_time = interval as uint32;
+        }
+        SUCCESS
+    })
+}
+
+// Tasks:
+
+#[no_mangle]
+extern "C" fn OS_TaskGetId() -> osal_id_t {
+    id_of(OS_OBJECT_TYPE_OS_TASK, 0)
+}
+
+#[no_mangle]
+extern "C" fn OS_TaskDelay(millisecond: uint32) -> int32 {
+    super::time::advance_micros(millisecond as u64 * 1000);
+    SUCCESS
+}
+
+#[no_mangle]
+extern "C" fn OS_TaskDelete(_task_id: osal_id_t) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_TaskExit() {}
+
+#[no_mangle]
+unsafe extern "C" fn OS_TaskGetIdByName(
+    task_id: *mut osal_id_t,
+    task_name: *const c_char,
+) -> int32 {
+    get_id_by_name(task_id, task_name, OS_OBJECT_TYPE_OS_TASK)
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_TaskGetInfo(task_id: osal_id_t, task_prop: *mut OS_task_prop_t) -> int32 {
+    if task_prop.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    with_object(task_id, OS_OBJECT_TYPE_OS_TASK, |obj| {
+        let prop = &mut *task_prop;
+        copy_to_c_array(&mut prop.name, &obj.name);
+        prop.creator = X_OS_OBJECT_ID_UNDEFINED;
+        prop.stack_size = 0;
+        if let Kind::Task { priority } = obj.kind {
+            prop.priority = priority;
+        }
+        SUCCESS
+    })
+}
+
+#[no_mangle]
+extern "C" fn OS_TaskSetPriority(task_id: osal_id_t, new_priority: osal_priority_t) -> int32 {
+    with_object(task_id, OS_OBJECT_TYPE_OS_TASK, |obj| {
+        obj.kind = Kind::Task { priority: new_priority };
+        SUCCESS
+    })
+}
+
+// Binary semaphores:
+
+#[no_mangle]
+unsafe extern "C" fn OS_BinSemCreate(
+    sem_id: *mut osal_id_t,
+    sem_name: *const c_char,
+    sem_initial_value: uint32,
+    _options: uint32,
+) -> int32 {
+    create(
+        sem_id,
+        sem_name,
+        Kind::BinSem {
+            value: sem_initial_value.min(1),
+        },
+    )
+}
+
+#[no_mangle]
+extern "C" fn OS_BinSemDelete(sem_id: osal_id_t) -> int32 {
+    lock(&STATE).remove(sem_id, OS_OBJECT_TYPE_OS_BINSEM)
+}
+
+#[no_mangle]
+extern "C" fn OS_BinSemFlush(sem_id: osal_id_t) -> int32 {
+    with_object(sem_id, OS_OBJECT_TYPE_OS_BINSEM, |_| SUCCESS)
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_BinSemGetIdByName(
+    sem_id: *mut osal_id_t,
+    sem_name: *const c_char,
+) -> int32 {
+    get_id_by_name(sem_id, sem_name, OS_OBJECT_TYPE_OS_BINSEM)
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_BinSemGetInfo(
+    sem_id: osal_id_t,
+    bin_prop: *mut OS_bin_sem_prop_t,
+) -> int32 {
+    if bin_prop.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    with_object(sem_id, OS_OBJECT_TYPE_OS_BINSEM, |obj| {
+        let prop = &mut *bin_prop;
+        copy_to_c_array(&mut prop.name, &obj.name);
+        prop.creator = OS_TaskGetId();
+        if let Kind::BinSem { value } = obj.kind {
+            prop.value = value as int32;
+        }
+        SUCCESS
+    })
+}
+
+#[no_mangle]
+extern "C" fn OS_BinSemGive(sem_id: osal_id_t) -> int32 {
+    with_object(sem_id, OS_OBJECT_TYPE_OS_BINSEM, |obj| {
+        obj.kind = Kind::BinSem { value: 1 };
+        SUCCESS
+    })
+}
+
+#[no_mangle]
+extern "C" fn OS_BinSemTake(sem_id: osal_id_t) -> int32 {
+    with_object(sem_id, OS_OBJECT_TYPE_OS_BINSEM, |obj| match obj.kind {
+        Kind::BinSem { value: 1 } => {
+            obj.kind = Kind::BinSem { value: 0 };
+            SUCCESS
+        }
+        _ => OS_SEM_FAILURE,
+    })
+}
+
+#[no_mangle]
+extern "C" fn OS_BinSemTimedWait(sem_id: osal_id_t, msecs: uint32) -> int32 {
+    match OS_BinSemTake(sem_id) {
+        OS_SEM_FAILURE => {
+            super::time::advance_micros(msecs as u64 * 1000);
+            OS_SEM_TIMEOUT
+        }
+        status => status,
+    }
+}
+
+// Counting semaphores:
+
+#[no_mangle]
+unsafe extern "C" fn OS_CountSemCreate(
+    sem_id: *mut osal_id_t,
+    sem_name: *const c_char,
+    sem_initial_value: uint32,
+    _options: uint32,
+) -> int32 {
+    if sem_initial_value > int32::MAX as uint32 {
+        return OS_INVALID_SEM_VALUE;
+    }
+    create(sem_id, sem_name, Kind::CountSem { value: sem_initial_value })
+}
+
+#[no_mangle]
+extern "C" fn OS_CountSemDelete(sem_id: osal_id_t) -> int32 {
+    lock(&STATE).remove(sem_id, OS_OBJECT_TYPE_OS_COUNTSEM)
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_CountSemGetIdByName(
+    sem_id: *mut osal_id_t,
+    sem_name: *const c_char,
+) -> int32 {
+    get_id_by_name(sem_id, sem_name, OS_OBJECT_TYPE_OS_COUNTSEM)
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_CountSemGetInfo(
+    sem_id: osal_id_t,
+    count_prop: *mut OS_count_sem_prop_t,
+) -> int32 {
+    if count_prop.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    with_object(sem_id, OS_OBJECT_TYPE_OS_COUNTSEM, |obj| {
+        let prop = &mut *count_prop;
+        copy_to_c_array(&mut prop.name, &obj.name);
+        prop.creator = OS_TaskGetId();
+        if let Kind::CountSem { value } = obj.kind {
+            prop.value = value as int32;
+        }
+        SUCCESS
+    })
+}
+
+#[no_mangle]
+extern "C" fn OS_CountSemGive(sem_id: osal_id_t) -> int32 {
+    with_object(sem_id, OS_OBJECT_TYPE_OS_COUNTSEM, |obj| match obj.kind {
+        Kind::CountSem { value } if value < int32::MAX as uint32 => {
+            obj.kind = Kind::CountSem { value: value + 1 };
+            SUCCESS
+        }
+        _ => OS_SEM_FAILURE,
+    })
+}
+
+#[no_mangle]
+extern "C" fn OS_CountSemTake(sem_id: osal_id_t) -> int32 {
+    with_object(sem_id, OS_OBJECT_TYPE_OS_COUNTSEM, |obj| match obj.kind {
+        Kind::CountSem { value } if value > 0 => {
+            obj.kind = Kind::CountSem { value: value - 1 };
+            SUCCESS
+        }
+        _ => OS_SEM_FAILURE,
+    })
+}
+
+#[no_mangle]
+extern "C" fn OS_CountSemTimedWait(sem_id: osal_id_t, msecs: uint32) -> int32 {
+    match OS_CountSemTake(sem_id) {
+        OS_SEM_FAILURE => {
+            super::time::advance_micros(msecs as u64 * 1000);
+            OS_SEM_TIMEOUT
+        }
+        status => status,
+    }
+}
+
+// Mutexes:
+
+#[no_mangle]
+unsafe extern "C" fn OS_MutSemCreate(
+    sem_id: *mut osal_id_t,
+    sem_name: *const c_char,
+    _options: uint32,
+) -> int32 {
+    create(sem_id, sem_name, Kind::Mutex { held: false })
+}
+
+#[no_mangle]
+extern "C" fn OS_MutSemDelete(sem_id: osal_id_t) -> int32 {
+    lock(&STATE).remove(sem_id, OS_OBJECT_TYPE_OS_MUTEX)
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_MutSemGetIdByName(
+    sem_id: *mut osal_id_t,
+    sem_name: *const c_char,
+) -> int32 {
+    get_id_by_name(sem_id, sem_name, OS_OBJECT_TYPE_OS_MUTEX)
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_MutSemGetInfo(
+    sem_id: osal_id_t,
+    mut_prop: *mut OS_mut_sem_prop_t,
+) -> int32 {
+    if mut_prop.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    with_object(sem_id, OS_OBJECT_TYPE_OS_MUTEX, |obj| {
+        let prop = &mut *mut_prop;
+        copy_to_c_array(&mut prop.name, &obj.name);
+        prop.creator = OS_TaskGetId();
+        SUCCESS
+    })
+}
+
+#[no_mangle]
+extern "C" fn OS_MutSemGive(sem_id: osal_id_t) -> int32 {
+    with_object(sem_id, OS_OBJECT_TYPE_OS_MUTEX, |obj| match obj.kind {
+        Kind::Mutex { held: true } => {
+            obj.kind = Kind::Mutex { held: false };
+            SUCCESS
+        }
+        _ => OS_SEM_FAILURE,
+    })
+}
+
+#[no_mangle]
+extern "C" fn OS_MutSemTake(sem_id: osal_id_t) -> int32 {
+    with_object(sem_id, OS_OBJECT_TYPE_OS_MUTEX, |obj| match obj.kind {
+        Kind::Mutex { held: false } => {
+            obj.kind = Kind::Mutex { held: true };
+            SUCCESS
+        }
+        _ => OS_SEM_FAILURE,
+    })
+}
+
+// Queues:
+
+#[no_mangle]
+unsafe extern "C" fn OS_QueueCreate(
+    queue_id: *mut osal_id_t,
+    queue_name: *const c_char,
+    queue_depth: osal_blockcount_t,
+    data_size: usize,
+    _flags: uint32,
+) -> int32 {
+    if queue_depth == 0 || queue_depth > OS_QUEUE_MAX_DEPTH as usize {
+        return OS_QUEUE_INVALID_SIZE;
+    }
+
+    let kind = Kind::Queue {
+        depth: queue_depth,
+        data_size,
+        items: VecDeque::new(),
+    };
+    create(queue_id, queue_name, kind)
+}
+
+#[no_mangle]
+extern "C" fn OS_QueueDelete(queue_id: osal_id_t) -> int32 {
+    lock(&STATE).remove(queue_id, OS_OBJECT_TYPE_OS_QUEUE)
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_QueueGet(
+    queue_id: osal_id_t,
+    data: *mut c_void,
+    size: usize,
+    size_copied: *mut usize,
+    timeout: int32,
+) -> int32 {
+    if data.is_null() || size_copied.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    let status = with_object(queue_id, OS_OBJECT_TYPE_OS_QUEUE, |obj| match &mut obj.kind {
+        Kind::Queue { data_size, .. } if size < *data_size => OS_QUEUE_INVALID_SIZE,
+        Kind::Queue { items, .. } => match items.pop_front() {
+            Some(item) => {
+                core::ptr::copy_nonoverlapping(item.as_ptr(), data as *mut u8, item.len());
+                *size_copied = item.len();
+                SUCCESS
+            }
+            None => OS_QUEUE_EMPTY,
+        },
+        _ => OS_ERR_INVALID_ID,
+    });
+
+    match status {
+        OS_QUEUE_EMPTY if timeout > 0 => {
+            super::time::advance_micros(timeout as u64 * 1000);
+            OS_QUEUE_TIMEOUT
+        }
+        status => status,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_QueuePut(
+    queue_id: osal_id_t,
+    data: *const c_void,
+    size: usize,
+    _flags: uint32,
+) -> int32 {
+    if data.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    with_object(queue_id, OS_OBJECT_TYPE_OS_QUEUE, |obj| match &mut obj.kind {
+        Kind::Queue { data_size, .. } if size > *data_size => OS_QUEUE_INVALID_SIZE,
+        Kind::Queue { depth, items, .. } if items.len() >= *depth => OS_QUEUE_FULL,
+        Kind::Queue { items, .. } => {
+            items.push_back(core::slice::from_raw_parts(data as *const u8, size).to_vec());
+            SUCCESS
+        }
+        _ => OS_ERR_INVALID_ID,
+    })
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_QueueGetIdByName(
+    queue_id: *mut osal_id_t,
+    queue_name: *const c_char,
+) -> int32 {
+    get_id_by_name(queue_id, queue_name, OS_OBJECT_TYPE_OS_QUEUE)
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_QueueGetInfo(
+    queue_id: osal_id_t,
+    queue_prop: *mut OS_queue_prop_t,
+) -> int32 {
+    if queue_prop.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    with_object(queue_id, OS_OBJECT_TYPE_OS_QUEUE, |obj| {
+        let prop = &mut *queue_prop;
+        copy_to_c_array(&mut prop.name, &obj.name);
+        prop.creator = OS_TaskGetId();
+        SUCCESS
+    })
+}
+
+// Files:
+
+#[no_mangle]
+unsafe extern "C" fn OS_TranslatePath(VirtualPath: *const c_char, LocalPath: *mut c_char) -> int32 {
+    if VirtualPath.is_null() || LocalPath.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    let path = string_from(VirtualPath);
+    if path.len() >= OS_MAX_PATH_LEN as usize {
+        return OS_FS_ERR_PATH_TOO_LONG;
+    }
+
+    copy_to_c_array(core::slice::from_raw_parts_mut(LocalPath, OS_MAX_PATH_LEN as usize), &path);
+    SUCCESS
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_OpenCreate(
+    filedes: *mut osal_id_t,
+    path: *const c_char,
+    flags: int32,
+    access_mode: int32,
+) -> int32 {
+    if filedes.is_null() || path.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    let path = string_from(path);
+    if path.len() >= OS_MAX_PATH_LEN as usize {
+        return OS_FS_ERR_PATH_TOO_LONG;
+    }
+
+    let access = access_mode as u32;
+    if ![OS_READ_ONLY, OS_WRITE_ONLY, OS_READ_WRITE].contains(&access) {
+        return OS_ERR_INVALID_ARGUMENT;
+    }
+
+    let flags = flags as OS_file_flag_t;
+    let mut state = lock(&STATE);
+
+    match files(&mut state.files).get_mut(&path) {
+        Some(file) => {
+            if flags & OS_file_flag_t_OS_FILE_FLAG_TRUNCATE != 0 {
+                file.data.clear();
+                file.mtime = mtime();
+            }
+        }
+        None if flags & OS_file_flag_t_OS_FILE_FLAG_CREATE != 0 => {
+            files(&mut state.files).insert(
+                path.clone(),
+                File {
+                    data:  Vec::new(),
+                    mtime: mtime(),
+                },
+            );
+        }
+        None => return OS_FS_ERR_PATH_INVALID,
+    }
+
+    *filedes = state.insert(Object {
+        name: String::new(),
+        kind: Kind::File { path, pos: 0, access },
+    });
+    SUCCESS
+}
+
+#[no_mangle]
+extern "C" fn OS_close(filedes: osal_id_t) -> int32 {
+    lock(&STATE).remove(filedes, OS_OBJECT_TYPE_OS_STREAM)
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_read(filedes: osal_id_t, buffer: *mut c_void, nbytes: usize) -> int32 {
+    if buffer.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    let mut guard = lock(&STATE);
+    let state = &mut *guard;
+    let (path, pos, access) =
+        match object_mut(&mut state.objects, filedes, OS_OBJECT_TYPE_OS_STREAM) {
+            Some(Object {
+                kind: Kind::File { path, pos, access },
+                ..
+            }) => (path, pos, *access),
+            _ => return OS_ERR_INVALID_ID,
+        };
+    if access == OS_WRITE_ONLY {
+        return OS_ERROR;
+    }
+
+    let data = match files(&mut state.files).get(path.as_str()) {
+        Some(file) => &file.data,
+        None => return OS_ERROR,
+    };
+
+    let start = (*pos).min(data.len());
+    let n = nbytes.min(data.len() - start).min(int32::MAX as usize);
+    core::ptr::copy_nonoverlapping(data[start..].as_ptr(), buffer as *mut u8, n);
+    *pos = start + n;
+    n as int32
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_write(filedes: osal_id_t, buffer: *const c_void, nbytes: usize) -> int32 {
+    if buffer.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    let now = mtime();
+    let mut guard = lock(&STATE);
+    let state = &mut *guard;
+    let (path, pos, access) =
+        match object_mut(&mut state.objects, filedes, OS_OBJECT_TYPE_OS_STREAM) {
+            Some(Object {
+                kind: Kind::File { path, pos, access },
+                ..
+            }) => (path, pos, *access),
+            _ => return OS_ERR_INVALID_ID,
+        };
+    if access == OS_READ_ONLY {
+        return OS_ERROR;
+    }
+
+    let file = match files(&mut state.files).get_mut(path.as_str()) {
+        Some(file) => file,
+        None => return OS_ERROR,
+    };
+
+    let n = nbytes.min(int32::MAX as usize);
+    let end = *pos + n;
+    if file.data.len() < end {
+        file.data.resize(end, 0);
+    }
+    file.data[*pos..end].copy_from_slice(core::slice::from_raw_parts(buffer as *const u8, n));
+    file.mtime = now;
+    *pos = end;
+    n as int32
+}
+
+#[no_mangle]
+extern "C" fn OS_lseek(filedes: osal_id_t, offset: int32, whence: uint32) -> int32 {
+    let mut guard = lock(&STATE);
+    let state = &mut *guard;
+    let (path, pos) = match object_mut(&mut state.objects, filedes, OS_OBJECT_TYPE_OS_STREAM) {
+        Some(Object {
+            kind: Kind::File { path, pos, .. },
+            ..
+        }) => (path, pos),
+        _ => return OS_ERR_INVALID_ID,
+    };
+
+    let base = match whence {
+        OS_SEEK_SET => 0,
+        OS_SEEK_CUR => *pos as i64,
+        OS_SEEK_END => {
+            files(&mut state.files).get(path.as_str()).map_or(0, |f| f.data.len() as i64)
+        }
+        _ => return OS_ERR_INVALID_ARGUMENT,
+    };
+
+    match int32::try_from(base + offset as i64) {
+        Ok(new_pos) if new_pos >= 0 => {
+            *pos = new_pos as usize;
+            new_pos
+        }
+        _ => OS_ERROR,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_stat(path: *const c_char, filestats: *mut os_fstat_t) -> int32 {
+    if path.is_null() || filestats.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    let path = string_from(path);
+    let state = lock(&STATE);
+    match state.files.as_ref().and_then(|files| files.get(&path)) {
+        Some(file) => {
+            *filestats = os_fstat_t {
+                FileModeBits: OS_FILESTAT_MODE_READ | OS_FILESTAT_MODE_WRITE,
+                FileTime:     OS_time_t { ticks: file.mtime },
+                FileSize:     file.data.len(),
+            };
+            SUCCESS
+        }
+        None if !dir_entries(&state, &path).is_empty() => {
+            *filestats = os_fstat_t {
+                FileModeBits: OS_FILESTAT_MODE_DIR
+                    | OS_FILESTAT_MODE_READ
+                    | OS_FILESTAT_MODE_WRITE
+                    | OS_FILESTAT_MODE_EXEC,
+                FileTime:     OS_time_t { ticks: 0 },
+                FileSize:     0,
+            };
+            SUCCESS
+        }
+        None => OS_ERROR,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_FileOpenCheck(Filename: *const c_char) -> int32 {
+    if Filename.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    let name = string_from(Filename);
+    let state = lock(&STATE);
+    let is_open = state.objects.iter().flatten().any(|obj| match &obj.kind {
+        Kind::File { path, .. } => *path == name,
+        _ => false,
+    });
+
+    if is_open {
+        SUCCESS
+    } else {
+        OS_ERROR
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_remove(path: *const c_char) -> int32 {
+    if path.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    match files(&mut lock(&STATE).files).remove(&string_from(path)) {
+        Some(_) => SUCCESS,
+        None => OS_ERROR,
+    }
+}
+
+/// The size of the (single) volume the in-memory filesystem reports, in 512-byte blocks.
+const VOLUME_BLOCKS: usize = 2048;
+
+#[no_mangle]
+unsafe extern "C" fn OS_FileSysStatVolume(
+    name: *const c_char,
+    statbuf: *mut OS_statvfs_t,
+) -> int32 {
+    if name.is_null() || statbuf.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    let mut state = lock(&STATE);
+    let used: usize = files(&mut state.files).values().map(|f| (f.data.len() + 511) / 512).sum();
+    *statbuf = OS_statvfs_t {
+        block_size:   512,
+        total_blocks: VOLUME_BLOCKS,
+        blocks_free:  VOLUME_BLOCKS.saturating_sub(used),
+    };
+    SUCCESS
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_rename(old_filename: *const c_char, new_filename: *const c_char) -> int32 {
+    if old_filename.is_null() || new_filename.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    let (old, new) = (string_from(old_filename), string_from(new_filename));
+    if new.len() >= OS_MAX_PATH_LEN as usize {
+        return OS_FS_ERR_PATH_TOO_LONG;
+    }
+
+    let mut state = lock(&STATE);
+    let file = match files(&mut state.files).remove(&old) {
+        Some(file) => file,
+        None => return OS_ERROR,
+    };
+    files(&mut state.files).insert(new.clone(), file);
+
+    // Open files follow the rename, as they would on a POSIX system.
+    for obj in state.objects.iter_mut().flatten() {
+        if let Kind::File { path, .. } = &mut obj.kind {
+            if *path == old {
+                *path = new.clone();
+            }
+        }
+    }
+
+    SUCCESS
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_cp(src: *const c_char, dest: *const c_char) -> int32 {
+    if src.is_null() || dest.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    let (src, dest) = (string_from(src), string_from(dest));
+    if dest.len() >= OS_MAX_PATH_LEN as usize {
+        return OS_FS_ERR_PATH_TOO_LONG;
+    }
+
+    let now = mtime();
+    let mut state = lock(&STATE);
+    let data = match files(&mut state.files).get(&src) {
+        Some(file) => file.data.clone(),
+        None => return OS_ERROR,
+    };
+    files(&mut state.files).insert(dest, File { data, mtime: now });
+    SUCCESS
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_mv(src: *const c_char, dest: *const c_char) -> int32 {
+    OS_rename(src, dest)
+}
+
+// Directories:
+
+/// Returns the names of the files and directories directly below `path`,
+/// which is empty if `path` isn't a directory.
+fn dir_entries(state: &OsalState, path: &str) -> Vec<String> {
+    let mut prefix = String::from(path);
+    if !prefix.ends_with('/') {
+        prefix.push('/');
+    }
+
+    let mut entries: Vec<String> = state
+        .files
+        .iter()
+        .flat_map(BTreeMap::keys)
+        .filter_map(|p| p.strip_prefix(prefix.as_str()))
+        .filter_map(|rest| rest.split('/').next())
+        .filter(|name| !name.is_empty())
+        .map(String::from)
+        .collect();
+    entries.dedup();
+    entries
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_DirectoryOpen(dir_id: *mut osal_id_t, path: *const c_char) -> int32 {
+    if dir_id.is_null() || path.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    let mut state = lock(&STATE);
+    let entries = dir_entries(&state, &string_from(path));
+    if entries.is_empty() {
+        return OS_ERROR;
+    }
+
+    *dir_id = state.insert(Object {
+        name: String::new(),
+        kind: Kind::Dir { entries, pos: 0 },
+    });
+    SUCCESS
+}
+
+#[no_mangle]
+extern "C" fn OS_DirectoryClose(dir_id: osal_id_t) -> int32 {
+    lock(&STATE).remove(dir_id, OS_OBJECT_TYPE_OS_DIR)
+}
+
+#[no_mangle]
+extern "C" fn OS_DirectoryRewind(dir_id: osal_id_t) -> int32 {
+    with_object(dir_id, OS_OBJECT_TYPE_OS_DIR, |obj| match &mut obj.kind {
+        Kind::Dir { pos, .. } => {
+            *pos = 0;
+            SUCCESS
+        }
+        _ => OS_ERROR,
+    })
+}
+
+#[no_mangle]
+unsafe extern "C" fn OS_DirectoryRead(dir_id: osal_id_t, dirent: *mut os_dirent_t) -> int32 {
+    if dirent.is_null() {
+        return OS_INVALID_POINTER;
+    }
+
+    with_object(dir_id, OS_OBJECT_TYPE_OS_DIR, |obj| match &mut obj.kind {
+        Kind::Dir { entries, pos } => match entries.get(*pos) {
+            Some(name) => {
+                copy_to_c_array(&mut (*dirent).FileName, name);
+                *pos += 1;
+                SUCCESS
+            }
+            None => OS_ERROR,
+        },
+        _ => OS_ERROR,
+    })
+}
+
+// Sockets:
+
+#[no_mangle]
+extern "C" fn OS_SocketOpen(
+    _sock_id: *mut osal_id_t,
+    _Domain: OS_SocketDomain_t,
+    _Type: OS_SocketType_t,
+) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_SocketBind(_sock_id: osal_id_t, _Addr: *const OS_SockAddr_t) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_SocketConnect(
+    _sock_id: osal_id_t,
+    _Addr: *const OS_SockAddr_t,
+    _timeout: int32,
+) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_SocketAccept(
+    _sock_id: osal_id_t,
+    _connsock_id: *mut osal_id_t,
+    _Addr: *mut OS_SockAddr_t,
+    _timeout: int32,
+) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_SocketShutdown(_sock_id: osal_id_t, _Mode: OS_SocketShutdownMode_t) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_SocketRecvFrom(
+    _sock_id: osal_id_t,
+    _buffer: *mut c_void,
+    _buflen: usize,
+    _RemoteAddr: *mut OS_SockAddr_t,
+    _timeout: int32,
+) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_SocketSendTo(
+    _sock_id: osal_id_t,
+    _buffer: *const c_void,
+    _buflen: usize,
+    _RemoteAddr: *const OS_SockAddr_t,
+) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_SelectSingle(_objid: osal_id_t, _StateFlags: *mut uint32, _msecs: int32) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_SocketGetInfo(_sock_id: osal_id_t, _sock_prop: *mut OS_socket_prop_t) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_SocketAddrInit(_Addr: *mut OS_SockAddr_t, _Domain: OS_SocketDomain_t) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_SocketAddrFromString(_Addr: *mut OS_SockAddr_t, _string: *const c_char) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_SocketAddrToString(
+    _buffer: *mut c_char,
+    _buflen: usize,
+    _Addr: *const OS_SockAddr_t,
+) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_SocketAddrGetPort(_PortNum: *mut uint16, _Addr: *const OS_SockAddr_t) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
+
+#[no_mangle]
+extern "C" fn OS_SocketAddrSetPort(_Addr: *mut OS_SockAddr_t, _PortNum: uint16) -> int32 {
+    OS_ERR_NOT_IMPLEMENTED
+}
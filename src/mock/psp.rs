@@ -0,0 +1,227 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Platform support.
+//!
+//! The fake's watchdog timer never expires;
+//! its state may be inspected with [`watchdog`].
+//!
+//! The fake's memory table starts out empty;
+//! ranges of host memory may be added to it with [`add_mem_range`].
+//! Memory reads and writes act directly on host memory.
+//! EEPROM writes fail unless some bank has been write-enabled.
+//!
+//! The reset and user-reserved areas are fixed-size buffers,
+//! zeroed when the fake is reset.
+
+use super::lock;
+use crate::cfe::psp::MemoryType;
+use crate::sys::*;
+
+use core::ffi::c_void;
+use std::sync::Mutex;
+
+/// The state of the fake watchdog timer.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct WatchdogState {
+    /// Whether the watchdog is enabled.
+    pub enabled: bool,
+
+    /// The watchdog's timeout period, in milliseconds.
+    pub timeout_ms: u32,
+
+    /// How many times the watchdog has been serviced.
+    pub services: u32,
+}
+
+const INITIAL: WatchdogState = WatchdogState {
+    enabled:    false,
+    timeout_ms: 0,
+    services:   0,
+};
+
+static WATCHDOG: Mutex<WatchdogState> = Mutex::new(INITIAL);
+
+static MEM_RANGES: Mutex<Vec<(usize, usize, u32)>> = Mutex::new(Vec::new());
+
+static EEPROM_BANKS_ENABLED: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+/// The size of the fake's reset and user-reserved areas, in bytes.
+pub const AREA_SIZE: usize = 1024;
+
+static mut RESET_AREA: [u8; AREA_SIZE] = [0; AREA_SIZE];
+static mut USER_RESERVED_AREA: [u8; AREA_SIZE] = [0; AREA_SIZE];
+
+pub(super) fn reset() {
+    *lock(&WATCHDOG) = INITIAL;
+    lock(&MEM_RANGES).clear();
+    lock(&EEPROM_BANKS_ENABLED).clear();
+    unsafe {
+        core::ptr::write_bytes(core::ptr::addr_of_mut!(RESET_AREA), 0, 1);
+        core::ptr::write_bytes(core::ptr::addr_of_mut!(USER_RESERVED_AREA), 0, 1);
+    }
+}
+
+/// Adds the `size` bytes starting at `start` to the memory table, as memory of type `mem_type`.
+pub fn add_mem_range(start: usize, size: usize, mem_type: MemoryType) {
+    lock(&MEM_RANGES).push((start, size, mem_type as u32));
+}
+
+/// Returns the current state of the watchdog timer.
+pub fn watchdog() -> WatchdogState {
+    *lock(&WATCHDOG)
+}
+
+#[no_mangle]
+extern "C" fn CFE_PSP_WatchdogEnable() {
+    lock(&WATCHDOG).enabled = true;
+}
+
+#[no_mangle]
+extern "C" fn CFE_PSP_WatchdogDisable() {
+    lock(&WATCHDOG).enabled = false;
+}
+
+#[no_mangle]
+extern "C" fn CFE_PSP_WatchdogService() {
+    let mut wd = lock(&WATCHDOG);
+    wd.services = wd.services.wrapping_add(1);
+}
+
+#[no_mangle]
+extern "C" fn CFE_PSP_WatchdogGet() -> uint32 {
+    lock(&WATCHDOG).timeout_ms
+}
+
+#[no_mangle]
+extern "C" fn CFE_PSP_WatchdogSet(WatchdogValue: uint32) {
+    lock(&WATCHDOG).timeout_ms = WatchdogValue;
+}
+
+#[no_mangle]
+extern "C" fn CFE_PSP_MemValidateRange(Address: cpuaddr, Size: usize, MemoryType: uint32) -> int32 {
+    if MemoryType != CFE_PSP_MEM_RAM
+        && MemoryType != CFE_PSP_MEM_EEPROM
+        && MemoryType != CFE_PSP_MEM_ANY
+    {
+        return CFE_PSP_INVALID_MEM_TYPE;
+    }
+    let end = match Address.checked_add(Size) {
+        Some(end) => end,
+        None => return CFE_PSP_INVALID_MEM_RANGE,
+    };
+
+    let ranges = lock(&MEM_RANGES);
+    let matching_type = |t: u32| MemoryType == CFE_PSP_MEM_ANY || t == MemoryType;
+    if ranges.iter().any(|&(s, n, t)| matching_type(t) && s <= Address && end <= s + n) {
+        CFE_PSP_SUCCESS as int32
+    } else if ranges.iter().any(|&(s, n, _)| s <= Address && Address < s + n) {
+        CFE_PSP_INVALID_MEM_RANGE
+    } else {
+        CFE_PSP_INVALID_MEM_ADDR
+    }
+}
+
+macro_rules! mock_mem_rw {
+    ($($read:ident, $write:ident, $t:ty);+ $(;)?) => {
+        $(
+            #[no_mangle]
+            unsafe extern "C" fn $read(MemoryAddress: cpuaddr, Value: *mut $t) -> int32 {
+                if Value.is_null() {
+                    return CFE_PSP_INVALID_POINTER;
+                }
+                if MemoryAddress % core::mem::align_of::<$t>() != 0 {
+                    return CFE_PSP_ERROR_ADDRESS_MISALIGNED;
+                }
+                *Value = core::ptr::read_volatile(MemoryAddress as *const $t);
+                CFE_PSP_SUCCESS as int32
+            }
+
+            #[no_mangle]
+            unsafe extern "C" fn $write(MemoryAddress: cpuaddr, Value: $t) -> int32 {
+                if MemoryAddress % core::mem::align_of::<$t>() != 0 {
+                    return CFE_PSP_ERROR_ADDRESS_MISALIGNED;
+                }
+                core::ptr::write_volatile(MemoryAddress as *mut $t, Value);
+                CFE_PSP_SUCCESS as int32
+            }
+        )+
+    };
+}
+
+mock_mem_rw! {
+    CFE_PSP_MemRead8, CFE_PSP_MemWrite8, u8;
+    CFE_PSP_MemRead16, CFE_PSP_MemWrite16, u16;
+    CFE_PSP_MemRead32, CFE_PSP_MemWrite32, u32;
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_PSP_MemCpy(dest: *mut c_void, src: *const c_void, n: uint32) -> int32 {
+    core::ptr::copy(src as *const u8, dest as *mut u8, n as usize);
+    CFE_PSP_SUCCESS as int32
+}
+
+#[no_mangle]
+extern "C" fn CFE_PSP_EepromWriteEnable(Bank: uint32) -> int32 {
+    let mut banks = lock(&EEPROM_BANKS_ENABLED);
+    if !banks.contains(&Bank) {
+        banks.push(Bank);
+    }
+    CFE_PSP_SUCCESS as int32
+}
+
+#[no_mangle]
+extern "C" fn CFE_PSP_EepromWriteDisable(Bank: uint32) -> int32 {
+    lock(&EEPROM_BANKS_ENABLED).retain(|&b| b != Bank);
+    CFE_PSP_SUCCESS as int32
+}
+
+macro_rules! mock_eeprom_write {
+    ($($write:ident, $t:ty);+ $(;)?) => {
+        $(
+            #[no_mangle]
+            unsafe extern "C" fn $write(MemoryAddress: cpuaddr, Value: $t) -> int32 {
+                if lock(&EEPROM_BANKS_ENABLED).is_empty() {
+                    return CFE_PSP_ERROR;
+                }
+                if MemoryAddress % core::mem::align_of::<$t>() != 0 {
+                    return CFE_PSP_ERROR_ADDRESS_MISALIGNED;
+                }
+                core::ptr::write_volatile(MemoryAddress as *mut $t, Value);
+                CFE_PSP_SUCCESS as int32
+            }
+        )+
+    };
+}
+
+mock_eeprom_write! {
+    CFE_PSP_EepromWrite8, u8;
+    CFE_PSP_EepromWrite16, u16;
+    CFE_PSP_EepromWrite32, u32;
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_PSP_GetResetArea(
+    PtrToResetArea: *mut cpuaddr,
+    SizeOfResetArea: *mut uint32,
+) -> int32 {
+    if PtrToResetArea.is_null() || SizeOfResetArea.is_null() {
+        return CFE_PSP_INVALID_POINTER;
+    }
+    *PtrToResetArea = core::ptr::addr_of_mut!(RESET_AREA) as cpuaddr;
+    *SizeOfResetArea = AREA_SIZE as uint32;
+    CFE_PSP_SUCCESS as int32
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_PSP_GetUserReservedArea(
+    PtrToUserArea: *mut cpuaddr,
+    SizeOfUserArea: *mut uint32,
+) -> int32 {
+    if PtrToUserArea.is_null() || SizeOfUserArea.is_null() {
+        return CFE_PSP_INVALID_POINTER;
+    }
+    *PtrToUserArea = core::ptr::addr_of_mut!(USER_RESERVED_AREA) as cpuaddr;
+    *SizeOfUserArea = AREA_SIZE as uint32;
+    CFE_PSP_SUCCESS as int32
+}
@@ -0,0 +1,338 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! In-memory tables.
+//!
+//! Tables registered through the fake are held in memory.
+//! They may be loaded from an address (which takes effect immediately,
+//! after the validation function, if any, accepts the new contents)
+//! but not from a file.
+//! A table's full name is its registered name prefixed with `MOCK_APP.`.
+
+use super::{copy_to_c_array, lock, string_from};
+use crate::sys::*;
+
+use core::ffi::{c_char, c_void};
+use std::format;
+use std::string::String;
+use std::sync::Mutex;
+use std::vec::Vec;
+
+struct Table {
+    full_name:   String,
+    size:        usize,
+    buf:         Vec<u64>,
+    user_addr:   usize,
+    validation:  CFE_TBL_CallbackFuncPtr_t,
+    dump_only:   bool,
+    user_def:    bool,
+    loaded:      bool,
+    updated:     bool,
+    last_update: CFE_TIME_SysTime_t,
+}
+
+impl Table {
+    /// The address of the table's active contents.
+    fn addr(&mut self) -> *mut c_void {
+        if self.user_def {
+            self.user_addr as *mut c_void
+        } else {
+            self.buf.as_mut_ptr() as *mut c_void
+        }
+    }
+}
+
+static TABLES: Mutex<Vec<Option<Table>>> = Mutex::new(Vec::new());
+
+pub(super) fn reset() {
+    lock(&TABLES).clear();
+}
+
+/// Returns a copy of the current contents of the table with full name `full_name`,
+/// or `None` if there is no such table or it has never been loaded.
+pub fn contents(full_name: &str) -> Option<Vec<u8>> {
+    let mut tables = lock(&TABLES);
+    let tbl = tables.iter_mut().flatten().find(|t| t.full_name == full_name)?;
+    if !tbl.loaded {
+        return None;
+    }
+
+    let bytes = unsafe { core::slice::from_raw_parts(tbl.addr() as *const u8, tbl.size) };
+    Some(bytes.to_vec())
+}
+
+/// Runs `f` on the table with handle `hdl`,
+/// returning `CFE_TBL_ERR_INVALID_HANDLE` if there is no such table.
+fn with_table(hdl: CFE_TBL_Handle_t, f: impl FnOnce(&mut Table) -> CFE_Status_t) -> CFE_Status_t {
+    let mut tables = lock(&TABLES);
+    match usize::try_from(hdl).ok().and_then(|i| tables.get_mut(i)) {
+        Some(Some(tbl)) => f(tbl),
+        _ => S_CFE_TBL_ERR_INVALID_HANDLE,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_TBL_Register(
+    TblHandlePtr: *mut CFE_TBL_Handle_t,
+    Name: *const c_char,
+    Size: usize,
+    TblOptionFlags: uint16,
+    TblValidationFuncPtr: CFE_TBL_CallbackFuncPtr_t,
+) -> CFE_Status_t {
+    if TblHandlePtr.is_null() || Name.is_null() {
+        return S_CFE_TBL_BAD_ARGUMENT;
+    }
+    *TblHandlePtr = X_CFE_TBL_BAD_TABLE_HANDLE;
+
+    let name = string_from(Name);
+    if name.is_empty() || name.len() >= CFE_MISSION_TBL_MAX_NAME_LENGTH as usize {
+        return S_CFE_TBL_ERR_INVALID_NAME;
+    }
+    if Size == 0 {
+        return S_CFE_TBL_ERR_INVALID_SIZE;
+    }
+
+    let opts = TblOptionFlags as u32;
+    let full_name = format!("{}.{}", super::es::APP_NAME, name);
+
+    let mut tables = lock(&TABLES);
+    if let Some(i) =
+        tables.iter().position(|t| t.as_ref().map_or(false, |t| t.full_name == full_name))
+    {
+        if tables[i].as_ref().map_or(false, |t| t.size == Size) {
+            *TblHandlePtr = i as CFE_TBL_Handle_t;
+            return S_CFE_TBL_WARN_DUPLICATE;
+        } else {
+            return S_CFE_TBL_ERR_DUPLICATE_DIFF_SIZE;
+        }
+    }
+
+    let table = Table {
+        full_name,
+        size: Size,
+        buf: std::vec![0; (Size + 7) / 8],
+        user_addr: 0,
+        validation: TblValidationFuncPtr,
+        dump_only: opts & CFE_TBL_OPT_LD_DMP_MSK == CFE_TBL_OPT_DUMP_ONLY,
+        user_def: opts & CFE_TBL_OPT_USR_DEF_MSK != 0,
+        loaded: false,
+        updated: false,
+        last_update: super::time::now().tm,
+    };
+
+    let i = match tables.iter().position(Option::is_none) {
+        Some(i) => {
+            tables[i] = Some(table);
+            i
+        }
+        None => {
+            tables.push(Some(table));
+            tables.len() - 1
+        }
+    };
+    *TblHandlePtr = i as CFE_TBL_Handle_t;
+
+    if opts & CFE_TBL_OPT_CRITICAL_MSK != 0 {
+        S_CFE_TBL_WARN_NOT_CRITICAL
+    } else {
+        S_CFE_SUCCESS
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_TBL_Share(
+    TblHandlePtr: *mut CFE_TBL_Handle_t,
+    TblName: *const c_char,
+) -> CFE_Status_t {
+    if TblHandlePtr.is_null() || TblName.is_null() {
+        return S_CFE_TBL_BAD_ARGUMENT;
+    }
+
+    let name = string_from(TblName);
+    let tables = lock(&TABLES);
+    match tables.iter().position(|t| t.as_ref().map_or(false, |t| t.full_name == name)) {
+        Some(i) => {
+            *TblHandlePtr = i as CFE_TBL_Handle_t;
+            S_CFE_SUCCESS
+        }
+        None => S_CFE_TBL_ERR_INVALID_NAME,
+    }
+}
+
+#[no_mangle]
+extern "C" fn CFE_TBL_Unregister(TblHandle: CFE_TBL_Handle_t) -> CFE_Status_t {
+    let mut tables = lock(&TABLES);
+    match usize::try_from(TblHandle).ok().and_then(|i| tables.get_mut(i)) {
+        Some(slot @ Some(_)) => {
+            *slot = None;
+            S_CFE_SUCCESS
+        }
+        _ => S_CFE_TBL_ERR_INVALID_HANDLE,
+    }
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_TBL_Load(
+    TblHandle: CFE_TBL_Handle_t,
+    SrcType: CFE_TBL_SrcEnum_t,
+    SrcDataPtr: *const c_void,
+) -> CFE_Status_t {
+    if SrcDataPtr.is_null() {
+        return S_CFE_TBL_BAD_ARGUMENT;
+    }
+
+    // The validation function is run without the lock held,
+    // as it's arbitrary application code.
+    let (size, validation) = {
+        let tables = lock(&TABLES);
+        match usize::try_from(TblHandle).ok().and_then(|i| tables.get(i)) {
+            Some(Some(tbl)) => (tbl.size, tbl.validation),
+            _ => return S_CFE_TBL_ERR_INVALID_HANDLE,
+        }
+    };
+
+    let mut working: Vec<u64> = std::vec![0; (size + 7) / 8];
+
+    match SrcType {
+        CFE_TBL_SrcEnum_CFE_TBL_SRC_ADDRESS => {
+            let mut tables = lock(&TABLES);
+            if let Some(Some(tbl)) = tables.get_mut(TblHandle as usize) {
+                if tbl.user_def {
+                    tbl.user_addr = SrcDataPtr as usize;
+                    tbl.loaded = true;
+                    tbl.last_update = super::time::now().tm;
+                    return S_CFE_SUCCESS;
+                } else if tbl.dump_only {
+                    return S_CFE_TBL_ERR_DUMP_ONLY;
+                }
+            }
+
+            core::ptr::copy_nonoverlapping(
+                SrcDataPtr as *const u8,
+                working.as_mut_ptr() as *mut u8,
+                size,
+            );
+        }
+        CFE_TBL_SrcEnum_CFE_TBL_SRC_FILE => return S_CFE_TBL_NOT_IMPLEMENTED,
+        _ => return S_CFE_TBL_ERR_ILLEGAL_SRC_TYPE,
+    }
+
+    if let Some(f) = validation {
+        let result = f(working.as_mut_ptr() as *mut c_void);
+        if result != S_CFE_SUCCESS {
+            return if result < 0 { result } else { S_CFE_TBL_ERR_LOAD_INCOMPLETE };
+        }
+    }
+
+    with_table(TblHandle, |tbl| {
+        tbl.buf.copy_from_slice(&working);
+        tbl.loaded = true;
+        tbl.updated = true;
+        tbl.last_update = super::time::now().tm;
+        S_CFE_SUCCESS
+    })
+}
+
+#[no_mangle]
+extern "C" fn CFE_TBL_Update(TblHandle: CFE_TBL_Handle_t) -> CFE_Status_t {
+    with_table(TblHandle, |_| S_CFE_TBL_INFO_NO_UPDATE_PENDING)
+}
+
+#[no_mangle]
+extern "C" fn CFE_TBL_Validate(TblHandle: CFE_TBL_Handle_t) -> CFE_Status_t {
+    with_table(TblHandle, |_| S_CFE_TBL_INFO_NO_VALIDATION_PENDING)
+}
+
+#[no_mangle]
+extern "C" fn CFE_TBL_Manage(TblHandle: CFE_TBL_Handle_t) -> CFE_Status_t {
+    with_table(TblHandle, |_| S_CFE_SUCCESS)
+}
+
+#[no_mangle]
+extern "C" fn CFE_TBL_DumpToBuffer(TblHandle: CFE_TBL_Handle_t) -> CFE_Status_t {
+    with_table(TblHandle, |_| S_CFE_SUCCESS)
+}
+
+#[no_mangle]
+extern "C" fn CFE_TBL_GetStatus(TblHandle: CFE_TBL_Handle_t) -> CFE_Status_t {
+    with_table(TblHandle, |_| S_CFE_SUCCESS)
+}
+
+#[no_mangle]
+extern "C" fn CFE_TBL_Modified(TblHandle: CFE_TBL_Handle_t) -> CFE_Status_t {
+    with_table(TblHandle, |tbl| {
+        tbl.last_update = super::time::now().tm;
+        S_CFE_SUCCESS
+    })
+}
+
+#[no_mangle]
+extern "C" fn CFE_TBL_NotifyByMessage(
+    TblHandle: CFE_TBL_Handle_t,
+    _MsgId: CFE_SB_MsgId_t,
+    _CommandCode: CFE_MSG_FcnCode_t,
+    _Parameter: uint32,
+) -> CFE_Status_t {
+    with_table(TblHandle, |_| S_CFE_SUCCESS)
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_TBL_GetAddress(
+    TblPtr: *mut *mut c_void,
+    TblHandle: CFE_TBL_Handle_t,
+) -> CFE_Status_t {
+    if TblPtr.is_null() {
+        return S_CFE_TBL_BAD_ARGUMENT;
+    }
+    *TblPtr = core::ptr::null_mut();
+
+    with_table(TblHandle, |tbl| {
+        *TblPtr = tbl.addr();
+
+        if !tbl.loaded {
+            S_CFE_TBL_ERR_NEVER_LOADED
+        } else if core::mem::take(&mut tbl.updated) {
+            S_CFE_TBL_INFO_UPDATED
+        } else {
+            S_CFE_SUCCESS
+        }
+    })
+}
+
+#[no_mangle]
+extern "C" fn CFE_TBL_ReleaseAddress(TblHandle: CFE_TBL_Handle_t) -> CFE_Status_t {
+    with_table(TblHandle, |_| S_CFE_SUCCESS)
+}
+
+#[no_mangle]
+unsafe extern "C" fn CFE_TBL_GetInfo(
+    TblInfoPtr: *mut CFE_TBL_Info_t,
+    TblName: *const c_char,
+) -> CFE_Status_t {
+    if TblInfoPtr.is_null() || TblName.is_null() {
+        return S_CFE_TBL_BAD_ARGUMENT;
+    }
+
+    let name = string_from(TblName);
+    let tables = lock(&TABLES);
+    let tbl = match tables.iter().flatten().find(|t| t.full_name == name) {
+        Some(tbl) => tbl,
+        None => return S_CFE_TBL_ERR_INVALID_NAME,
+    };
+
+    let info = &mut *TblInfoPtr;
+    info.Size = tbl.size;
+    info.NumUsers = 1;
+    info.FileCreateTimeSecs = 0;
+    info.FileCreateTimeSubSecs = 0;
+    info.Crc = 0;
+    info.TimeOfLastUpdate = tbl.last_update;
+    info.TableLoadedOnce = tbl.loaded;
+    info.DumpOnly = tbl.dump_only;
+    info.DoubleBuffered = false;
+    info.UserDefAddr = tbl.user_def;
+    info.Critical = false;
+    copy_to_c_array(&mut info.LastFileLoaded, "");
+
+    S_CFE_SUCCESS
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Virtual time.
+//!
+//! The fake's clock starts at zero and only changes
+//! when [`set`] or [`advance_micros`] is called
+//! (or when a task "delays" via [`task::delay`](crate::osal::task::delay)).
+
+use super::lock;
+use crate::cfe::time::{ClockInfo, SysTime};
+use crate::sys::*;
+
+use std::sync::Mutex;
+
+static NOW: Mutex<CFE_TIME_SysTime_t> = Mutex::new(ZERO);
+
+static CLOCK_INFO: Mutex<u16> = Mutex::new(0);
+
+const ZERO: CFE_TIME_SysTime_t = CFE_TIME_SysTime_t { Seconds: 0, Subseconds: 0 };
+
+pub(super) fn reset() {
+    *lock(&NOW) = ZERO;
+    *lock(&CLOCK_INFO) = 0;
+}
+
+/// Sets the current virtual time.
+pub fn set(time: SysTime) {
+    *lock(&NOW) = time.tm;
+}
+
+/// Returns the current virtual time.
+pub fn now() -> SysTime {
+    SysTime { tm: *lock(&NOW) }
+}
+
+/// Sets the flags reported by `CFE_TIME_GetClockInfo` (initially none).
+pub fn set_clock_info(info: ClockInfo) {
+    *lock(&CLOCK_INFO) = info.bits();
+}
+
+/// Advances the virtual time by `micros` microseconds.
+///
+/// Any OSAL timers that expire in the meantime fire.
+pub fn advance_micros(micros: u64) {
+    {
+        let mut now = lock(&NOW);
+        let total = sub2micros(now.Subseconds) as u64 + micros;
+        now.Seconds = now.Seconds.wrapping_add((total / 1_000_000) as u32);
+        now.Subseconds = micros2sub((total % 1_000_000) as u32);
+    }
+
+    super::osal::fire_timers();
+}
+
+fn sub2micros(sub: u32) -> u32 {
+    ((sub as u64 * 1_000_000) >> 32) as u32
+}
+
+fn micros2sub(micros: u32) -> u32 {
+    if micros >= 1_000_000 {
+        0xffff_ffff
+    } else {
+        (((micros as u64) << 32) / 1_000_000) as u32
+    }
+}
+
+#[no_mangle]
+extern "C" fn CFE_TIME_GetTime() -> CFE_TIME_SysTime_t {
+    *lock(&NOW)
+}
+
+#[no_mangle]
+extern "C" fn CFE_TIME_GetClockInfo() -> uint16 {
+    *lock(&CLOCK_INFO)
+}
+
+#[no_mangle]
+extern "C" fn CFE_TIME_Compare(
+    TimeA: CFE_TIME_SysTime_t,
+    TimeB: CFE_TIME_SysTime_t,
+) -> CFE_TIME_Compare_t {
+    let a = (TimeA.Seconds, TimeA.Subseconds);
+    let b = (TimeB.Seconds, TimeB.Subseconds);
+
+    match a.cmp(&b) {
+        core::cmp::Ordering::Less => CFE_TIME_Compare_CFE_TIME_A_LT_B,
+        core::cmp::Ordering::Equal => CFE_TIME_Compare_CFE_TIME_EQUAL,
+        core::cmp::Ordering::Greater => CFE_TIME_Compare_CFE_TIME_A_GT_B,
+    }
+}
+
+#[no_mangle]
+extern "C" fn CFE_TIME_Sub2MicroSecs(SubSeconds: uint32) -> uint32 {
+    sub2micros(SubSeconds)
+}
+
+#[no_mangle]
+extern "C" fn CFE_TIME_Micro2SubSecs(MicroSeconds: uint32) -> uint32 {
+    micros2sub(MicroSeconds)
+}
+
+#[no_mangle]
+extern "C" fn CFE_TIME_Add(
+    Time1: CFE_TIME_SysTime_t,
+    Time2: CFE_TIME_SysTime_t,
+) -> CFE_TIME_SysTime_t {
+    let (sub, carry) = Time1.Subseconds.overflowing_add(Time2.Subseconds);
+    CFE_TIME_SysTime_t {
+        Seconds:    Time1.Seconds.wrapping_add(Time2.Seconds).wrapping_add(carry as u32),
+        Subseconds: sub,
+    }
+}
+
+#[no_mangle]
+extern "C" fn CFE_TIME_Subtract(
+    Time1: CFE_TIME_SysTime_t,
+    Time2: CFE_TIME_SysTime_t,
+) -> CFE_TIME_SysTime_t {
+    let (sub, borrow) = Time1.Subseconds.overflowing_sub(Time2.Subseconds);
+    CFE_TIME_SysTime_t {
+        Seconds:    Time1.Seconds.wrapping_sub(Time2.Seconds).wrapping_sub(borrow as u32),
+        Subseconds: sub,
+    }
+}
@@ -0,0 +1,20 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Message ID and topic ID constants from the mission's header files.
+//!
+//! The build script generates this module's contents
+//! from the headers listed (`@`-separated) in the `RUST_CFS_SYS_MSGID_HEADERS` environment variable,
+//! each either an absolute path or a path to find through the configured include directories.
+//! Each macro named `*_MID` becomes a [`MsgId_Atom`](crate::cfe::sb::MsgId_Atom) constant,
+//! and each macro named `*_TOPICID` a [`u16`] constant,
+//! so that, e.g., `SAMPLE_APP_CMD_MID` from `sample_app_msgids.h`
+//! is available as `n2o4::msgids::SAMPLE_APP_CMD_MID`.
+//!
+//! If `RUST_CFS_SYS_MSGID_HEADERS` isn't set, this module is empty.
+
+#![allow(non_upper_case_globals)]
+#![allow(missing_docs)]
+
+// ${OUT_DIR}/msgids.rs is generated by the build script
+include!(concat!(env!("OUT_DIR"), "/msgids.rs"));
@@ -0,0 +1,169 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Directory listing.
+
+use super::file::{stat, FileStat};
+use super::fs::{OsPath, MAX_FILE_NAME};
+use super::{I32Ext, ObjectId, OsalError};
+use crate::sys::*;
+use crate::utils::CStrBuf;
+
+/// An open directory, which closes itself when dropped.
+///
+/// Iterating over a `Directory` yields its entries (other than `.` and `..`),
+/// each of which remembers the directory's path,
+/// so that it can find its own full path and metadata:
+///
+/// ```rust,ignore
+/// for entry in Directory::open(&OsPath::new("/cf/logs")?)? {
+///     let entry = entry?;
+///     let size = entry.stat()?.file_size;
+///     // ...
+/// }
+/// ```
+///
+/// Wraps `osal_id_t`.
+#[doc(alias = "osal_id_t")]
+#[derive(Debug)]
+pub struct Directory {
+    id:   osal_id_t,
+    path: OsPath,
+    done: bool,
+}
+
+impl Directory {
+    /// Opens the directory at `path` for reading.
+    ///
+    /// Wraps `OS_DirectoryOpen`.
+    #[doc(alias = "OS_DirectoryOpen")]
+    #[inline]
+    pub fn open(path: &OsPath) -> Result<Self, OsalError> {
+        let mut id = X_OS_OBJECT_ID_UNDEFINED;
+
+        // Safety: path is a null-terminated string which outlasts the call.
+        unsafe { OS_DirectoryOpen(&mut id, path.as_cstrbuf().as_ptr()) }.as_osal_status()?;
+
+        Ok(Directory { id, path: *path, done: false })
+    }
+
+    /// Returns the path the directory was opened with.
+    #[inline]
+    pub fn path(&self) -> &OsPath {
+        &self.path
+    }
+
+    /// Reads the next entry from the directory,
+    /// returning `Ok(None)` once there are no more.
+    ///
+    /// Unlike iteration, this doesn't skip `.` and `..`.
+    ///
+    /// Wraps `OS_DirectoryRead`.
+    #[doc(alias = "OS_DirectoryRead")]
+    #[inline]
+    pub fn read(&mut self) -> Result<Option<DirEntry>, OsalError> {
+        let mut dirent = os_dirent_t { FileName: [0; MAX_FILE_NAME] };
+
+        // Safety: any bit-pattern is a valid os_dirent_t,
+        // and dirent outlasts the call.
+        match unsafe { OS_DirectoryRead(self.id, &mut dirent) } {
+            // OSAL reports the end of the directory as a generic error:
+            OS_ERROR => Ok(None),
+            status => {
+                status.as_osal_status()?;
+                Ok(Some(DirEntry {
+                    name:   CStrBuf::new_into(dirent.FileName),
+                    parent: self.path,
+                }))
+            }
+        }
+    }
+
+    /// Moves back to the start of the directory,
+    /// so that iteration starts over.
+    ///
+    /// Wraps `OS_DirectoryRewind`.
+    #[doc(alias = "OS_DirectoryRewind")]
+    #[inline]
+    pub fn rewind(&mut self) -> Result<(), OsalError> {
+        unsafe { OS_DirectoryRewind(self.id) }.as_osal_status()?;
+        self.done = false;
+        Ok(())
+    }
+
+    /// Returns the [`ObjectId`] for the directory.
+    #[inline]
+    pub fn as_id(&self) -> ObjectId {
+        ObjectId { id: self.id }
+    }
+}
+
+/// Wraps `OS_DirectoryClose`.
+impl Drop for Directory {
+    #[inline]
+    fn drop(&mut self) {
+        crate::ffi_errors::check_osal(unsafe { OS_DirectoryClose(self.id) });
+    }
+}
+
+/// Yields the directory's entries, skipping `.` and `..`.
+///
+/// Iteration ends after the end of the directory or the first error.
+impl Iterator for Directory {
+    type Item = Result<DirEntry, OsalError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            match self.read() {
+                Ok(Some(entry)) if entry.name == "." || entry.name == ".." => continue,
+                Ok(Some(entry)) => return Some(Ok(entry)),
+                Ok(None) => self.done = true,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// An entry in a [`Directory`].
+///
+/// Wraps `os_dirent_t`.
+#[doc(alias = "os_dirent_t")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DirEntry {
+    name:   CStrBuf<MAX_FILE_NAME>,
+    parent: OsPath,
+}
+
+impl DirEntry {
+    /// Returns the entry's name within its directory.
+    #[inline]
+    pub fn file_name(&self) -> &CStrBuf<MAX_FILE_NAME> {
+        &self.name
+    }
+
+    /// Returns the full path of the entry (the directory's path joined with its name).
+    ///
+    /// Fails as [`OsPath::join`] does if the result isn't a valid path.
+    #[inline]
+    pub fn path(&self) -> Result<OsPath, OsalError> {
+        let name = self.name.to_str().map_err(|_| OsalError::OS_FS_ERR_PATH_INVALID)?;
+        self.parent.join(name)
+    }
+
+    /// Obtains information about the entry, using its [full path](Self::path).
+    ///
+    /// The information isn't cached; each call queries the file system afresh.
+    ///
+    /// Wraps `OS_stat`.
+    #[doc(alias = "OS_stat")]
+    #[inline]
+    pub fn stat(&self) -> Result<FileStat, OsalError> {
+        stat(&self.path()?)
+    }
+}
@@ -67,6 +67,59 @@ osal_err_consts! {
     OS_FS_ERR_PATH_INVALID, "FS path invalid",
 }
 
+impl From<NegativeI32> for OsalError {
+    #[inline]
+    fn from(code: NegativeI32) -> Self {
+        OsalError { code }
+    }
+}
+
+/// This also provides an (infallible) `TryFrom<OsalError>` for [`NegativeI32`].
+impl From<OsalError> for NegativeI32 {
+    #[inline]
+    fn from(err: OsalError) -> Self {
+        err.code
+    }
+}
+
+impl core::fmt::Display for OsalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "OSAL error {}", self.code.as_i32())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OsalError {}
+
+/// Common OSAL failure codes,
+/// for use where a bare [`NegativeI32`] is needed.
+impl NegativeI32 {
+    /// Failed execution.
+    ///
+    /// Wraps `OS_ERROR`.
+    pub const OS_ERROR: Self = OsalError::OS_ERROR.code;
+
+    /// Invalid pointer.
+    ///
+    /// Wraps `OS_INVALID_POINTER`.
+    pub const OS_INVALID_POINTER: Self = OsalError::OS_INVALID_POINTER.code;
+
+    /// Not implemented.
+    ///
+    /// Wraps `OS_ERR_NOT_IMPLEMENTED`.
+    pub const OS_ERR_NOT_IMPLEMENTED: Self = OsalError::OS_ERR_NOT_IMPLEMENTED.code;
+
+    /// Invalid Size.
+    ///
+    /// Wraps `OS_ERR_INVALID_SIZE`.
+    pub const OS_ERR_INVALID_SIZE: Self = OsalError::OS_ERR_INVALID_SIZE.code;
+
+    /// Invalid argument value (other than ID or size).
+    ///
+    /// Wraps `OS_ERR_INVALID_ARGUMENT`.
+    pub const OS_ERR_INVALID_ARGUMENT: Self = OsalError::OS_ERR_INVALID_ARGUMENT.code;
+}
+
 pub(crate) trait I32Ext {
     /// If the `i32` represents an OSAL error value, returns `Err`;
     /// otherwise, returns `Ok`.
@@ -4,9 +4,10 @@
 //! Types and methods for interacting with files.
 
 use crate::sys::*;
+use crate::utils::bitflags_impl;
 use core::convert::TryFrom;
 use core::ffi::{c_void, CStr};
-use core::ops::{BitOr, BitOrAssign, Deref, DerefMut};
+use core::ops::{Deref, DerefMut};
 
 use super::*;
 
@@ -16,9 +17,18 @@ use super::*;
 #[doc(alias = "osal_id_t")]
 #[derive(Clone, Debug)]
 pub struct File {
-    id: osal_id_t,
+    pub(crate) id: osal_id_t,
 }
 
+/// OSAL file handles aren't tied to the task that opened them.
+unsafe impl Send for File {}
+
+/// OSAL serializes access to its object table,
+/// so file operations may be called concurrently from multiple tasks
+/// (though reads and writes through the same handle share a file position,
+/// as with POSIX file descriptors).
+unsafe impl Sync for File {}
+
 impl File {
     /// Opens a handle to a file, possibly creating the file if [`FileFlags::CREATE`] is set.
     ///
@@ -71,8 +81,20 @@ impl File {
     #[doc(alias = "OS_write")]
     #[inline]
     pub fn write(&mut self, buf: &[u8]) -> Result<usize, OsalError> {
-        let buffer = buf.as_ptr() as *const c_void;
-        let retval = unsafe { OS_write(self.id, buffer, buf.len()) }.as_osal_status()?;
+        unsafe { self.write_raw(buf.as_ptr(), buf.len()) }
+    }
+
+    /// As with [`write`](Self::write), but taking the bytes as a pointer and length,
+    /// for memory that mustn't be viewed as a `&[u8]` in Rust
+    /// (such as a message whose payload may have padding).
+    ///
+    /// Safety: `buf` must point to `len` bytes that are valid for reads.
+    pub(crate) unsafe fn write_raw(
+        &mut self,
+        buf: *const u8,
+        len: usize,
+    ) -> Result<usize, OsalError> {
+        let retval = OS_write(self.id, buf as *const c_void, len).as_osal_status()?;
 
         Ok(retval as usize)
     }
@@ -85,6 +107,7 @@ impl File {
     ///
     /// Wraps `OS_lseek`.
     #[doc(alias = "OS_lseek")]
+    #[deprecated(note = "use `seek`, which takes a `SeekFrom`")]
     #[inline]
     pub fn lseek(&mut self, offset: i32, whence: SeekReference) -> Result<u32, OsalError> {
         let retval = unsafe { OS_lseek(self.id, offset, whence as u32) }.as_osal_status()?;
@@ -92,6 +115,31 @@ impl File {
         Ok(retval as u32)
     }
 
+    /// Seeks the file handle `self` to the location `pos`.
+    ///
+    /// Returns the new offset from the file start if successful,
+    /// the error code if not.
+    /// As `OS_lseek` takes and returns 32-bit signed offsets,
+    /// offsets outside the range of an [`i32`]
+    /// result in [`OS_ERR_INVALID_SIZE`](OsalError::OS_ERR_INVALID_SIZE)
+    /// without the file position being changed.
+    ///
+    /// Wraps `OS_lseek`.
+    #[doc(alias = "OS_lseek")]
+    #[inline]
+    pub fn seek(&mut self, pos: SeekFrom) -> Result<u64, OsalError> {
+        let (offset, whence) = match pos {
+            SeekFrom::Start(offset) => (i32::try_from(offset).ok(), SeekReference::Beginning),
+            SeekFrom::Current(offset) => (i32::try_from(offset).ok(), SeekReference::Current),
+            SeekFrom::End(offset) => (i32::try_from(offset).ok(), SeekReference::End),
+        };
+        let offset = offset.ok_or(OsalError::OS_ERR_INVALID_SIZE)?;
+
+        let retval = unsafe { OS_lseek(self.id, offset, whence as u32) }.as_osal_status()?;
+
+        Ok(retval as u64)
+    }
+
     /// Closes the file handle `self`.
     ///
     /// Wraps `OS_close`.
@@ -163,7 +211,7 @@ impl DerefMut for OwnedFile {
 impl Drop for OwnedFile {
     #[inline]
     fn drop(&mut self) {
-        let _ = unsafe { OS_close(self.f.id) };
+        crate::ffi_errors::check_osal(unsafe { OS_close(self.f.id) });
     }
 }
 
@@ -180,7 +228,7 @@ impl From<OwnedFile> for File {
 
 /// The access mode a file should be opened with.
 ///
-/// Used with [`File::open_create`].
+/// Used with [`File::open_create`] and [`OpenOptions`].
 #[repr(i32)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[non_exhaustive]
@@ -241,25 +289,123 @@ impl FileFlags {
     };
 }
 
-impl BitOr<FileFlags> for FileFlags {
-    type Output = Self;
+bitflags_impl!(FileFlags, flag: OS_file_flag_t);
+
+/// Options for opening a [`File`], in the manner of `std::fs::OpenOptions`:
+///
+/// ```rust,ignore
+/// let f = OpenOptions::new().create(true).truncate(true).read_write().open(c"/ram/log.txt")?;
+/// ```
+///
+/// This is an alternative to passing [`FileFlags`] and an [`AccessMode`]
+/// to [`File::open_create`] directly.
+/// By default, files are opened read-only, and neither created nor truncated.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OpenOptions {
+    flags:       FileFlags,
+    access_mode: AccessMode,
+}
+
+impl OpenOptions {
+    /// Creates a set of options with the defaults
+    /// (read-only access, no [flags](FileFlags::NONE)).
+    #[inline]
+    pub const fn new() -> Self {
+        OpenOptions {
+            flags:       FileFlags::NONE,
+            access_mode: AccessMode::ReadOnly,
+        }
+    }
+
+    /// Sets whether to create the file if it doesn't exist ([`FileFlags::CREATE`]).
+    #[doc(alias = "OS_FILE_FLAG_CREATE")]
+    #[inline]
+    pub fn create(&mut self, create: bool) -> &mut Self {
+        self.set_flag(FileFlags::CREATE, create)
+    }
+
+    /// Sets whether to truncate the file if it exists ([`FileFlags::TRUNCATE`]).
+    #[doc(alias = "OS_FILE_FLAG_TRUNCATE")]
+    #[inline]
+    pub fn truncate(&mut self, truncate: bool) -> &mut Self {
+        self.set_flag(FileFlags::TRUNCATE, truncate)
+    }
+
+    /// Sets the access mode to open the file with.
+    #[inline]
+    pub fn access_mode(&mut self, access_mode: AccessMode) -> &mut Self {
+        self.access_mode = access_mode;
+        self
+    }
+
+    /// Opens the file for [reading only](AccessMode::ReadOnly).
+    #[doc(alias = "OS_READ_ONLY")]
+    #[inline]
+    pub fn read_only(&mut self) -> &mut Self {
+        self.access_mode(AccessMode::ReadOnly)
+    }
+
+    /// Opens the file for [writing only](AccessMode::WriteOnly).
+    #[doc(alias = "OS_WRITE_ONLY")]
+    #[inline]
+    pub fn write_only(&mut self) -> &mut Self {
+        self.access_mode(AccessMode::WriteOnly)
+    }
+
+    /// Opens the file for [reading and writing](AccessMode::ReadWrite).
+    #[doc(alias = "OS_READ_WRITE")]
+    #[inline]
+    pub fn read_write(&mut self) -> &mut Self {
+        self.access_mode(AccessMode::ReadWrite)
+    }
+
+    /// Returns the flags that will be used to open the file.
+    #[inline]
+    pub const fn flags(&self) -> FileFlags {
+        self.flags
+    }
+
+    /// Returns the access mode the file will be opened with.
+    #[inline]
+    pub const fn mode(&self) -> AccessMode {
+        self.access_mode
+    }
+
+    /// Opens the file at `path` with these options.
+    ///
+    /// Wraps `OS_OpenCreate`.
+    #[doc(alias = "OS_OpenCreate")]
+    #[inline]
+    pub fn open<S: AsRef<CStr> + ?Sized>(&self, path: &S) -> Result<File, OsalError> {
+        File::open_create(path, self.flags, self.access_mode)
+    }
+
+    /// Like [`open`](Self::open), but returning an [`OwnedFile`] on success instead.
+    #[doc(alias = "OS_OpenCreate")]
+    #[inline]
+    pub fn open_owned<S: AsRef<CStr> + ?Sized>(&self, path: &S) -> Result<OwnedFile, OsalError> {
+        OwnedFile::open_create(path, self.flags, self.access_mode)
+    }
 
+    /// Sets or clears `flag`.
     #[inline]
-    fn bitor(self, rhs: FileFlags) -> Self::Output {
-        FileFlags { flag: self.flag | rhs.flag }
+    fn set_flag(&mut self, flag: FileFlags, set: bool) -> &mut Self {
+        self.flags.set(flag, set);
+        self
     }
 }
 
-impl BitOrAssign for FileFlags {
+impl Default for OpenOptions {
     #[inline]
-    fn bitor_assign(&mut self, rhs: Self) {
-        *self = *self | rhs;
+    fn default() -> Self {
+        Self::new()
     }
 }
 
 /// The reference point for a seek offset.
 ///
-/// Used as the `whence` argument of [`File::lseek`].
+/// Used as the `whence` argument of [`File::lseek`];
+/// [`File::seek`] takes a [`SeekFrom`] instead.
 #[repr(u32)]
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 #[non_exhaustive]
@@ -283,51 +429,122 @@ pub enum SeekReference {
     End       = OS_SEEK_END,
 }
 
+/// A location in a file, as passed to [`File::seek`].
+///
+/// This mirrors `std::io::SeekFrom`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SeekFrom {
+    /// The given number of bytes from the beginning of the file.
+    ///
+    /// Corresponds to `OS_SEEK_SET`.
+    #[doc(alias = "OS_SEEK_SET")]
+    Start(u64),
+
+    /// The given number of bytes (possibly negative) from the current location in the file.
+    ///
+    /// Corresponds to `OS_SEEK_CUR`.
+    #[doc(alias = "OS_SEEK_CUR")]
+    Current(i64),
+
+    /// The given number of bytes (possibly negative) from the end of the file.
+    ///
+    /// Corresponds to `OS_SEEK_END`.
+    #[doc(alias = "OS_SEEK_END")]
+    End(i64),
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::SeekFrom> for SeekFrom {
+    #[inline]
+    fn from(pos: std::io::SeekFrom) -> Self {
+        match pos {
+            std::io::SeekFrom::Start(offset) => SeekFrom::Start(offset),
+            std::io::SeekFrom::Current(offset) => SeekFrom::Current(offset),
+            std::io::SeekFrom::End(offset) => SeekFrom::End(offset),
+        }
+    }
+}
+
 /// Information about a file or directory.
 ///
 /// Semantically equivalent to `os_fstat_t`.
 #[doc(alias = "os_fstat_t")]
 pub struct FileStat {
-    /// The file's mode bits.
-    ///
-    /// For the individual bits,
-    /// see [`DIR`](Self::DIR), [`READ`](Self::READ),
-    /// [`WRITE`](Self::WRITE), and [`EXEC`](Self::EXEC).
-    pub file_mode_bits: u32,
+    /// The file's type and permissions.
+    pub file_mode: FileMode,
 
     /// The time the file was last modified.
-    pub file_time:      super::OSTime,
+    pub file_time: super::OSTime,
 
     /// The size of the file, in bytes.
-    pub file_size:      usize,
+    pub file_size: usize,
 }
 
-impl FileStat {
+/// The type and permissions of a file, as reported by [`stat`].
+///
+/// This is a bitfield; elements may be combined using the `|` operator.
+///
+/// Wraps the `OS_FILESTAT_MODE_*` bits of `os_fstat_t`'s `FileModeBits`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FileMode {
+    bits: u32,
+}
+
+impl FileMode {
+    /// No bits set.
+    pub const NONE: FileMode = Self { bits: 0 };
+
     /// Set if the file is a directory.
     ///
     /// Wraps `OS_FILESTAT_MODE_DIR`.
     #[doc(alias = "OS_FILESTAT_MODE_DIR")]
-    pub const DIR: u32 = OS_FILESTAT_MODE_DIR;
+    pub const DIR: FileMode = Self { bits: OS_FILESTAT_MODE_DIR };
 
     /// Set if the file is readable.
     ///
     /// Wraps `OS_FILESTAT_MODE_READ`.
     #[doc(alias = "OS_FILESTAT_MODE_READ")]
-    pub const READ: u32 = OS_FILESTAT_MODE_READ;
+    pub const READ: FileMode = Self { bits: OS_FILESTAT_MODE_READ };
 
     /// Set if the file is writable.
     ///
     /// Wraps `OS_FILESTAT_MODE_WRITE`.
     #[doc(alias = "OS_FILESTAT_MODE_WRITE")]
-    pub const WRITE: u32 = OS_FILESTAT_MODE_WRITE;
+    pub const WRITE: FileMode = Self { bits: OS_FILESTAT_MODE_WRITE };
 
     /// Set if the file is executable.
     ///
     /// Wraps `OS_FILESTAT_MODE_EXEC`.
     #[doc(alias = "OS_FILESTAT_MODE_EXEC")]
-    pub const EXEC: u32 = OS_FILESTAT_MODE_EXEC;
+    pub const EXEC: FileMode = Self { bits: OS_FILESTAT_MODE_EXEC };
+
+    /// Returns whether the file is a directory.
+    #[inline]
+    pub const fn is_dir(&self) -> bool {
+        self.contains(Self::DIR)
+    }
+
+    /// Returns whether the file is readable.
+    #[inline]
+    pub const fn readable(&self) -> bool {
+        self.contains(Self::READ)
+    }
+
+    /// Returns whether the file is writable.
+    #[inline]
+    pub const fn writable(&self) -> bool {
+        self.contains(Self::WRITE)
+    }
+
+    /// Returns whether the file is executable.
+    #[inline]
+    pub const fn executable(&self) -> bool {
+        self.contains(Self::EXEC)
+    }
 }
 
+bitflags_impl!(FileMode, bits: u32);
+
 /// Obtains information about the file or directory at `path`.
 ///
 /// Wraps `OS_stat`.
@@ -346,9 +563,9 @@ pub fn stat<S: AsRef<CStr>>(path: &S) -> Result<FileStat, OsalError> {
     unsafe { OS_stat(path, &mut filestats) }.as_osal_status()?;
 
     Ok(FileStat {
-        file_mode_bits: filestats.FileModeBits,
-        file_time:      OSTime::from_os_time(filestats.FileTime),
-        file_size:      filestats.FileSize,
+        file_mode: FileMode::from_bits(filestats.FileModeBits),
+        file_time: OSTime::from_os_time(filestats.FileTime),
+        file_size: filestats.FileSize,
     })
 }
 
@@ -30,3 +30,176 @@ pub fn translate_path<S: AsRef<CStr>>(
 
     Ok(CStrBuf::new_into(local_path))
 }
+
+/// Space usage of a file-system volume, as returned by [`stat_volume`].
+///
+/// Wraps `OS_statvfs_t`.
+#[doc(alias = "OS_statvfs_t")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FileSysStat {
+    /// The size of a block, in bytes.
+    pub block_size: usize,
+
+    /// The number of blocks in the volume.
+    pub total_blocks: usize,
+
+    /// The number of free blocks in the volume.
+    pub blocks_free: usize,
+}
+
+impl FileSysStat {
+    /// Returns the size of the volume, in bytes.
+    #[inline]
+    pub fn total_bytes(&self) -> u64 {
+        self.block_size as u64 * self.total_blocks as u64
+    }
+
+    /// Returns the free space in the volume, in bytes.
+    #[inline]
+    pub fn free_bytes(&self) -> u64 {
+        self.block_size as u64 * self.blocks_free as u64
+    }
+}
+
+/// Returns the space usage of the file-system volume mounted at `name`
+/// (a virtual mount point, e.g., `"/ram"`).
+///
+/// Wraps `OS_FileSysStatVolume`.
+#[doc(alias = "OS_FileSysStatVolume")]
+#[inline]
+pub fn stat_volume<S: AsRef<CStr> + ?Sized>(name: &S) -> Result<FileSysStat, OsalError> {
+    let mut statbuf = OS_statvfs_t {
+        block_size:   0,
+        total_blocks: 0,
+        blocks_free:  0,
+    };
+
+    unsafe { OS_FileSysStatVolume(name.as_ref().as_ptr(), &mut statbuf) }.as_osal_status()?;
+
+    Ok(FileSysStat {
+        block_size:   statbuf.block_size,
+        total_blocks: statbuf.total_blocks,
+        blocks_free:  statbuf.blocks_free,
+    })
+}
+
+/// The maximum length of a single file name (i.e., path component),
+/// including terminating NUL character.
+///
+/// Wraps `OS_MAX_FILE_NAME`.
+#[doc(alias = "OS_MAX_FILE_NAME")]
+pub const MAX_FILE_NAME: usize = OS_MAX_FILE_NAME as usize;
+
+/// An OSAL file-system path of at most `SIZE` bytes (including null terminator).
+///
+/// All the constructors and path-manipulation methods check that
+/// the resulting path fits, so instances are always valid
+/// to pass to OSAL APIs.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OsPath<const SIZE: usize = MAX_PATH_LEN> {
+    buf: CStrBuf<SIZE>,
+}
+
+impl<const SIZE: usize> OsPath<SIZE> {
+    /// Creates a new path from `path`.
+    ///
+    /// Returns [`OS_FS_ERR_PATH_TOO_LONG`](OsalError::OS_FS_ERR_PATH_TOO_LONG)
+    /// if `path` doesn't fit,
+    /// or [`OS_FS_ERR_PATH_INVALID`](OsalError::OS_FS_ERR_PATH_INVALID)
+    /// if `path` contains a null byte.
+    #[inline]
+    pub fn new(path: &str) -> Result<Self, OsalError> {
+        let mut p = Self { buf: CStrBuf::new_u8(b"") };
+        p.push_str(path)?;
+        Ok(p)
+    }
+
+    /// Returns a new path consisting of `self` followed by the path component `name`,
+    /// with a `/` separator inserted if needed.
+    ///
+    /// Returns [`OS_FS_ERR_NAME_TOO_LONG`](OsalError::OS_FS_ERR_NAME_TOO_LONG)
+    /// if `name` is longer than a file name can be,
+    /// [`OS_FS_ERR_PATH_TOO_LONG`](OsalError::OS_FS_ERR_PATH_TOO_LONG)
+    /// if the resulting path doesn't fit,
+    /// or [`OS_FS_ERR_PATH_INVALID`](OsalError::OS_FS_ERR_PATH_INVALID)
+    /// if `name` contains a null byte or a `/`.
+    #[inline]
+    pub fn join(&self, name: &str) -> Result<Self, OsalError> {
+        if name.contains('/') {
+            return Err(OsalError::OS_FS_ERR_PATH_INVALID);
+        }
+        if name.len() >= MAX_FILE_NAME {
+            return Err(OsalError::OS_FS_ERR_NAME_TOO_LONG);
+        }
+
+        let mut p = *self;
+        if !p.buf.is_empty() && !p.as_str().ends_with('/') {
+            p.push_str("/")?;
+        }
+        p.push_str(name)?;
+        Ok(p)
+    }
+
+    /// Returns the final component of the path, if there is one.
+    #[inline]
+    pub fn file_name(&self) -> Option<&str> {
+        match self.as_str().rsplit('/').next() {
+            Some("") | None => None,
+            Some(name) => Some(name),
+        }
+    }
+
+    /// Returns the extension of [`file_name`](Self::file_name), if there is one.
+    ///
+    /// The extension is the part after the last `.`,
+    /// unless the only `.` is at the start of the file name.
+    #[inline]
+    pub fn extension(&self) -> Option<&str> {
+        let name = self.file_name()?;
+        match name.rfind('.') {
+            Some(0) | None => None,
+            Some(idx) => Some(&name[idx + 1..]),
+        }
+    }
+
+    /// Returns the path as a `&str`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        self.buf.as_str()
+    }
+
+    /// Returns a reference to the underlying [`CStrBuf`].
+    #[inline]
+    pub const fn as_cstrbuf(&self) -> &CStrBuf<SIZE> {
+        &self.buf
+    }
+
+    /// Appends `s` to the path,
+    /// leaving the path unchanged if `s` doesn't fit or contains a null byte.
+    fn push_str(&mut self, s: &str) -> Result<(), OsalError> {
+        use core::fmt::Write;
+
+        if s.contains('\0') {
+            return Err(OsalError::OS_FS_ERR_PATH_INVALID);
+        }
+        if self.buf.len() + s.len() >= SIZE {
+            return Err(OsalError::OS_FS_ERR_PATH_TOO_LONG);
+        }
+
+        self.buf.write_str(s).map_err(|_| OsalError::OS_FS_ERR_PATH_TOO_LONG)
+    }
+}
+
+impl<const SIZE: usize> AsRef<CStr> for OsPath<SIZE> {
+    #[inline]
+    fn as_ref(&self) -> &CStr {
+        self.buf.as_ref()
+    }
+}
+
+impl<const SIZE: usize> core::fmt::Display for OsPath<SIZE> {
+    #[inline]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Display::fmt(&self.buf, f)
+    }
+}
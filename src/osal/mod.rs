@@ -9,12 +9,81 @@ use core::ffi::c_ulong;
 use crate::utils::NegativeI32;
 pub(crate) use error::I32Ext;
 
+/// Defines `$type_name`, a wrapper around the OSAL object handle `$wrapped_type`
+/// that deletes the object (using `$destructor`) when dropped.
+///
+/// If `new: $constructor(...)` is given, `$type_name::new` is defined too,
+/// calling `$wrapped_type::new` with a name and the listed parameters.
+macro_rules! owned_variant {
+    ($(#[$attr:meta])* $type_name:ident $(<$g:ident: $bound:ident>)?, $wrapped_type:ident, $destructor:ident; new: $constructor:ident($($cparam:ident: $ctype:ty),*)) => {
+        owned_variant!($(#[$attr])* $type_name $(<$g: $bound>)?, $wrapped_type, $destructor);
+
+        impl $(<$g: $bound>)? $type_name $(<$g>)? {
+            #[doc = concat!("Like [`", stringify!($wrapped_type), "::new`], but returns an [`", stringify!($type_name), "`] instead.")]
+            #[doc = "\n\n"]
+            #[doc = concat!("Wraps `", stringify!($constructor), "`.")]
+            #[inline]
+            pub fn new<S: AsRef<CStr> + ?Sized>(name: &S $(, $cparam: $ctype)*) -> Result<Self, OsalError> {
+                <$wrapped_type $(<$g>)?>::new(name $(, $cparam)*).map(|inner| $type_name { inner })
+            }
+        }
+    };
+    ($(#[$attr:meta])* $type_name:ident $(<$g:ident: $bound:ident>)?, $wrapped_type:ident, $destructor:ident) => {
+        #[doc = concat!("A wrapper around a [`", stringify!($wrapped_type), "`] that automatically deletes it when dropped.")]
+        $(#[$attr])*
+        #[derive(Debug)]
+        pub struct $type_name $(<$g: $bound>)? {
+            inner: $wrapped_type $(<$g>)?,
+        }
+
+        impl $(<$g: $bound>)? core::ops::Deref for $type_name $(<$g>)? {
+            type Target = $wrapped_type $(<$g>)?;
+
+            #[inline]
+            fn deref(&self) -> &Self::Target {
+                &self.inner
+            }
+        }
+
+        #[doc = concat!("Wraps `", stringify!($destructor), "`.")]
+        impl $(<$g: $bound>)? Drop for $type_name $(<$g>)? {
+            #[inline]
+            fn drop(&mut self) {
+                crate::ffi_errors::check_osal(unsafe { $destructor(self.inner.id) });
+            }
+        }
+
+        #[doc = concat!("Takes ownership of a [`", stringify!($wrapped_type), "`], so it gets deleted when the [`", stringify!($type_name), "`] is dropped.")]
+        impl $(<$g: $bound>)? From<$wrapped_type $(<$g>)?> for $type_name $(<$g>)? {
+            #[inline]
+            fn from(inner: $wrapped_type $(<$g>)?) -> Self {
+                $type_name { inner }
+            }
+        }
+
+        #[doc = concat!("Takes the wrapped [`", stringify!($wrapped_type), "`] out of the [`", stringify!($type_name), "`] wrapper without deleting it.")]
+        impl $(<$g: $bound>)? From<$type_name $(<$g>)?> for $wrapped_type $(<$g>)? {
+            #[inline]
+            fn from(owned: $type_name $(<$g>)?) -> Self {
+                let owned = core::mem::ManuallyDrop::new(owned);
+                unsafe { core::ptr::read(&owned.inner) }
+            }
+        }
+    };
+}
+
 pub(crate) mod error;
+
+pub mod dir;
 pub mod file;
 pub mod fs;
+pub mod queue;
+pub mod select;
 pub mod socket;
+pub mod storage;
 pub mod sync;
 pub mod task;
+pub mod timer;
 
 // NOTE: much of the following will probably get moved to submodules as `osal` gets flushed out.
 
@@ -34,7 +103,8 @@ pub const MAX_NAME_LEN: usize = sys::OS_MAX_API_NAME as usize;
 pub const MAX_PATH_LEN: usize = sys::OS_MAX_PATH_LEN as usize;
 
 /// An error code, as returned by many OSAL API functions.
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
 pub struct OsalError {
     /// Numeric error code from OSAL.
     pub code: NegativeI32,
@@ -200,6 +270,93 @@ macro_rules! time_methods {
 time_methods!(OSTime, tm, "time");
 time_methods!(OSTimeInterval, int, "interval");
 
+/// An [`OSTime`] broken down into a calendar date and a time of day (in UTC).
+///
+/// Dates use the proleptic Gregorian calendar.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CalendarTime {
+    /// The year.
+    pub year:       i32,
+    /// The month of the year (1 to 12).
+    pub month:      u8,
+    /// The day of the month (1 to 31).
+    pub day:        u8,
+    /// The hour of the day (0 to 23).
+    pub hour:       u8,
+    /// The minute of the hour (0 to 59).
+    pub minute:     u8,
+    /// The second of the minute (0 to 59).
+    pub second:     u8,
+    /// The fraction of the second, in nanoseconds.
+    pub nanosecond: u32,
+}
+
+impl OSTime {
+    /// Breaks the time down into a calendar date and time of day,
+    /// taking it to be relative to the POSIX epoch (1970-01-01 00:00:00 UTC),
+    /// as it is for OSAL's POSIX implementation (e.g., for [file times](file::FileStat)).
+    pub fn to_calendar(&self) -> CalendarTime {
+        const TICKS_PER_SEC: i64 = sys::OS_TIME_TICKS_PER_SECOND as i64;
+        const TICK_NS: i64 = sys::OS_TIME_TICK_RESOLUTION_NS as i64;
+        const SECS_PER_DAY: i64 = 86_400;
+
+        // OSAL splits the time into whole seconds and ticks truncated toward zero,
+        // so before the epoch, the ticks may be negative;
+        // this counts such times back from the start of their second instead.
+        let frac = self.fractional_part();
+        let secs = self.total_seconds() + frac.div_euclid(TICKS_PER_SEC);
+        let ticks = frac.rem_euclid(TICKS_PER_SEC);
+        let days = secs.div_euclid(SECS_PER_DAY);
+        let secs_of_day = secs.rem_euclid(SECS_PER_DAY);
+
+        // Converts days since the epoch to a civil date,
+        // per Howard Hinnant's `civil_from_days` algorithm:
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        CalendarTime {
+            year:       year as i32,
+            month:      month as u8,
+            day:        day as u8,
+            hour:       (secs_of_day / 3600) as u8,
+            minute:     (secs_of_day / 60 % 60) as u8,
+            second:     (secs_of_day % 60) as u8,
+            nanosecond: (ticks * TICK_NS) as u32,
+        }
+    }
+}
+
+/// Converts a [`Duration`](core::time::Duration) into an interval,
+/// saturating at the longest interval OSAL can represent
+/// (`i64::MAX` ticks of 100&nbsp;ns, or about 29,000 years).
+impl From<core::time::Duration> for OSTimeInterval {
+    #[inline]
+    fn from(d: core::time::Duration) -> Self {
+        const TICKS_PER_SECOND: i64 = sys::OS_TIME_TICKS_PER_SECOND as i64;
+        const TICK_NS: u32 = (1_000_000_000 / TICKS_PER_SECOND) as u32;
+
+        // The largest whole number of seconds, and the most nanoseconds beyond that,
+        // that fit in an `i64` count of ticks:
+        const MAX_SECONDS: i64 = i64::MAX / TICKS_PER_SECOND;
+        const MAX_EXTRA_NANOS: u32 = (i64::MAX % TICKS_PER_SECOND) as u32 * TICK_NS;
+
+        match d.as_secs() {
+            s if s < MAX_SECONDS as u64 => Self::from_nanoseconds(s as i64, d.subsec_nanos()),
+            s if s == MAX_SECONDS as u64 => {
+                Self::from_nanoseconds(MAX_SECONDS, d.subsec_nanos().min(MAX_EXTRA_NANOS))
+            }
+            _ => Self::from_nanoseconds(MAX_SECONDS, MAX_EXTRA_NANOS),
+        }
+    }
+}
+
 /// Quick generation of an implementation of arithmetic for times, time intervals.
 macro_rules! arith_impl {
     ($trait:ident, $lhs:ident, $rhs:ident, $method:ident, $result:ident, $func:ident, $func_cname:literal) => {
@@ -234,6 +391,19 @@ mod time_arith_impls {
     arith_impl!(Sub, OSTimeInterval, OSTimeInterval, sub, OSTimeInterval, SHIM_OS_TimeSubtract, "OS_TimeSubtract");
 }
 
+/// Returns the current local time, as kept by OSAL.
+///
+/// Wraps `OS_GetLocalTime`.
+#[doc(alias = "OS_GetLocalTime")]
+#[inline]
+pub fn local_time() -> Result<OSTime, OsalError> {
+    let mut tm = sys::OS_time_t { ticks: 0 };
+
+    unsafe { sys::OS_GetLocalTime(&mut tm) }.as_osal_status()?;
+
+    Ok(OSTime { tm })
+}
+
 /// An identifier for an object managed by OSAL.
 ///
 /// Wraps `osal_id_t`.
@@ -258,7 +428,7 @@ impl ObjectId {
     #[doc(alias = "OS_ObjectIdDefined")]
     #[inline]
     pub fn is_defined(&self) -> bool {
-        unsafe { sys::SHIM_OS_ObjectIdDefined(self.id) }
+        crate::shims::OS_ObjectIdDefined(self.id)
     }
 
     /// Returns the object type of `self` as a raw
@@ -278,7 +448,7 @@ impl From<c_ulong> for ObjectId {
     #[inline]
     fn from(val: c_ulong) -> ObjectId {
         ObjectId {
-            id: unsafe { sys::SHIM_OS_ObjectIdFromInteger(val) },
+            id: crate::shims::OS_ObjectIdFromInteger(val),
         }
     }
 }
@@ -288,7 +458,7 @@ impl From<ObjectId> for c_ulong {
     #[doc(alias = "OS_ObjectIdToInteger")]
     #[inline]
     fn from(oid: ObjectId) -> c_ulong {
-        unsafe { sys::SHIM_OS_ObjectIdToInteger(oid.id) }
+        crate::shims::OS_ObjectIdToInteger(oid.id)
     }
 }
 
@@ -297,7 +467,7 @@ impl PartialEq<Self> for ObjectId {
     #[doc(alias = "OS_ObjectIdEqual")]
     #[inline]
     fn eq(&self, other_id: &Self) -> bool {
-        unsafe { sys::SHIM_OS_ObjectIdEqual(self.id, other_id.id) }
+        crate::shims::OS_ObjectIdEqual(self.id, other_id.id)
     }
 }
 
@@ -308,6 +478,15 @@ impl Eq for ObjectId {}
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub struct ObjectTypeConvertError {}
 
+impl core::fmt::Display for ObjectTypeConvertError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("object ID is not of the expected type")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ObjectTypeConvertError {}
+
 /// Utility function to convert a "timeout or `None`" option into an `i32`,
 /// as used by multiple OSAL functions as a timeout value
 /// (where negative values mean "wait indefinitely").
@@ -315,3 +494,12 @@ pub struct ObjectTypeConvertError {}
 pub(crate) fn as_timeout(timeout: Option<u32>) -> i32 {
     timeout.map(|t| t.min(i32::MAX as u32) as i32).unwrap_or(-1)
 }
+
+/// Converts `interval` into a count of milliseconds, as taken by many OSAL functions,
+/// rounding up (so as not to wait for less than `interval`)
+/// and saturating at `0` and [`u32::MAX`].
+#[inline]
+pub(crate) fn as_millis(interval: OSTimeInterval) -> u32 {
+    let micros = interval.total_microseconds().max(0) as u64;
+    ((micros + 999) / 1000).min(u32::MAX as u64) as u32
+}
@@ -0,0 +1,300 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Message queues.
+
+use crate::sys::*;
+
+use super::*;
+use crate::utils::CStrBuf;
+use core::ffi::{c_char, c_void, CStr};
+use core::marker::PhantomData;
+use core::mem::{size_of, MaybeUninit};
+
+/// A handle for a message queue carrying values of type `T`.
+///
+/// Values are copied into and out of the queue byte-for-byte,
+/// so `T` should be a plain-data type, such as a `#[repr(C)]` struct
+/// matching what any C code using the same queue sends.
+///
+/// Wraps `osal_id_t`.
+#[doc(alias = "osal_id_t")]
+#[derive(Debug)]
+pub struct Queue<T: Copy> {
+    pub(crate) id: osal_id_t,
+    _t:            PhantomData<fn(T) -> T>,
+}
+
+impl<T: Copy> Clone for Queue<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Queue { id: self.id, _t: PhantomData }
+    }
+}
+
+/// OSAL queues exist to pass data between tasks,
+/// so their handles aren't tied to the task that created them.
+unsafe impl<T: Copy + Send> Send for Queue<T> {}
+
+/// OSAL queue operations may be called concurrently from any number of tasks.
+unsafe impl<T: Copy + Send> Sync for Queue<T> {}
+
+impl<T: Copy> Queue<T> {
+    /// Attempts to create a new queue with name `name`
+    /// holding up to `depth` values; if successful, returns a handle to it.
+    ///
+    /// Wraps `OS_QueueCreate`.
+    #[doc(alias = "OS_QueueCreate")]
+    #[inline]
+    pub fn new<S: AsRef<CStr> + ?Sized>(name: &S, depth: usize) -> Result<Self, OsalError> {
+        let mut id: osal_id_t = X_OS_OBJECT_ID_UNDEFINED;
+
+        unsafe { OS_QueueCreate(&mut id, name.as_ref().as_ptr(), depth, size_of::<T>(), 0) }
+            .as_osal_status()?;
+
+        if id != X_OS_OBJECT_ID_UNDEFINED {
+            Ok(Queue { id, _t: PhantomData })
+        } else {
+            Err(OsalError::OS_ERR_INVALID_ID)
+        }
+    }
+
+    /// If a queue with the name `name` exists, returns `Ok(Some(`a handle to it`))`.
+    ///
+    /// If no queue with the name exists, returns `Ok(None)`.
+    /// If an error occurred, returns `Err(err_code)`.
+    ///
+    /// This allows queues created elsewhere (including by C applications) to be used from Rust.
+    ///
+    /// # Safety
+    ///
+    /// Every value put in the queue by other code must be a valid `T`.
+    ///
+    /// Wraps `OS_QueueGetIdByName`.
+    #[doc(alias = "OS_QueueGetIdByName")]
+    #[inline]
+    pub unsafe fn find_by_name<S: AsRef<CStr> + ?Sized>(
+        name: &S,
+    ) -> Result<Option<Self>, OsalError> {
+        let mut id: osal_id_t = X_OS_OBJECT_ID_UNDEFINED;
+
+        match OS_QueueGetIdByName(&mut id, name.as_ref().as_ptr()).as_osal_status() {
+            Ok(_) => {
+                if id != X_OS_OBJECT_ID_UNDEFINED {
+                    Ok(Some(Queue { id, _t: PhantomData }))
+                } else {
+                    Err(OsalError::OS_ERR_INVALID_ID)
+                }
+            }
+            Err(OsalError::OS_ERR_NAME_NOT_FOUND) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Puts a copy of `value` at the end of the queue.
+    ///
+    /// Returns [`OS_QUEUE_FULL`](OsalError::OS_QUEUE_FULL) if the queue is full.
+    ///
+    /// Wraps `OS_QueuePut`.
+    #[doc(alias = "OS_QueuePut")]
+    #[inline]
+    pub fn put(&self, value: &T) -> Result<(), OsalError> {
+        let data = value as *const T as *const c_void;
+
+        unsafe { OS_QueuePut(self.id, data, size_of::<T>(), 0) }.as_osal_status()?;
+
+        Ok(())
+    }
+
+    /// The backend of the `get` methods.
+    #[inline]
+    fn get_with_timeout(&self, timeout: i32) -> Result<T, OsalError> {
+        let mut value = MaybeUninit::<T>::uninit();
+        let mut size_copied: usize = 0;
+
+        unsafe {
+            OS_QueueGet(
+                self.id,
+                value.as_mut_ptr() as *mut c_void,
+                size_of::<T>(),
+                &mut size_copied,
+                timeout,
+            )
+        }
+        .as_osal_status()?;
+
+        if size_copied != size_of::<T>() {
+            return Err(OsalError::OS_QUEUE_INVALID_SIZE);
+        }
+
+        // Safety: all of `value` was written by OSAL,
+        // and it's up to the creator of the handle that what was written is a valid `T`.
+        Ok(unsafe { value.assume_init() })
+    }
+
+    /// Takes the value at the front of the queue, blocking until there is one if need be.
+    ///
+    /// Wraps `OS_QueueGet`.
+    #[doc(alias = "OS_QueueGet")]
+    #[inline]
+    pub fn get(&self) -> Result<T, OsalError> {
+        self.get_with_timeout(OS_PEND)
+    }
+
+    /// Takes the value at the front of the queue, if there is one, without blocking.
+    ///
+    /// Returns `Ok(None)` if the queue is empty.
+    ///
+    /// Wraps `OS_QueueGet`.
+    #[doc(alias = "OS_QueueGet")]
+    #[inline]
+    pub fn try_get(&self) -> Result<Option<T>, OsalError> {
+        match self.get_with_timeout(OS_CHECK as i32) {
+            Ok(value) => Ok(Some(value)),
+            Err(OsalError::OS_QUEUE_EMPTY) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Takes the value at the front of the queue,
+    /// blocking for up to `timeout_ms` milliseconds if need be.
+    ///
+    /// Returns `Ok(None)` if the request timed out.
+    ///
+    /// Wraps `OS_QueueGet`.
+    #[doc(alias = "OS_QueueGet")]
+    #[inline]
+    pub fn timed_get(&self, timeout_ms: u32) -> Result<Option<T>, OsalError> {
+        let timeout = timeout_ms.min(i32::MAX as u32) as i32;
+
+        match self.get_with_timeout(timeout) {
+            Ok(value) => Ok(Some(value)),
+            Err(OsalError::OS_QUEUE_TIMEOUT) | Err(OsalError::OS_QUEUE_EMPTY) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Takes the value at the front of the queue,
+    /// blocking until (OSAL's [local time](local_time)) `deadline` if need be.
+    ///
+    /// Returns `Ok(None)` if the deadline passed without a value arriving;
+    /// if `deadline` has already passed, this doesn't block.
+    ///
+    /// Wraps `OS_GetLocalTime` and `OS_QueueGet`.
+    #[doc(alias("OS_GetLocalTime", "OS_QueueGet"))]
+    #[inline]
+    pub fn get_until(&self, deadline: OSTime) -> Result<Option<T>, OsalError> {
+        let now = local_time()?;
+
+        if deadline <= now {
+            self.try_get()
+        } else {
+            self.timed_get(as_millis(deadline - now))
+        }
+    }
+
+    /// Returns an iterator taking values from the front of the queue, without blocking,
+    /// until the queue is empty.
+    ///
+    /// Errors other than the queue being empty are yielded,
+    /// after which the iterator stops.
+    ///
+    /// ```rust,ignore
+    /// for sample in samples.drain() {
+    ///     process(sample?);
+    /// }
+    /// ```
+    ///
+    /// Wraps `OS_QueueGet`.
+    #[doc(alias = "OS_QueueGet")]
+    #[inline]
+    pub fn drain(&self) -> Drain<'_, T> {
+        Drain { queue: self, done: false }
+    }
+
+    /// Deletes the queue.
+    ///
+    /// Wraps `OS_QueueDelete`.
+    #[doc(alias = "OS_QueueDelete")]
+    #[inline]
+    pub fn delete(self) -> Result<(), OsalError> {
+        unsafe { OS_QueueDelete(self.id) }.as_osal_status()?;
+
+        Ok(())
+    }
+
+    /// If successful, returns details about the queue.
+    ///
+    /// Wraps `OS_QueueGetInfo`.
+    #[doc(alias = "OS_QueueGetInfo")]
+    #[inline]
+    pub fn info(&self) -> Result<QueueProperties, OsalError> {
+        let mut props = OS_queue_prop_t {
+            name:    [b'\0' as c_char; MAX_NAME_LEN],
+            creator: X_OS_OBJECT_ID_UNDEFINED,
+        };
+
+        unsafe { OS_QueueGetInfo(self.id, &mut props) }.as_osal_status()?;
+
+        Ok(QueueProperties {
+            name:    CStrBuf::new(&props.name),
+            creator: ObjectId { id: props.creator },
+        })
+    }
+
+    /// Returns the [`ObjectId`] for the queue.
+    #[inline]
+    pub fn as_id(&self) -> ObjectId {
+        ObjectId { id: self.id }
+    }
+}
+
+owned_variant!(OwnedQueue<T: Copy>, Queue, OS_QueueDelete; new: OS_QueueCreate(depth: usize));
+
+/// An iterator taking the values in a [`Queue`]
+/// until it's empty.
+///
+/// Created by [`Queue::drain`].
+#[derive(Debug)]
+pub struct Drain<'a, T: Copy> {
+    queue: &'a Queue<T>,
+    done:  bool,
+}
+
+impl<'a, T: Copy> Iterator for Drain<'a, T> {
+    type Item = Result<T, OsalError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        match self.queue.try_get() {
+            Ok(Some(value)) => Some(Ok(value)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+impl<'a, T: Copy> core::iter::FusedIterator for Drain<'a, T> {}
+
+/// The properties associated with a [`Queue`].
+///
+/// Substitutes for `OS_queue_prop_t`.
+#[doc(alias = "OS_queue_prop_t")]
+#[derive(Debug)]
+pub struct QueueProperties {
+    /// The queue's name.
+    pub name: CStrBuf<{ MAX_NAME_LEN }>,
+
+    /// The queue's creator.
+    pub creator: ObjectId,
+}
@@ -0,0 +1,87 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Waiting for file handles and sockets to become ready for I/O.
+
+use crate::sys::*;
+use crate::utils::bitflags_impl;
+
+use super::*;
+
+/// A set of stream states that can be [waited on](select_single).
+///
+/// This is a bitfield; elements may be combined using the `|` operator.
+///
+/// Wraps `OS_StreamState_t`.
+#[doc(alias = "OS_StreamState_t")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StreamState {
+    flags: uint32,
+}
+
+impl StreamState {
+    /// No states.
+    pub const NONE: StreamState = Self { flags: 0 };
+
+    /// Whether the stream is bound.
+    ///
+    /// Wraps `OS_STREAM_STATE_BOUND`.
+    #[doc(alias = "OS_STREAM_STATE_BOUND")]
+    pub const BOUND: StreamState = Self {
+        flags: OS_StreamState_t_OS_STREAM_STATE_BOUND,
+    };
+
+    /// Whether the stream is connected.
+    ///
+    /// Wraps `OS_STREAM_STATE_CONNECTED`.
+    #[doc(alias = "OS_STREAM_STATE_CONNECTED")]
+    pub const CONNECTED: StreamState = Self {
+        flags: OS_StreamState_t_OS_STREAM_STATE_CONNECTED,
+    };
+
+    /// Whether the stream can be read from without blocking
+    /// (for a listening socket, whether a connection can be accepted).
+    ///
+    /// Wraps `OS_STREAM_STATE_READABLE`.
+    #[doc(alias = "OS_STREAM_STATE_READABLE")]
+    pub const READABLE: StreamState = Self {
+        flags: OS_StreamState_t_OS_STREAM_STATE_READABLE,
+    };
+
+    /// Whether the stream can be written to without blocking.
+    ///
+    /// Wraps `OS_STREAM_STATE_WRITABLE`.
+    #[doc(alias = "OS_STREAM_STATE_WRITABLE")]
+    pub const WRITABLE: StreamState = Self {
+        flags: OS_StreamState_t_OS_STREAM_STATE_WRITABLE,
+    };
+}
+
+bitflags_impl!(StreamState, flags: uint32);
+
+/// Waits for the file handle or socket `id` to be in (any of) the states in `wanted`
+/// ([`READABLE`](StreamState::READABLE) and/or [`WRITABLE`](StreamState::WRITABLE)).
+///
+/// Waits for up to `timeout_ms.min(`[`i32::MAX`]`)` milliseconds
+/// (or indefinitely if `timeout_ms` is `None`);
+/// a timeout of `Some(0)` just checks the current state.
+///
+/// On success, returns the subset of `wanted` that the object is now in,
+/// which is [`NONE`](StreamState::NONE) if the timeout expired.
+///
+/// Wraps `OS_SelectSingle`.
+#[doc(alias = "OS_SelectSingle")]
+#[inline]
+pub fn select_single(
+    id: ObjectId,
+    wanted: StreamState,
+    timeout_ms: Option<u32>,
+) -> Result<StreamState, OsalError> {
+    let mut flags = wanted.flags;
+
+    match unsafe { OS_SelectSingle(id.id, &mut flags, as_timeout(timeout_ms)) }.as_osal_status() {
+        Ok(_) => Ok(StreamState { flags } & wanted),
+        Err(OsalError::OS_ERROR_TIMEOUT) => Ok(StreamState::NONE),
+        Err(e) => Err(e),
+    }
+}
@@ -9,7 +9,7 @@ use core::ffi::{c_char, c_void, CStr};
 use core::marker::PhantomData;
 use core::mem::ManuallyDrop;
 
-use super::{I32Ext, ObjectId, OsalError};
+use super::{I32Ext, ObjectId, ObjectTypeConvertError, OsalError};
 use crate::sealed_traits::{SocketDomainSealed, SocketRoleSealed, SocketTypeSealed};
 use crate::utils::CStrBuf;
 
@@ -110,18 +110,26 @@ pub struct SockAddr<T> {
 impl<T: SocketDomain> SockAddr<T> {
     /// Tries to initialize a [`SockAddr`] with the given [domain](`SocketDomain`), address, and port.
     ///
-    /// `address` is a network address in string form (e.g., `"192.0.2.5"`, `"2001:db8:0:2::5"`)
+    /// `address` is a network address in string form (e.g., `"192.0.2.5"`, `"2001:db8:0:2::5"`);
+    /// OSAL doesn't resolve host names (but see `SockAddr::resolve`, with the `std` feature).
     ///
     /// Wraps `OS_SocketAddrInit`, `OS_SocketAddrFromString`, and `OS_SocketAddrSetPort`.
     #[doc(alias = "OS_SocketAddrInit")]
     #[inline]
-    pub fn new<S: AsRef<CStr> + ?Sized>(network_address: &S, port: u16) -> Result<Self, OsalError> {
+    pub fn new<S: AsRef<CStr> + ?Sized>(
+        network_address: &S,
+        port: u16,
+    ) -> Result<Self, SockAddrError> {
         let network_address = network_address.as_ref().as_ptr();
         let mut addr: OS_SockAddr_t = dummy_sock_addr();
 
-        unsafe { OS_SocketAddrInit(&mut addr, T::DOMAIN) }.as_osal_status()?;
+        unsafe { OS_SocketAddrInit(&mut addr, T::DOMAIN) }
+            .as_osal_status()
+            .map_err(SockAddrError::UnsupportedDomain)?;
 
-        unsafe { OS_SocketAddrFromString(&mut addr, network_address) }.as_osal_status()?;
+        unsafe { OS_SocketAddrFromString(&mut addr, network_address) }
+            .as_osal_status()
+            .map_err(SockAddrError::InvalidAddress)?;
 
         unsafe { OS_SocketAddrSetPort(&mut addr, port) }.as_osal_status()?;
 
@@ -131,6 +139,33 @@ impl<T: SocketDomain> SockAddr<T> {
         })
     }
 
+    /// Tries to create a [`SockAddr`] for the host named `host`
+    /// (or with the network address `host`) and port `port`,
+    /// using the host system's name resolution (as OSAL has none),
+    /// and taking the first address found in the right [domain](SocketDomain).
+    ///
+    /// Returns [`HostNotFound`](SockAddrError::HostNotFound)
+    /// if resolution fails or finds no address in the domain.
+    ///
+    /// Wraps `OS_SocketAddrInit`, `OS_SocketAddrFromString`, and `OS_SocketAddrSetPort`.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn resolve(host: &str, port: u16) -> Result<Self, SockAddrError> {
+        use std::net::ToSocketAddrs;
+
+        let want_ipv4 = T::DOMAIN == OS_SocketDomain_t_OS_SocketDomain_INET;
+        let ip = (host, port)
+            .to_socket_addrs()
+            .map_err(|_| SockAddrError::HostNotFound)?
+            .map(|addr| addr.ip())
+            .find(|ip| ip.is_ipv4() == want_ipv4)
+            .ok_or(SockAddrError::HostNotFound)?;
+
+        // IP addresses never contain NUL characters:
+        let ip = std::ffi::CString::new(ip.to_string()).map_err(|_| SockAddrError::HostNotFound)?;
+        Self::new(&ip, port)
+    }
+
     /// Tries to write the address's host address to `buf` as a C-style string.
     ///
     /// Wraps `OS_SocketAddrToString`.
@@ -185,6 +220,125 @@ impl<T: SocketDomain> SockAddr<T> {
     }
 }
 
+/// Error: creating a [`SockAddr`] failed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SockAddrError {
+    /// OSAL doesn't support the address's [domain](SocketDomain)
+    /// (e.g., IPv6, if OSAL was built without it).
+    UnsupportedDomain(OsalError),
+
+    /// The network address isn't a valid address in the domain.
+    InvalidAddress(OsalError),
+
+    /// The host name couldn't be resolved to an address in the domain
+    /// (see `SockAddr::resolve`).
+    HostNotFound,
+
+    /// Some other OSAL call failed.
+    Osal(OsalError),
+}
+
+impl From<OsalError> for SockAddrError {
+    #[inline]
+    fn from(err: OsalError) -> Self {
+        SockAddrError::Osal(err)
+    }
+}
+
+/// Converts to the underlying OSAL error
+/// ([`OS_ERR_NAME_NOT_FOUND`](OsalError::OS_ERR_NAME_NOT_FOUND) for
+/// [`HostNotFound`](SockAddrError::HostNotFound)).
+impl From<SockAddrError> for OsalError {
+    #[inline]
+    fn from(err: SockAddrError) -> Self {
+        match err {
+            SockAddrError::UnsupportedDomain(err)
+            | SockAddrError::InvalidAddress(err)
+            | SockAddrError::Osal(err) => err,
+            SockAddrError::HostNotFound => OsalError::OS_ERR_NAME_NOT_FOUND,
+        }
+    }
+}
+
+impl From<SockAddrError> for crate::error::Error {
+    #[inline]
+    fn from(err: SockAddrError) -> Self {
+        crate::error::Error::Osal(err.into())
+    }
+}
+
+impl core::fmt::Display for SockAddrError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SockAddrError::UnsupportedDomain(err) => {
+                write!(f, "socket domain not supported by OSAL: {}", err)
+            }
+            SockAddrError::InvalidAddress(err) => write!(f, "invalid network address: {}", err),
+            SockAddrError::HostNotFound => f.write_str("host name not found"),
+            SockAddrError::Osal(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SockAddrError {}
+
+/// An error from a socket operation that can time out
+/// ([`accept`](Socket::accept), [`connect`](EarlySocket::connect), or [`recv`](Socket::recv)).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SocketError {
+    /// The operation didn't complete within its timeout
+    /// (OSAL's [`OS_ERROR_TIMEOUT`](OsalError::OS_ERROR_TIMEOUT)).
+    TimedOut,
+
+    /// Some other OSAL error.
+    Osal(OsalError),
+}
+
+/// Converts [`OS_ERROR_TIMEOUT`](OsalError::OS_ERROR_TIMEOUT) to
+/// [`TimedOut`](SocketError::TimedOut), and anything else to [`Osal`](SocketError::Osal).
+impl From<OsalError> for SocketError {
+    #[inline]
+    fn from(err: OsalError) -> Self {
+        match err {
+            OsalError::OS_ERROR_TIMEOUT => SocketError::TimedOut,
+            err => SocketError::Osal(err),
+        }
+    }
+}
+
+/// Converts to the underlying OSAL error
+/// ([`OS_ERROR_TIMEOUT`](OsalError::OS_ERROR_TIMEOUT) for
+/// [`TimedOut`](SocketError::TimedOut)).
+impl From<SocketError> for OsalError {
+    #[inline]
+    fn from(err: SocketError) -> Self {
+        match err {
+            SocketError::TimedOut => OsalError::OS_ERROR_TIMEOUT,
+            SocketError::Osal(err) => err,
+        }
+    }
+}
+
+impl From<SocketError> for crate::error::Error {
+    #[inline]
+    fn from(err: SocketError) -> Self {
+        crate::error::Error::Osal(err.into())
+    }
+}
+
+impl core::fmt::Display for SocketError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SocketError::TimedOut => f.write_str("socket operation timed out"),
+            SocketError::Osal(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SocketError {}
+
 /// A network socket that has been created, but has yet to be either
 /// [connected to a peer](EarlySocket::connect) or [bound to a local port](EarlySocket::bind).
 ///
@@ -220,7 +374,8 @@ impl<D: SocketDomain, T: SocketType> EarlySocket<D, T> {
     /// Connects a socket to a peer at the remote address `addr`.
     ///
     /// Waits up to `timeout_ms.min(`[`i32::MAX`]`)` milliseconds for a successful connection,
-    /// or indefinitely if `timeout_ms` is `None`.
+    /// or indefinitely if `timeout_ms` is `None`;
+    /// if the wait times out, returns [`SocketError::TimedOut`].
     ///
     /// Wraps `OS_SocketConnect`.
     #[doc(alias = "OS_SocketConnect")]
@@ -229,7 +384,7 @@ impl<D: SocketDomain, T: SocketType> EarlySocket<D, T> {
         self,
         addr: &SockAddr<D>,
         timeout_ms: Option<u32>,
-    ) -> Result<Socket<D, T, Connected>, OsalError> {
+    ) -> Result<Socket<D, T, Connected>, SocketError> {
         let timeout: i32 = super::as_timeout(timeout_ms);
 
         unsafe { OS_SocketConnect(self.sock_id, &addr.inner, timeout) }.as_osal_status()?;
@@ -285,6 +440,28 @@ impl<D: SocketDomain, T: SocketType> EarlySocket<D, T> {
         }
     }
 
+    /// Creates an [`EarlySocket`] from an OSAL ID,
+    /// if OSAL confirms the ID is that of a stream object (a file or socket).
+    ///
+    /// Otherwise, returns `Err(ObjectTypeConvertError)`.
+    ///
+    /// Wraps `OS_IdentifyObject`.
+    ///
+    /// # Safety
+    ///
+    /// OSAL offers no way to tell a socket from a file,
+    /// or to query a socket's [domain](SocketDomain), [type](SocketType), or point in socket lifecycle.
+    ///
+    /// It is the programmer's responsibility to ensure that any OSAL ID passed
+    /// to `try_from_id` corresponds to a socket (not a file)
+    /// with the correct socket domain, type, and state.
+    #[doc(alias = "OS_IdentifyObject")]
+    #[inline]
+    pub unsafe fn try_from_id(id: ObjectId) -> Result<Self, ObjectTypeConvertError> {
+        check_stream(id)?;
+        Ok(Self::from_id(id))
+    }
+
     /// If successful, returns information about the socket.
     ///
     /// Wraps `OS_SocketGetInfo`.
@@ -305,13 +482,21 @@ impl<D: SocketDomain, T: SocketType> EarlySocket<D, T> {
     }
 }
 
+/// Returns `Ok(())` if `id` is that of an OSAL stream object (which sockets are).
+#[inline]
+fn check_stream(id: ObjectId) -> Result<(), ObjectTypeConvertError> {
+    if id.obj_type() == OS_OBJECT_TYPE_OS_STREAM {
+        Ok(())
+    } else {
+        Err(ObjectTypeConvertError {})
+    }
+}
+
 /// Wraps `OS_close`.
 impl<D: SocketDomain, T: SocketType> Drop for EarlySocket<D, T> {
     #[inline]
     fn drop(&mut self) {
-        unsafe {
-            let _ = OS_close(self.sock_id);
-        }
+        crate::ffi_errors::check_osal(unsafe { OS_close(self.sock_id) });
     }
 }
 
@@ -333,6 +518,14 @@ pub struct Socket<D: SocketDomain, T: SocketType, R: SocketRole> {
     phantom:   PhantomData<(D, T, R)>,
 }
 
+/// OSAL sockets aren't tied to the task that created them,
+/// so a socket may be sent to another task.
+///
+/// [`Socket`] is _not_ [`Sync`]: cloning a socket marks the original as cloned
+/// (so [`close`](Socket::close) can refuse to close a socket that's still in use),
+/// which isn't done atomically.
+unsafe impl<D: SocketDomain, T: SocketType, R: SocketRole> Send for Socket<D, T, R> {}
+
 impl<D: SocketDomain, T: SocketType, R: SocketRole> Clone for Socket<D, T, R> {
     fn clone(&self) -> Self {
         self.is_cloned.set(true);
@@ -378,6 +571,33 @@ impl<D: SocketDomain, T: SocketType, R: SocketRole> Socket<D, T, R> {
         }
     }
 
+    /// Creates a [`Socket`] from an OSAL ID,
+    /// if OSAL confirms the ID is that of a stream object (a file or socket).
+    ///
+    /// Otherwise, returns `Err(ObjectTypeConvertError)`.
+    ///
+    /// `exclusive` has the same meaning as for [`from_id`](Self::from_id).
+    ///
+    /// Wraps `OS_IdentifyObject`.
+    ///
+    /// # Safety
+    ///
+    /// OSAL offers no way to tell a socket from a file,
+    /// or to query a socket's [domain](SocketDomain), [type](SocketType), or point in socket lifecycle.
+    ///
+    /// It is the programmer's responsibility to ensure that any OSAL ID passed
+    /// to `try_from_id` corresponds to a socket (not a file)
+    /// with the correct socket domain, type, state, and exclusivity.
+    #[doc(alias = "OS_IdentifyObject")]
+    #[inline]
+    pub unsafe fn try_from_id(
+        id: ObjectId,
+        exclusive: bool,
+    ) -> Result<Self, ObjectTypeConvertError> {
+        check_stream(id)?;
+        Ok(Self::from_id(id, exclusive))
+    }
+
     /// If the [`Socket`] hasn't been [`Clone`]d, closes the socket.
     ///
     /// If the socket has been cloned, returns `Err(OS_ERR_OBJECT_IN_USE)`.
@@ -492,12 +712,13 @@ impl<D: SocketDomain> Socket<D, Stream, Connected> {
 
 impl<D: SocketDomain> Socket<D, Datagram, Connected> {
     /// Tries to change the socket's remote endpoint to `addr`,
-    /// waiting up to `timeout_ms.min(`[`i32::MAX`]`)` to complete the operation.
+    /// waiting up to `timeout_ms.min(`[`i32::MAX`]`)` to complete the operation;
+    /// if the wait times out, returns [`SocketError::TimedOut`].
     ///
     /// Wraps `OS_SocketConnect`.
     #[doc(alias = "OS_SocketConnect")]
     #[inline]
-    pub fn connect(&self, addr: &SockAddr<D>, timeout_ms: Option<u32>) -> Result<(), OsalError> {
+    pub fn connect(&self, addr: &SockAddr<D>, timeout_ms: Option<u32>) -> Result<(), SocketError> {
         let timeout = super::as_timeout(timeout_ms);
 
         unsafe { OS_SocketConnect(self.sock_id, &addr.inner, timeout) }.as_osal_status()?;
@@ -510,7 +731,8 @@ impl<D: SocketDomain> Socket<D, Stream, Bound> {
     /// Waits for and accepts the next incoming connection on the given listening socket.
     ///
     /// Waits for up to `timeout_ms.min(`[`i32::MAX`]`)` milliseconds for a new connection
-    /// (or indefinitely if `timeout_ms` is `None`).
+    /// (or indefinitely if `timeout_ms` is `None`);
+    /// if none arrives in time, returns [`SocketError::TimedOut`].
     ///
     /// On success, results a socket for the new connection
     /// and the address of the connection's remote side.
@@ -521,7 +743,7 @@ impl<D: SocketDomain> Socket<D, Stream, Bound> {
     pub fn accept(
         &self,
         timeout_ms: Option<u32>,
-    ) -> Result<(Socket<D, Stream, Connected>, SockAddr<D>), OsalError> {
+    ) -> Result<(Socket<D, Stream, Connected>, SockAddr<D>), SocketError> {
         let mut connsock_id: osal_id_t = X_OS_OBJECT_ID_UNDEFINED;
         let mut conn_addr = dummy_sock_addr();
         let timeout = super::as_timeout(timeout_ms);
@@ -542,7 +764,7 @@ impl<D: SocketDomain> Socket<D, Stream, Bound> {
                 },
             ))
         } else {
-            Err(OsalError::OS_ERR_INVALID_ID)
+            Err(SocketError::Osal(OsalError::OS_ERR_INVALID_ID))
         }
     }
 }
@@ -575,7 +797,8 @@ impl<D: SocketDomain> Socket<D, Datagram, Bound> {
     /// Reads a message from the bound datagram socket into `buf`.
     ///
     /// Wait up to `timeout_ms.min(`[`i32::MAX`]`)` milliseconds for a message
-    /// (or indefinitely if `timeout_ms` is `None`).
+    /// (or indefinitely if `timeout_ms` is `None`);
+    /// if none arrives in time, returns [`SocketError::TimedOut`].
     ///
     /// On success, returns the number of bytes written to `buf`
     /// and the address of the message sender.
@@ -587,7 +810,7 @@ impl<D: SocketDomain> Socket<D, Datagram, Bound> {
         &self,
         buf: &mut [u8],
         timeout_ms: Option<u32>,
-    ) -> Result<(usize, SockAddr<D>), OsalError> {
+    ) -> Result<(usize, SockAddr<D>), SocketError> {
         let mut remote_addr = dummy_sock_addr();
         let timeout = super::as_timeout(timeout_ms);
 
@@ -0,0 +1,150 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Keeping directories from filling up.
+//!
+//! A [`StorageManager`] watches over a directory that an application keeps adding files to
+//! (such as the output directory of a [`Recorder`](crate::cfe::recorder::Recorder)),
+//! deleting the oldest files whenever the directory goes over its quota
+//! or the volume holding it runs low on space:
+//!
+//! ```rust,ignore
+//! let storage = StorageManager {
+//!     dir:            OsPath::new("/ram/rec")?,
+//!     volume:         OsPath::new("/ram")?,
+//!     max_bytes:      512 * 1024,
+//!     min_free_bytes: 64 * 1024,
+//! };
+//!
+//! // e.g., on each housekeeping request:
+//! let cleanup = storage.enforce()?;
+//! hk.files_deleted += cleanup.files_removed;
+//! ```
+
+use super::dir::Directory;
+use super::file::{file_open_check, remove};
+use super::fs::{stat_volume, OsPath};
+use super::{OSTime, OsalError};
+
+/// Limits on the space used by the files in a directory,
+/// which [`enforce`](Self::enforce) applies by deleting the oldest files.
+///
+/// Only the files directly in the directory are counted or deleted;
+/// subdirectories and their contents are left alone,
+/// as are files that are open (e.g., the file currently being written).
+#[derive(Clone, Copy, Debug)]
+pub struct StorageManager {
+    /// The directory to manage.
+    pub dir: OsPath,
+
+    /// The mount point of the volume holding `dir`
+    /// (for checking [`min_free_bytes`](Self::min_free_bytes)).
+    pub volume: OsPath,
+
+    /// The most bytes the files in `dir` may take up in total, or 0 for no limit.
+    pub max_bytes: u64,
+
+    /// The fewest bytes that must be left free on `volume`, or 0 for no minimum.
+    pub min_free_bytes: u64,
+}
+
+/// The files in a directory managed by a [`StorageManager`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct DirUsage {
+    /// The number of files.
+    pub files: u32,
+
+    /// The total size of the files, in bytes.
+    pub bytes: u64,
+}
+
+/// What [`StorageManager::enforce`] deleted.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Cleanup {
+    /// The number of files deleted.
+    pub files_removed: u32,
+
+    /// The total size of the files deleted, in bytes.
+    pub bytes_freed: u64,
+}
+
+/// The oldest deletable file found while scanning a directory.
+struct Oldest {
+    path: OsPath,
+    time: OSTime,
+    size: u64,
+}
+
+impl StorageManager {
+    /// Returns how many files are in the directory and how much space they take up.
+    ///
+    /// Wraps `OS_DirectoryOpen`, `OS_DirectoryRead`, and `OS_stat`.
+    #[doc(alias("OS_DirectoryOpen", "OS_DirectoryRead", "OS_stat"))]
+    #[inline]
+    pub fn usage(&self) -> Result<DirUsage, OsalError> {
+        self.scan().map(|(usage, _)| usage)
+    }
+
+    /// Deletes the oldest files in the directory (by modification time)
+    /// until it's within [`max_bytes`](Self::max_bytes)
+    /// and the volume has at least [`min_free_bytes`](Self::min_free_bytes) free,
+    /// or until there's nothing left to delete.
+    ///
+    /// If deleting a file fails, returns the error without deleting any more.
+    ///
+    /// Wraps `OS_DirectoryOpen`, `OS_DirectoryRead`, `OS_stat`, `OS_FileOpenCheck`,
+    /// `OS_FileSysStatVolume`, and `OS_remove`.
+    #[doc(alias("OS_FileSysStatVolume", "OS_remove"))]
+    pub fn enforce(&self) -> Result<Cleanup, OsalError> {
+        let mut cleanup = Cleanup::default();
+
+        loop {
+            let (usage, oldest) = self.scan()?;
+
+            let over_quota = self.max_bytes != 0 && usage.bytes > self.max_bytes;
+            let low_space = self.min_free_bytes != 0
+                && stat_volume(&self.volume)?.free_bytes() < self.min_free_bytes;
+
+            let oldest = match oldest {
+                Some(oldest) if over_quota || low_space => oldest,
+                _ => return Ok(cleanup),
+            };
+
+            remove(&oldest.path)?;
+            cleanup.files_removed += 1;
+            cleanup.bytes_freed += oldest.size;
+        }
+    }
+
+    /// Totals up the files in the directory,
+    /// and finds the oldest one that isn't open.
+    fn scan(&self) -> Result<(DirUsage, Option<Oldest>), OsalError> {
+        let mut usage = DirUsage::default();
+        let mut oldest: Option<Oldest> = None;
+
+        for entry in Directory::open(&self.dir)? {
+            let entry = entry?;
+            let stat = entry.stat()?;
+            if stat.file_mode.is_dir() {
+                continue;
+            }
+
+            let size = stat.file_size as u64;
+            usage.files += 1;
+            usage.bytes += size;
+
+            if oldest.as_ref().map_or(true, |o| stat.file_time < o.time) {
+                let path = entry.path()?;
+                if !file_open_check(&path)? {
+                    oldest = Some(Oldest {
+                        path,
+                        time: stat.file_time,
+                        size,
+                    });
+                }
+            }
+        }
+
+        Ok((usage, oldest))
+    }
+}
@@ -18,6 +18,13 @@ pub struct BinSem {
     pub(crate) id: osal_id_t,
 }
 
+/// OSAL semaphores exist to synchronize tasks,
+/// so their handles aren't tied to the task that created them.
+unsafe impl Send for BinSem {}
+
+/// OSAL semaphore operations may be called concurrently from any number of tasks.
+unsafe impl Sync for BinSem {}
+
 impl BinSem {
     /// Attempts to create a new binary semaphore with name `name`,
     /// initial value `initial_value`, and default options; if successful, returns it.
@@ -93,6 +100,16 @@ impl BinSem {
         }
     }
 
+    /// Like [`timed_wait`](Self::timed_wait), but with the timeout given as an interval
+    /// (rounded up to the next millisecond).
+    ///
+    /// Wraps `OS_BinSemTimedWait`.
+    #[doc(alias = "OS_BinSemTimedWait")]
+    #[inline]
+    pub fn timed_wait_for(&self, timeout: OSTimeInterval) -> Result<bool, OsalError> {
+        self.timed_wait(super::as_millis(timeout))
+    }
+
     /// Increments the semaphore value, waking up a blocked thread (if any).
     ///
     /// Wraps `OS_BinSemGive`.
@@ -203,6 +220,13 @@ pub struct CountSem {
     pub(crate) id: osal_id_t,
 }
 
+/// OSAL semaphores exist to synchronize tasks,
+/// so their handles aren't tied to the task that created them.
+unsafe impl Send for CountSem {}
+
+/// OSAL semaphore operations may be called concurrently from any number of tasks.
+unsafe impl Sync for CountSem {}
+
 impl CountSem {
     /// Attempts to create a new counting semaphore with name `sem_name`,
     /// initial value `initial_value`, and default options;
@@ -279,6 +303,16 @@ impl CountSem {
         }
     }
 
+    /// Like [`timed_wait`](Self::timed_wait), but with the timeout given as an interval
+    /// (rounded up to the next millisecond).
+    ///
+    /// Wraps `OS_CountSemTimedWait`.
+    #[doc(alias = "OS_CountSemTimedWait")]
+    #[inline]
+    pub fn timed_wait_for(&self, timeout: OSTimeInterval) -> Result<bool, OsalError> {
+        self.timed_wait(super::as_millis(timeout))
+    }
+
     /// Increments the semaphore value, waking up a blocked thread (if any).
     ///
     /// Wraps `OS_CountSemGive`.
@@ -366,6 +400,13 @@ pub struct MutSem {
     pub(crate) id: osal_id_t,
 }
 
+/// OSAL semaphores exist to synchronize tasks,
+/// so their handles aren't tied to the task that created them.
+unsafe impl Send for MutSem {}
+
+/// OSAL semaphore operations may be called concurrently from any number of tasks.
+unsafe impl Sync for MutSem {}
+
 impl MutSem {
     /// Tries to create a new mutex semaphore with default options;
     /// if successful, returns it.
@@ -425,7 +466,9 @@ impl MutSem {
         }
         impl Drop for MutGuard {
             fn drop(&mut self) {
-                let _ = self.x.give();
+                if self.x.give().is_err() {
+                    crate::ffi_errors::record(crate::ffi_errors::Subsystem::Osal);
+                }
             }
         }
 
@@ -523,52 +566,6 @@ pub struct MutSemProperties {
     pub creator: ObjectId,
 }
 
-macro_rules! owned_sem_variant {
-    ($type_name:ident, $wrapped_type:ty, $destructor:ident, $constructor:ident $(; $cparam:ident: $ctype:ty)*) => {
-        #[doc = concat!("A wrapper around a [`", stringify!($wrapped_type), "`] that automatically deletes it when dropped.")]
-        pub struct $type_name {
-            sem: $wrapped_type,
-        }
-
-        impl $type_name {
-            #[doc = concat!("Like [`", stringify!($wrapped_type), "::new`], but creates an owned semaphore instead.")]
-            #[doc = "\n\n"]
-            #[doc = concat!("Wraps `", stringify!($constructor), "`.")]
-            #[inline]
-            pub fn new<S: AsRef<CStr> + ?Sized>(sem_name: &S $(, $cparam: $ctype )*) -> Result<Self, OsalError> {
-                <$wrapped_type>::new(sem_name $(, $cparam)*).map(|sem| $type_name { sem })
-            }
-        }
-
-        impl core::ops::Deref for $type_name {
-            type Target = $wrapped_type;
-
-            #[inline]
-            fn deref(&self) -> &$wrapped_type {
-                &self.sem
-            }
-        }
-
-        #[doc = concat!("Wraps `", stringify!($destructor), "`.")]
-        impl Drop for $type_name {
-            #[inline]
-            fn drop(&mut self) {
-                let _ = unsafe { $destructor(self.sem.id) };
-            }
-        }
-
-        #[doc = concat!("Takes the wrapped [`", stringify!($wrapped_type), "`] out of the [`", stringify!($type_name), "`] wrapper without dropping it.")]
-        impl From<$type_name> for $wrapped_type {
-            #[inline]
-            fn from(o_sem: $type_name) -> Self {
-                let x = Self { id: o_sem.sem.id };
-                let _ = core::mem::ManuallyDrop::new(o_sem);
-                x
-            }
-        }
-    };
-}
-
-owned_sem_variant!(OwnedBinSem, BinSem, OS_BinSemDelete, OS_BinSemCreate; initial_value: BinSemState);
-owned_sem_variant!(OwnedCountSem, CountSem, OS_CountSemDelete, OS_CountSemCreate; initial_value: u32);
-owned_sem_variant!(OwnedMutSem, MutSem, OS_MutSemDelete, OS_MutSemCreate);
+owned_variant!(OwnedBinSem, BinSem, OS_BinSemDelete; new: OS_BinSemCreate(initial_value: BinSemState));
+owned_variant!(OwnedCountSem, CountSem, OS_CountSemDelete; new: OS_CountSemCreate(initial_value: u32));
+owned_variant!(OwnedMutSem, MutSem, OS_MutSemDelete; new: OS_MutSemCreate());
@@ -7,6 +7,7 @@ use crate::sys::*;
 use core::ffi::CStr;
 
 use super::*;
+use crate::time_source::{CfsClock, TimeSource};
 use crate::utils::CStrBuf;
 
 /// An identifier for an OSAL task.
@@ -65,14 +66,20 @@ impl Task {
     /// Wraps `OS_TaskSetPriority`.
     #[doc(alias = "OS_TaskSetPriority")]
     #[inline]
-    pub fn set_priority(&self, new_priority: TaskPriority) -> Result<(), OsalError> {
-        unsafe { OS_TaskSetPriority(self.id, new_priority) }.as_osal_status()?;
+    pub fn set_priority<P: Into<TaskPriority>>(&self, new_priority: P) -> Result<(), OsalError> {
+        unsafe { OS_TaskSetPriority(self.id, new_priority.into()) }.as_osal_status()?;
 
         Ok(())
     }
 
     /// Deletes the task.
     ///
+    /// This is only for tasks created directly through OSAL:
+    /// tasks created through cFE Executive Services
+    /// (e.g., by [`create_child_task`](crate::cfe::es::create_child_task))
+    /// should be deleted with `CFE_ES_DeleteChildTask` instead,
+    /// so that cFE's records of them are cleaned up too.
+    ///
     /// Wraps `OS_TaskDelete`.
     #[doc(alias = "OS_TaskDelete")]
     #[inline]
@@ -103,12 +110,22 @@ impl TryFrom<ObjectId> for Task {
     }
 }
 
+owned_variant!(
+    ///
+    /// As with [`Task::delete`], this is only for tasks created directly through OSAL;
+    /// it mustn't wrap tasks created through cFE Executive Services,
+    /// which deleting with `OS_TaskDelete` would leave registered with cFE.
+    OwnedTask, Task, OS_TaskDelete
+);
+
 /// An OSAL task priority.
 ///
 /// This is in reverse numeric order, so 0 is the highest priority
 /// and 255 the lowest.
 ///
-/// This is the same as `osal_priority_t`.
+/// This is the same as `osal_priority_t`;
+/// it converts to and from [`cfe::es::TaskPriority`](crate::cfe::es::TaskPriority),
+/// which has constants for commonly used levels.
 #[doc(alias = "osal_priority_t")]
 #[doc(inline)]
 pub use crate::sys::osal_priority_t as TaskPriority;
@@ -174,3 +191,137 @@ pub fn delay(millis: u32) -> Result<(), OsalError> {
 
     Ok(())
 }
+
+/// Stops execution of this task for `interval` (rounded up to the next millisecond).
+///
+/// Wraps `OS_TaskDelay`.
+#[doc(alias = "OS_TaskDelay")]
+#[inline]
+pub fn delay_for(interval: OSTimeInterval) -> Result<(), OsalError> {
+    delay(super::as_millis(interval))
+}
+
+/// A schedule for running a loop once every `interval`,
+/// with wakeups at fixed times (as reckoned by OSAL's [local time](local_time))
+/// rather than at fixed delays after each iteration,
+/// so that the time taken by each iteration doesn't accumulate as drift:
+///
+/// ```rust,ignore
+/// let mut period = Periodic::new(OSTimeInterval::from_milliseconds(0, 100))?;
+/// loop {
+///     period.wait_next()?;
+///     run_control_step();
+/// }
+/// ```
+///
+/// If an iteration overruns so badly that wakeups are missed entirely,
+/// those wakeups are skipped (and counted), keeping the schedule's phase.
+///
+/// The time is read, and waited for, through a [`TimeSource`];
+/// see [`with_time_source`](Self::with_time_source).
+#[derive(Clone, Copy, Debug)]
+pub struct Periodic<S = CfsClock> {
+    interval: OSTimeInterval,
+    next:     OSTime,
+    missed:   u32,
+    clock:    S,
+}
+
+impl Periodic {
+    /// Starts a schedule with wakeups every `interval`, the first being `interval` from now.
+    ///
+    /// Returns [`OS_ERR_INVALID_ARGUMENT`](OsalError::OS_ERR_INVALID_ARGUMENT)
+    /// if `interval` isn't positive.
+    ///
+    /// Wraps `OS_GetLocalTime`.
+    #[doc(alias = "OS_GetLocalTime")]
+    #[inline]
+    pub fn new(interval: OSTimeInterval) -> Result<Self, OsalError> {
+        Self::with_time_source(interval, CfsClock)
+    }
+}
+
+impl<S: TimeSource> Periodic<S> {
+    /// Like [`new`](Periodic::new),
+    /// but reading and waiting for the time through `clock`.
+    #[inline]
+    pub fn with_time_source(interval: OSTimeInterval, clock: S) -> Result<Self, OsalError> {
+        if interval.total_nanoseconds() <= 0 {
+            return Err(OsalError::OS_ERR_INVALID_ARGUMENT);
+        }
+
+        Ok(Periodic {
+            interval,
+            next: clock.local_time()? + interval,
+            missed: 0,
+            clock,
+        })
+    }
+
+    /// Returns the interval between wakeups.
+    #[inline]
+    pub fn interval(&self) -> OSTimeInterval {
+        self.interval
+    }
+
+    /// Returns the time of the next scheduled wakeup.
+    #[inline]
+    pub fn next_wakeup(&self) -> OSTime {
+        self.next
+    }
+
+    /// Returns the total number of wakeups skipped because of overruns.
+    #[inline]
+    pub fn missed(&self) -> u32 {
+        self.missed
+    }
+
+    /// Restarts the schedule, with the next wakeup `interval` from now,
+    /// and sets the count of skipped wakeups back to zero.
+    ///
+    /// Wraps `OS_GetLocalTime`.
+    #[doc(alias = "OS_GetLocalTime")]
+    #[inline]
+    pub fn reset(&mut self) -> Result<(), OsalError> {
+        self.next = self.clock.local_time()? + self.interval;
+        self.missed = 0;
+        Ok(())
+    }
+
+    /// Waits until the next scheduled wakeup,
+    /// returning immediately if it's already past.
+    ///
+    /// Returns the number of wakeups skipped
+    /// because they were already a whole interval or more in the past.
+    ///
+    /// Wraps `OS_GetLocalTime` and `OS_TaskDelay`.
+    #[doc(alias("OS_GetLocalTime", "OS_TaskDelay"))]
+    #[inline]
+    pub fn wait_next(&mut self) -> Result<u32, OsalError> {
+        const NANOS_PER_SEC: i64 = 1_000_000_000;
+
+        let now = self.clock.local_time()?;
+        let mut skipped = 0;
+
+        if now < self.next {
+            self.clock.delay(self.next - now)?;
+        } else {
+            let late = (now - self.next).total_nanoseconds();
+            let periods = late / self.interval.total_nanoseconds();
+
+            if periods > 0 {
+                let skip = periods * self.interval.total_nanoseconds();
+                let skip = OSTimeInterval::from_nanoseconds(
+                    skip / NANOS_PER_SEC,
+                    (skip % NANOS_PER_SEC) as u32,
+                );
+                self.next = self.next + skip;
+                skipped = periods.min(u32::MAX as i64) as u32;
+                self.missed = self.missed.saturating_add(skipped);
+            }
+        }
+
+        self.next = self.next + self.interval;
+        Ok(skipped)
+    }
+}
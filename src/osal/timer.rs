@@ -0,0 +1,151 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Timers.
+//!
+//! Timers run callbacks on OSAL-internal tasks,
+//! so creating them is left to higher-level code
+//! (such as the `asynch` module, with the `async` feature);
+//! this module allows finding and inspecting timers,
+//! including those created by C applications.
+
+use crate::sys::*;
+
+use super::*;
+use crate::utils::CStrBuf;
+use core::ffi::{c_char, CStr};
+
+/// A handle for a timer.
+///
+/// Wraps `osal_id_t`.
+#[doc(alias = "osal_id_t")]
+#[derive(Clone, Debug)]
+pub struct Timer {
+    pub(crate) id: osal_id_t,
+}
+
+/// OSAL timer handles aren't tied to the task that created them.
+unsafe impl Send for Timer {}
+
+/// OSAL serializes access to its object table,
+/// so timer operations may be called concurrently from multiple tasks.
+unsafe impl Sync for Timer {}
+
+impl Timer {
+    /// If a timer with the name `name` exists, returns `Ok(Some(`a handle to it`))`.
+    ///
+    /// If no timer with the name exists, returns `Ok(None)`.
+    /// If an error occurred, returns `Err(err_code)`.
+    ///
+    /// Wraps `OS_TimerGetIdByName`.
+    #[doc(alias = "OS_TimerGetIdByName")]
+    #[inline]
+    pub fn find_by_name<S: AsRef<CStr> + ?Sized>(name: &S) -> Result<Option<Self>, OsalError> {
+        let mut id: osal_id_t = X_OS_OBJECT_ID_UNDEFINED;
+
+        match unsafe { OS_TimerGetIdByName(&mut id, name.as_ref().as_ptr()) }.as_osal_status() {
+            Ok(_) => {
+                if id != X_OS_OBJECT_ID_UNDEFINED {
+                    Ok(Some(Self { id }))
+                } else {
+                    Err(OsalError::OS_ERR_INVALID_ID)
+                }
+            }
+            Err(OsalError::OS_ERR_NAME_NOT_FOUND) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// (Re)arms the timer to expire `start_us` microseconds from now,
+    /// then every `interval_us` microseconds
+    /// (or only once, if `interval_us` is 0).
+    ///
+    /// A `start_us` of 0 disarms the timer.
+    ///
+    /// Wraps `OS_TimerSet`.
+    #[doc(alias = "OS_TimerSet")]
+    #[inline]
+    pub fn set(&self, start_us: u32, interval_us: u32) -> Result<(), OsalError> {
+        unsafe { OS_TimerSet(self.id, start_us, interval_us) }.as_osal_status()?;
+
+        Ok(())
+    }
+
+    /// Deletes the timer.
+    ///
+    /// Wraps `OS_TimerDelete`.
+    #[doc(alias = "OS_TimerDelete")]
+    #[inline]
+    pub fn delete(self) -> Result<(), OsalError> {
+        unsafe { OS_TimerDelete(self.id) }.as_osal_status()?;
+
+        Ok(())
+    }
+
+    /// If successful, returns details about the timer.
+    ///
+    /// Wraps `OS_TimerGetInfo`.
+    #[doc(alias = "OS_TimerGetInfo")]
+    #[inline]
+    pub fn info(&self) -> Result<TimerProperties, OsalError> {
+        let mut props = OS_timer_prop_t {
+            name:          [b'\0' as c_char; MAX_NAME_LEN],
+            creator:       X_OS_OBJECT_ID_UNDEFINED,
+            start_time:    0,
+            interval_time: 0,
+            accuracy:      0,
+        };
+
+        unsafe { OS_TimerGetInfo(self.id, &mut props) }.as_osal_status()?;
+
+        Ok(TimerProperties {
+            name:          CStrBuf::new(&props.name),
+            creator:       ObjectId { id: props.creator },
+            start_time:    props.start_time,
+            interval_time: props.interval_time,
+            accuracy:      props.accuracy,
+        })
+    }
+
+    /// Returns the [`ObjectId`] for the timer.
+    #[inline]
+    pub fn as_id(&self) -> ObjectId {
+        ObjectId { id: self.id }
+    }
+}
+
+impl TryFrom<ObjectId> for Timer {
+    type Error = ObjectTypeConvertError;
+
+    #[inline]
+    fn try_from(value: ObjectId) -> Result<Self, Self::Error> {
+        match value.obj_type() {
+            OS_OBJECT_TYPE_OS_TIMECB => Ok(Timer { id: value.id }),
+            _ => Err(ObjectTypeConvertError {}),
+        }
+    }
+}
+
+owned_variant!(OwnedTimer, Timer, OS_TimerDelete);
+
+/// The properties associated with a [`Timer`].
+///
+/// Substitutes for `OS_timer_prop_t`.
+#[doc(alias = "OS_timer_prop_t")]
+#[derive(Debug)]
+pub struct TimerProperties {
+    /// The timer's name.
+    pub name: CStrBuf<{ MAX_NAME_LEN }>,
+
+    /// The timer's creator.
+    pub creator: ObjectId,
+
+    /// The delay before the timer's first expiration, in microseconds.
+    pub start_time: u32,
+
+    /// The interval between the timer's expirations, in microseconds (0 if one-shot).
+    pub interval_time: u32,
+
+    /// The timer's accuracy, in microseconds.
+    pub accuracy: u32,
+}
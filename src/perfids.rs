@@ -0,0 +1,21 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Performance-log ID constants from the mission's header files.
+//!
+//! The build script generates this module's contents
+//! from the headers listed (`@`-separated) in the `RUST_CFS_SYS_PERFID_HEADERS` environment variable,
+//! each either an absolute path or a path to find through the configured include directories.
+//! Each macro named `*_PERF_ID` becomes a [`u32`] constant,
+//! for use with [`perf_log_add`](crate::cfe::es::perf_log_add) and its relatives,
+//! so that, e.g., `SAMPLE_APP_PERF_ID` from `sample_app_perfids.h`
+//! is available as `n2o4::perfids::SAMPLE_APP_PERF_ID`.
+//!
+//! If `RUST_CFS_SYS_PERFID_HEADERS` isn't set, this module is empty.
+//! (cFE's own performance IDs are available in [`sys`](crate::sys) regardless.)
+
+#![allow(non_upper_case_globals)]
+#![allow(missing_docs)]
+
+// ${OUT_DIR}/perfids.rs is generated by the build script
+include!(concat!(env!("OUT_DIR"), "/perfids.rs"));
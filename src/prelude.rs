@@ -0,0 +1,34 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! The types (and traits) almost every cFS application uses,
+//! for glob-importing:
+//!
+//! ```rust,ignore
+//! use n2o4::prelude::*;
+//! ```
+//!
+//! Everything here is a re-export, so it can also be named by its full path.
+
+#[doc(no_inline)]
+pub use crate::cfe::es::{RunStatus, TaskPriority};
+#[doc(no_inline)]
+pub use crate::cfe::evs::{BinFilter, EventSender, EventType, StatusResultExt};
+#[doc(no_inline)]
+pub use crate::cfe::msg::{Command, FunctionCode, Message, MsgType, Telemetry};
+#[doc(no_inline)]
+pub use crate::cfe::sb::{MsgId, Pipe, TimeOut};
+#[doc(no_inline)]
+pub use crate::cfe::tbl::{TableType, TblHandle, TblLoadSource, TblOptions};
+#[doc(no_inline)]
+pub use crate::cfe::time::SysTime;
+#[doc(no_inline)]
+pub use crate::cfe::Status;
+#[doc(no_inline)]
+pub use crate::error::Error;
+#[doc(no_inline)]
+pub use crate::osal::OsalError;
+#[doc(no_inline)]
+pub use crate::utils::CStrBuf;
+#[doc(no_inline)]
+pub use printf_wrap::PrintfFmt;
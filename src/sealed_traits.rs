@@ -3,9 +3,15 @@
 
 //! Module for the creation of [sealed traits](https://rust-lang.github.io/api-guidelines/future-proofing.html#c-sealed).
 
+use crate::sys::{CFE_ES_AppId_t, CFE_Status_t, CFE_TIME_SysTime_t};
+use core::ffi::c_char;
+
 /// Sealing trait for [`FilterScheme`](crate::cfe::evs::FilterScheme).
 pub trait FilterSchemeSealed {}
 
+/// Sealing trait for [`StatusResultExt`](crate::cfe::evs::StatusResultExt).
+pub trait StatusResultExtSealed {}
+
 /// Sealing trait for [`SocketDomain`](crate::osal::socket::SocketDomain).
 pub trait SocketDomainSealed {
     const DOMAIN: crate::sys::OS_SocketDomain_t;
@@ -18,3 +24,63 @@ pub trait SocketTypeSealed {
 
 /// Sealing trait for [`SocketRole`](crate::osal::socket::SocketRole).
 pub trait SocketRoleSealed {}
+
+/// Sealing trait for [`Endian`](crate::utils::Endian);
+/// its methods do the byte swapping.
+pub trait EndianSealed: Sized {
+    /// `Self` as an array of bytes.
+    type Bytes;
+
+    /// The unsigned integer type of the same size as `Self`.
+    type Bits: Copy + Eq + core::hash::Hash + Default;
+
+    /// Returns the bits of `self` in big-endian byte order.
+    fn to_be_bits(self) -> Self::Bits;
+
+    /// Converts `bits` from big-endian byte order.
+    fn from_be_bits(bits: Self::Bits) -> Self;
+
+    /// Returns the bits of `self` as they are.
+    fn to_bits(self) -> Self::Bits;
+
+    /// Reinterprets `bits` as a `Self`.
+    fn from_bits(bits: Self::Bits) -> Self;
+
+    /// Returns the bytes of `self` in big-endian order.
+    fn to_be_bytes(self) -> Self::Bytes;
+}
+
+/// Sealing trait for [`TypedMessage`](crate::cfe::msg::TypedMessage);
+/// its method does the checked cast.
+pub trait TypedMessageSealed: Sized {
+    /// If `msg` has the right type, size, and alignment, returns it as a `&Self`.
+    fn try_cast_from(msg: &crate::cfe::msg::Message) -> Result<&Self, crate::cfe::Status>;
+}
+
+/// Sealing trait for [`PrintfArgs`](crate::cfe::PrintfArgs);
+/// its methods pass the arguments on to the variadic C functions.
+pub trait PrintfArgsSealed {
+    /// Calls `CFE_ES_WriteToSysLog` with format string `fmt` and `self` as the arguments.
+    unsafe fn write_to_syslog(self, fmt: *const c_char) -> CFE_Status_t;
+
+    /// Calls `CFE_EVS_SendEvent` with format string `fmt` and `self` as the arguments.
+    unsafe fn send_event(self, event_id: u16, event_type: u16, fmt: *const c_char) -> CFE_Status_t;
+
+    /// Calls `CFE_EVS_SendEventWithAppID` with format string `fmt` and `self` as the arguments.
+    unsafe fn send_event_with_app_id(
+        self,
+        event_id: u16,
+        event_type: u16,
+        app_id: CFE_ES_AppId_t,
+        fmt: *const c_char,
+    ) -> CFE_Status_t;
+
+    /// Calls `CFE_EVS_SendTimedEvent` with format string `fmt` and `self` as the arguments.
+    unsafe fn send_timed_event(
+        self,
+        time: CFE_TIME_SysTime_t,
+        event_id: u16,
+        event_type: u16,
+        fmt: *const c_char,
+    ) -> CFE_Status_t;
+}
@@ -0,0 +1,148 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Pure-Rust implementations of some of cFE's and OSAL's C inline functions.
+//!
+//! Calling the out-of-line C shims (`SHIM_*`) for what are single comparisons or moves
+//! costs a function call each, which adds up in (e.g.) software bus receive loops.
+//! Where the build script has determined how the types involved are represented
+//! (the `n2o4_*_repr` cfgs), the functions here do the work in Rust;
+//! otherwise, they call the shims.
+//! Each function has the same name and behavior as the C function it replaces.
+
+#![allow(non_snake_case)]
+
+use crate::sys::*;
+use core::ffi::c_ulong;
+
+#[cfg(any(n2o4_msgid_repr = "plain", n2o4_msgid_repr = "struct"))]
+const _: () =
+    assert!(core::mem::size_of::<CFE_SB_MsgId_t>() == core::mem::size_of::<CFE_SB_MsgId_Atom_t>());
+
+#[cfg(any(n2o4_resourceid_repr = "plain", n2o4_resourceid_repr = "struct"))]
+const _: () = assert!(core::mem::size_of::<CFE_ResourceId_t>() <= core::mem::size_of::<c_ulong>());
+
+#[cfg(n2o4_osal_id_repr = "plain")]
+const _: () = assert!(core::mem::size_of::<osal_id_t>() <= core::mem::size_of::<c_ulong>());
+
+/// Same as `CFE_SB_MsgId_Equal`.
+#[inline]
+pub(crate) fn CFE_SB_MsgId_Equal(MsgId1: CFE_SB_MsgId_t, MsgId2: CFE_SB_MsgId_t) -> bool {
+    #[cfg(any(n2o4_msgid_repr = "plain", n2o4_msgid_repr = "struct"))]
+    return CFE_SB_MsgIdToValue(MsgId1) == CFE_SB_MsgIdToValue(MsgId2);
+
+    #[cfg(not(any(n2o4_msgid_repr = "plain", n2o4_msgid_repr = "struct")))]
+    return unsafe { SHIM_CFE_SB_MsgId_Equal(MsgId1, MsgId2) };
+}
+
+/// Same as `CFE_SB_MsgIdToValue`.
+#[inline]
+pub(crate) fn CFE_SB_MsgIdToValue(MsgId: CFE_SB_MsgId_t) -> CFE_SB_MsgId_Atom_t {
+    #[cfg(n2o4_msgid_repr = "plain")]
+    return MsgId;
+
+    #[cfg(n2o4_msgid_repr = "struct")]
+    return MsgId.Value;
+
+    #[cfg(not(any(n2o4_msgid_repr = "plain", n2o4_msgid_repr = "struct")))]
+    return unsafe { SHIM_CFE_SB_MsgIdToValue(MsgId) };
+}
+
+/// Same as `CFE_SB_ValueToMsgId`.
+#[inline]
+pub(crate) fn CFE_SB_ValueToMsgId(MsgIdValue: CFE_SB_MsgId_Atom_t) -> CFE_SB_MsgId_t {
+    #[cfg(n2o4_msgid_repr = "plain")]
+    return MsgIdValue;
+
+    #[cfg(n2o4_msgid_repr = "struct")]
+    return CFE_SB_MsgId_t { Value: MsgIdValue };
+
+    #[cfg(not(any(n2o4_msgid_repr = "plain", n2o4_msgid_repr = "struct")))]
+    return unsafe { SHIM_CFE_SB_ValueToMsgId(MsgIdValue) };
+}
+
+/// Same as `CFE_ResourceId_ToInteger`.
+#[inline]
+pub(crate) fn CFE_ResourceId_ToInteger(id: CFE_ResourceId_t) -> c_ulong {
+    #[cfg(n2o4_resourceid_repr = "plain")]
+    return id as c_ulong;
+
+    #[cfg(n2o4_resourceid_repr = "struct")]
+    return id.id as c_ulong;
+
+    #[cfg(not(any(n2o4_resourceid_repr = "plain", n2o4_resourceid_repr = "struct")))]
+    return unsafe { SHIM_CFE_ResourceId_ToInteger(id) };
+}
+
+/// Same as `CFE_ResourceId_FromInteger`.
+#[inline]
+pub(crate) fn CFE_ResourceId_FromInteger(Value: c_ulong) -> CFE_ResourceId_t {
+    #[cfg(n2o4_resourceid_repr = "plain")]
+    return Value as CFE_ResourceId_t;
+
+    #[cfg(n2o4_resourceid_repr = "struct")]
+    return CFE_ResourceId_t { id: Value as _ };
+
+    #[cfg(not(any(n2o4_resourceid_repr = "plain", n2o4_resourceid_repr = "struct")))]
+    return unsafe { SHIM_CFE_ResourceId_FromInteger(Value) };
+}
+
+/// Same as `CFE_ResourceId_Equal`.
+#[inline]
+pub(crate) fn CFE_ResourceId_Equal(id1: CFE_ResourceId_t, id2: CFE_ResourceId_t) -> bool {
+    #[cfg(any(n2o4_resourceid_repr = "plain", n2o4_resourceid_repr = "struct"))]
+    return CFE_ResourceId_ToInteger(id1) == CFE_ResourceId_ToInteger(id2);
+
+    #[cfg(not(any(n2o4_resourceid_repr = "plain", n2o4_resourceid_repr = "struct")))]
+    return unsafe { SHIM_CFE_ResourceId_Equal(id1, id2) };
+}
+
+/// Same as `CFE_ResourceId_IsDefined`.
+#[inline]
+pub(crate) fn CFE_ResourceId_IsDefined(id: CFE_ResourceId_t) -> bool {
+    #[cfg(any(n2o4_resourceid_repr = "plain", n2o4_resourceid_repr = "struct"))]
+    return CFE_ResourceId_ToInteger(id) != 0;
+
+    #[cfg(not(any(n2o4_resourceid_repr = "plain", n2o4_resourceid_repr = "struct")))]
+    return unsafe { SHIM_CFE_ResourceId_IsDefined(id) };
+}
+
+/// Same as `OS_ObjectIdToInteger`.
+#[inline]
+pub(crate) fn OS_ObjectIdToInteger(object_id: osal_id_t) -> c_ulong {
+    #[cfg(n2o4_osal_id_repr = "plain")]
+    return object_id as c_ulong;
+
+    #[cfg(not(n2o4_osal_id_repr = "plain"))]
+    return unsafe { SHIM_OS_ObjectIdToInteger(object_id) };
+}
+
+/// Same as `OS_ObjectIdFromInteger`.
+#[inline]
+pub(crate) fn OS_ObjectIdFromInteger(value: c_ulong) -> osal_id_t {
+    #[cfg(n2o4_osal_id_repr = "plain")]
+    return value as osal_id_t;
+
+    #[cfg(not(n2o4_osal_id_repr = "plain"))]
+    return unsafe { SHIM_OS_ObjectIdFromInteger(value) };
+}
+
+/// Same as `OS_ObjectIdEqual`.
+#[inline]
+pub(crate) fn OS_ObjectIdEqual(object_id1: osal_id_t, object_id2: osal_id_t) -> bool {
+    #[cfg(n2o4_osal_id_repr = "plain")]
+    return object_id1 == object_id2;
+
+    #[cfg(not(n2o4_osal_id_repr = "plain"))]
+    return unsafe { SHIM_OS_ObjectIdEqual(object_id1, object_id2) };
+}
+
+/// Same as `OS_ObjectIdDefined`.
+#[inline]
+pub(crate) fn OS_ObjectIdDefined(object_id: osal_id_t) -> bool {
+    #[cfg(n2o4_osal_id_repr = "plain")]
+    return OS_ObjectIdToInteger(object_id) != 0;
+
+    #[cfg(not(n2o4_osal_id_repr = "plain"))]
+    return unsafe { SHIM_OS_ObjectIdDefined(object_id) };
+}
@@ -23,6 +23,11 @@
 //! of hand-written C code required to make `bindgen` create bindings for
 //! certain constants and function; each is the same as the C constant
 //! or function without the prefix in its name.
+//!
+//! When the bindings were generated by the build script
+//! (rather than pregenerated; see `notes/bindings-generation.md`),
+//! cFE's and OSAL's `static inline` functions are also available under their own names,
+//! through wrappers generated by bindgen.
 
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -0,0 +1,157 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Where time-dependent helpers get the time from.
+//!
+//! [`Periodic`](crate::osal::task::Periodic),
+//! [`EventLimiter`](crate::cfe::evs::EventLimiter),
+//! and [`Recorder`](crate::cfe::recorder::Recorder)
+//! read the time (and, for `Periodic`, wait for it to pass) through a [`TimeSource`].
+//! By default, that's [`CfsClock`], which asks cFE and OSAL;
+//! for deterministic tests of time-dependent logic
+//! (or for simulating time on a host),
+//! they can be given a [`ManualClock`] instead, whose time only changes when told to:
+//!
+//! ```rust,ignore
+//! let clock = ManualClock::new(OSTime::from_nanoseconds(0, 0), SysTime::new(0, 0));
+//! let mut limiter = EventLimiter::with_time_source(
+//!     events,
+//!     [RateLimit { event_id: SENSOR_ERR_EID, per_second: 1, burst: 1 }],
+//!     EVENTS_SUPPRESSED_EID,
+//!     60,
+//!     &clock,
+//! );
+//!
+//! assert!(limiter.allow(SENSOR_ERR_EID));
+//! assert!(!limiter.allow(SENSOR_ERR_EID));
+//! clock.advance(OSTimeInterval::from_milliseconds(1, 0));
+//! assert!(limiter.allow(SENSOR_ERR_EID));
+//! ```
+
+use crate::cfe::time::{DeltaTime, SysTime};
+use crate::osal::{OSTime, OSTimeInterval, OsalError};
+use core::cell::Cell;
+use core::convert::TryFrom;
+
+/// A source of the current time, and a way to wait for time to pass.
+pub trait TimeSource {
+    /// Returns the current local time, as [`crate::osal::local_time`] does.
+    fn local_time(&self) -> Result<OSTime, OsalError>;
+
+    /// Returns the current spacecraft time, as [`crate::cfe::time::get_time`] does.
+    fn spacecraft_time(&self) -> SysTime;
+
+    /// Waits for `interval` to pass, as [`crate::osal::task::delay_for`] does.
+    fn delay(&self, interval: OSTimeInterval) -> Result<(), OsalError>;
+}
+
+impl<T: TimeSource + ?Sized> TimeSource for &T {
+    #[inline]
+    fn local_time(&self) -> Result<OSTime, OsalError> {
+        (**self).local_time()
+    }
+
+    #[inline]
+    fn spacecraft_time(&self) -> SysTime {
+        (**self).spacecraft_time()
+    }
+
+    #[inline]
+    fn delay(&self, interval: OSTimeInterval) -> Result<(), OsalError> {
+        (**self).delay(interval)
+    }
+}
+
+/// The time as kept by cFE and OSAL.
+///
+/// Wraps `OS_GetLocalTime`, `CFE_TIME_GetTime`, and `OS_TaskDelay`.
+#[doc(alias("OS_GetLocalTime", "CFE_TIME_GetTime", "OS_TaskDelay"))]
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+pub struct CfsClock;
+
+impl TimeSource for CfsClock {
+    #[inline]
+    fn local_time(&self) -> Result<OSTime, OsalError> {
+        crate::osal::local_time()
+    }
+
+    #[inline]
+    fn spacecraft_time(&self) -> SysTime {
+        crate::cfe::time::get_time()
+    }
+
+    #[inline]
+    fn delay(&self, interval: OSTimeInterval) -> Result<(), OsalError> {
+        crate::osal::task::delay_for(interval)
+    }
+}
+
+/// A clock that only moves when told to, for testing time-dependent logic.
+///
+/// [`delay`](TimeSource::delay) returns immediately
+/// after advancing the clock by the interval waited for.
+///
+/// Shared by reference (`&ManualClock` is also a [`TimeSource`]),
+/// the clock can be advanced while a helper using it is alive.
+#[derive(Clone, Debug)]
+pub struct ManualClock {
+    local:      Cell<OSTime>,
+    spacecraft: Cell<SysTime>,
+}
+
+impl ManualClock {
+    /// Creates a clock starting at local time `local` and spacecraft time `spacecraft`.
+    #[inline]
+    pub const fn new(local: OSTime, spacecraft: SysTime) -> Self {
+        ManualClock {
+            local:      Cell::new(local),
+            spacecraft: Cell::new(spacecraft),
+        }
+    }
+
+    /// Moves both the local and the spacecraft time forward by `interval`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is negative or doesn't fit in a [`DeltaTime`].
+    #[inline]
+    pub fn advance(&self, interval: OSTimeInterval) {
+        let delta = DeltaTime::try_from(interval).expect("invalid interval to advance clock by");
+
+        self.local.set(self.local.get() + interval);
+        self.spacecraft.set(self.spacecraft.get() + delta);
+    }
+
+    /// Sets the local time to `local`, leaving the spacecraft time unchanged.
+    #[inline]
+    pub fn set_local_time(&self, local: OSTime) {
+        self.local.set(local);
+    }
+
+    /// Sets the spacecraft time to `spacecraft`, leaving the local time unchanged
+    /// (e.g., to simulate the spacecraft clock being corrected).
+    #[inline]
+    pub fn set_spacecraft_time(&self, spacecraft: SysTime) {
+        self.spacecraft.set(spacecraft);
+    }
+}
+
+impl TimeSource for ManualClock {
+    #[inline]
+    fn local_time(&self) -> Result<OSTime, OsalError> {
+        Ok(self.local.get())
+    }
+
+    #[inline]
+    fn spacecraft_time(&self) -> SysTime {
+        self.spacecraft.get()
+    }
+
+    #[inline]
+    fn delay(&self, interval: OSTimeInterval) -> Result<(), OsalError> {
+        if interval.total_nanoseconds() > 0 {
+            self.advance(interval);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,366 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Glue for NASA's `ut_assert` unit-test framework and the cFE/OSAL unit-test stubs.
+//!
+//! When the `ut-stubs` feature is enabled,
+//! code built on `n2o4` can be linked against the official stub libraries
+//! (e.g., `ut_core_api_stubs` and `ut_osapi_stubs`) and `ut_assert`,
+//! the same way C cFS applications are unit-tested.
+//! This module lets Rust test code drive those stubs
+//! (setting return codes, reading call counts, checking for sent events)
+//! and report results through `ut_assert`.
+//!
+//! Stubs are identified by [`StubKey`]s, most easily made with the [`ut_key`](crate::ut_key) macro.
+
+use crate::cfe::Status;
+use crate::sys::*;
+use core::ffi::{c_char, c_int, c_uint, c_void, CStr};
+use core::sync::atomic::{AtomicU32, Ordering};
+
+/// The `ut_assert` declarations we need; these aren't part of the regular cFE/OSAL API,
+/// so they aren't in [`sys`](crate::sys).
+mod ffi {
+    #![allow(non_camel_case_types)]
+    #![allow(non_snake_case)]
+
+    use core::ffi::{c_char, c_uint, c_void};
+
+    pub(super) type UT_EntryKey_t = usize;
+
+    /// `va_list` is only passed through (never read),
+    /// and is pointer-sized or passed by reference on the ABIs cFS supports.
+    pub(super) type UT_VaHookFunc_t =
+        Option<unsafe extern "C" fn(*mut c_void, i32, u32, *const c_void, *mut c_void) -> i32>;
+
+    extern "C" {
+        pub(super) fn UT_ResetState(FuncKey: UT_EntryKey_t);
+        pub(super) fn UT_SetDefaultReturnValue(FuncKey: UT_EntryKey_t, Value: i32);
+        pub(super) fn UT_ClearDefaultReturnValue(FuncKey: UT_EntryKey_t);
+        pub(super) fn UT_SetDeferredRetcode(FuncKey: UT_EntryKey_t, Count: i32, Retcode: i32);
+        pub(super) fn UT_GetStubCount(FuncKey: UT_EntryKey_t) -> u32;
+        pub(super) fn UT_SetVaHookFunction(
+            FuncKey: UT_EntryKey_t,
+            HookFunc: UT_VaHookFunc_t,
+            UserObj: *mut c_void,
+        );
+        pub(super) fn UT_Hook_GetArgPtr(
+            ContextPtr: *const c_void,
+            Name: *const c_char,
+            ExpectedTypeSize: usize,
+        ) -> *const c_void;
+
+        pub(super) fn UtAssertEx(
+            Expression: bool,
+            CaseType: c_uint,
+            File: *const c_char,
+            Line: u32,
+            MessageFormat: *const c_char,
+            ...
+        ) -> bool;
+        pub(super) fn UtTest_Add(
+            Test: Option<unsafe extern "C" fn()>,
+            Setup: Option<unsafe extern "C" fn()>,
+            Teardown: Option<unsafe extern "C" fn()>,
+            TestName: *const c_char,
+        );
+    }
+}
+
+/// Identifies the stub for one cFE or OSAL function.
+///
+/// Wraps `UT_EntryKey_t`.
+#[doc(alias = "UT_EntryKey_t")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StubKey {
+    key: ffi::UT_EntryKey_t,
+}
+
+impl StubKey {
+    /// A key that stands for all stubs at once
+    /// (only meaningful for [`reset_state`]).
+    pub const ALL: StubKey = StubKey { key: 0 };
+
+    /// Returns the key for the stub function at address `fn_addr`.
+    ///
+    /// Prefer the [`ut_key`](crate::ut_key) macro, which takes care of the address-taking.
+    #[inline]
+    pub const fn from_fn_addr(fn_addr: usize) -> StubKey {
+        StubKey { key: fn_addr }
+    }
+}
+
+/// Returns the [`StubKey`] for the cFE or OSAL function `$f`,
+/// which is looked up in [`n2o4::sys`](crate::sys).
+///
+/// ```rust,ignore
+/// let key = n2o4::ut_key!(CFE_SB_ReceiveBuffer);
+/// ```
+///
+/// Equivalent to C's `UT_KEY`.
+#[doc(alias = "UT_KEY")]
+#[macro_export]
+macro_rules! ut_key {
+    ($f:ident) => {
+        $crate::ut::StubKey::from_fn_addr($crate::sys::$f as *const () as usize)
+    };
+}
+
+/// Resets the stub identified by `key`
+/// (or, if `key` is [`StubKey::ALL`], all stubs)
+/// to its default behavior, clearing its call count, return codes, and hooks.
+///
+/// Wraps `UT_ResetState`.
+#[doc(alias = "UT_ResetState")]
+#[inline]
+pub fn reset_state(key: StubKey) {
+    unsafe { ffi::UT_ResetState(key.key) };
+}
+
+/// Makes every call to the stub identified by `key` return `value`
+/// (unless overridden by [`set_deferred_retcode`]).
+///
+/// Wraps `UT_SetDefaultReturnValue`.
+#[doc(alias = "UT_SetDefaultReturnValue")]
+#[inline]
+pub fn set_default_return(key: StubKey, value: i32) {
+    unsafe { ffi::UT_SetDefaultReturnValue(key.key, value) };
+}
+
+/// Makes every call to the stub identified by `key` return `status`.
+///
+/// Equivalent to [`set_default_return`] for cFE functions returning a `CFE_Status_t`.
+#[doc(alias = "UT_SetDefaultReturnValue")]
+#[inline]
+pub fn set_default_status(key: StubKey, status: Status) {
+    set_default_return(key, status.status);
+}
+
+/// Undoes [`set_default_return`] for the stub identified by `key`.
+///
+/// Wraps `UT_ClearDefaultReturnValue`.
+#[doc(alias = "UT_ClearDefaultReturnValue")]
+#[inline]
+pub fn clear_default_return(key: StubKey) {
+    unsafe { ffi::UT_ClearDefaultReturnValue(key.key) };
+}
+
+/// Makes the `count`th call (from now) to the stub identified by `key` return `retcode`.
+///
+/// Wraps `UT_SetDeferredRetcode`.
+#[doc(alias = "UT_SetDeferredRetcode")]
+#[inline]
+pub fn set_deferred_retcode(key: StubKey, count: i32, retcode: i32) {
+    unsafe { ffi::UT_SetDeferredRetcode(key.key, count, retcode) };
+}
+
+/// Returns how many times the stub identified by `key` has been called
+/// since it was last reset.
+///
+/// Wraps `UT_GetStubCount`.
+#[doc(alias = "UT_GetStubCount")]
+#[inline]
+pub fn stub_count(key: StubKey) -> u32 {
+    unsafe { ffi::UT_GetStubCount(key.key) }
+}
+
+/// The kind of a `ut_assert` test result.
+///
+/// Wraps `UtAssert_CaseType_t`.
+#[doc(alias = "UtAssert_CaseType_t")]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u32)]
+pub enum CaseType {
+    /// Test-sequence abort (major failure; cannot continue).
+    #[doc(alias = "UTASSERT_CASETYPE_ABORT")]
+    Abort   = 1,
+
+    /// Test-case failure.
+    #[doc(alias = "UTASSERT_CASETYPE_FAILURE")]
+    Failure = 2,
+
+    /// Test-setup failure.
+    #[doc(alias = "UTASSERT_CASETYPE_TSF")]
+    Tsf     = 3,
+
+    /// Test-teardown failure.
+    #[doc(alias = "UTASSERT_CASETYPE_TTF")]
+    Ttf     = 4,
+
+    /// Manual inspection required.
+    #[doc(alias = "UTASSERT_CASETYPE_MIR")]
+    Mir     = 5,
+
+    /// Test not applicable.
+    #[doc(alias = "UTASSERT_CASETYPE_NA")]
+    Na      = 6,
+
+    /// Test-platform support message.
+    #[doc(alias = "UTASSERT_CASETYPE_BSP")]
+    Bsp     = 7,
+
+    /// Test case passed.
+    #[doc(alias = "UTASSERT_CASETYPE_PASS")]
+    Pass    = 8,
+
+    /// Informational message.
+    #[doc(alias = "UTASSERT_CASETYPE_INFO")]
+    Info    = 9,
+
+    /// Test-flow message.
+    #[doc(alias = "UTASSERT_CASETYPE_FLOW")]
+    Flow    = 10,
+
+    /// Debugging message.
+    #[doc(alias = "UTASSERT_CASETYPE_DEBUG")]
+    Debug   = 11,
+}
+
+/// Records the result of a test assertion with `ut_assert`,
+/// as `case_type` if `expression` is false (and as a pass otherwise),
+/// and returns `expression`.
+///
+/// Usually invoked through the [`ut_assert`](crate::ut_assert) macro.
+///
+/// Wraps `UtAssertEx`.
+#[doc(alias = "UtAssertEx")]
+#[inline]
+pub fn assert_ex(expression: bool, case_type: CaseType, file: &CStr, line: u32, msg: &str) -> bool {
+    unsafe {
+        ffi::UtAssertEx(
+            expression,
+            case_type as c_uint,
+            file.as_ptr(),
+            line,
+            crate::cfe::RUST_STR_FMT.as_ptr(),
+            msg.len() as c_int,
+            msg.as_ptr() as *const c_char,
+        )
+    }
+}
+
+/// Asserts that `$cond` is true, reporting the result (and the source location) with `ut_assert`.
+///
+/// The message reported is `$msg` if given, or the text of `$cond` otherwise.
+/// Evaluates to the value of `$cond`.
+///
+/// Equivalent to C's `UtAssert_True`.
+#[doc(alias = "UtAssert_True")]
+#[macro_export]
+macro_rules! ut_assert {
+    ($cond:expr $(,)?) => {
+        $crate::ut_assert!($cond, ::core::stringify!($cond))
+    };
+    ($cond:expr, $msg:expr $(,)?) => {
+        $crate::ut::assert_ex(
+            $cond,
+            $crate::ut::CaseType::Failure,
+            ::core::ffi::CStr::from_bytes_with_nul(
+                ::core::concat!(::core::file!(), "\0").as_bytes(),
+            )
+            .unwrap_or_default(),
+            ::core::line!(),
+            $msg,
+        )
+    };
+}
+
+/// Registers a test case with `ut_assert`,
+/// to be run (between `setup` and `teardown`, if given) once `UtTest_Setup` returns.
+///
+/// Wraps `UtTest_Add`.
+#[doc(alias = "UtTest_Add")]
+#[inline]
+pub fn add_test(
+    test: unsafe extern "C" fn(),
+    setup: Option<unsafe extern "C" fn()>,
+    teardown: Option<unsafe extern "C" fn()>,
+    name: &'static CStr,
+) {
+    unsafe { ffi::UtTest_Add(Some(test), setup, teardown, name.as_ptr()) };
+}
+
+/// Counts the events with a given ID sent through the `CFE_EVS_SendEvent` family of stubs.
+///
+/// Install the check with [`install`](Self::install),
+/// exercise the code under test,
+/// then read the result with [`count`](Self::count).
+/// The check hooks the stubs, so it must be `'static`:
+///
+/// ```rust,ignore
+/// static CHECK: EventCheck = EventCheck::new(MY_CMD_ERR_EID);
+///
+/// CHECK.install();
+/// process_command(&bad_cmd);
+/// ut_assert!(CHECK.count() == 1);
+/// ```
+///
+/// Equivalent to C's `UT_CheckEvent_t`.
+#[doc(alias("UT_CheckEvent_t", "UT_CheckEvent_Setup"))]
+#[derive(Debug)]
+pub struct EventCheck {
+    event_id: u16,
+    count:    AtomicU32,
+}
+
+impl EventCheck {
+    /// Returns a new check for events with ID `event_id`.
+    #[inline]
+    pub const fn new(event_id: u16) -> Self {
+        EventCheck {
+            event_id,
+            count: AtomicU32::new(0),
+        }
+    }
+
+    /// Resets the count to zero and hooks the event stubs,
+    /// replacing any other hooks on them.
+    ///
+    /// Wraps `UT_SetVaHookFunction`.
+    #[doc(alias = "UT_SetVaHookFunction")]
+    #[inline]
+    pub fn install(&'static self) {
+        self.count.store(0, Ordering::SeqCst);
+
+        let user_obj = self as *const EventCheck as *mut c_void;
+        for key in [
+            crate::ut_key!(CFE_EVS_SendEvent),
+            crate::ut_key!(CFE_EVS_SendEventWithAppID),
+            crate::ut_key!(CFE_EVS_SendTimedEvent),
+        ] {
+            unsafe { ffi::UT_SetVaHookFunction(key.key, Some(event_hook), user_obj) };
+        }
+    }
+
+    /// Returns the number of matching events sent since [`install`](Self::install) was called.
+    #[inline]
+    pub fn count(&self) -> u32 {
+        self.count.load(Ordering::SeqCst)
+    }
+}
+
+/// The hook installed by [`EventCheck::install`].
+unsafe extern "C" fn event_hook(
+    user_obj: *mut c_void,
+    stub_retcode: i32,
+    _call_count: u32,
+    context: *const c_void,
+    _va: *mut c_void,
+) -> i32 {
+    const EVENT_ID: &[u8] = b"EventID\0";
+
+    let check = &*(user_obj as *const EventCheck);
+    let id_ptr = ffi::UT_Hook_GetArgPtr(
+        context,
+        EVENT_ID.as_ptr() as *const c_char,
+        core::mem::size_of::<uint16>(),
+    ) as *const uint16;
+
+    if let Some(&id) = id_ptr.as_ref() {
+        if id == check.event_id {
+            check.count.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    stub_retcode
+}
@@ -8,10 +8,19 @@
 //! enough to spin out into their own crates.
 
 use core::ffi::{c_char, CStr};
+use core::fmt::{self, Write as _};
+use core::iter::FusedIterator;
+use core::mem::MaybeUninit;
 use core::ops::Deref;
+use core::str::Utf8Error;
 
 /// A wrapper for [`i32`] that guarantees its value is always negative.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "i32", into = "i32")
+)]
 #[repr(transparent)]
 pub struct NegativeI32 {
     n: i32,
@@ -59,6 +68,42 @@ impl NegativeI32 {
     pub const fn as_i32(self) -> i32 {
         self.n
     }
+
+    /// Returns `self + rhs`, or [`None`] if the result
+    /// wouldn't be a valid [`NegativeI32`].
+    #[inline]
+    pub const fn checked_add(self, rhs: i32) -> Option<Self> {
+        match self.n.checked_add(rhs) {
+            Some(n) => Self::new(n),
+            None => None,
+        }
+    }
+
+    /// Returns `self - rhs`, or [`None`] if the result
+    /// wouldn't be a valid [`NegativeI32`].
+    #[inline]
+    pub const fn checked_sub(self, rhs: i32) -> Option<Self> {
+        match self.n.checked_sub(rhs) {
+            Some(n) => Self::new(n),
+            None => None,
+        }
+    }
+
+    /// Returns `self + rhs`, clamped to the range
+    /// [`MIN`](Self::MIN)..=[`MAX`](Self::MAX).
+    #[inline]
+    pub const fn saturating_add(self, rhs: i32) -> Self {
+        let n = self.n.saturating_add(rhs);
+        Self { n: if n < 0 { n } else { -1 } }
+    }
+
+    /// Returns `self - rhs`, clamped to the range
+    /// [`MIN`](Self::MIN)..=[`MAX`](Self::MAX).
+    #[inline]
+    pub const fn saturating_sub(self, rhs: i32) -> Self {
+        let n = self.n.saturating_sub(rhs);
+        Self { n: if n < 0 { n } else { -1 } }
+    }
 }
 
 impl From<NegativeI32> for i32 {
@@ -72,6 +117,15 @@ impl From<NegativeI32> for i32 {
 #[derive(Clone, Copy, Debug)]
 pub struct NotNegativeError {}
 
+impl fmt::Display for NotNegativeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("value is not negative")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotNegativeError {}
+
 impl TryFrom<i32> for NegativeI32 {
     type Error = NotNegativeError;
 
@@ -199,6 +253,44 @@ impl<const SIZE: usize> CStrBuf<SIZE> {
         Self { buf }
     }
 
+    /// Creates a new `CStrBuf<SIZE>` from the string `src`,
+    /// never failing.
+    ///
+    /// If `src` contains a null byte, the string is cut short at that point.
+    /// If `src` is longer than `SIZE - 1` bytes, it gets truncated to fit,
+    /// at a `char` boundary.
+    ///
+    /// # Panics
+    ///
+    /// Panics if and only if `SIZE` is `0`.
+    #[inline]
+    pub fn from_str_lossy(src: &str) -> Self {
+        let mut len = min(src.len(), SIZE.saturating_sub(1));
+        while !src.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        Self::new_u8(&src.as_bytes()[..len])
+    }
+
+    /// Shared implementation of the `TryFrom` impls.
+    fn try_from_bytes(src: &[u8]) -> Result<Self, CStrBufError> {
+        let src = match src.split_last() {
+            Some((b'\0', rest)) => rest,
+            _ => src,
+        };
+
+        if let Some(pos) = src.iter().position(|&b| b == b'\0') {
+            return Err(CStrBufError::InteriorNul(pos));
+        }
+
+        if src.len() >= SIZE {
+            return Err(CStrBufError::TooLong(src.len()));
+        }
+
+        Ok(Self::new_u8(src))
+    }
+
     /// Returns a pointer to the start of the string.
     #[inline]
     pub const fn as_ptr(&self) -> *const c_char {
@@ -210,6 +302,51 @@ impl<const SIZE: usize> CStrBuf<SIZE> {
     pub const fn as_array(&self) -> &[c_char; SIZE] {
         &self.buf
     }
+
+    /// Returns the length of the string in bytes, not including the null terminator.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buf.iter().position(|&c| c == b'\0' as c_char).unwrap_or(SIZE)
+    }
+
+    /// Returns whether the string is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Truncates the string to zero length.
+    #[inline]
+    pub fn clear(&mut self) {
+        if SIZE > 0 {
+            self.buf[0] = b'\0' as c_char;
+        }
+    }
+
+    /// Returns the bytes of the string, not including the null terminator.
+    #[inline]
+    pub fn to_bytes(&self) -> &[u8] {
+        AsRef::<CStr>::as_ref(self).to_bytes()
+    }
+
+    /// If the string is valid UTF-8, returns it as a `&str`.
+    #[inline]
+    pub fn to_str(&self) -> Result<&str, Utf8Error> {
+        core::str::from_utf8(self.to_bytes())
+    }
+
+    /// Returns the string as a `&str`.
+    ///
+    /// If the string isn't entirely valid UTF-8,
+    /// only the longest valid prefix is returned.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        let bytes = self.to_bytes();
+        match core::str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(e) => unsafe { core::str::from_utf8_unchecked(&bytes[..e.valid_up_to()]) },
+        }
+    }
 }
 
 impl<const SIZE: usize> Deref for CStrBuf<SIZE> {
@@ -240,6 +377,916 @@ impl<const SIZE: usize, const OTHER: usize> PartialEq<CStrBuf<OTHER>> for CStrBu
 
 impl<const SIZE: usize> Eq for CStrBuf<SIZE> {}
 
+impl<const SIZE: usize> PartialEq<str> for CStrBuf<SIZE> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.to_bytes() == other.as_bytes()
+    }
+}
+
+impl<const SIZE: usize> PartialEq<&str> for CStrBuf<SIZE> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.to_bytes() == other.as_bytes()
+    }
+}
+
+/// Appends to the end of the string, keeping it null-terminated.
+///
+/// If the string to append contains a null byte, or doesn't entirely fit,
+/// as much as possible (up to the null byte, if any) is appended,
+/// then [`fmt::Error`] is returned.
+impl<const SIZE: usize> fmt::Write for CStrBuf<SIZE> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let start = self.len();
+        let room = SIZE.saturating_sub(1).saturating_sub(start);
+
+        let src = s.as_bytes();
+        let src = match src.iter().position(|&b| b == b'\0') {
+            Some(pos) => &src[..pos],
+            None => src,
+        };
+        let copy_len = min(src.len(), room);
+
+        for (dst, &b) in self.buf[start..start + copy_len].iter_mut().zip(src) {
+            *dst = b as c_char;
+        }
+        if start + copy_len < SIZE {
+            self.buf[start + copy_len] = b'\0' as c_char;
+        }
+
+        if copy_len == s.len() {
+            Ok(())
+        } else {
+            Err(fmt::Error)
+        }
+    }
+}
+
+/// Invalid UTF-8 sequences get displayed as `U+FFFD REPLACEMENT CHARACTER`.
+impl<const SIZE: usize> fmt::Display for CStrBuf<SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut bytes = self.to_bytes();
+
+        loop {
+            match core::str::from_utf8(bytes) {
+                Ok(s) => return f.write_str(s),
+                Err(e) => {
+                    let (valid, rest) = bytes.split_at(e.valid_up_to());
+                    f.write_str(unsafe { core::str::from_utf8_unchecked(valid) })?;
+                    f.write_char(char::REPLACEMENT_CHARACTER)?;
+                    match e.error_len() {
+                        Some(len) => bytes = &rest[len..],
+                        None => return Ok(()),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Error: a fallible conversion into a [`CStrBuf`] failed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CStrBufError {
+    /// The source contained a null byte at the given position
+    /// (other than a single permitted trailing null byte).
+    InteriorNul(usize),
+
+    /// The source, at the given length (not including any null terminator),
+    /// was too long to fit in the buffer along with a null terminator.
+    TooLong(usize),
+}
+
+impl fmt::Display for CStrBufError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CStrBufError::InteriorNul(pos) => write!(f, "interior null byte at position {}", pos),
+            CStrBufError::TooLong(len) => write!(f, "string of length {} is too long", len),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CStrBufError {}
+
+/// Fails if `src` contains a null byte (other than as its last byte)
+/// or is too long to fit.
+impl<const SIZE: usize> TryFrom<&str> for CStrBuf<SIZE> {
+    type Error = CStrBufError;
+
+    #[inline]
+    fn try_from(src: &str) -> Result<Self, Self::Error> {
+        Self::try_from_bytes(src.as_bytes())
+    }
+}
+
+/// Fails if `src` contains a null byte (other than as its last byte)
+/// or is too long to fit.
+impl<const SIZE: usize> TryFrom<&[u8]> for CStrBuf<SIZE> {
+    type Error = CStrBufError;
+
+    #[inline]
+    fn try_from(src: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_from_bytes(src)
+    }
+}
+
+/// Serializes as a string; fails if the contents aren't valid UTF-8.
+#[cfg(feature = "serde")]
+impl<const SIZE: usize> serde::Serialize for CStrBuf<SIZE> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.to_str() {
+            Ok(s) => serializer.serialize_str(s),
+            Err(e) => Err(serde::ser::Error::custom(e)),
+        }
+    }
+}
+
+/// Deserializes from a string (or bytes),
+/// with the same restrictions as the [`TryFrom`] conversions.
+#[cfg(feature = "serde")]
+impl<'de, const SIZE: usize> serde::Deserialize<'de> for CStrBuf<SIZE> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct Visitor<const SIZE: usize>;
+
+        impl<const SIZE: usize> serde::de::Visitor<'_> for Visitor<SIZE> {
+            type Value = CStrBuf<SIZE>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a string of at most {} bytes without null bytes", SIZE.saturating_sub(1))
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                CStrBuf::try_from(v).map_err(E::custom)
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                CStrBuf::try_from(v).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(Visitor::<SIZE>)
+    }
+}
+
+/// A fixed-capacity buffer of bytes, filled from the front;
+/// useful for assembling packets and message payloads without allocation.
+#[derive(Clone, Copy, Debug)]
+pub struct ByteBuf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+/// Error: not enough room remained in a [`ByteBuf`] for an operation.
+#[derive(Clone, Copy, Debug)]
+pub struct BufferFullError {}
+
+impl fmt::Display for BufferFullError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("buffer full")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferFullError {}
+
+impl<const N: usize> ByteBuf<N> {
+    /// Creates a new, empty `ByteBuf`.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { buf: [0; N], len: 0 }
+    }
+
+    /// Returns the number of bytes in the buffer.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the buffer is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the total capacity of the buffer, in bytes.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of bytes that can still be added to the buffer.
+    #[inline]
+    pub const fn remaining(&self) -> usize {
+        N - self.len
+    }
+
+    /// Empties the buffer.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.buf = [0; N];
+        self.len = 0;
+    }
+
+    /// Returns the contents of the buffer.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+
+    /// Appends a single byte to the buffer.
+    #[inline]
+    pub fn push(&mut self, byte: u8) -> Result<(), BufferFullError> {
+        self.push_slice(&[byte])
+    }
+
+    /// Appends `data` to the buffer.
+    ///
+    /// If `data` doesn't entirely fit, the buffer is left unchanged.
+    #[inline]
+    pub fn push_slice(&mut self, data: &[u8]) -> Result<(), BufferFullError> {
+        self.reserve(data.len(), 1)?.copy_from_slice(data);
+        Ok(())
+    }
+
+    /// Reserves `len` bytes at the end of the buffer,
+    /// starting at an offset (from the start of the buffer) that is a multiple of `align`,
+    /// and returns the reserved bytes for the caller to fill in.
+    ///
+    /// Any padding bytes needed to reach the required alignment are zeroed.
+    /// If there isn't enough room, the buffer is left unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is `0`.
+    #[inline]
+    pub fn reserve(&mut self, len: usize, align: usize) -> Result<&mut [u8], BufferFullError> {
+        let start = match self.len % align {
+            0 => self.len,
+            rem => self.len + (align - rem),
+        };
+        let end = start.checked_add(len).filter(|&end| end <= N).ok_or(BufferFullError {})?;
+
+        self.buf[self.len..start].fill(0);
+        self.buf[start..end].fill(0);
+        self.len = end;
+
+        Ok(&mut self.buf[start..end])
+    }
+
+    /// Returns a copy of the whole underlying array,
+    /// with all bytes past [`len`](Self::len) zeroed.
+    ///
+    /// This is suitable for use as the payload of a
+    /// [`Command`](crate::cfe::msg::Command) or
+    /// [`Telemetry`](crate::cfe::msg::Telemetry) message,
+    /// with [`len`](Self::len) as the length to pass to `transmit_partial`.
+    #[inline]
+    pub const fn to_array(&self) -> [u8; N] {
+        self.buf
+    }
+}
+
+impl<const N: usize> Default for ByteBuf<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Deref for ByteBuf<N> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl<const N: usize> From<ByteBuf<N>> for [u8; N] {
+    #[inline]
+    fn from(buf: ByteBuf<N>) -> Self {
+        buf.buf
+    }
+}
+
+/// What a [`RingBuf`] does with a new item when it's full.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OverflowPolicy {
+    /// Reject the new item, keeping the buffer's contents.
+    Reject,
+
+    /// Discard the oldest item in the buffer to make room for the new one.
+    OverwriteOldest,
+}
+
+/// A fixed-capacity FIFO queue of up to `N` items,
+/// where `N` is a power of two.
+///
+/// This is suitable for, e.g., buffering samples taken at a high rate
+/// until a lower-rate task packs them into telemetry:
+///
+/// ```rust,ignore
+/// let mut samples: RingBuf<Sample, 64> = RingBuf::new(OverflowPolicy::OverwriteOldest);
+///
+/// // in the sampling loop:
+/// let _ = samples.push(take_sample());
+///
+/// // when it's time to send telemetry (a `Telemetry<[Sample; 32]>`):
+/// let n = samples.drain_into(&mut tlm.payload);
+/// tlm.transmit_partial(true, n)?;
+/// ```
+///
+/// To share one between tasks, protect it with a mutex
+/// (e.g., [`MutSem`](crate::osal::sync::MutSem)).
+#[derive(Clone, Copy)]
+pub struct RingBuf<T: Copy, const N: usize> {
+    buf:    [MaybeUninit<T>; N],
+    head:   usize,
+    len:    usize,
+    policy: OverflowPolicy,
+    lost:   u32,
+}
+
+impl<T: Copy, const N: usize> RingBuf<T, N> {
+    /// Fails to compile (when `new` is used) if `N` isn't a power of two.
+    const CAPACITY_CHECK: () =
+        assert!(N.is_power_of_two(), "RingBuf capacity must be a power of two");
+
+    /// Creates a new, empty `RingBuf` with the given overflow policy.
+    #[inline]
+    pub const fn new(policy: OverflowPolicy) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::CAPACITY_CHECK;
+
+        Self {
+            buf: [MaybeUninit::uninit(); N],
+            head: 0,
+            len: 0,
+            policy,
+            lost: 0,
+        }
+    }
+
+    /// Returns the number of items in the buffer.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns whether the buffer is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns whether the buffer is full.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the total capacity of the buffer, in items.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns what the buffer does with new items when it's full.
+    #[inline]
+    pub const fn policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    /// Changes what the buffer does with new items when it's full.
+    #[inline]
+    pub fn set_policy(&mut self, policy: OverflowPolicy) {
+        self.policy = policy;
+    }
+
+    /// Returns the number of items lost to overflow
+    /// (rejected or overwritten, per the [policy](OverflowPolicy))
+    /// since the buffer was created or [`reset_lost`](Self::reset_lost) was last called.
+    ///
+    /// The count saturates at [`u32::MAX`].
+    #[inline]
+    pub const fn lost(&self) -> u32 {
+        self.lost
+    }
+
+    /// Resets the count of items [lost](Self::lost) to overflow to 0.
+    #[inline]
+    pub fn reset_lost(&mut self) {
+        self.lost = 0;
+    }
+
+    /// Adds `value` to the back of the buffer.
+    ///
+    /// If the buffer is full, either `value` is handed back as an error
+    /// or the oldest item is discarded, according to the buffer's [policy](OverflowPolicy).
+    #[inline]
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            self.lost = self.lost.saturating_add(1);
+            match self.policy {
+                OverflowPolicy::Reject => return Err(value),
+                OverflowPolicy::OverwriteOldest => {
+                    self.head = (self.head + 1) & (N - 1);
+                    self.len -= 1;
+                }
+            }
+        }
+
+        self.buf[(self.head + self.len) & (N - 1)] = MaybeUninit::new(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the oldest item in the buffer, if any.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        let value = self.front()?;
+        self.head = (self.head + 1) & (N - 1);
+        self.len -= 1;
+        Some(value)
+    }
+
+    /// Returns (a copy of) the oldest item in the buffer, if any, without removing it.
+    #[inline]
+    pub fn front(&self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.get(0))
+        }
+    }
+
+    /// Returns the `i`th-oldest item in the buffer; `i` must be less than `self.len`.
+    #[inline]
+    fn get(&self, i: usize) -> T {
+        debug_assert!(i < self.len);
+
+        // SAFETY: the `len` items starting at `head` have all been written.
+        unsafe { self.buf[(self.head + i) & (N - 1)].assume_init() }
+    }
+
+    /// Empties the buffer.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.head = 0;
+        self.len = 0;
+    }
+
+    /// Returns an iterator that removes and yields items from the buffer, oldest first.
+    ///
+    /// Unlike `Vec::drain`, items the iterator doesn't get to stay in the buffer.
+    #[inline]
+    pub fn drain(&mut self) -> Drain<'_, T, N> {
+        Drain { ring: self }
+    }
+
+    /// Moves as many items as fit from the buffer into the front of `dest`, oldest first,
+    /// and returns how many were moved.
+    ///
+    /// With `dest` the payload of a [`Command`](crate::cfe::msg::Command)
+    /// or [`Telemetry`](crate::cfe::msg::Telemetry) message,
+    /// the return value is the length to pass to `transmit_partial`.
+    #[inline]
+    pub fn drain_into(&mut self, dest: &mut [T]) -> usize {
+        let mut n = 0;
+        for (slot, value) in dest.iter_mut().zip(self.drain()) {
+            *slot = value;
+            n += 1;
+        }
+        n
+    }
+}
+
+impl<T: Copy + fmt::Debug, const N: usize> fmt::Debug for RingBuf<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// Formats a `RingBuf`'s items, oldest first.
+        struct Items<'a, T: Copy, const N: usize>(&'a RingBuf<T, N>);
+
+        impl<'a, T: Copy + fmt::Debug, const N: usize> fmt::Debug for Items<'a, T, N> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_list().entries((0..self.0.len).map(|i| self.0.get(i))).finish()
+            }
+        }
+
+        f.debug_struct("RingBuf")
+            .field("items", &Items(self))
+            .field("policy", &self.policy)
+            .field("lost", &self.lost)
+            .finish()
+    }
+}
+
+/// A draining iterator over a [`RingBuf`], created by [`RingBuf::drain`].
+#[derive(Debug)]
+pub struct Drain<'a, T: Copy, const N: usize> {
+    ring: &'a mut RingBuf<T, N>,
+}
+
+impl<'a, T: Copy, const N: usize> Iterator for Drain<'a, T, N> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.ring.pop()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.ring.len, Some(self.ring.len))
+    }
+}
+
+impl<'a, T: Copy, const N: usize> ExactSizeIterator for Drain<'a, T, N> {}
+
+impl<'a, T: Copy, const N: usize> FusedIterator for Drain<'a, T, N> {}
+
+/// A number stored in big-endian byte order, whatever the CPU's byte order,
+/// for message payloads and tables whose layout is defined as big-endian:
+///
+/// ```rust
+/// use n2o4::utils::BigEndian;
+///
+/// #[derive(Clone, Copy, Default)]
+/// #[repr(C)]
+/// struct Payload {
+///     mode:    BigEndian<u16>,
+///     spare:   u16,
+///     counter: BigEndian<u32>,
+/// }
+///
+/// let mut p = Payload::default();
+/// p.counter.set(0x0102_0304);
+/// assert_eq!(p.counter.get(), 0x0102_0304);
+/// assert_eq!(p.counter.to_bytes(), [1, 2, 3, 4]);
+/// ```
+///
+/// A `BigEndian<T>` has the same size and alignment as `T`,
+/// so it can replace a `T` field without changing the struct's layout.
+///
+/// The value is stored as an unsigned integer of the same size as `T`
+/// (so that, for floating-point types, byte-swapped bits are never handled as floats),
+/// and two `BigEndian`s are equal if their bits are;
+/// e.g., `BigEndian::new(f32::NAN) == BigEndian::new(f32::NAN)`,
+/// but `BigEndian::new(0.0) != BigEndian::new(-0.0)`.
+#[derive(Clone, Copy, Default)]
+#[repr(transparent)]
+pub struct BigEndian<T: Endian> {
+    /// The value's bits, in big-endian byte order.
+    raw: T::Bits,
+}
+
+impl<T: Endian> BigEndian<T> {
+    /// Returns a `BigEndian` holding `value`.
+    #[inline]
+    pub fn new(value: T) -> Self {
+        BigEndian { raw: value.to_be_bits() }
+    }
+
+    /// Returns the value (in the CPU's byte order).
+    #[inline]
+    pub fn get(self) -> T {
+        T::from_be_bits(self.raw)
+    }
+
+    /// Sets the value to `value`.
+    #[inline]
+    pub fn set(&mut self, value: T) {
+        self.raw = value.to_be_bits();
+    }
+
+    /// Returns the value as stored: `T`'s bytes in big-endian order,
+    /// reinterpreted as a `T`.
+    ///
+    /// For floating-point types, the result may well be a NaN;
+    /// [`to_bytes`](Self::to_bytes) is usually a better choice.
+    #[inline]
+    pub fn to_raw(self) -> T {
+        T::from_bits(self.raw)
+    }
+
+    /// Returns a `BigEndian` whose stored value is `raw`,
+    /// which should already be in big-endian order
+    /// (e.g., copied out of a received message).
+    #[inline]
+    pub fn from_raw(raw: T) -> Self {
+        BigEndian { raw: raw.to_bits() }
+    }
+
+    /// Returns the value as big-endian bytes.
+    #[inline]
+    pub fn to_bytes(self) -> T::Bytes {
+        self.get().to_be_bytes()
+    }
+}
+
+impl<T: Endian> From<T> for BigEndian<T> {
+    #[inline]
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Endian> PartialEq for BigEndian<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl<T: Endian> Eq for BigEndian<T> {}
+
+impl<T: Endian> core::hash::Hash for BigEndian<T> {
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl<T: Endian + fmt::Debug> fmt::Debug for BigEndian<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("BigEndian").field(&self.get()).finish()
+    }
+}
+
+impl<T: Endian + fmt::Display> fmt::Display for BigEndian<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.get(), f)
+    }
+}
+
+/// The numeric types that can be stored in a [`BigEndian`].
+///
+/// This is a [sealed trait](https://rust-lang.github.io/api-guidelines/future-proofing.html#c-sealed):
+/// it's implemented for the primitive integer and floating-point types
+/// of 2 to 8 bytes.
+pub trait Endian: crate::sealed_traits::EndianSealed + Copy {}
+
+macro_rules! endian_impl {
+    ($($t:ty => $bits:ty),* $(,)?) => {
+        $(
+            impl crate::sealed_traits::EndianSealed for $t {
+                type Bytes = [u8; core::mem::size_of::<$t>()];
+                type Bits = $bits;
+
+                #[inline]
+                fn to_be_bits(self) -> $bits {
+                    <$bits>::from_ne_bytes(self.to_be_bytes())
+                }
+
+                #[inline]
+                fn from_be_bits(bits: $bits) -> Self {
+                    <$t>::from_be_bytes(bits.to_ne_bytes())
+                }
+
+                #[inline]
+                fn to_bits(self) -> $bits {
+                    <$bits>::from_ne_bytes(self.to_ne_bytes())
+                }
+
+                #[inline]
+                fn from_bits(bits: $bits) -> Self {
+                    <$t>::from_ne_bytes(bits.to_ne_bytes())
+                }
+
+                #[inline]
+                fn to_be_bytes(self) -> Self::Bytes {
+                    <$t>::to_be_bytes(self)
+                }
+            }
+
+            impl Endian for $t {}
+        )*
+    };
+}
+
+endian_impl!(
+    u16 => u16,
+    i16 => u16,
+    u32 => u32,
+    i32 => u32,
+    u64 => u64,
+    i64 => u64,
+    f32 => u32,
+    f64 => u64,
+);
+
+/// Implements the common operations of a bitfield type:
+/// `$t` must be a `Copy` struct whose one field, `$field` (of integer type `$bits`),
+/// holds the flags.
+///
+/// This gives `$t`
+/// the inherent methods `from_bits`, `bits`, `is_empty`, `contains`, `intersects`,
+/// `insert`, `remove`, `set`, and `iter`,
+/// and the `|`, `|=`, `&`, and `&=` operators.
+macro_rules! bitflags_impl {
+    ($t:ident, $field:ident: $bits:ty) => {
+        impl $t {
+            /// Creates a value from raw bits.
+            ///
+            /// Bits without a named constant are kept as they are.
+            #[inline]
+            pub const fn from_bits(bits: $bits) -> Self {
+                Self { $field: bits }
+            }
+
+            /// Returns the raw bits.
+            #[inline]
+            pub const fn bits(&self) -> $bits {
+                self.$field
+            }
+
+            /// Returns whether no bits are set in `self`.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.$field == 0
+            }
+
+            /// Returns whether all the bits set in `other` are also set in `self`.
+            #[inline]
+            pub const fn contains(&self, other: Self) -> bool {
+                self.$field & other.$field == other.$field
+            }
+
+            /// Returns whether any of the bits set in `other` are also set in `self`.
+            #[inline]
+            pub const fn intersects(&self, other: Self) -> bool {
+                self.$field & other.$field != 0
+            }
+
+            /// Sets the bits set in `other`.
+            #[inline]
+            pub fn insert(&mut self, other: Self) {
+                self.$field |= other.$field;
+            }
+
+            /// Clears the bits set in `other`.
+            #[inline]
+            pub fn remove(&mut self, other: Self) {
+                self.$field &= !other.$field;
+            }
+
+            /// Sets (if `value` is `true`) or clears (otherwise) the bits set in `other`.
+            #[inline]
+            pub fn set(&mut self, other: Self, value: bool) {
+                if value {
+                    self.insert(other);
+                } else {
+                    self.remove(other);
+                }
+            }
+
+            /// Returns an iterator over the bits set in `self`,
+            /// each as a value with just that bit set, from least to most significant.
+            #[inline]
+            pub fn iter(&self) -> impl Iterator<Item = Self> {
+                let bits = self.$field;
+                (0..<$bits>::BITS)
+                    .map(|i| (1 as $bits) << i)
+                    .filter(move |bit| bits & bit != 0)
+                    .map(Self::from_bits)
+            }
+        }
+
+        impl core::ops::BitOr for $t {
+            type Output = Self;
+
+            #[inline]
+            fn bitor(self, rhs: Self) -> Self {
+                Self::from_bits(self.$field | rhs.$field)
+            }
+        }
+
+        impl core::ops::BitOrAssign for $t {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: Self) {
+                self.insert(rhs);
+            }
+        }
+
+        impl core::ops::BitAnd for $t {
+            type Output = Self;
+
+            #[inline]
+            fn bitand(self, rhs: Self) -> Self {
+                Self::from_bits(self.$field & rhs.$field)
+            }
+        }
+
+        impl core::ops::BitAndAssign for $t {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: Self) {
+                self.$field &= rhs.$field;
+            }
+        }
+    };
+}
+
+pub(crate) use bitflags_impl;
+
+/// Asserts at compile time that type `$t` is exactly `$size` bytes in size.
+///
+/// This is intended for enforcing externally mandated sizes
+/// (e.g., of message payloads or table types);
+/// it may be used anywhere an item may appear.
+///
+/// ```rust
+/// use n2o4::const_assert_size;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct HkPayload {
+///     cmd_count: u16,
+///     err_count: u16,
+///     uptime:    u32,
+/// }
+///
+/// const_assert_size!(HkPayload, 8);
+/// ```
+#[macro_export]
+macro_rules! const_assert_size {
+    ($t:ty, $size:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<$t>() == $size,
+            concat!("size of `", stringify!($t), "` is not ", stringify!($size), " bytes"),
+        );
+    };
+}
+
+/// Asserts at compile time that type `$t` has an alignment of exactly `$align` bytes.
+///
+/// ```rust
+/// use n2o4::const_assert_align;
+///
+/// const_assert_align!(u32, 4);
+/// ```
+#[macro_export]
+macro_rules! const_assert_align {
+    ($t:ty, $align:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            ::core::mem::align_of::<$t>() == $align,
+            concat!("alignment of `", stringify!($t), "` is not ", stringify!($align), " bytes"),
+        );
+    };
+}
+
+/// A type whose values have no padding or other uninitialized bytes,
+/// so that they may be viewed as plain `u8`s.
+///
+/// This is what allows, e.g., [`Command::as_bytes`](crate::cfe::msg::Command::as_bytes)
+/// to be safe.
+///
+/// # Safety
+///
+/// Every byte of every value of the type must be initialized.
+/// For a struct, this generally means it's `#[repr(C)]` (or `#[repr(transparent)]`),
+/// all its fields are `NoPadding`,
+/// and the fields are laid out without gaps between them or after the last one.
+pub unsafe trait NoPadding: Copy {}
+
+/// A type for which any sequence of bytes (of the right length) is a valid value,
+/// so that values may be overwritten with arbitrary bytes.
+///
+/// This is what allows, e.g.,
+/// [`TblHandle::load_from_slice`](crate::cfe::tbl::TblHandle::load_from_slice) to be safe
+/// (and, along with [`NoPadding`],
+/// [`Command::as_bytes_mut`](crate::cfe::msg::Command::as_bytes_mut)).
+///
+/// # Safety
+///
+/// Any initialized bytes must make a valid value of the type.
+/// For a struct, this generally means it's `#[repr(C)]` (or `#[repr(transparent)]`)
+/// and all its fields are `AnyBitPattern`;
+/// `bool`s, `char`s, `enum`s, references and the like rule a type out.
+pub unsafe trait AnyBitPattern: Copy {}
+
+mod plain_bytes_impls {
+    use super::{AnyBitPattern, NoPadding};
+
+    macro_rules! plain {
+        ($($t:ty),+) => {
+            $(
+                unsafe impl NoPadding for $t {}
+                unsafe impl AnyBitPattern for $t {}
+            )+
+        };
+    }
+
+    plain!(u8, u16, u32, u64, u128, usize);
+    plain!(i8, i16, i32, i64, i128, isize);
+    plain!(f32, f64);
+
+    unsafe impl NoPadding for bool {}
+    unsafe impl NoPadding for char {}
+
+    unsafe impl<T: NoPadding, const N: usize> NoPadding for [T; N] {}
+    unsafe impl<T: AnyBitPattern, const N: usize> AnyBitPattern for [T; N] {}
+}
+
 /// A way to get the `Atomic*` type associated with a given integer type.
 pub(crate) trait AtomicVersion {
     /// The atomic type of the same size and signedness as `Self`.
@@ -0,0 +1,210 @@
+// Copyright (c) 2023 The Pennsylvania State University and the project contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Property-based tests of some of the crate's basic types:
+//! [`CStrBuf`]'s truncation and null termination,
+//! `OSTime`/`OSTimeInterval` arithmetic (checked against a wide-integer model),
+//! and [`Status`]'s bit packing.
+//!
+//! The time arithmetic goes through the C shims,
+//! which only link outside a cFS build with the `mock` backend,
+//! so those tests only run with `cargo test --features mock`.
+
+use core::ffi::c_char;
+use n2o4::cfe::{CFE_Status, Status, StatusServiceId, StatusSeverity};
+use n2o4::utils::CStrBuf;
+use proptest::prelude::*;
+use std::ffi::CString;
+
+/// Small enough to exercise truncation often.
+const SIZE: usize = 16;
+
+/// The bytes `CStrBuf` should hold for `src`:
+/// everything up to the first null byte, and no more than `SIZE - 1` bytes.
+fn expected_prefix(src: &[u8]) -> &[u8] {
+    let src = match src.iter().position(|&b| b == b'\0') {
+        Some(nul) => &src[..nul],
+        None => src,
+    };
+    &src[..src.len().min(SIZE - 1)]
+}
+
+proptest! {
+    #[test]
+    fn cstrbuf_new_u8_truncates_and_terminates(
+        src in prop::collection::vec(any::<u8>(), 0..3 * SIZE),
+    ) {
+        let buf = CStrBuf::<SIZE>::new_u8(&src);
+
+        prop_assert_eq!(buf.as_array()[SIZE - 1], 0);
+        prop_assert!(buf.len() < SIZE);
+        prop_assert_eq!(buf.to_bytes(), expected_prefix(&src));
+    }
+
+    #[test]
+    fn cstrbuf_from_cstr_truncates_and_terminates(
+        src in prop::collection::vec(1..=u8::MAX, 0..3 * SIZE),
+    ) {
+        let src = CString::new(src).unwrap();
+        let buf = CStrBuf::<SIZE>::from_cstr(&src);
+
+        prop_assert_eq!(buf.as_array()[SIZE - 1], 0);
+        prop_assert_eq!(buf.to_bytes(), expected_prefix(src.as_bytes()));
+    }
+
+    // (`uniform16`, since `SIZE` is 16.)
+    #[test]
+    fn cstrbuf_new_into_terminates(src in prop::array::uniform16(any::<c_char>())) {
+        let buf = CStrBuf::<SIZE>::new_into(src);
+
+        prop_assert_eq!(buf.as_array()[SIZE - 1], 0);
+        prop_assert!(buf.len() < SIZE);
+    }
+
+    #[test]
+    fn cstrbuf_from_str_lossy_keeps_longest_whole_prefix(src in any::<String>()) {
+        let buf = CStrBuf::<SIZE>::from_str_lossy(&src);
+        let s = src.split('\0').next().unwrap();
+
+        prop_assert_eq!(buf.as_array()[SIZE - 1], 0);
+        prop_assert!(buf.to_str().is_ok());
+        prop_assert!(s.starts_with(buf.as_str()));
+
+        // Nothing more would have fit.
+        if let Some(next) = s[buf.len()..].chars().next() {
+            prop_assert!(buf.len() + next.len_utf8() > SIZE - 1);
+        }
+    }
+
+    #[test]
+    fn cstrbuf_of_size_one_is_empty(src in prop::collection::vec(any::<u8>(), 0..SIZE)) {
+        prop_assert!(CStrBuf::<1>::new_u8(&src).is_empty());
+    }
+}
+
+#[cfg(feature = "mock")]
+mod time_arith {
+    use n2o4::osal::{OSTime, OSTimeInterval};
+    use proptest::prelude::*;
+
+    /// Keeps sums and differences of two times within what
+    /// [`total_nanoseconds`](OSTime::total_nanoseconds) can represent (about 292 years).
+    const MAX_SECONDS: i64 = 1 << 32;
+
+    prop_compose! {
+        fn time()(
+            seconds in -MAX_SECONDS..MAX_SECONDS,
+            nanoseconds in 0..1_000_000_000u32,
+        ) -> OSTime {
+            OSTime::from_nanoseconds(seconds, nanoseconds)
+        }
+    }
+
+    prop_compose! {
+        fn interval()(
+            seconds in -MAX_SECONDS..MAX_SECONDS,
+            nanoseconds in 0..1_000_000_000u32,
+        ) -> OSTimeInterval {
+            OSTimeInterval::from_nanoseconds(seconds, nanoseconds)
+        }
+    }
+
+    /// The wide-integer model of a time.
+    fn ns_of_time(t: OSTime) -> i128 {
+        i128::from(t.total_nanoseconds())
+    }
+
+    /// The wide-integer model of an interval.
+    fn ns_of_interval(i: OSTimeInterval) -> i128 {
+        i128::from(i.total_nanoseconds())
+    }
+
+    proptest! {
+        #[test]
+        fn time_add_matches_model(t in time(), i in interval(), j in interval()) {
+            prop_assert_eq!(ns_of_time(t + i), ns_of_time(t) + ns_of_interval(i));
+            prop_assert_eq!(ns_of_interval(i + j), ns_of_interval(i) + ns_of_interval(j));
+        }
+
+        #[test]
+        fn time_sub_matches_model(t in time(), u in time(), i in interval(), j in interval()) {
+            prop_assert_eq!(ns_of_interval(t - u), ns_of_time(t) - ns_of_time(u));
+            prop_assert_eq!(ns_of_time(t - i), ns_of_time(t) - ns_of_interval(i));
+            prop_assert_eq!(ns_of_interval(i - j), ns_of_interval(i) - ns_of_interval(j));
+        }
+
+        #[test]
+        fn time_add_commutes(t in time(), i in interval(), j in interval()) {
+            prop_assert_eq!(t + i, i + t);
+            prop_assert_eq!(i + j, j + i);
+        }
+
+        #[test]
+        fn time_zero_is_identity(t in time(), i in interval()) {
+            let zero = OSTimeInterval::from_nanoseconds(0, 0);
+
+            prop_assert_eq!(t + zero, t);
+            prop_assert_eq!(t - zero, t);
+            prop_assert_eq!(i + zero, i);
+            prop_assert_eq!(t - t, zero);
+        }
+
+        #[test]
+        fn time_sub_undoes_add(t in time(), u in time(), i in interval()) {
+            prop_assert_eq!((t + i) - i, t);
+            prop_assert_eq!(u + (t - u), t);
+        }
+
+        #[test]
+        fn time_order_matches_model(t in time(), u in time()) {
+            prop_assert_eq!(t.cmp(&u), ns_of_time(t).cmp(&ns_of_time(u)));
+        }
+    }
+}
+
+fn severity() -> impl Strategy<Value = StatusSeverity> {
+    use StatusSeverity::*;
+    prop::sample::select(vec![Success, Informational, Error])
+}
+
+fn service() -> impl Strategy<Value = StatusServiceId> {
+    use StatusServiceId::*;
+    prop::sample::select(vec![NotCfe, EVS, ES, FS, Generic, SB, TBL, TIME])
+}
+
+proptest! {
+    #[test]
+    fn status_new_round_trips(
+        sev in severity(),
+        svc in service(),
+        mission in any::<u16>(),
+        code in any::<u16>(),
+    ) {
+        let status = Status::new(sev, svc, mission, code);
+
+        prop_assert_eq!(status.severity(), sev);
+        prop_assert_eq!(status.service(), svc);
+        prop_assert_eq!(status.mission_defined(), mission & 0x01ff);
+        prop_assert_eq!(status.code(), code);
+    }
+
+    #[test]
+    fn status_raw_round_trips(raw in any::<u32>()) {
+        // The reserved severity 0b10 is read back as `Error` (0b11), so can't round-trip.
+        prop_assume!(raw >> 30 != 0b10);
+
+        // Bits 28 and 29 are reserved, and `Status::new` leaves them clear.
+        let raw = raw & !(0b11 << 28);
+
+        let status = Status::from(raw as CFE_Status);
+        let rebuilt = Status::new(
+            status.severity(),
+            status.service(),
+            status.mission_defined(),
+            status.code(),
+        );
+
+        prop_assert_eq!(rebuilt, status);
+        prop_assert_eq!(rebuilt.as_num(), raw);
+    }
+}